@@ -0,0 +1,109 @@
+//! Parse throughput benchmark
+//!
+//! Runs a synthetic stream of server packets through the same offline path as
+//! `cli bench parse` and prints its report, so decoder regressions show up
+//! with `cargo bench -p gromnie-cli`. Set `GROMNIE_BENCH_CAPTURE` to a pcap
+//! file or hex log to benchmark a real capture instead.
+
+use gromnie_cli::bench::{read_capture, run_parse_bench};
+
+/// Times the synthetic messages are repeated
+const ROUNDS: u32 = 20_000;
+/// Server port captures are filtered on
+const SERVER_PORT: u16 = 9000;
+
+const PACKET_HEADER_SIZE: usize = 20;
+const FRAGMENT_HEADER_SIZE: usize = 16;
+/// PacketHeaderFlags::BLOB_FRAGMENTS
+const BLOB_FRAGMENTS: u32 = 0x0000_0004;
+
+const OPCODE_DDD_INTERROGATION: u32 = 0xF7E5;
+const OPCODE_LOGIN_CHARACTER_SET: u32 = 0xF658;
+
+/// Wrap a complete message in a single-fragment BLOB_FRAGMENTS packet
+fn fragment_packet(sequence: u32, message: &[u8]) -> Vec<u8> {
+    let fragment_size = FRAGMENT_HEADER_SIZE + message.len();
+    let mut packet = Vec::with_capacity(PACKET_HEADER_SIZE + fragment_size);
+    packet.extend_from_slice(&sequence.to_le_bytes());
+    packet.extend_from_slice(&BLOB_FRAGMENTS.to_le_bytes());
+    packet.extend_from_slice(&0u32.to_le_bytes()); // checksum
+    packet.extend_from_slice(&0u16.to_le_bytes()); // recipient_id
+    packet.extend_from_slice(&0u16.to_le_bytes()); // time_since_last_packet
+    packet.extend_from_slice(&(fragment_size as u16).to_le_bytes());
+    packet.extend_from_slice(&0u16.to_le_bytes()); // iteration
+
+    packet.extend_from_slice(&sequence.to_le_bytes()); // fragment sequence
+    packet.extend_from_slice(&0x8000_0000u32.to_le_bytes()); // fragment id
+    packet.extend_from_slice(&1u16.to_le_bytes()); // count
+    packet.extend_from_slice(&(fragment_size as u16).to_le_bytes());
+    packet.extend_from_slice(&0u16.to_le_bytes()); // index
+    packet.extend_from_slice(&5u16.to_le_bytes()); // group
+    packet.extend_from_slice(message);
+    packet
+}
+
+/// AC `string`: u16 length, bytes, then padding to a 4-byte boundary
+fn write_string16l(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u16).to_le_bytes());
+    buf.extend_from_slice(s.as_bytes());
+    let written = 2 + s.len();
+    buf.resize(buf.len() + (4 - written % 4) % 4, 0);
+}
+
+fn ddd_interrogation() -> Vec<u8> {
+    let mut msg = Vec::new();
+    msg.extend_from_slice(&OPCODE_DDD_INTERROGATION.to_le_bytes());
+    msg.extend_from_slice(&1u32.to_le_bytes()); // servers_region
+    msg.extend_from_slice(&1u32.to_le_bytes()); // name_rule_language
+    msg.extend_from_slice(&1u32.to_le_bytes()); // product_id
+    msg.extend_from_slice(&1u32.to_le_bytes()); // supported_languages count
+    msg.extend_from_slice(&1u32.to_le_bytes()); // supported_languages[0]
+    msg
+}
+
+fn character_set() -> Vec<u8> {
+    let mut msg = Vec::new();
+    msg.extend_from_slice(&OPCODE_LOGIN_CHARACTER_SET.to_le_bytes());
+    msg.extend_from_slice(&0u32.to_le_bytes()); // status
+    msg.extend_from_slice(&3u32.to_le_bytes());
+    for (id, name) in [
+        (0x5000_0001u32, "Bench One"),
+        (0x5000_0002, "Bench Two"),
+        (0x5000_0003, "Bench Three"),
+    ] {
+        msg.extend_from_slice(&id.to_le_bytes());
+        write_string16l(&mut msg, name);
+        msg.extend_from_slice(&0u32.to_le_bytes()); // seconds_greyed_out
+    }
+    msg.extend_from_slice(&0u32.to_le_bytes()); // deleted_characters count
+    msg.extend_from_slice(&11u32.to_le_bytes()); // num_allowed_characters
+    write_string16l(&mut msg, "bench");
+    msg.extend_from_slice(&1u32.to_le_bytes()); // use_turbine_chat
+    msg.extend_from_slice(&1u32.to_le_bytes()); // has_throneof_destiny
+    msg
+}
+
+fn synthetic_packets() -> Vec<Vec<u8>> {
+    let messages = [ddd_interrogation(), character_set()];
+    (0..ROUNDS)
+        .flat_map(|_| messages.iter())
+        .enumerate()
+        .map(|(i, message)| fragment_packet(i as u32 + 1, message))
+        .collect()
+}
+
+#[tokio::main]
+async fn main() {
+    let packets = match std::env::var_os("GROMNIE_BENCH_CAPTURE") {
+        Some(path) => match read_capture(path.as_ref(), SERVER_PORT) {
+            Ok(packets) => packets,
+            Err(e) => {
+                eprintln!("Failed to read capture: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => synthetic_packets(),
+    };
+
+    print!("{}", run_parse_bench(&packets).await);
+}
@@ -0,0 +1,303 @@
+//! Offline parse benchmark
+//!
+//! Replays captured server packets through the client's packet, fragment and
+//! message handling path without a network connection, and reports throughput
+//! and per-message-type timing. Parse failures are counted from the errors the
+//! handlers log while processing each message.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use gromnie_client::client::Client;
+use gromnie_client::transport::NullTransport;
+use tokio::sync::mpsc;
+use tracing::Level;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+/// Size of the transport packet header; anything shorter can't be a packet
+const PACKET_HEADER_SIZE: usize = 20;
+/// PacketHeaderFlags::CONNECT_REQUEST; replaying it would start a handshake
+const CONNECT_REQUEST_FLAG: u32 = 0x0004_0000;
+
+/// Timing for one message type
+#[derive(Debug, Clone, Default)]
+pub struct MessageStats {
+    pub name: String,
+    pub count: u64,
+    pub total: Duration,
+    pub errors: u64,
+}
+
+/// Result of a parse benchmark run
+#[derive(Debug, Default)]
+pub struct BenchReport {
+    /// Packets fed to the client
+    pub packets: u64,
+    /// Packets skipped because they were too short or were handshake packets
+    pub skipped: u64,
+    /// Messages handled after fragment reassembly
+    pub messages: u64,
+    /// Errors logged while handling packets and messages
+    pub parse_errors: u64,
+    /// Total time spent in the client
+    pub elapsed: Duration,
+    /// Per message type, slowest total first
+    pub by_type: Vec<MessageStats>,
+}
+
+impl BenchReport {
+    pub fn messages_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.messages as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} packets ({} skipped), {} messages in {:.3?} ({:.0} msg/s), {} parse errors",
+            self.packets,
+            self.skipped,
+            self.messages,
+            self.elapsed,
+            self.messages_per_sec(),
+            self.parse_errors
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<40} {:>8} {:>12} {:>10} {:>7}",
+            "Message", "Count", "Total", "Avg", "Errors"
+        )?;
+        for stats in &self.by_type {
+            let avg = stats.total / stats.count.max(1) as u32;
+            writeln!(
+                f,
+                "{:<40} {:>8} {:>12.3?} {:>10.1?} {:>7}",
+                stats.name, stats.count, stats.total, avg, stats.errors
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Read server-to-client packets from a capture file
+///
+/// Accepts a classic pcap file (Ethernet, raw IP, loopback or Linux cooked
+/// captures), keeping UDP datagrams sent from `server_port` or `server_port + 1`,
+/// or a text log with one packet per line as hex (blank lines and `#` comments
+/// are ignored).
+pub fn read_capture(path: &Path, server_port: u16) -> Result<Vec<Vec<u8>>, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    if let Some(packets) = read_pcap(&bytes, server_port)? {
+        return Ok(packets);
+    }
+
+    let text = String::from_utf8(bytes)
+        .map_err(|_| format!("{}: not a pcap file or hex log", path.display()))?;
+    read_hex_log(&text)
+}
+
+/// Parse a pcap file. Returns None if the data doesn't start with a pcap header.
+fn read_pcap(bytes: &[u8], server_port: u16) -> Result<Option<Vec<Vec<u8>>>, String> {
+    let Some(magic) = bytes.get(0..4) else {
+        return Ok(None);
+    };
+    let big_endian = match magic {
+        [0xD4, 0xC3, 0xB2, 0xA1] | [0x4D, 0x3C, 0xB2, 0xA1] => false,
+        [0xA1, 0xB2, 0xC3, 0xD4] | [0xA1, 0xB2, 0x3C, 0x4D] => true,
+        _ => return Ok(None),
+    };
+    let read_u32 = |at: usize| -> Option<u32> {
+        let b: [u8; 4] = bytes.get(at..at + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        })
+    };
+
+    let link_type = read_u32(20).ok_or("truncated pcap header")?;
+    let mut packets = Vec::new();
+    let mut offset = 24;
+    while offset < bytes.len() {
+        let captured_len = read_u32(offset + 8).ok_or("truncated pcap record header")? as usize;
+        let start = offset + 16;
+        let frame = bytes
+            .get(start..start + captured_len)
+            .ok_or("truncated pcap record")?;
+        offset = start + captured_len;
+
+        if let Some((src_port, payload)) = udp_payload(link_type, frame)
+            && (src_port == server_port || src_port == server_port.wrapping_add(1))
+        {
+            packets.push(payload.to_vec());
+        }
+    }
+
+    Ok(Some(packets))
+}
+
+/// Extract (source port, payload) from a captured UDP frame
+fn udp_payload(link_type: u32, frame: &[u8]) -> Option<(u16, &[u8])> {
+    let ip = match link_type {
+        // DLT_NULL: 4-byte address family
+        0 => frame.get(4..)?,
+        // DLT_EN10MB: Ethernet, optionally with one VLAN tag
+        1 => {
+            let ether_type = u16::from_be_bytes([*frame.get(12)?, *frame.get(13)?]);
+            if ether_type == 0x8100 {
+                frame.get(18..)?
+            } else {
+                frame.get(14..)?
+            }
+        }
+        // DLT_RAW
+        101 => frame,
+        // DLT_LINUX_SLL
+        113 => frame.get(16..)?,
+        // DLT_LINUX_SLL2
+        276 => frame.get(20..)?,
+        _ => return None,
+    };
+
+    let udp = match ip.first()? >> 4 {
+        4 => {
+            let header_len = ((ip[0] & 0x0F) as usize) * 4;
+            if *ip.get(9)? != 17 {
+                return None;
+            }
+            ip.get(header_len..)?
+        }
+        6 => {
+            if *ip.get(6)? != 17 {
+                return None;
+            }
+            ip.get(40..)?
+        }
+        _ => return None,
+    };
+
+    let src_port = u16::from_be_bytes([*udp.first()?, *udp.get(1)?]);
+    let udp_len = u16::from_be_bytes([*udp.get(4)?, *udp.get(5)?]) as usize;
+    let payload = udp.get(8..udp_len.max(8))?;
+    Some((src_port, payload))
+}
+
+fn read_hex_log(text: &str) -> Result<Vec<Vec<u8>>, String> {
+    let mut packets = Vec::new();
+    for (line_no, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let digits: Vec<u8> = line.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+        if !digits.len().is_multiple_of(2) {
+            return Err(format!("line {}: odd number of hex digits", line_no + 1));
+        }
+        let packet = digits
+            .chunks(2)
+            .map(|pair| {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|s| u8::from_str_radix(s, 16).ok())
+                    .ok_or_else(|| format!("line {}: invalid hex", line_no + 1))
+            })
+            .collect::<Result<Vec<u8>, String>>()?;
+        packets.push(packet);
+    }
+    Ok(packets)
+}
+
+/// Tracing layer that counts error events
+struct ErrorCounter(Arc<AtomicU64>);
+
+impl<S: tracing::Subscriber> Layer<S> for ErrorCounter {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() == Level::ERROR {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Run every packet through a fresh offline client and time message handling
+pub async fn run_parse_bench(packets: &[Vec<u8>]) -> BenchReport {
+    let errors = Arc::new(AtomicU64::new(0));
+    // Only errors reach the subscriber, so the run measures decoding rather
+    // than log formatting
+    let subscriber = tracing_subscriber::registry()
+        .with(ErrorCounter(errors.clone()))
+        .with(tracing_subscriber::filter::LevelFilter::ERROR);
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    // Events are dropped; the benchmark only measures the client side
+    let (event_tx, _) = mpsc::channel(1);
+    let (mut client, _action_tx) = Client::new_with_transport(
+        0,
+        "127.0.0.1:9000".to_string(),
+        "bench".to_string(),
+        String::new(),
+        None,
+        event_tx,
+        false,
+        Box::new(NullTransport),
+    )
+    .await;
+    let peer: SocketAddr = ([127, 0, 0, 1], 9000).into();
+
+    let mut report = BenchReport::default();
+    let mut by_type: HashMap<String, MessageStats> = HashMap::new();
+    let started = Instant::now();
+
+    for packet in packets {
+        let flags = packet
+            .get(4..8)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .unwrap_or_default();
+        if packet.len() < PACKET_HEADER_SIZE || flags & CONNECT_REQUEST_FLAG != 0 {
+            report.skipped += 1;
+            continue;
+        }
+        report.packets += 1;
+
+        client.process_packet(packet, packet.len(), &peer).await;
+        // Errors from packet/fragment parsing aren't tied to a message
+        report.parse_errors = errors.load(Ordering::Relaxed);
+
+        client.process_messages_timed(|name, elapsed| {
+            let after = errors.load(Ordering::Relaxed);
+            let stats = by_type
+                .entry(name.to_string())
+                .or_insert_with(|| MessageStats {
+                    name: name.to_string(),
+                    ..Default::default()
+                });
+            stats.count += 1;
+            stats.total += elapsed;
+            // Errors are logged synchronously inside the handler, so anything
+            // new since the last message belongs to this one
+            stats.errors += after - report.parse_errors;
+            report.parse_errors = after;
+            report.messages += 1;
+        });
+    }
+
+    report.elapsed = started.elapsed();
+    report.by_type = by_type.into_values().collect();
+    report
+        .by_type
+        .sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(&b.name)));
+    report
+}
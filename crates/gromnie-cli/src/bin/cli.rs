@@ -1,13 +1,20 @@
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fs;
+use std::path::{Path, PathBuf};
 
-use clap::Parser;
-use gromnie_runner::{ClientConfig, ClientRunner, LoggingConsumer, logging};
+use clap::{Parser, Subcommand};
+use gromnie_runner::{
+    BuilderRunResult, ClientConfig, ClientRunner, LoggingConsumer, ReplayConfig, logging,
+    setup_shutdown_signal_handler,
+};
 use ratatui::{TerminalOptions, Viewport};
-use tracing::info;
+use tokio::sync::watch;
+use tracing::{info, warn};
 
 use gromnie_cli::App;
-use gromnie_client::config::{ConfigLoadError, GromnieConfig};
+use gromnie_client::client::MessageTrace;
+use gromnie_client::config::{ConfigLoadError, GromnieConfig, ProjectPaths};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -35,6 +42,97 @@ pub struct Cli {
     /// Disable automatic reconnection (overrides config file)
     #[arg(long)]
     no_reconnect: bool,
+
+    /// Run without any terminal UI until Ctrl+C, e.g. in a container. Uses
+    /// --server and --account, or the only ones in the config if unambiguous
+    #[arg(long)]
+    headless: bool,
+
+    /// Write every sent and received message to this file as newline-delimited JSON
+    #[arg(long, value_name = "PATH")]
+    trace: Option<PathBuf>,
+
+    /// Replay a file written by --trace instead of connecting, feeding the
+    /// received messages to logging and scripts; actions are logged only
+    #[arg(long, value_name = "PATH", conflicts_with = "trace")]
+    replay: Option<PathBuf>,
+
+    /// Playback rate for --replay (2 replays twice as fast as recorded)
+    #[arg(long, default_value_t = 1.0, requires = "replay")]
+    replay_speed: f64,
+
+    /// Start --replay over from the beginning when it reaches the end
+    #[arg(long, requires = "replay")]
+    replay_loop: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Performance benchmarks
+    Bench {
+        #[command(subcommand)]
+        target: BenchTarget,
+    },
+    /// Inspect the config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Validate the config without connecting, listing every problem found
+    Check {
+        /// Config file to check (default: the one gromnie loads)
+        path: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum BenchTarget {
+    /// Replay a packet capture through the client's parse path and report timing
+    Parse {
+        /// pcap file, or a text file with one hex-encoded packet per line
+        capture: PathBuf,
+
+        /// Server login port; UDP packets from this port and the next are replayed
+        #[arg(long, default_value_t = 9000)]
+        port: u16,
+    },
+}
+
+/// Validate a config file and print what's wrong with it
+fn check_config(path: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let path = path.map_or_else(GromnieConfig::config_path, Path::to_path_buf);
+    let content = GromnieConfig::read(&path).map_err(|e| match e {
+        ConfigLoadError::NotFound => format!("No config found at {}", path.display()),
+        e => e.to_string(),
+    })?;
+    let report = GromnieConfig::check(&content)?;
+
+    for warning in &report.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    for error in &report.errors {
+        eprintln!("error: {}", error);
+    }
+    if !report.is_valid() {
+        return Err(format!(
+            "{} has {} invalid setting(s)",
+            path.display(),
+            report.errors.len()
+        )
+        .into());
+    }
+
+    // Anything the checks don't cover still has to survive loading
+    GromnieConfig::parse(&content)?;
+    eprintln!("{} is valid", path.display());
+    Ok(())
 }
 
 fn create_example_config() -> Result<(), Box<dyn Error>> {
@@ -45,10 +143,10 @@ fn create_example_config() -> Result<(), Box<dyn Error>> {
         return Err("Config file already exists at {}. Please edit it manually or delete it to create a new one.".into());
     }
 
-    // Create parent directories if they don't exist
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
+    // Create the config directory, failing with an actionable message if it's read-only
+    ProjectPaths::new("gromnie")
+        .ok_or("Failed to determine config directory")?
+        .ensure_config_dir_writable()?;
 
     // Create example config content
     let example_config = r#"# Gromnie Configuration
@@ -76,12 +174,179 @@ enabled = true
     Ok(())
 }
 
+/// Look up a config entry by name, falling back to the only entry when no
+/// name is given
+fn select_entry<'a, T>(
+    entries: &'a BTreeMap<String, T>,
+    name: Option<&str>,
+    kind: &str,
+) -> Result<(String, &'a T), String> {
+    let available = || entries.keys().cloned().collect::<Vec<_>>().join(", ");
+    match name {
+        Some(name) => entries
+            .get(name)
+            .map(|entry| (name.to_string(), entry))
+            .ok_or_else(|| {
+                format!(
+                    "{} '{}' not found. Available {}s: {}",
+                    capitalize(kind),
+                    name,
+                    kind,
+                    available()
+                )
+            }),
+        None if entries.len() == 1 => {
+            let (name, entry) = entries.iter().next().expect("one entry");
+            Ok((name.clone(), entry))
+        }
+        None => Err(format!(
+            "--{} is required when the config has {} {}s. Available {}s: {}",
+            kind,
+            entries.len(),
+            kind,
+            kind,
+            available()
+        )),
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Connect without a terminal UI, with logging and the configured scripts,
+/// and run until Ctrl+C or SIGTERM
+async fn run_headless(
+    cli: &Cli,
+    config: GromnieConfig,
+    message_trace: Option<MessageTrace>,
+) -> Result<(), Box<dyn Error>> {
+    let (server_name, server) = select_entry(&config.servers, cli.server.as_deref(), "server")?;
+    let (account_name, account) =
+        select_entry(&config.accounts, cli.account.as_deref(), "account")?;
+
+    if !config.scripting.enabled {
+        warn!("Scripting is disabled in the config; running headless with logging only");
+    }
+
+    let client_config = ClientConfig {
+        id: 0,
+        address: format!("{}:{}", server.host, server.port),
+        account_name: account.username.clone(),
+        password: account.password.clone(),
+        reconnect: if cli.no_reconnect {
+            false
+        } else if cli.reconnect {
+            true
+        } else {
+            server.reconnect_enabled(config.reconnect)
+        },
+        initial_reconnect_delay_secs: config.initial_reconnect_delay_secs,
+        resume_in_world: config.resume_in_world,
+        strict_checksums: config.strict_checksums,
+        fragment_timeout_secs: config.fragment_timeout_secs,
+        keepalive_interval_secs: config.keepalive_interval_secs,
+        idle_timeout_secs: config.idle_timeout_secs,
+        max_login_attempts: config.max_login_attempts,
+        port_routing: server.routing.clone(),
+        bind_addr: server.bind_addr(),
+        reconnect_override: server.reconnect.clone(),
+        character_name: cli.character.clone().or_else(|| account.character.clone()),
+    };
+
+    info!(
+        "Running headless on {} with account {}",
+        server_name, account_name
+    );
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    setup_shutdown_signal_handler(shutdown_tx);
+
+    let mut builder = ClientRunner::builder()
+        .with_clients(client_config)
+        .with_consumer(LoggingConsumer::from_factory())
+        .with_config(config)
+        .with_shutdown(shutdown_rx);
+    if let Some(trace) = message_trace {
+        builder = builder.with_message_trace(trace);
+    }
+    if let BuilderRunResult::Failed(e) = builder.build()?.run().await {
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
+/// Replay a message trace offline with logging and the configured scripts,
+/// until it ends (or Ctrl+C when looping)
+async fn run_replay(cli: &Cli, path: &Path) -> Result<(), Box<dyn Error>> {
+    let replay = ReplayConfig::from_file(path, cli.replay_speed, cli.replay_loop)
+        .map_err(|e| format!("Failed to read trace {}: {}", path.display(), e))?;
+    info!(
+        "Replaying {} received messages from {}",
+        replay.len(),
+        path.display()
+    );
+
+    // Only used to name the client; nothing is sent
+    let client_config = ClientConfig::new(
+        0,
+        "127.0.0.1:9000".to_string(),
+        "replay".to_string(),
+        String::new(),
+    );
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    setup_shutdown_signal_handler(shutdown_tx);
+
+    ClientRunner::builder()
+        .with_clients(client_config)
+        .with_consumer(LoggingConsumer::from_factory())
+        .with_replay(replay)
+        .with_shutdown(shutdown_rx)
+        .build()?
+        .run()
+        .await;
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let _log_guard = logging::init_logging("cli")?;
 
     let cli = Cli::parse();
 
+    if let Some(Command::Bench {
+        target: BenchTarget::Parse { capture, port },
+    }) = &cli.command
+    {
+        let packets = gromnie_cli::bench::read_capture(capture, *port)?;
+        eprintln!(
+            "Replaying {} packets from {}",
+            packets.len(),
+            capture.display()
+        );
+        let report = gromnie_cli::bench::run_parse_bench(&packets).await;
+        print!("{}", report);
+        return Ok(());
+    }
+
+    if let Some(Command::Config {
+        action: ConfigAction::Check { path },
+    }) = &cli.command
+    {
+        return check_config(path.as_deref());
+    }
+
+    if let Some(path) = &cli.replay {
+        return run_replay(&cli, path).await;
+    }
+
     info!("Starting gromnie client...");
 
     // Load or create config
@@ -90,6 +355,13 @@ async fn main() -> Result<(), Box<dyn Error>> {
             info!("Loaded existing config");
             cfg
         }
+        Err(ConfigLoadError::NotFound) if cli.headless => {
+            return Err(format!(
+                "No config found at {}. Create one before running headless.",
+                GromnieConfig::config_path().display()
+            )
+            .into());
+        }
         Err(ConfigLoadError::NotFound) => {
             info!("No config found, creating example config");
             create_example_config()?;
@@ -100,6 +372,20 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     };
 
+    let message_trace = match &cli.trace {
+        Some(path) => {
+            let trace = MessageTrace::create(path)
+                .map_err(|e| format!("Failed to create trace file {}: {}", path.display(), e))?;
+            info!("Tracing messages to {}", path.display());
+            Some(trace)
+        }
+        None => None,
+    };
+
+    if cli.headless {
+        return run_headless(&cli, config, message_trace).await;
+    }
+
     // If server and account are provided via CLI args, use them directly
     match (cli.server.clone(), cli.account.clone()) {
         (Some(server_name), Some(account_name)) => {
@@ -141,8 +427,18 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 } else if cli.reconnect {
                     true
                 } else {
-                    config.reconnect
+                    server.reconnect_enabled(config.reconnect)
                 },
+                initial_reconnect_delay_secs: config.initial_reconnect_delay_secs,
+                resume_in_world: config.resume_in_world,
+                strict_checksums: config.strict_checksums,
+                fragment_timeout_secs: config.fragment_timeout_secs,
+                keepalive_interval_secs: config.keepalive_interval_secs,
+                idle_timeout_secs: config.idle_timeout_secs,
+                max_login_attempts: config.max_login_attempts,
+                port_routing: server.routing.clone(),
+                bind_addr: server.bind_addr(),
+                reconnect_override: server.reconnect.clone(),
                 // CLI flag takes precedence over account config
                 character_name: cli.character.clone().or_else(|| account.character.clone()),
             };
@@ -158,14 +454,23 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 }
             );
 
+            // SIGINT/SIGTERM stop the client loop through the shutdown channel so
+            // the character logs off and outgoing messages are flushed before exiting
+            let (shutdown_tx, shutdown_rx) = watch::channel(false);
+            setup_shutdown_signal_handler(shutdown_tx);
+
             // Build and run the client using the new builder API
-            ClientRunner::builder()
+            let mut builder = ClientRunner::builder()
                 .with_clients(client_config)
                 .with_consumer(LoggingConsumer::from_factory())
                 .with_config(config.clone())
-                .build()?
-                .run()
-                .await;
+                .with_shutdown(shutdown_rx);
+            if let Some(trace) = message_trace {
+                builder = builder.with_message_trace(trace);
+            }
+            if let BuilderRunResult::Failed(e) = builder.build()?.run().await {
+                return Err(e.into());
+            }
 
             return Ok(());
         }
@@ -206,19 +511,36 @@ async fn main() -> Result<(), Box<dyn Error>> {
             } else if cli.reconnect {
                 true
             } else {
-                wizard.config.reconnect
+                server.reconnect_enabled(wizard.config.reconnect)
             },
+            initial_reconnect_delay_secs: wizard.config.initial_reconnect_delay_secs,
+            resume_in_world: wizard.config.resume_in_world,
+            strict_checksums: wizard.config.strict_checksums,
+            fragment_timeout_secs: wizard.config.fragment_timeout_secs,
+            keepalive_interval_secs: wizard.config.keepalive_interval_secs,
+            idle_timeout_secs: wizard.config.idle_timeout_secs,
+            max_login_attempts: wizard.config.max_login_attempts,
+            port_routing: server.routing.clone(),
+            bind_addr: server.bind_addr(),
+            reconnect_override: server.reconnect.clone(),
             character_name: account.character.clone(),
         };
 
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        setup_shutdown_signal_handler(shutdown_tx);
+
         // Build and run the client using the new builder API
-        ClientRunner::builder()
+        let mut builder = ClientRunner::builder()
             .with_clients(client_config)
             .with_consumer(LoggingConsumer::from_factory())
             .with_config(wizard.config.clone())
-            .build()?
-            .run()
-            .await;
+            .with_shutdown(shutdown_rx);
+        if let Some(trace) = message_trace {
+            builder = builder.with_message_trace(trace);
+        }
+        if let BuilderRunResult::Failed(e) = builder.build()?.run().await {
+            return Err(e.into());
+        }
     }
 
     Ok(())
@@ -200,9 +200,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         account_name: game_username,
         password: game_password,
         reconnect: false,
+        initial_reconnect_delay_secs: None,
+        resume_in_world: false,
+        strict_checksums: false,
+        fragment_timeout_secs: None,
+        keepalive_interval_secs: None,
+        idle_timeout_secs: None,
+        max_login_attempts: None,
         character_name: None,
+        port_routing: Default::default(),
+        bind_addr: None,
+        reconnect_override: None,
     };
 
+    // Which events to relay comes from the [discord] section of config.toml
+    let discord_config = gromnie_client::config::GromnieConfig::load()
+        .map(|config| config.discord)
+        .unwrap_or_default();
+    info!("Relaying to Discord: {:?}", discord_config.relay);
+
     let event_bus_manager = Arc::new(EventBusManager::new(100));
 
     // Spawn client task
@@ -218,6 +234,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 channel_id,
                 uptime_data_clone.clone(),
             )
+            .with_config(discord_config.clone())
         },
         action_tx_channel,
         shutdown_rx,
@@ -2,12 +2,15 @@ use std::error::Error;
 use std::fs;
 
 use clap::Parser;
-use gromnie_runner::{ClientConfig, ClientRunner, LoggingConsumer, logging};
+use gromnie_runner::{
+    BuilderRunResult, ClientConfig, ClientRunner, LoggingConsumer, logging,
+    setup_shutdown_signal_handler,
+};
 use ratatui::{TerminalOptions, Viewport};
 use tracing::info;
 
 use gromnie_cli::{app::App, run as cli_run};
-use gromnie_client::config::{ConfigLoadError, GromnieConfig};
+use gromnie_client::config::{ConfigLoadError, GromnieConfig, ProjectPaths};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -41,10 +44,10 @@ fn create_example_config() -> Result<(), Box<dyn Error>> {
         return Err("Config file already exists at {}. Please edit it manually or delete it to create a new one.".into());
     }
 
-    // Create parent directories if they don't exist
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
+    // Create the config directory, failing with an actionable message if it's read-only
+    ProjectPaths::new("gromnie")
+        .ok_or("Failed to determine config directory")?
+        .ensure_config_dir_writable()?;
 
     // Create example config content
     let example_config = r#"# Gromnie Configuration
@@ -141,8 +144,18 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 } else if cli.reconnect {
                     true
                 } else {
-                    config.reconnect
+                    server.reconnect_enabled(config.reconnect)
                 },
+                initial_reconnect_delay_secs: config.initial_reconnect_delay_secs,
+                resume_in_world: config.resume_in_world,
+                strict_checksums: config.strict_checksums,
+                fragment_timeout_secs: config.fragment_timeout_secs,
+                keepalive_interval_secs: config.keepalive_interval_secs,
+                idle_timeout_secs: config.idle_timeout_secs,
+                max_login_attempts: config.max_login_attempts,
+                port_routing: server.routing.clone(),
+                bind_addr: server.bind_addr(),
+                reconnect_override: server.reconnect.clone(),
                 character_name: None,
             };
 
@@ -151,15 +164,24 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 server_name, account_name
             );
 
+            // SIGINT/SIGTERM stop the client loop through the shutdown channel so
+            // outgoing messages and consumers are flushed before exiting
+            let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+            setup_shutdown_signal_handler(shutdown_tx);
+
             // Build and run the client using the new builder API
             // Note: with_config() is optional - config is loaded from default location if not specified
-            ClientRunner::builder()
+            let result = ClientRunner::builder()
                 .with_clients(client_config)
                 .with_consumer(LoggingConsumer::from_factory())
+                .with_shutdown(shutdown_rx)
                 .with_config(config.clone())
                 .build()?
                 .run()
                 .await;
+            if let BuilderRunResult::Failed(e) = result {
+                return Err(e.into());
+            }
 
             return Ok(());
         }
@@ -200,19 +222,36 @@ async fn main() -> Result<(), Box<dyn Error>> {
             } else if cli.reconnect {
                 true
             } else {
-                wizard.config.reconnect
+                server.reconnect_enabled(wizard.config.reconnect)
             },
+            initial_reconnect_delay_secs: wizard.config.initial_reconnect_delay_secs,
+            resume_in_world: wizard.config.resume_in_world,
+            strict_checksums: wizard.config.strict_checksums,
+            fragment_timeout_secs: wizard.config.fragment_timeout_secs,
+            keepalive_interval_secs: wizard.config.keepalive_interval_secs,
+            idle_timeout_secs: wizard.config.idle_timeout_secs,
+            max_login_attempts: wizard.config.max_login_attempts,
+            port_routing: server.routing.clone(),
+            bind_addr: server.bind_addr(),
+            reconnect_override: server.reconnect.clone(),
             character_name: None,
         };
 
+        let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+        setup_shutdown_signal_handler(shutdown_tx);
+
         // Build and run the client using the new builder API
-        ClientRunner::builder()
+        let result = ClientRunner::builder()
             .with_clients(client_config)
             .with_consumer(LoggingConsumer::from_factory())
+            .with_shutdown(shutdown_rx)
             .with_config(wizard.config.clone())
             .build()?
             .run()
             .await;
+        if let BuilderRunResult::Failed(e) = result {
+            return Err(e.into());
+        }
     }
 
     Ok(())
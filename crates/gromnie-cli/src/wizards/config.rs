@@ -60,6 +60,10 @@ impl ConfigWizard {
             gromnie_client::config::ServerConfig {
                 host: self.server_host.clone(),
                 port: self.server_port.parse().unwrap_or(9000),
+                routing: Default::default(),
+                bind_address: None,
+                bind_port: None,
+                reconnect: None,
             },
         );
 
@@ -79,7 +83,18 @@ impl ConfigWizard {
             servers,
             accounts,
             scripting: Default::default(),
+            discord: Default::default(),
+            database: None,
+            trace_events: false,
+            character_creation: Default::default(),
             reconnect: false,
+            initial_reconnect_delay_secs: None,
+            resume_in_world: false,
+            strict_checksums: false,
+            fragment_timeout_secs: None,
+            keepalive_interval_secs: None,
+            idle_timeout_secs: None,
+            max_login_attempts: None,
         }
     }
 }
@@ -244,3 +244,111 @@ impl ACWritable for AceCharGenResult {
         Ok(())
     }
 }
+
+/// Game action type of Vendor_Buy
+pub const VENDOR_BUY_ACTION: u32 = 0x005F;
+/// Game action type of Vendor_Sell
+pub const VENDOR_SELL_ACTION: u32 = 0x0060;
+
+/// Item entry in a vendor buy or sell request
+///
+/// protocol.xml's ItemProfile carries a weenie description after the object
+/// ID, but ACE only reads the amount and object ID when handling Vendor_Buy
+/// and Vendor_Sell, so that's all we send.
+#[derive(Clone, Debug)]
+pub struct AceVendorItem {
+    pub amount: u32,
+    pub object_id: u32,
+}
+
+impl ACWritable for AceVendorItem {
+    fn write(&self, writer: &mut dyn ACWriter) -> Result<(), Box<dyn std::error::Error>> {
+        write_u32(writer, self.amount)?;
+        write_u32(writer, self.object_id)?;
+        Ok(())
+    }
+}
+
+/// ACE-compatible Vendor_Buy body (everything after the action type)
+#[derive(Clone, Debug)]
+pub struct AceVendorBuy {
+    pub vendor_id: u32,
+    pub items: PackableList<AceVendorItem>,
+    /// Alternate currency to pay with; 0 pays in pyreals
+    pub alternate_currency_id: u32,
+}
+
+impl ACWritable for AceVendorBuy {
+    fn write(&self, writer: &mut dyn ACWriter) -> Result<(), Box<dyn std::error::Error>> {
+        write_u32(writer, self.vendor_id)?;
+        asheron_rs::writers::write_packable_list::<AceVendorItem>(writer, &self.items)?;
+        write_u32(writer, self.alternate_currency_id)?;
+        Ok(())
+    }
+}
+
+/// ACE-compatible Vendor_Sell body (everything after the action type)
+#[derive(Clone, Debug)]
+pub struct AceVendorSell {
+    pub vendor_id: u32,
+    pub items: PackableList<AceVendorItem>,
+}
+
+impl ACWritable for AceVendorSell {
+    fn write(&self, writer: &mut dyn ACWriter) -> Result<(), Box<dyn std::error::Error>> {
+        write_u32(writer, self.vendor_id)?;
+        asheron_rs::writers::write_packable_list::<AceVendorItem>(writer, &self.items)?;
+        Ok(())
+    }
+}
+
+/// Game action body passed through as already-serialized bytes, for actions
+/// the client doesn't model (see `SimpleClientAction::SendRawGameAction`)
+#[derive(Clone, Debug)]
+pub struct RawGameActionBody(pub Vec<u8>);
+
+impl ACWritable for RawGameActionBody {
+    fn write(&self, writer: &mut dyn ACWriter) -> Result<(), Box<dyn std::error::Error>> {
+        writer.write_all(&self.0)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_vendor_buy_layout() {
+        let buy = AceVendorBuy {
+            vendor_id: 0x8000_0001,
+            items: PackableList {
+                count: 1,
+                list: vec![AceVendorItem {
+                    amount: 5,
+                    object_id: 0x8000_0002,
+                }],
+            },
+            alternate_currency_id: 0,
+        };
+
+        let mut data = Vec::new();
+        buy.write(&mut Cursor::new(&mut data)).unwrap();
+
+        let words: Vec<u32> = data
+            .chunks(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(words, vec![0x8000_0001, 1, 5, 0x8000_0002, 0]);
+    }
+
+    #[test]
+    fn test_raw_game_action_body_is_written_verbatim() {
+        let body = RawGameActionBody(vec![0x01, 0x02, 0x03]);
+
+        let mut data = Vec::new();
+        body.write(&mut Cursor::new(&mut data)).unwrap();
+        assert_eq!(data, [0x01, 0x02, 0x03]);
+    }
+}
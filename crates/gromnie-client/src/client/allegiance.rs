@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use gromnie_events::AllegianceMember;
+
+/// The character's allegiance as last sent in Allegiance_AllegianceUpdate
+///
+/// AllegianceLoginNotification only carries an object id, so member names
+/// are looked up here.
+#[derive(Debug, Default)]
+pub struct AllegianceRoster {
+    name: String,
+    /// Members keyed by object ID
+    members: BTreeMap<u32, AllegianceMember>,
+}
+
+impl AllegianceRoster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the roster with a fresh update
+    pub fn update(&mut self, name: &str, members: &[AllegianceMember]) {
+        self.name = name.to_string();
+        self.members = members
+            .iter()
+            .map(|member| (member.object_id.get(), member.clone()))
+            .collect();
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn member(&self, object_id: u32) -> Option<&AllegianceMember> {
+        self.members.get(&object_id)
+    }
+
+    /// Forget the roster (logout or new connection)
+    pub fn clear(&mut self) {
+        self.name.clear();
+        self.members.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(object_id: u32, name: &str, patron_id: Option<u32>) -> AllegianceMember {
+        AllegianceMember {
+            object_id: object_id.into(),
+            name: name.to_string(),
+            rank: 1,
+            level: 50,
+            patron_id: patron_id.map(Into::into),
+            officer_level: 0,
+        }
+    }
+
+    #[test]
+    fn test_update_replaces_members() {
+        let mut roster = AllegianceRoster::new();
+        roster.update(
+            "Order of the Drudge",
+            &[member(1, "Monarch", None), member(2, "Vassal", Some(1))],
+        );
+        assert_eq!(roster.name(), "Order of the Drudge");
+        assert_eq!(roster.member(2).unwrap().name, "Vassal");
+
+        // A later update drops members that left
+        roster.update("Order of the Drudge", &[member(1, "Monarch", None)]);
+        assert!(roster.member(2).is_none());
+
+        roster.clear();
+        assert!(roster.member(1).is_none());
+        assert_eq!(roster.name(), "");
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+
+/// Unbuffed value of a primary attribute
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttributeValue {
+    /// Points from character creation
+    pub innate: u32,
+    /// Points raised with experience
+    pub raised: u32,
+}
+
+impl AttributeValue {
+    pub fn base(&self) -> u32 {
+        self.innate + self.raised
+    }
+}
+
+/// The six primary attributes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attributes {
+    pub strength: AttributeValue,
+    pub endurance: AttributeValue,
+    pub quickness: AttributeValue,
+    pub coordination: AttributeValue,
+    pub focus: AttributeValue,
+    pub self_: AttributeValue,
+}
+
+impl Attributes {
+    /// Attribute by AttributeId (1 = Strength ... 6 = Self)
+    fn get_mut(&mut self, attribute_id: u32) -> Option<&mut AttributeValue> {
+        match attribute_id {
+            1 => Some(&mut self.strength),
+            2 => Some(&mut self.endurance),
+            3 => Some(&mut self.quickness),
+            4 => Some(&mut self.coordination),
+            5 => Some(&mut self.focus),
+            6 => Some(&mut self.self_),
+            _ => None,
+        }
+    }
+}
+
+/// How far a skill has been advanced (SkillAdvancementClass)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SkillTraining {
+    #[default]
+    Inactive,
+    Untrained,
+    Trained,
+    Specialized,
+}
+
+impl From<u32> for SkillTraining {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => SkillTraining::Untrained,
+            2 => SkillTraining::Trained,
+            3 => SkillTraining::Specialized,
+            _ => SkillTraining::Inactive,
+        }
+    }
+}
+
+/// A skill's training and invested points
+///
+/// `level()` excludes the part of the skill derived from attributes, which
+/// depends on per-skill formulas the client doesn't have.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SkillValue {
+    pub skill_id: u32,
+    pub training: SkillTraining,
+    /// Ranks raised with experience
+    pub ranks: u32,
+    /// Free points from training or specializing
+    pub init_level: u32,
+    pub experience_spent: u32,
+}
+
+impl SkillValue {
+    pub fn level(&self) -> u32 {
+        self.init_level + self.ranks
+    }
+}
+
+/// The current character's attributes, skills and qualities
+///
+/// Filled from LoginPlayerDescription and kept current by
+/// QualitiesPrivateUpdate* messages.
+#[derive(Debug, Clone, Default)]
+pub struct CharacterSheet {
+    /// Whether LoginPlayerDescription has arrived; until then everything is default
+    pub received: bool,
+    pub attributes: Attributes,
+    /// Skills by SkillId
+    pub skills: BTreeMap<u32, SkillValue>,
+    /// Integer qualities by the variant name of their PropertyInt key, plus
+    /// the current vitals by their vital name (`Health`, `Stamina`, `Mana`)
+    pub ints: BTreeMap<&'static str, i64>,
+    /// Float qualities by the variant name of their PropertyFloat key
+    pub floats: BTreeMap<&'static str, f64>,
+}
+
+impl CharacterSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace everything with the contents of a player description
+    pub fn load(&mut self, attributes: Attributes, skills: impl IntoIterator<Item = SkillValue>) {
+        self.received = true;
+        self.attributes = attributes;
+        self.skills = skills.into_iter().map(|s| (s.skill_id, s)).collect();
+    }
+
+    /// Apply an attribute update. Returns false for unknown attribute IDs.
+    pub fn update_attribute(&mut self, attribute_id: u32, value: AttributeValue) -> bool {
+        match self.attributes.get_mut(attribute_id) {
+            Some(attribute) => {
+                *attribute = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Apply a skill update
+    pub fn update_skill(&mut self, skill: SkillValue) {
+        self.skills.insert(skill.skill_id, skill);
+    }
+
+    /// Record an integer quality or current vital
+    pub fn set_int(&mut self, name: &'static str, value: i64) {
+        self.ints.insert(name, value);
+    }
+
+    /// Record a float quality
+    pub fn set_float(&mut self, name: &'static str, value: f64) {
+        self.floats.insert(name, value);
+    }
+
+    /// Last known value of an integer quality or current vital
+    pub fn int(&self, name: &str) -> Option<i64> {
+        self.ints.get(name).copied()
+    }
+
+    /// Last known value of a float quality
+    pub fn float(&self, name: &str) -> Option<f64> {
+        self.floats.get(name).copied()
+    }
+
+    /// Skills at Trained or above
+    pub fn trained_skills(&self) -> impl Iterator<Item = &SkillValue> {
+        self.skills.values().filter(|s| {
+            matches!(
+                s.training,
+                SkillTraining::Trained | SkillTraining::Specialized
+            )
+        })
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Convert an asheron-rs AttributeInfo
+pub(crate) fn attribute_value(info: &asheron_rs::types::AttributeInfo) -> AttributeValue {
+    AttributeValue {
+        innate: info.innate_points,
+        raised: info.points_raised,
+    }
+}
+
+/// Convert an asheron-rs Skill
+pub(crate) fn skill_value(skill_id: u32, skill: &asheron_rs::types::Skill) -> SkillValue {
+    SkillValue {
+        skill_id,
+        training: SkillTraining::from(skill.training_level as u32),
+        ranks: skill.points_raised as u32,
+        init_level: skill.init_level,
+        experience_spent: skill.experience_spent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_updates_after_load() {
+        let mut sheet = CharacterSheet::new();
+        assert!(!sheet.received);
+
+        let attributes = Attributes {
+            strength: AttributeValue {
+                innate: 100,
+                raised: 0,
+            },
+            ..Default::default()
+        };
+        let skill = SkillValue {
+            skill_id: 6,
+            training: SkillTraining::Trained,
+            ranks: 4,
+            init_level: 10,
+            experience_spent: 500,
+        };
+        sheet.load(attributes, [skill]);
+        assert!(sheet.received);
+
+        assert!(sheet.update_attribute(
+            1,
+            AttributeValue {
+                innate: 100,
+                raised: 5
+            }
+        ));
+        assert!(!sheet.update_attribute(9, AttributeValue::default()));
+        assert_eq!(sheet.attributes.strength.base(), 105);
+
+        sheet.update_skill(SkillValue { ranks: 6, ..skill });
+        sheet.update_skill(SkillValue {
+            skill_id: 7,
+            training: SkillTraining::Untrained,
+            ..Default::default()
+        });
+        let trained: Vec<_> = sheet.trained_skills().collect();
+        assert_eq!(trained.len(), 1);
+        assert_eq!(trained[0].level(), 16);
+    }
+
+    #[test]
+    fn test_qualities_survive_load() {
+        let mut sheet = CharacterSheet::new();
+        sheet.set_int("Health", 80);
+        sheet.set_float("ArmorModVsSlash", 1.5);
+        sheet.load(Attributes::default(), []);
+
+        assert_eq!(sheet.int("Health"), Some(80));
+        assert_eq!(sheet.float("ArmorModVsSlash"), Some(1.5));
+        assert_eq!(sheet.int("Mana"), None);
+
+        sheet.clear();
+        assert_eq!(sheet.int("Health"), None);
+    }
+}
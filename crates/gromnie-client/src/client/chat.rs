@@ -0,0 +1,206 @@
+use std::time::Duration;
+
+use crate::instant::Instant;
+
+/// Shortest gap between lines sent with `SendChatLines`, whatever interval
+/// was asked for, so a pasted macro can't get the character throttled
+pub const MIN_CHAT_LINE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What an outgoing chat line asks the client to send
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatCommand {
+    Say(String),
+    Tell { recipient: String, message: String },
+    Emote(String),
+}
+
+/// Split an outgoing chat line on its leading token
+///
+/// `@name rest` is a tell, `/e text` an emote, and `/s text` or bare text is
+/// said aloud. Quote names containing spaces, as in `@"Some Name" hi`.
+/// Returns `None` when nothing is left to send after the prefix.
+pub fn parse_chat_message(message: &str) -> Option<ChatCommand> {
+    let message = message.trim();
+
+    let command = if let Some(rest) = message.strip_prefix('@') {
+        let (recipient, text) = match rest.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => rest
+                .split_once(char::is_whitespace)
+                .map(|(name, text)| (name.trim_end_matches(','), text))
+                .unwrap_or((rest, "")),
+        };
+        let (recipient, text) = (recipient.trim(), text.trim());
+        if recipient.is_empty() {
+            return None;
+        }
+        ChatCommand::Tell {
+            recipient: recipient.to_string(),
+            message: text.to_string(),
+        }
+    } else if let Some(text) = strip_command(message, &["e", "emote"]) {
+        ChatCommand::Emote(text.to_string())
+    } else if let Some(text) = strip_command(message, &["s", "say"]) {
+        ChatCommand::Say(text.to_string())
+    } else {
+        ChatCommand::Say(message.to_string())
+    };
+
+    let text = match &command {
+        ChatCommand::Say(text) | ChatCommand::Emote(text) => text,
+        ChatCommand::Tell { message, .. } => message,
+    };
+    (!text.is_empty()).then_some(command)
+}
+
+/// The text after `/<name>` for any of `names`, matched case-insensitively
+fn strip_command<'a>(message: &'a str, names: &[&str]) -> Option<&'a str> {
+    let command_line = message.strip_prefix('/')?;
+    let (command, rest) = command_line
+        .split_once(char::is_whitespace)
+        .unwrap_or((command_line, ""));
+    names
+        .iter()
+        .any(|name| command.eq_ignore_ascii_case(name))
+        .then(|| rest.trim())
+}
+
+/// Spaces out multi-line chat so it never goes out faster than
+/// [`MIN_CHAT_LINE_INTERVAL`]
+///
+/// Lines from a later batch wait for the earlier batch to finish rather than
+/// interleaving with it.
+#[derive(Debug, Default)]
+pub struct ChatPacer {
+    /// Earliest time the next line may go out
+    next_free: Option<Instant>,
+}
+
+impl ChatPacer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// When each of `count` lines should be sent, `interval` apart (raised to
+    /// the minimum), starting at `now` or once earlier lines are out
+    pub fn schedule(&mut self, now: Instant, count: usize, interval: Duration) -> Vec<Instant> {
+        let interval = interval.max(MIN_CHAT_LINE_INTERVAL);
+        let mut at = self.next_free.map_or(now, |free| free.max(now));
+        let mut deadlines = Vec::with_capacity(count);
+        for _ in 0..count {
+            deadlines.push(at);
+            at = at + interval;
+        }
+        if count > 0 {
+            self.next_free = Some(at);
+        }
+        deadlines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn say(text: &str) -> Option<ChatCommand> {
+        Some(ChatCommand::Say(text.to_string()))
+    }
+
+    fn tell(recipient: &str, message: &str) -> Option<ChatCommand> {
+        Some(ChatCommand::Tell {
+            recipient: recipient.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_bare_and_say_prefix() {
+        assert_eq!(parse_chat_message("hello there"), say("hello there"));
+        assert_eq!(parse_chat_message("  hello  "), say("hello"));
+        assert_eq!(parse_chat_message("/s hello"), say("hello"));
+        assert_eq!(parse_chat_message("/SAY hello"), say("hello"));
+        // Unknown commands go out as typed
+        assert_eq!(parse_chat_message("/wave"), say("/wave"));
+        assert_eq!(parse_chat_message("/sit down"), say("/sit down"));
+    }
+
+    #[test]
+    fn test_tell_prefix() {
+        assert_eq!(parse_chat_message("@Bob hi"), tell("Bob", "hi"));
+        assert_eq!(
+            parse_chat_message("@Bob, hi there"),
+            tell("Bob", "hi there")
+        );
+        assert_eq!(
+            parse_chat_message("@\"Some Name\" hi"),
+            tell("Some Name", "hi")
+        );
+    }
+
+    #[test]
+    fn test_tell_names_with_special_characters() {
+        assert_eq!(
+            parse_chat_message("@O'Brien-the+Bold hi"),
+            tell("O'Brien-the+Bold", "hi")
+        );
+        assert_eq!(parse_chat_message("@+Admin hi"), tell("+Admin", "hi"));
+        assert_eq!(
+            parse_chat_message("@\"Sir O'Neil, Jr.\" hail"),
+            tell("Sir O'Neil, Jr.", "hail")
+        );
+    }
+
+    #[test]
+    fn test_emote_prefix() {
+        assert_eq!(
+            parse_chat_message("/e waves hello"),
+            Some(ChatCommand::Emote("waves hello".to_string()))
+        );
+        assert_eq!(
+            parse_chat_message("/emote bows"),
+            Some(ChatCommand::Emote("bows".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_empty_message_after_prefix() {
+        assert_eq!(parse_chat_message(""), None);
+        assert_eq!(parse_chat_message("   "), None);
+        assert_eq!(parse_chat_message("/s"), None);
+        assert_eq!(parse_chat_message("/e   "), None);
+        assert_eq!(parse_chat_message("@Bob"), None);
+        assert_eq!(parse_chat_message("@Bob   "), None);
+        assert_eq!(parse_chat_message("@"), None);
+        assert_eq!(parse_chat_message("@\"\" hi"), None);
+    }
+
+    #[test]
+    fn test_pacer_enforces_minimum_interval() {
+        let start = Instant::now();
+        let mut pacer = ChatPacer::new();
+        let deadlines = pacer.schedule(start, 3, Duration::from_millis(10));
+        assert_eq!(
+            deadlines,
+            [
+                start,
+                start + MIN_CHAT_LINE_INTERVAL,
+                start + MIN_CHAT_LINE_INTERVAL * 2,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pacer_queues_batches_behind_each_other() {
+        let start = Instant::now();
+        let mut pacer = ChatPacer::new();
+        pacer.schedule(start, 2, Duration::from_secs(2));
+
+        // A second batch right away waits for the first to finish
+        let deadlines = pacer.schedule(start, 1, Duration::from_secs(2));
+        assert_eq!(deadlines, [start + Duration::from_secs(4)]);
+
+        // Once the queue has gone out, lines start straight away
+        let later = start + Duration::from_secs(60);
+        assert_eq!(pacer.schedule(later, 1, Duration::ZERO), [later]);
+    }
+}
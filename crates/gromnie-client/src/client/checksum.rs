@@ -0,0 +1,240 @@
+use asheron_rs::enums::PacketHeaderFlags;
+
+use crate::client::constants::{CHECKSUM_OFFSET, CHECKSUM_PLACEHOLDER, PACKET_HEADER_SIZE};
+use crate::crypto::crypto_system::ReceiveKeys;
+use crate::crypto::magic_number::get_magic_number;
+
+/// ENCRYPTED_CHECKSUM bit in the packet header flags
+const ENCRYPTED_CHECKSUM_FLAG: u32 = 0x2;
+/// Fragment header: sequence, id, count, size, index, group
+const FRAGMENT_HEADER_SIZE: usize = 16;
+/// Offset of the fragment's total size (header included) in its header
+const FRAGMENT_SIZE_OFFSET: usize = 10;
+
+/// Outcome of checking a received packet's checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumCheck {
+    Valid,
+    /// The checksum doesn't match the contents, or no server key explains it
+    Invalid,
+    /// The packet can't be checked, e.g. it's encrypted but no session is
+    /// established yet
+    Unchecked,
+}
+
+/// Verify the checksum of a received packet against its contents
+///
+/// Matches how the server builds it: the header is hashed with a placeholder
+/// in the checksum field, and the optional headers and each fragment are
+/// hashed separately. For ENCRYPTED_CHECKSUM packets the body hash is XORed
+/// with the next server key, which is looked up (and consumed) in `keys`.
+pub fn check_received_checksum(
+    packet: &[u8],
+    flags: PacketHeaderFlags,
+    keys: Option<&mut ReceiveKeys>,
+) -> ChecksumCheck {
+    let Some(header) = packet.get(..PACKET_HEADER_SIZE) else {
+        return ChecksumCheck::Invalid;
+    };
+    let received = read_u32(header, CHECKSUM_OFFSET);
+    let encrypted = read_u32(header, 4) & ENCRYPTED_CHECKSUM_FLAG != 0;
+    let size = u16::from_le_bytes([header[16], header[17]]) as usize;
+    let Some(body) = packet.get(PACKET_HEADER_SIZE..PACKET_HEADER_SIZE + size) else {
+        return ChecksumCheck::Invalid;
+    };
+
+    let mut header = header.to_vec();
+    header[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 4]
+        .copy_from_slice(&CHECKSUM_PLACEHOLDER.to_le_bytes());
+    let header_hash = get_magic_number(&header, PACKET_HEADER_SIZE, true);
+
+    let Some(options_size) = optional_headers_size(flags, body) else {
+        return ChecksumCheck::Unchecked;
+    };
+    let Some(body_hash) = body_hash(body, options_size, flags) else {
+        return ChecksumCheck::Invalid;
+    };
+
+    if !encrypted {
+        return if received == header_hash.wrapping_add(body_hash) {
+            ChecksumCheck::Valid
+        } else {
+            ChecksumCheck::Invalid
+        };
+    }
+
+    let Some(keys) = keys else {
+        return ChecksumCheck::Unchecked;
+    };
+    let key = received.wrapping_sub(header_hash) ^ body_hash;
+    if keys.consume(key) {
+        ChecksumCheck::Valid
+    } else {
+        ChecksumCheck::Invalid
+    }
+}
+
+/// Hash of the optional headers plus each fragment
+fn body_hash(body: &[u8], options_size: usize, flags: PacketHeaderFlags) -> Option<u32> {
+    let (options, mut fragments) = body.split_at_checked(options_size)?;
+    let mut hash = if options.is_empty() {
+        0
+    } else {
+        get_magic_number(options, options.len(), true)
+    };
+
+    if !flags.contains(PacketHeaderFlags::BLOB_FRAGMENTS) {
+        // Anything after the optional headers is hashed as one block
+        if !fragments.is_empty() {
+            hash = hash.wrapping_add(get_magic_number(fragments, fragments.len(), true));
+        }
+        return Some(hash);
+    }
+
+    while !fragments.is_empty() {
+        let size_bytes = fragments.get(FRAGMENT_SIZE_OFFSET..FRAGMENT_SIZE_OFFSET + 2)?;
+        let fragment_size = u16::from_le_bytes([size_bytes[0], size_bytes[1]]) as usize;
+        if fragment_size < FRAGMENT_HEADER_SIZE {
+            return None;
+        }
+        let (fragment, rest) = fragments.split_at_checked(fragment_size)?;
+        hash = hash.wrapping_add(get_magic_number(fragment, fragment_size, true));
+        fragments = rest;
+    }
+    Some(hash)
+}
+
+/// Total size of the optional headers at the start of `body`, read in flag
+/// order. Returns None for packets whose layout isn't known here (login and
+/// connect requests).
+fn optional_headers_size(flags: PacketHeaderFlags, body: &[u8]) -> Option<usize> {
+    if flags.intersects(PacketHeaderFlags::LOGIN_REQUEST | PacketHeaderFlags::CONNECT_REQUEST) {
+        return None;
+    }
+
+    let mut size = 0;
+    if flags.contains(PacketHeaderFlags::SERVER_SWITCH) {
+        size += 8;
+    }
+    // Retransmit lists are a count followed by that many sequences
+    for flag in [
+        PacketHeaderFlags::REQUEST_RETRANSMIT,
+        PacketHeaderFlags::REJECT_RETRANSMIT,
+    ] {
+        if flags.contains(flag) {
+            let count = body.get(size..size + 4).map(|b| read_u32(b, 0))? as usize;
+            size += 4 + count.checked_mul(4)?;
+        }
+    }
+    let fixed_headers = [
+        (PacketHeaderFlags::ACK_SEQUENCE, 4),
+        (PacketHeaderFlags::WORLD_LOGIN_REQUEST, 8),
+        (PacketHeaderFlags::CONNECT_RESPONSE, 8),
+        (PacketHeaderFlags::CICMDCOMMAND, 8),
+        (PacketHeaderFlags::TIME_SYNC, 8),
+        (PacketHeaderFlags::ECHO_REQUEST, 4),
+        (PacketHeaderFlags::ECHO_RESPONSE, 8),
+        (PacketHeaderFlags::FLOW, 6),
+    ];
+    size += fixed_headers
+        .iter()
+        .filter(|(flag, _)| flags.contains(*flag))
+        .map(|(_, size)| size)
+        .sum::<usize>();
+    (size <= body.len()).then_some(size)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::crypto_system::CryptoSystem;
+
+    const SERVER_SEED: u32 = 0x1111_1111;
+
+    /// Build a single-fragment packet the way the server does, XORing the
+    /// body hash with `key` when given
+    fn fragment_packet(message: &[u8], key: Option<u32>) -> Vec<u8> {
+        let fragment_size = FRAGMENT_HEADER_SIZE + message.len();
+        let mut flags = PacketHeaderFlags::BLOB_FRAGMENTS.bits();
+        if key.is_some() {
+            flags |= ENCRYPTED_CHECKSUM_FLAG;
+        }
+
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&7u32.to_le_bytes()); // sequence
+        packet.extend_from_slice(&flags.to_le_bytes());
+        packet.extend_from_slice(&CHECKSUM_PLACEHOLDER.to_le_bytes());
+        packet.extend_from_slice(&0u16.to_le_bytes()); // recipient_id
+        packet.extend_from_slice(&0u16.to_le_bytes()); // time_since_last_packet
+        packet.extend_from_slice(&(fragment_size as u16).to_le_bytes());
+        packet.extend_from_slice(&0u16.to_le_bytes()); // iteration
+        packet.extend_from_slice(&7u32.to_le_bytes()); // fragment sequence
+        packet.extend_from_slice(&0x8000_0000u32.to_le_bytes()); // fragment id
+        packet.extend_from_slice(&1u16.to_le_bytes()); // count
+        packet.extend_from_slice(&(fragment_size as u16).to_le_bytes());
+        packet.extend_from_slice(&0u16.to_le_bytes()); // index
+        packet.extend_from_slice(&5u16.to_le_bytes()); // group
+        packet.extend_from_slice(message);
+
+        let header_hash = get_magic_number(&packet[..PACKET_HEADER_SIZE], PACKET_HEADER_SIZE, true);
+        let body = &packet[PACKET_HEADER_SIZE..];
+        let body_hash = get_magic_number(body, body.len(), true) ^ key.unwrap_or(0);
+        let checksum = header_hash.wrapping_add(body_hash);
+        packet[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 4].copy_from_slice(&checksum.to_le_bytes());
+        packet
+    }
+
+    fn check(packet: &[u8], keys: Option<&mut ReceiveKeys>) -> ChecksumCheck {
+        let flags = PacketHeaderFlags::from_bits_truncate(read_u32(packet, 4));
+        check_received_checksum(packet, flags, keys)
+    }
+
+    #[test]
+    fn test_accepts_encrypted_checksum() {
+        let key = CryptoSystem::new(SERVER_SEED).get_send_key();
+        let packet = fragment_packet(&[0xB0, 0xF7, 0, 0, 1, 2, 3, 4], Some(key));
+
+        let mut keys = ReceiveKeys::new(SERVER_SEED);
+        assert_eq!(check(&packet, Some(&mut keys)), ChecksumCheck::Valid);
+        // Without a session there's no key to check against
+        assert_eq!(check(&packet, None), ChecksumCheck::Unchecked);
+    }
+
+    #[test]
+    fn test_rejects_flipped_byte() {
+        let key = CryptoSystem::new(SERVER_SEED).get_send_key();
+        let mut packet = fragment_packet(&[0xB0, 0xF7, 0, 0, 1, 2, 3, 4], Some(key));
+        let last = packet.len() - 1;
+        packet[last] ^= 0x40;
+
+        let mut keys = ReceiveKeys::new(SERVER_SEED);
+        assert_eq!(check(&packet, Some(&mut keys)), ChecksumCheck::Invalid);
+    }
+
+    #[test]
+    fn test_unencrypted_checksum() {
+        let mut packet = fragment_packet(&[0xB0, 0xF7, 0, 0], None);
+        assert_eq!(check(&packet, None), ChecksumCheck::Valid);
+
+        packet[PACKET_HEADER_SIZE] ^= 1;
+        assert_eq!(check(&packet, None), ChecksumCheck::Invalid);
+    }
+
+    #[test]
+    fn test_truncated_packet_is_invalid() {
+        let packet = fragment_packet(&[0xB0, 0xF7, 0, 0], None);
+        assert_eq!(
+            check(&packet[..packet.len() - 2], None),
+            ChecksumCheck::Invalid
+        );
+    }
+}
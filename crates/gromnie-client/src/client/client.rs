@@ -1,31 +1,53 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::io::Cursor;
 use std::net::SocketAddr;
 
 use asheron_rs::enums::{
-    AuthFlags, FragmentGroup, GameEvent as GameEventType, PacketHeaderFlags, S2CMessage,
+    AuthFlags, ConfirmationType, FragmentGroup, GameEvent as GameEventType, PacketHeaderFlags,
+    S2CMessage,
 };
 
 use asheron_rs::gameactions::CharacterLoginCompleteNotification;
 use asheron_rs::message::{C2SMessage, GameActionMessage};
 use asheron_rs::messages::c2s::{
-    CharacterSendCharGenResult, LoginSendEnterWorld, LoginSendEnterWorldRequest,
+    CharacterSendCharGenResult, LoginLogOffCharacter, LoginSendEnterWorld,
+    LoginSendEnterWorldRequest,
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 
 // Import from our new modules
-use crate::client::connection::ServerInfo;
+use crate::client::allegiance::AllegianceRoster;
+use crate::client::character_sheet::{CharacterSheet, attribute_value, skill_value};
+use crate::client::chat::{ChatCommand, ChatPacer, parse_chat_message};
+use crate::client::checksum::{ChecksumCheck, check_received_checksum};
+use crate::client::confirmations::{
+    CONFIRMATION_TYPE_FELLOWSHIP, PendingConfirmation, PendingConfirmations,
+};
+use crate::client::connection::{ServerInfo, WorldInfo};
+use crate::client::containers::OpenContainerRequests;
+use crate::client::echo::{EchoTracker, echo_response_offset};
+use crate::client::fragments::{self, FragmentReassembler};
+use crate::client::keepalive::KeepAlive;
+use crate::client::message_trace::{MessageTrace, TraceDirection};
 use crate::client::messages::{OutgoingMessage, OutgoingMessageContent};
+use crate::client::movement::{PlayerMovement, PlayerPosition};
+use crate::client::objects::ObjectTable;
+use crate::client::ping::PingTracker;
 use crate::client::protocol::{C2SPacketExt, CustomLoginRequest};
+use crate::client::reconnect::{ReconnectAction, ReconnectFailure, ReconnectState, ResumeTarget};
+use crate::client::retransmit::SentPacketBuffer;
 use crate::client::scene::{
     CharacterCreateScene, CharacterSelectScene, ClientError,
     ConnectingProgress as SceneConnectingProgress, ConnectingScene, EnteringWorldState, ErrorScene,
     InWorldScene, PatchingProgress as ScenePatchingProgress, Scene,
 };
+use crate::client::server_time::ServerClock;
 use crate::client::session::{Account, ClientSession, ConnectionState, SessionState};
+use crate::client::squelch::SquelchList;
+use crate::client::unknown_opcodes::{UnknownOpcode, UnknownOpcodes};
 
+use asheron_rs::network::RawMessage;
 use asheron_rs::network::packet::PacketHeader;
-use asheron_rs::network::{Fragment, RawMessage};
 use asheron_rs::packets::c2s_packet::C2SPacket;
 use asheron_rs::packets::s2c_packet::S2CPacket;
 use asheron_rs::readers::ACDataType;
@@ -36,27 +58,50 @@ use tracing::{debug, error, info, warn};
 use crate::client::constants::*;
 use crate::client::game_event_handler::dispatch_game_event;
 use crate::client::message_handler::dispatch_message;
+use crate::client::property_names::property_int_name;
 use crate::client::protocol_conversions::{
+    allegiance_info_response_to_game_event_msg, allegiance_login_notification_to_game_event_msg,
+    allegiance_members, allegiance_update_to_game_event_msg, appraise_info_to_game_event_msg,
+    attacker_notification_to_game_event_msg, channel_broadcast_to_game_event_msg,
+    character_confirmation_request_to_game_event_msg, defender_notification_to_game_event_msg,
+    evasion_attacker_notification_to_game_event_msg,
+    evasion_defender_notification_to_game_event_msg, fellow_member,
+    fellowship_disband_to_game_event_msg, fellowship_full_update_to_game_event_msg,
+    fellowship_quit_to_game_event_msg, fellowship_update_fellow_to_game_event_msg,
     hear_direct_speech_to_game_event_msg, magic_remove_enchantment_to_game_event_msg,
-    magic_update_enchantment_to_game_event_msg, trade_accept_trade_event_to_game_event_msg,
+    magic_update_enchantment_to_game_event_msg, magic_update_spell_to_game_event_msg,
+    player_description_to_game_event_msg, query_health_response_to_game_event_msg,
+    squelch_db_to_game_event_msg, trade_accept_trade_event_to_game_event_msg,
     trade_add_to_trade_to_game_event_msg, trade_close_trade_to_game_event_msg,
     trade_decline_trade_event_to_game_event_msg, trade_failure_to_game_event_msg,
     trade_open_trade_to_game_event_msg, trade_register_trade_to_game_event_msg,
     trade_remove_from_trade_to_game_event_msg, trade_reset_trade_event_to_game_event_msg,
-    transient_string_to_game_event_msg,
+    transient_string_to_game_event_msg, vendor_info_to_game_event_msg, vendor_item_from_profile,
+    victim_notification_other_to_game_event_msg, victim_notification_self_to_game_event_msg,
+    view_contents_items, view_contents_to_game_event_msg, weenie_error_to_game_event_msg,
+    weenie_error_with_string_to_game_event_msg,
 };
-use crate::client::{ClientEvent, ClientSystemEvent, GameEvent};
-use crate::crypto::crypto_system::CryptoSystem;
+use crate::client::{ClientEvent, ClientSystemEvent, ConnectionStats, GameEvent};
+use crate::crypto::crypto_system::{CryptoSystem, ReceiveKeys};
 use crate::crypto::magic_number::get_magic_number;
+use crate::transport::ClientTransport;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::transport::NativeUdpTransport;
-use crate::transport::{ClientTransport, TransportChannel};
 use asheron_rs::gameevents::{
-    CommunicationHearDirectSpeech, CommunicationTransientString, MagicRemoveEnchantment,
-    MagicUpdateEnchantment, TradeAcceptTrade as TradeAcceptTradeEvent, TradeAddToTrade,
-    TradeCloseTrade, TradeDeclineTrade as TradeDeclineTradeEvent, TradeOpenTrade,
-    TradeRegisterTrade, TradeRemoveFromTrade, TradeResetTrade as TradeResetTradeEvent,
-    TradeTradeFailure,
+    AllegianceAllegianceInfoResponseEvent, AllegianceAllegianceLoginNotificationEvent,
+    AllegianceAllegianceUpdate, CharacterConfirmationRequest,
+    CombatHandleAttackerNotificationEvent, CombatHandleDefenderNotificationEvent,
+    CombatHandleEvasionAttackerNotificationEvent, CombatHandleEvasionDefenderNotificationEvent,
+    CombatHandleVictimNotificationEventOther, CombatHandleVictimNotificationEventSelf,
+    CombatQueryHealthResponse, CommunicationChannelBroadcast, CommunicationHearDirectSpeech,
+    CommunicationSetSquelchDB, CommunicationTransientString, CommunicationWeenieError,
+    CommunicationWeenieErrorWithString, FellowshipDisband, FellowshipFullUpdate, FellowshipQuit,
+    FellowshipUpdateFellow, ItemOnViewContents, ItemSetAppraiseInfo, LoginPlayerDescription,
+    MagicRemoveEnchantment, MagicUpdateEnchantment, MagicUpdateSpell,
+    TradeAcceptTrade as TradeAcceptTradeEvent, TradeAddToTrade, TradeCloseTrade,
+    TradeDeclineTrade as TradeDeclineTradeEvent, TradeOpenTrade, TradeRegisterTrade,
+    TradeRemoveFromTrade, TradeResetTrade as TradeResetTradeEvent, TradeTradeFailure,
+    VendorVendorInfo,
 };
 
 /// Maximum number of packets we can send without receiving before considering connection dead
@@ -83,11 +128,12 @@ pub struct Client {
     pub recv_count: u32,
     last_ack_sent: u32,      // Track the last sequence we ACKed to the server
     unacked_send_count: u32, // Track how many sends we've done without receiving a packet
-    last_receive_time: Option<crate::instant::Instant>, // Track when we last received any packet from server
-    fragment_sequence: u32,                             // Counter for outgoing fragment sequences
-    next_game_action_sequence: u32,                     // Sequence counter for GameAction messages
-    pending_fragments: HashMap<u32, Fragment>,          // Track incomplete fragment sequences
-    message_queue: VecDeque<RawMessage>,                // Queue of parsed messages to process
+    sent_packets: SentPacketBuffer, // Recently sent sequenced packets, for retransmission
+    keepalive: KeepAlive,    // Keep-alive cadence and idle detection
+    fragment_sequence: u32,  // Counter for outgoing fragment sequences
+    next_game_action_sequence: u32, // Sequence counter for GameAction messages
+    pending_fragments: FragmentReassembler, // Track incomplete fragment sequences
+    message_queue: VecDeque<RawMessage>, // Queue of parsed messages to process
     pub(crate) outgoing_message_queue: VecDeque<OutgoingMessage>, // Queue of messages to send with optional delays
     pub(crate) raw_event_tx: mpsc::Sender<ClientEvent>,           // Raw event sender to runner
     action_rx: mpsc::UnboundedReceiver<gromnie_events::SimpleClientAction>, // Receive actions from handlers
@@ -97,14 +143,86 @@ pub struct Client {
     pub(crate) known_characters: Vec<asheron_rs::types::CharacterIdentity>, // Track characters from list and creation
     // Reconnection state
     reconnect_config: crate::config::ReconnectConfig,
-    pub(crate) reconnect_attempt_count: u32, // Track reconnection attempts across state transitions
-    pub(crate) reconnect_at: Option<crate::instant::Instant>, // When to attempt reconnection (None if not waiting)
+    pub(crate) reconnect_state: ReconnectState, // Survives scene transitions
+    /// Character to log back into after reconnecting (set once in the world)
+    pub(crate) resume_target: Option<ResumeTarget>,
     /// Optional character name to auto-login with after receiving character list
     pub(crate) character: Option<String>,
     /// Pending auto-login action to be processed after character list is received
     pub(crate) pending_auto_login: Option<gromnie_events::SimpleClientAction>,
+    /// Character to create when the account turns out to have none
+    character_creation: Option<crate::client::ace_protocol::AceCharGenResult>,
+    /// Account a character is being created for, and when the creation was
+    /// sent, until the server verifies it
+    pub(crate) creating_character: Option<(String, crate::instant::Instant)>,
     /// Cached trade registration data (set when server sends TradeRegisterTrade)
     pub(crate) pending_trade: Option<PendingTradeState>,
+    /// Allegiance members from the last AllegianceUpdate, for naming logins
+    allegiance: AllegianceRoster,
+    /// The character's position and where `move_to` is walking it
+    pub(crate) movement: PlayerMovement,
+    /// Unanswered CharacterConfirmationRequest prompts (fellowship invites, allegiance, ...)
+    pub(crate) pending_confirmations: PendingConfirmations,
+    /// Spacing for multi-line chat sent with `SendChatLines`
+    chat_pacer: ChatPacer,
+    /// Players whose chat is dropped; survives logouts and reconnects
+    pub(crate) squelch: SquelchList,
+    /// Which server port outgoing packets go to in each session phase
+    port_routing: crate::config::PortRouting,
+    /// Server/world identity (from DDDInterrogation and Login_WorldInfo)
+    pub(crate) world_info: WorldInfo,
+    /// Spells in the current character's spellbook (from MagicUpdateSpell)
+    pub(crate) known_spells: BTreeSet<u32>,
+    /// Outstanding pings and the last measured round-trip time
+    ping: PingTracker,
+    /// Server clock estimated from TIME_SYNC packets
+    server_clock: ServerClock,
+    /// Round-trip time measured from TimeSync echoes
+    echo: EchoTracker,
+    /// Message opcodes seen this session that we have no parser for
+    unknown_opcodes: UnknownOpcodes,
+    /// Packets sent and received since the client started (not reset on reconnect)
+    packets_sent: u64,
+    packets_received: u64,
+    /// Reconnection attempts made since the client started
+    reconnect_attempts: u32,
+    /// Callers waiting for the current character login to succeed or fail
+    login_waiters: Vec<oneshot::Sender<Result<(), String>>>,
+    /// Callers waiting for the server to send the character list
+    character_list_waiters: Vec<oneshot::Sender<Vec<asheron_rs::types::CharacterIdentity>>>,
+    /// Where to record every sent and received message, if tracing
+    message_trace: Option<MessageTrace>,
+    /// Drop received fragment packets whose checksum doesn't verify
+    strict_checksums: bool,
+    /// How long a partially received message waits for its missing fragments
+    fragment_timeout: std::time::Duration,
+    /// LoginRequests sent per connection attempt before retries stop
+    max_login_attempts: u32,
+    /// Attributes and skills from LoginPlayerDescription and quality updates
+    pub(crate) character_sheet: CharacterSheet,
+    /// Last reported health of other objects, in percent (from CombatQueryHealthResponse)
+    pub(crate) object_health: HashMap<u32, f32>,
+    /// Containers opened with OpenContainer that haven't reported their contents yet
+    open_container_requests: OpenContainerRequests,
+    pub(crate) objects: ObjectTable, // Objects the server has created and not yet removed
+}
+
+/// Name of a message for reporting; OrderedGameEvents use the nested event type
+fn message_type_name(message: &RawMessage) -> String {
+    if let Ok(S2CMessage::OrderedGameEvent) = S2CMessage::try_from(message.opcode)
+        && let Some(bytes) = message.data.get(8..12)
+    {
+        let event_opcode = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        return match GameEventType::try_from(event_opcode) {
+            Ok(event_type) => format!("{:?}", event_type),
+            Err(_) => format!("GameEvent 0x{:04X}", event_opcode),
+        };
+    }
+
+    match S2CMessage::try_from(message.opcode) {
+        Ok(msg_type) => format!("{:?}", msg_type),
+        Err(_) => format!("Unknown 0x{:04X}", message.opcode),
+    }
 }
 
 /// Cached state from a server TradeRegisterTrade event, needed to accept the trade
@@ -113,6 +231,17 @@ pub struct PendingTradeState {
     pub initiator_id: u32,
     pub partner_id: u32,
     pub stamp: i64,
+    /// Items we've put into the trade, in the order they were added
+    pub items: Vec<u32>,
+    /// Resets we've sent whose Trade_ResetTrade hasn't come back yet; the
+    /// items re-added after them must survive it
+    resets_in_flight: u32,
+}
+
+impl PendingTradeState {
+    pub fn contains(&self, item_id: u32) -> bool {
+        self.items.contains(&item_id)
+    }
 }
 
 impl Client {
@@ -205,9 +334,12 @@ impl Client {
             crate::config::ReconnectConfig::default()
         };
 
+        let server = ServerInfo::new(host, login_port);
+        let world_info = WorldInfo::new(&server);
+
         let client = Client {
             id,
-            server: ServerInfo::new(host, login_port),
+            server,
             account: Account { name, password },
             transport,
 
@@ -217,12 +349,13 @@ impl Client {
 
             send_count: 0,
             recv_count: 0,
-            last_ack_sent: 0,             // Initialize to 0
-            unacked_send_count: 0,        // Initialize to 0
-            last_receive_time: None,      // Initialize to None
+            last_ack_sent: 0,      // Initialize to 0
+            unacked_send_count: 0, // Initialize to 0
+            sent_packets: SentPacketBuffer::new(reconnect_config.retransmit_buffer_size),
+            keepalive: KeepAlive::default(),
             fragment_sequence: 1,         // Start at 1 as per actestclient
             next_game_action_sequence: 0, // Start at 0 for GameAction sequences
-            pending_fragments: HashMap::new(),
+            pending_fragments: FragmentReassembler::new(),
             message_queue: VecDeque::new(),
             outgoing_message_queue: VecDeque::new(),
             raw_event_tx, // Raw event sender to runner
@@ -232,16 +365,217 @@ impl Client {
             ddd_response: None,
             known_characters: Vec::new(),
             reconnect_config,
-            reconnect_attempt_count: 0,
-            reconnect_at: None,
+            reconnect_state: ReconnectState::Idle,
+            resume_target: None,
             character,
             pending_auto_login: None,
+            character_creation: None,
+            creating_character: None,
             pending_trade: None,
+            allegiance: AllegianceRoster::new(),
+            movement: PlayerMovement::new(),
+            pending_confirmations: PendingConfirmations::new(),
+            chat_pacer: ChatPacer::new(),
+            squelch: SquelchList::new(),
+            port_routing: crate::config::PortRouting::default(),
+            world_info,
+            known_spells: BTreeSet::new(),
+            ping: PingTracker::new(),
+            server_clock: ServerClock::new(),
+            unknown_opcodes: UnknownOpcodes::new(),
+            echo: EchoTracker::new(crate::instant::Instant::now()),
+            packets_sent: 0,
+            packets_received: 0,
+            reconnect_attempts: 0,
+            login_waiters: Vec::new(),
+            character_list_waiters: Vec::new(),
+            message_trace: None,
+            strict_checksums: false,
+            fragment_timeout: super::fragments::DEFAULT_FRAGMENT_TIMEOUT,
+            max_login_attempts: DEFAULT_MAX_LOGIN_ATTEMPTS,
+            character_sheet: CharacterSheet::new(),
+            object_health: HashMap::new(),
+            open_container_requests: OpenContainerRequests::new(),
+            objects: ObjectTable::new(),
         };
 
         (client, action_tx)
     }
 
+    /// Server/world identity learned so far during this connection
+    pub fn world_info(&self) -> &WorldInfo {
+        &self.world_info
+    }
+
+    /// Characters on the account, from the last character list plus any
+    /// created since. Empty until the server has sent the list.
+    pub fn known_characters(&self) -> &[asheron_rs::types::CharacterIdentity] {
+        &self.known_characters
+    }
+
+    /// Spells known to the current character: the spellbook from
+    /// LoginPlayerDescription plus any learned since via MagicUpdateSpell
+    pub fn known_spells(&self) -> &BTreeSet<u32> {
+        &self.known_spells
+    }
+
+    /// Replace the known spells with a complete spellbook
+    pub fn load_spell_book(&mut self, spell_ids: impl IntoIterator<Item = u32>) {
+        self.known_spells = spell_ids.into_iter().collect();
+    }
+
+    /// Attributes and skills of the current character
+    ///
+    /// Check `received` before trusting the values; everything is default until
+    /// LoginPlayerDescription arrives.
+    pub fn character_sheet(&self) -> &CharacterSheet {
+        &self.character_sheet
+    }
+
+    /// Last reported health of an object as a percentage (0.0-100.0)
+    ///
+    /// Only objects that have been queried with `QueryHealth` (or whose health
+    /// the server otherwise reported) have a value.
+    pub fn object_health(&self, object_id: u32) -> Option<f32> {
+        self.object_health.get(&object_id).copied()
+    }
+
+    /// Objects the server has created and not yet deleted or removed
+    pub fn objects(&self) -> &ObjectTable {
+        &self.objects
+    }
+
+    /// Most recent ping round-trip time, if a ping has been answered
+    pub fn last_ping_rtt(&self) -> Option<std::time::Duration> {
+        self.ping.last_rtt()
+    }
+
+    /// Estimated server time in milliseconds, once the server has sent a TIME_SYNC
+    pub fn server_time_millis(&self) -> Option<u64> {
+        self.server_clock
+            .server_time_at(crate::instant::Instant::now())
+            .map(|secs| (secs * 1000.0).max(0.0) as u64)
+    }
+
+    /// Publish a system event from outside the client, e.g. a script failure
+    /// reported by the script runner
+    pub fn emit_system_event(&self, event: ClientSystemEvent) {
+        let _ = self.raw_event_tx.try_send(ClientEvent::System(event));
+    }
+
+    /// Current reconnect settings
+    pub fn reconnect_config(&self) -> &crate::config::ReconnectConfig {
+        &self.reconnect_config
+    }
+
+    /// Replace every reconnect setting, e.g. with a server's overrides merged in
+    pub fn set_reconnect_config(&mut self, config: crate::config::ReconnectConfig) {
+        self.sent_packets
+            .set_capacity(config.retransmit_buffer_size);
+        self.reconnect_config = config;
+    }
+
+    /// Set a fixed wait before the first reconnect attempt (None = use the backoff delay)
+    pub fn set_initial_reconnect_delay_secs(&mut self, secs: Option<u64>) {
+        self.reconnect_config.initial_reconnect_delay_secs = secs;
+    }
+
+    /// Vary reconnect backoff delays by up to this fraction either way (clamped to 0..=1)
+    pub fn set_reconnect_jitter(&mut self, fraction: f64) {
+        self.reconnect_config.jitter_fraction = fraction;
+    }
+
+    /// Log back into the last character after reconnecting instead of stopping at character select
+    pub fn set_resume_in_world(&mut self, resume: bool) {
+        self.reconnect_config.resume_in_world = resume;
+    }
+
+    /// Set how many sent packets are kept for retransmission (0 disables retransmission)
+    pub fn set_retransmit_buffer_size(&mut self, size: usize) {
+        self.reconnect_config.retransmit_buffer_size = size;
+        self.sent_packets.set_capacity(size);
+    }
+
+    /// Record every sent and received message to `trace`, or stop tracing
+    pub fn set_message_trace(&mut self, trace: Option<MessageTrace>) {
+        self.message_trace = trace;
+    }
+
+    /// Drop received fragment packets with a bad checksum instead of only logging them
+    pub fn set_strict_checksums(&mut self, strict: bool) {
+        self.strict_checksums = strict;
+    }
+
+    /// Set how long a partially received message waits for its missing
+    /// fragments before [`Client::sweep_fragments`] drops it
+    pub fn set_fragment_timeout(&mut self, timeout: std::time::Duration) {
+        self.fragment_timeout = timeout;
+    }
+
+    /// Create this character, then log into it, when the account has no
+    /// characters. Named after the auto-login character when there is one
+    pub fn set_character_creation(
+        &mut self,
+        char_gen: Option<crate::client::ace_protocol::AceCharGenResult>,
+    ) {
+        self.character_creation = char_gen;
+    }
+
+    /// Set how many LoginRequests are sent, the first one included, before
+    /// waiting out the login timeout without retrying
+    pub fn set_max_login_attempts(&mut self, attempts: u32) {
+        self.max_login_attempts = attempts.max(1);
+    }
+
+    /// Set how often [`Client::keepalive_due`] asks for a TimeSync keep-alive
+    pub fn set_keepalive_interval(&mut self, interval: std::time::Duration) {
+        self.keepalive.set_interval(interval);
+    }
+
+    /// Set how long the server may go without sending anything before
+    /// [`Client::check_idle_timeout`] treats the connection as dead
+    pub fn set_idle_timeout(&mut self, timeout: std::time::Duration) {
+        self.keepalive.set_idle_timeout(timeout);
+    }
+
+    /// Load the account's squelch list from `path` and save changes to it.
+    /// A file that can't be read leaves the list empty and unsaved.
+    pub fn set_squelch_file(&mut self, path: impl Into<std::path::PathBuf>) {
+        match SquelchList::load(path) {
+            Ok(squelch) => {
+                self.squelch = squelch;
+                self.emit_squelch_list();
+            }
+            Err(e) => warn!(target: "net", "Failed to load squelch list: {}", e),
+        }
+    }
+
+    /// Names whose chat is being dropped, sorted
+    pub fn squelched(&self) -> Vec<String> {
+        self.squelch.names()
+    }
+
+    /// Whether `name` is squelched, matched the same way chat senders are
+    pub fn is_name_squelched(&self, name: &str) -> bool {
+        self.squelch.contains_name(name)
+    }
+
+    fn emit_squelch_list(&self) {
+        self.emit_system_event(ClientSystemEvent::SquelchListChanged {
+            names: self.squelch.names(),
+        });
+    }
+
+    /// Set the destination-port policy used for outgoing packets
+    pub fn set_port_routing(&mut self, port_routing: crate::config::PortRouting) {
+        self.port_routing = port_routing;
+    }
+
+    /// Get the destination-port policy used for outgoing packets
+    pub fn port_routing(&self) -> &crate::config::PortRouting {
+        &self.port_routing
+    }
+
     /// Centralized packet sending with sequence management
     /// Matches actestclient's Send() method logic:
     /// - incrementSequence: increment send_count BEFORE using it
@@ -282,23 +616,13 @@ impl Client {
             ));
         }
 
-        // Also check if we haven't received ANY packet in too long (30 second timeout)
+        // Also check if we haven't received ANY packet in too long
         // This catches the case where we're only sending TimeSync packets but getting no response
-        const RECEIVE_TIMEOUT_SECS: u64 = 60;
-        if let Some(last_recv) = self.last_receive_time {
-            let time_since_last_recv = last_recv.elapsed().as_secs();
-            if time_since_last_recv > RECEIVE_TIMEOUT_SECS {
-                error!(
-                    target: "net",
-                    "Haven't received any packet from server in {}s - connection appears dead",
-                    time_since_last_recv
-                );
-                self.enter_disconnected();
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::ConnectionReset,
-                    "Server not responding",
-                ));
-            }
+        if self.check_idle_timeout(crate::instant::Instant::now()) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "Server not responding",
+            ));
         }
 
         // CRITICAL: Automatically include ACK if we have received packets that need acknowledging
@@ -322,13 +646,15 @@ impl Client {
 
         // Serialize with checksum (pass session for encryption key if fragmented)
         let buffer = packet.serialize(self.session.connection.as_ref())?;
+        if include_sequence {
+            self.sent_packets.record(packet.sequence, &buffer);
+        }
 
-        // Determine destination channel
-        let dest_channel = if packet.flags.contains(PacketHeaderFlags::CONNECT_RESPONSE) {
-            TransportChannel::World
-        } else {
-            TransportChannel::Login
-        };
+        // Determine destination channel from the current session phase
+        let dest_channel = self.port_routing.channel_for(
+            &self.session.state,
+            packet.flags.contains(PacketHeaderFlags::CONNECT_RESPONSE),
+        );
 
         debug!(
             target: "net",
@@ -342,9 +668,27 @@ impl Client {
         self.transport
             .send(&self.server, dest_channel, buffer)
             .await?;
+        self.packets_sent += 1;
         Ok(())
     }
 
+    /// Resend buffered packets the server asked for. Sequences that are no
+    /// longer buffered are logged and skipped.
+    async fn retransmit_packets(&mut self, sequences: &[u32]) {
+        let dest_channel = self.port_routing.channel_for(&self.session.state, false);
+        for &sequence in sequences {
+            let Some(bytes) = self.sent_packets.get(sequence).map(<[u8]>::to_vec) else {
+                warn!(target: "net", "Server requested retransmit of seq={}, which is no longer buffered", sequence);
+                continue;
+            };
+            debug!(target: "net", "Retransmitting seq={} ({} bytes)", sequence, bytes.len());
+            match self.transport.send(&self.server, dest_channel, bytes).await {
+                Ok(()) => self.packets_sent += 1,
+                Err(e) => error!(target: "net", "Failed to retransmit seq={}: {}", sequence, e),
+            }
+        }
+    }
+
     pub async fn recv_packet(
         &mut self,
         buf: &mut [u8],
@@ -364,6 +708,32 @@ impl Client {
         }
     }
 
+    /// Process all queued messages, reporting each message's type name and
+    /// the time spent handling it. Game events are named by their event type.
+    pub fn process_messages_timed(&mut self, mut observe: impl FnMut(&str, std::time::Duration)) {
+        while let Some(message) = self.message_queue.pop_front() {
+            let name = message_type_name(&message);
+            let start = crate::instant::Instant::now();
+            self.handle_message(message);
+            observe(&name, start.elapsed());
+        }
+    }
+
+    /// Handle a message read back from a trace as if it had just been
+    /// reassembled from the network. `data` includes the opcode.
+    ///
+    /// Nothing is ever sent during a replay, so anything the handlers queued
+    /// in reply is dropped rather than piling up over a looped replay.
+    pub fn replay_message(&mut self, data: Vec<u8>, sequence: u32) {
+        match RawMessage::from_fragment(data, sequence, 0) {
+            Ok(message) => self.handle_message(message),
+            Err(e) => {
+                error!(target: "net", "Error parsing replayed message {}: {}", sequence, e);
+            }
+        }
+        self.outgoing_message_queue.clear();
+    }
+
     /// Check if there are pending outgoing messages to send
     pub fn has_pending_outgoing_messages(&self) -> bool {
         // Check if there are any messages ready to be sent
@@ -391,9 +761,51 @@ impl Client {
         Ok(())
     }
 
+    /// Send everything in the outgoing queue before shutting down
+    ///
+    /// Delayed messages are waited for as long as they become ready within
+    /// `timeout`; anything due later is dropped. Returns how many messages
+    /// were left unsent. On wasm32, where [`crate::instant::sleep`] can't
+    /// wait, only messages that are already ready are sent.
+    pub async fn drain_outgoing_messages(
+        &mut self,
+        timeout: std::time::Duration,
+    ) -> Result<usize, std::io::Error> {
+        let give_up_at = crate::instant::Instant::now() + timeout;
+
+        loop {
+            self.send_pending_messages().await?;
+
+            let Some(next) = self
+                .outgoing_message_queue
+                .iter()
+                .filter_map(|msg| msg.deadline)
+                .min()
+            else {
+                return Ok(0);
+            };
+            // Sleeping is a no-op on wasm32, so waiting there would spin
+            if next > give_up_at || cfg!(target_arch = "wasm32") {
+                break;
+            }
+            crate::instant::sleep(next.duration_since(crate::instant::Instant::now())).await;
+        }
+
+        let dropped = self.outgoing_message_queue.len();
+        warn!(target: "net", "Dropping {} outgoing message(s) not ready within {:?}", dropped, timeout);
+        self.outgoing_message_queue.clear();
+        Ok(dropped)
+    }
+
+    /// Whether a keep-alive is due at `now` (every keep-alive interval)
+    pub fn keepalive_due(&self, now: crate::instant::Instant) -> bool {
+        self.keepalive.is_due(now)
+    }
+
     /// Send keep-alive packet (TimeSync) to maintain connection
     /// Note: ACKs should be piggybacked on outgoing packets, not sent standalone
     pub async fn send_keepalive(&mut self) -> Result<(), std::io::Error> {
+        self.keepalive.on_sent(crate::instant::Instant::now());
         if self.session.connection.is_some() {
             debug!(target: "net", "Sending TimeSync keep-alive");
             self.send_timesync().await?;
@@ -439,6 +851,60 @@ impl Client {
         Ok(())
     }
 
+    /// Attempt to log in as the specified character and get notified when the
+    /// attempt resolves
+    ///
+    /// The receiver yields `Ok(())` once the character is in the world, or the
+    /// server's error message if it sends a CharacterError. It is closed without
+    /// a value if the connection drops before either happens.
+    pub fn attempt_character_login_with_result(
+        &mut self,
+        character_id: u32,
+        character_name: String,
+        account: String,
+    ) -> Result<oneshot::Receiver<Result<(), String>>, String> {
+        self.attempt_character_login(character_id, character_name, account)?;
+        let (tx, rx) = oneshot::channel();
+        self.login_waiters.push(tx);
+        Ok(rx)
+    }
+
+    /// Get the account's characters, waiting for the server's character list
+    /// if none has arrived yet
+    ///
+    /// There's no message asking for the list: the server sends it after
+    /// authenticating and again after every logoff. The receiver yields the
+    /// cached list right away if there is one. It is closed without a value if
+    /// the connection drops first.
+    pub fn character_list_with_result(
+        &mut self,
+    ) -> Result<oneshot::Receiver<Vec<asheron_rs::types::CharacterIdentity>>, String> {
+        if matches!(self.scene, Scene::Error(_)) {
+            return Err("Not connected".to_string());
+        }
+        let (tx, rx) = oneshot::channel();
+        if self.known_characters.is_empty() {
+            self.character_list_waiters.push(tx);
+        } else {
+            let _ = tx.send(self.known_characters.clone());
+        }
+        Ok(rx)
+    }
+
+    /// Hand the character list to everyone waiting for it
+    pub(crate) fn resolve_character_list_waiters(&mut self) {
+        for waiter in self.character_list_waiters.drain(..) {
+            let _ = waiter.send(self.known_characters.clone());
+        }
+    }
+
+    /// Complete every pending login waiter with `result`
+    pub(crate) fn resolve_login_waiters(&mut self, result: Result<(), String>) {
+        for waiter in self.login_waiters.drain(..) {
+            let _ = waiter.send(result.clone());
+        }
+    }
+
     /// Send LoginComplete notification to server after receiving initial world state
     /// Also handles the transition to InWorld and emits LoginSucceeded event
     pub fn send_login_complete_notification(&mut self) {
@@ -487,26 +953,63 @@ impl Client {
 
         // Emit LoginSucceeded event to update UI
         let game_event = GameEvent::LoginSucceeded {
-            character_id,
+            character_id: character_id.into(),
             character_name: character_name.clone(),
         };
 
         let _ = self.raw_event_tx.try_send(ClientEvent::Game(game_event));
+        self.resolve_login_waiters(Ok(()));
 
         // Mark login as complete in scene
         if let Some(char_select) = self.scene.as_character_select_mut() {
             char_select.mark_login_complete();
         }
 
+        // Remember the character so a reconnect can log back into it
+        self.resume_target = Some(ResumeTarget::new(character_id, character_name.clone()));
+
         // Transition to InWorld scene now that login is complete
+        let from = self.scene.name();
         self.scene = Scene::InWorld(InWorldScene::new(character_id, character_name));
         info!(target: "net", "Scene transition: CharacterSelect -> InWorld");
+        self.emit_scene_changed(from);
     }
 
-    /// Send a chat message to the server
-    /// This sends a general chat message that will appear as a /say command
-    /// TODO: Parse message for @tell, /say, /emote, etc. commands
+    /// Send a chat line to the server
+    /// The leading token picks how it goes out: `@name rest` sends a tell,
+    /// `/e text` an emote, and `/s text` or bare text is said aloud
     fn send_chat_say(&mut self, message: String) {
+        match parse_chat_message(&message) {
+            Some(ChatCommand::Say(text)) => self.send_chat_talk(text),
+            Some(ChatCommand::Tell { recipient, message }) => {
+                self.send_chat_tell(recipient, message)
+            }
+            Some(ChatCommand::Emote(text)) => self.send_text_emote(text),
+            None => warn!(target: "net", "Not sending empty chat message: {:?}", message),
+        }
+    }
+
+    /// Queue chat lines to go out one at a time, at least
+    /// [`crate::client::chat::MIN_CHAT_LINE_INTERVAL`] apart
+    fn send_chat_lines(&mut self, lines: Vec<String>, interval: std::time::Duration) {
+        let lines: Vec<String> = lines
+            .into_iter()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        let deadlines =
+            self.chat_pacer
+                .schedule(crate::instant::Instant::now(), lines.len(), interval);
+
+        info!(target: "net", "Queuing {} chat lines", lines.len());
+        for (line, deadline) in lines.into_iter().zip(deadlines) {
+            self.outgoing_message_queue.push_back(
+                OutgoingMessage::new(OutgoingMessageContent::ChatLine(line)).at(deadline),
+            );
+        }
+    }
+
+    /// Say a message aloud, as with /say
+    fn send_chat_talk(&mut self, message: String) {
         info!(target: "net", "Sending chat say: {}", message);
 
         // Create OrderedGameAction with CommunicationTalk (for general chat)
@@ -561,6 +1064,20 @@ impl Client {
         info!(target: "net", "Chat tell message queued for sending");
     }
 
+    fn squelch(&mut self, name: &str) {
+        if self.squelch.add(name) {
+            info!(target: "net", "Squelched {}", name);
+            self.emit_squelch_list();
+        }
+    }
+
+    fn unsquelch(&mut self, name: &str) {
+        if self.squelch.remove(name) {
+            info!(target: "net", "Unsquelched {}", name);
+            self.emit_squelch_list();
+        }
+    }
+
     // ===== Direct Game Actions =====
 
     /// Get a reference to cached pending trade data (needed by scripting host to build AcceptTrade)
@@ -568,6 +1085,65 @@ impl Client {
         self.pending_trade.as_ref()
     }
 
+    /// Get the most recent outstanding fellowship invite, if any
+    pub fn pending_fellowship_invite(&self) -> Option<&PendingConfirmation> {
+        self.pending_confirmations
+            .latest(CONFIRMATION_TYPE_FELLOWSHIP)
+    }
+
+    /// Get the unanswered confirmation prompt with `context_id`, if any
+    pub fn pending_confirmation(&self, context_id: u32) -> Option<&PendingConfirmation> {
+        self.pending_confirmations.get(context_id)
+    }
+
+    /// The character's last known position, if the server has sent one
+    pub fn player_position(&self) -> Option<PlayerPosition> {
+        self.movement.position()
+    }
+
+    /// Leave the world and return to character select
+    ///
+    /// Anything still waiting to be sent is dropped first so no game actions
+    /// queued while in the world go out after the logoff request. Everything
+    /// known about the character and its surroundings is forgotten, so the
+    /// next character starts from a clean slate.
+    pub fn logout(&mut self) -> Result<(), String> {
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            return Err("Not in world".to_string());
+        }
+
+        let dropped = self.outgoing_message_queue.len();
+        self.outgoing_message_queue.clear();
+        while self.game_action_rx.try_recv().is_ok() {}
+        if dropped > 0 {
+            debug!(target: "net", "Dropped {} pending outgoing message(s) on logout", dropped);
+        }
+
+        let _ = self.raw_event_tx.try_send(ClientEvent::State(
+            crate::client::ClientStateEvent::ExitingWorld,
+        ));
+
+        self.outgoing_message_queue.push_back(OutgoingMessage::new(
+            OutgoingMessageContent::LogOffCharacter,
+        ));
+
+        self.session.transition_to(SessionState::AuthConnected);
+        self.known_spells.clear();
+        self.character_sheet.clear();
+        self.object_health.clear();
+        self.open_container_requests.clear();
+        self.objects.clear();
+        self.allegiance.clear();
+        self.movement.clear();
+        self.pending_confirmations.clear();
+        self.pending_trade = None;
+        self.resume_target = None;
+        self.transition_to_char_select(self.known_characters.clone());
+
+        info!(target: "net", "Sent LoginLogOffCharacter");
+        Ok(())
+    }
+
     /// Serialize and enqueue a GameActionMessage for sending to the server
     pub fn queue_game_action(&mut self, action: GameActionMessage) {
         let mut message_data = Vec::new();
@@ -587,9 +1163,40 @@ impl Client {
         ));
     }
 
+    /// Queue a game action serialized with one of the `ace_protocol` types
+    /// instead of asheron_rs
+    fn queue_ace_game_action(&mut self, action_type: u32, body: &dyn ACWritable) {
+        let mut message_data = Vec::new();
+        {
+            let mut cursor = Cursor::new(&mut message_data);
+            let result = write_u32(&mut cursor, ORDERED_GAME_ACTION_OPCODE)
+                .and_then(|()| write_u32(&mut cursor, self.next_game_action_sequence))
+                .and_then(|()| write_u32(&mut cursor, action_type))
+                .and_then(|()| body.write(&mut cursor));
+            if let Err(e) = result {
+                error!(target: "net", "Failed to serialize game action 0x{:04X}: {}", action_type, e);
+                return;
+            }
+            self.next_game_action_sequence += 1;
+        }
+
+        self.outgoing_message_queue.push_back(OutgoingMessage::new(
+            OutgoingMessageContent::GameAction(message_data),
+        ));
+    }
+
     /// Drain the direct game action channel and queue each message for sending
     pub fn process_game_actions(&mut self) {
         while let Ok(action) = self.game_action_rx.try_recv() {
+            // Game actions are meaningless outside the world, e.g. after logout
+            if !matches!(self.scene, Scene::InWorld(_)) {
+                debug!(target: "net", "Dropping game action sent outside of world");
+                continue;
+            }
+            // Answering a confirmation prompt consumes it
+            if let GameActionMessage::CharacterConfirmationResponse(ref response) = action {
+                self.pending_confirmations.take(response.context);
+            }
             self.queue_game_action(action);
         }
     }
@@ -610,96 +1217,554 @@ impl Client {
         ));
     }
 
-    fn send_stop_movement_command(&mut self, motion: u32, hold_key: u32) {
-        use asheron_rs::enums::HoldKey;
-        use asheron_rs::gameactions::MovementStopMovementCommand;
+    fn send_use_item(&mut self, object_id: u32) {
+        use asheron_rs::gameactions::InventoryUseEvent;
+        use asheron_rs::types::ObjectId;
 
-        let hold_key_enum = HoldKey::try_from(hold_key).unwrap_or(HoldKey::None);
-        info!(target: "net", "Sending stop movement command: motion=0x{:08X}, hold_key={:?}",
-            motion, hold_key_enum);
-        self.queue_game_action(GameActionMessage::MovementStopMovementCommand(
-            MovementStopMovementCommand {
-                motion,
-                hold_key: hold_key_enum,
-            },
-        ));
+        info!(target: "net", "Using item 0x{:08X}", object_id);
+        self.queue_game_action(GameActionMessage::InventoryUseEvent(InventoryUseEvent {
+            object_id: ObjectId(object_id),
+        }));
     }
 
-    /// Send a TimeSync packet to keep connection alive
-    /// Uses includeSequence=false, incrementSequence=false (sequence will be 0)
-    async fn send_timesync(&mut self) -> Result<(), std::io::Error> {
-        let (client_id, table) = {
-            let connection = self
-                .session
-                .connection
-                .as_ref()
-                .ok_or_else(|| std::io::Error::other("Session not established"))?;
-            (connection.client_id, connection.table)
-        };
+    fn send_drop_item(&mut self, object_id: u32) {
+        use asheron_rs::gameactions::InventoryDropItem;
+        use asheron_rs::types::ObjectId;
 
-        // Get current time as Unix timestamp (seconds since epoch)
-        let current_time = {
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs()
-            }
-            #[cfg(target_arch = "wasm32")]
-            {
-                (js_sys::Date::now() / 1000.0) as u64
-            }
-        };
+        info!(target: "net", "Dropping item 0x{:08X}", object_id);
+        self.queue_game_action(GameActionMessage::InventoryDropItem(InventoryDropItem {
+            object_id: ObjectId(object_id),
+        }));
+    }
 
-        // CRITICAL: Only set recipient_id and iteration if client_id > 0 (matches actestclient line 292-297)
-        // When client_id is 0, these should be 0 (default values)
-        let (recipient_id, iteration) = if client_id > 0 {
-            (client_id, table)
-        } else {
-            (0, 0)
-        };
+    fn send_query_health(&mut self, object_id: u32) {
+        use asheron_rs::gameactions::CombatQueryHealth;
+        use asheron_rs::types::ObjectId;
 
-        let packet = C2SPacket {
-            sequence: 0, // Will be set by send_packet
-            flags: PacketHeaderFlags::TIME_SYNC,
-            checksum: 0,
-            recipient_id,
-            time_since_last_packet: 0,
-            size: 8, // TimeSync payload is a u64
-            iteration,
-            server_switch: None,
-            retransmit_sequences: None,
-            reject_sequences: None,
-            ack_sequence: None,
-            login_request: None,
-            world_login_request: None,
-            connect_response: None,
-            cicmd_command: None,
-            time: Some(current_time),
-            echo_time: None,
-            flow: None,
-            fragments: None,
-        };
+        debug!(target: "net", "Querying health of 0x{:08X}", object_id);
+        self.queue_game_action(GameActionMessage::CombatQueryHealth(CombatQueryHealth {
+            object_id: ObjectId(object_id),
+        }));
+    }
 
-        // TimeSync: includeSequence=false, incrementSequence=false (like actestclient line 678)
-        self.send_packet(packet, false, false).await
+    fn send_appraise(&mut self, object_id: u32) {
+        use asheron_rs::gameactions::ItemAppraise;
+        use asheron_rs::types::ObjectId;
+
+        debug!(target: "net", "Appraising 0x{:08X}", object_id);
+        self.queue_game_action(GameActionMessage::ItemAppraise(ItemAppraise {
+            object_id: ObjectId(object_id),
+        }));
     }
 
-    /// Get the client ID
-    pub fn client_id(&self) -> u32 {
-        self.id
+    /// Answer the confirmation prompt with `context_id`, consuming it
+    fn send_confirmation_response(&mut self, context_id: u32, accept: bool) -> Result<(), String> {
+        use asheron_rs::gameactions::CharacterConfirmationResponse;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            return Err("not in world".to_string());
+        }
+        let confirmation = self
+            .pending_confirmations
+            .take(context_id)
+            .ok_or_else(|| "no pending confirmation with that context".to_string())?;
+        let type_ = ConfirmationType::try_from(confirmation.confirmation_type).map_err(|_| {
+            format!(
+                "unknown confirmation type {}",
+                confirmation.confirmation_type
+            )
+        })?;
+        self.queue_game_action(GameActionMessage::CharacterConfirmationResponse(
+            CharacterConfirmationResponse {
+                type_,
+                context: context_id,
+                accepted: accept,
+            },
+        ));
+        Ok(())
     }
 
-    /// Check if current state has timed out (20s timeout for Connecting and Patching)
-    pub fn check_state_timeout(&mut self) -> bool {
-        const TIMEOUT_DURATION: std::time::Duration = std::time::Duration::from_secs(20);
+    fn send_fellowship_create(&mut self, name: String) {
+        use asheron_rs::gameactions::FellowshipCreate;
 
-        if let Some(connecting) = self.scene.as_connecting()
-            && connecting.has_timed_out(TIMEOUT_DURATION)
-        {
-            // Determine if we're in Connecting or Patching phase
-            let phase = if matches!(connecting.patch_progress, PatchingProgress::NotStarted) {
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring fellowship create outside of world");
+            return;
+        }
+        info!(target: "net", "Creating fellowship {}", name);
+        self.queue_game_action(GameActionMessage::FellowshipCreate(FellowshipCreate {
+            name,
+            share_xp: true,
+        }));
+    }
+
+    fn send_fellowship_invite(&mut self, object_id: u32) {
+        use asheron_rs::gameactions::FellowshipRecruit;
+        use asheron_rs::types::ObjectId;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring fellowship invite outside of world");
+            return;
+        }
+        let Some(player) = self.objects.get(object_id) else {
+            warn!(target: "net", "Not inviting unknown object 0x{:08X} to the fellowship", object_id);
+            return;
+        };
+        info!(target: "net", "Inviting {} (0x{:08X}) to the fellowship", player.name, object_id);
+        self.queue_game_action(GameActionMessage::FellowshipRecruit(FellowshipRecruit {
+            object_id: ObjectId(object_id),
+        }));
+    }
+
+    fn send_fellowship_dismiss(&mut self, object_id: u32) {
+        use asheron_rs::gameactions::FellowshipDismiss;
+        use asheron_rs::types::ObjectId;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring fellowship dismiss outside of world");
+            return;
+        }
+        info!(target: "net", "Dismissing 0x{:08X} from the fellowship", object_id);
+        self.queue_game_action(GameActionMessage::FellowshipDismiss(FellowshipDismiss {
+            object_id: ObjectId(object_id),
+        }));
+    }
+
+    fn send_join_channel(&mut self, channel: u32) {
+        use asheron_rs::enums::Channel;
+        use asheron_rs::gameactions::CommunicationAddToChannel;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring channel join outside of world");
+            return;
+        }
+        info!(target: "net", "Joining channel 0x{:08X}", channel);
+        self.queue_game_action(GameActionMessage::CommunicationAddToChannel(
+            CommunicationAddToChannel {
+                channel: Channel::from_bits_retain(channel),
+            },
+        ));
+    }
+
+    fn send_leave_channel(&mut self, channel: u32) {
+        use asheron_rs::enums::Channel;
+        use asheron_rs::gameactions::CommunicationRemoveFromChannel;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring channel leave outside of world");
+            return;
+        }
+        info!(target: "net", "Leaving channel 0x{:08X}", channel);
+        self.queue_game_action(GameActionMessage::CommunicationRemoveFromChannel(
+            CommunicationRemoveFromChannel {
+                channel: Channel::from_bits_retain(channel),
+            },
+        ));
+    }
+
+    fn send_fellowship_quit(&mut self) {
+        use asheron_rs::gameactions::FellowshipQuit;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring fellowship quit outside of world");
+            return;
+        }
+        info!(target: "net", "Leaving the fellowship");
+        // A leader who quits hands the fellowship on rather than disbanding it
+        self.queue_game_action(GameActionMessage::FellowshipQuit(FellowshipQuit {
+            disband: false,
+        }));
+    }
+
+    fn send_trade_add(&mut self, item_id: u32, slot: u32) {
+        use asheron_rs::gameactions::TradeAddToTrade;
+        use asheron_rs::types::ObjectId;
+
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring trade add outside of world");
+            return;
+        }
+        if let Some(trade) = self.pending_trade.as_mut()
+            && !trade.contains(item_id)
+        {
+            trade.items.push(item_id);
+        }
+        self.queue_game_action(GameActionMessage::TradeAddToTrade(TradeAddToTrade {
+            object_id: ObjectId(item_id),
+            slot_index: slot,
+        }));
+    }
+
+    fn send_trade_remove(&mut self, item_id: u32) {
+        use asheron_rs::gameactions::{TradeAddToTrade, TradeResetTrade};
+        use asheron_rs::types::ObjectId;
+
+        let Some(trade) = self
+            .pending_trade
+            .as_mut()
+            .filter(|trade| trade.contains(item_id))
+        else {
+            warn!(target: "net", "Item 0x{:08X} isn't in the trade", item_id);
+            return;
+        };
+        trade.items.retain(|&id| id != item_id);
+        trade.resets_in_flight += 1;
+        let remaining = trade.items.clone();
+
+        info!(target: "net", "Removing 0x{:08X} from the trade", item_id);
+        self.queue_game_action(GameActionMessage::TradeResetTrade(TradeResetTrade {}));
+        for (slot, id) in remaining.into_iter().enumerate() {
+            self.queue_game_action(GameActionMessage::TradeAddToTrade(TradeAddToTrade {
+                object_id: ObjectId(id),
+                slot_index: slot as u32,
+            }));
+        }
+    }
+
+    fn send_trade_accept(&mut self) {
+        use asheron_rs::gameactions::TradeAcceptTrade;
+        use asheron_rs::types::{ObjectId, Trade};
+
+        let Some(trade) = &self.pending_trade else {
+            warn!(target: "net", "Not accepting trade: no trade is registered");
+            return;
+        };
+        let contents = Trade {
+            partner_id: ObjectId(trade.partner_id),
+            sequence: trade.stamp as u64,
+            status: 0,
+            initiator_id: ObjectId(trade.initiator_id),
+            accepted: true,
+            partner_accepted: false,
+        };
+        info!(target: "net", "Accepting trade with 0x{:08X}", trade.partner_id);
+        self.queue_game_action(GameActionMessage::TradeAcceptTrade(TradeAcceptTrade {
+            contents,
+        }));
+    }
+
+    fn send_trade_decline(&mut self) {
+        use asheron_rs::gameactions::TradeDeclineTrade;
+
+        info!(target: "net", "Declining trade");
+        self.queue_game_action(GameActionMessage::TradeDeclineTrade(TradeDeclineTrade {}));
+    }
+
+    fn send_trade_reset(&mut self) {
+        use asheron_rs::gameactions::TradeResetTrade;
+
+        let Some(trade) = self.pending_trade.as_mut() else {
+            warn!(target: "net", "No trade to reset");
+            return;
+        };
+        trade.items.clear();
+        trade.resets_in_flight += 1;
+
+        info!(target: "net", "Resetting trade");
+        self.queue_game_action(GameActionMessage::TradeResetTrade(TradeResetTrade {}));
+    }
+
+    /// Open a container by using it; its contents arrive as ItemOnViewContents
+    fn send_open_container(&mut self, container_id: u32) {
+        self.send_use_item(container_id);
+        self.open_container_requests
+            .request(container_id, crate::instant::Instant::now());
+    }
+
+    /// Tell scripts a requested container's contents arrived
+    fn report_container_contents(&mut self, container_id: u32, items: &[u32]) {
+        let now = crate::instant::Instant::now();
+        for expired in self.open_container_requests.expire(now) {
+            warn!(target: "net", "No contents received for container 0x{:08X} after {:?}", expired, crate::client::OPEN_CONTAINER_TIMEOUT);
+        }
+        if self.open_container_requests.take(container_id, now) {
+            let _ = self
+                .raw_event_tx
+                .try_send(ClientEvent::Game(GameEvent::ContainerContents {
+                    container_id: container_id.into(),
+                    items: items.iter().map(|&id| id.into()).collect(),
+                }));
+        } else {
+            debug!(target: "net", "Contents of container 0x{:08X} were not requested by a script", container_id);
+        }
+    }
+
+    /// Send a script-built game action as-is
+    fn send_raw_game_action(&mut self, opcode: u32, payload: Vec<u8>) {
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring raw game action 0x{:04X} outside of world", opcode);
+            return;
+        }
+
+        info!(target: "net", "Sending raw game action 0x{:04X} ({} bytes)", opcode, payload.len());
+        self.queue_ace_game_action(
+            opcode,
+            &crate::client::ace_protocol::RawGameActionBody(payload),
+        );
+    }
+
+    fn send_vendor_transaction(&mut self, buy: bool, vendor_id: u32, item_id: u32, quantity: u32) {
+        use crate::client::ace_protocol::{
+            AceVendorBuy, AceVendorItem, AceVendorSell, VENDOR_BUY_ACTION, VENDOR_SELL_ACTION,
+        };
+        use asheron_rs::types::PackableList;
+
+        let verb = if buy { "buy" } else { "sell" };
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            debug!(target: "net", "Ignoring vendor {} outside of world", verb);
+            return;
+        }
+
+        debug!(target: "net", "Vendor 0x{:08X}: {} {} x 0x{:08X}", vendor_id, verb, quantity, item_id);
+        let items = PackableList {
+            count: 1,
+            list: vec![AceVendorItem {
+                amount: quantity,
+                object_id: item_id,
+            }],
+        };
+        if buy {
+            self.queue_ace_game_action(
+                VENDOR_BUY_ACTION,
+                &AceVendorBuy {
+                    vendor_id,
+                    items,
+                    alternate_currency_id: 0,
+                },
+            );
+        } else {
+            self.queue_ace_game_action(VENDOR_SELL_ACTION, &AceVendorSell { vendor_id, items });
+        }
+    }
+
+    fn send_emote(&mut self, emote: gromnie_events::EmoteKind) {
+        info!(target: "net", "Sending emote: {:?}", emote);
+        self.queue_game_action(crate::client::emote::emote_action(emote));
+    }
+
+    /// Send a Character_PingRequest; the server answers with Character_ReturnPing
+    pub fn send_ping(&mut self) {
+        use asheron_rs::gameactions::CharacterPingRequest;
+
+        debug!(target: "net", "Sending ping");
+        self.queue_game_action(GameActionMessage::CharacterPingRequest(
+            CharacterPingRequest {},
+        ));
+        self.ping.on_sent(crate::instant::Instant::now());
+    }
+
+    /// Send a ping if in the world and one is due. Returns true if a ping was queued.
+    pub fn tick_ping(&mut self, now: crate::instant::Instant) -> bool {
+        if !matches!(self.scene, Scene::InWorld(_)) || !self.ping.is_due(now) {
+            return false;
+        }
+        self.send_ping();
+        true
+    }
+
+    /// Walk toward the `move_to` destination if in the world and a position
+    /// update is due. Returns true if an update was queued.
+    pub fn tick_movement(&mut self, now: crate::instant::Instant) -> bool {
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            return false;
+        }
+        let Some(position) = self.movement.step(now) else {
+            return false;
+        };
+        if !self.movement.is_moving() {
+            info!(target: "net", "Arrived at ({}, {}, {})", position.x, position.y, position.z);
+        }
+        self.send_autonomous_position(position);
+        true
+    }
+
+    /// Tell the server where the character is now (Movement_AutonomousPosition)
+    fn send_autonomous_position(&mut self, position: PlayerPosition) {
+        use asheron_rs::gameactions::MovementAutonomousPosition;
+        use asheron_rs::types::{AutonomousPositionPack, Frame, Position, Quaternion, Vector3};
+
+        debug!(target: "net", "Sending position: cell 0x{:08X} ({}, {}, {}) heading {}",
+            position.landcell, position.x, position.y, position.z, position.heading);
+        let (w, x, y, z) = position.rotation();
+        let sequences = self.movement.sequences();
+        self.queue_game_action(GameActionMessage::MovementAutonomousPosition(
+            MovementAutonomousPosition {
+                position: AutonomousPositionPack {
+                    position: Position {
+                        landcell: position.landcell.into(),
+                        frame: Frame {
+                            origin: Vector3 {
+                                x: position.x,
+                                y: position.y,
+                                z: position.z,
+                            },
+                            orientation: Quaternion { w, x, y, z },
+                        },
+                    },
+                    instance_sequence: sequences.instance,
+                    // The client never takes server control of its own character
+                    server_control_sequence: 0,
+                    teleport_sequence: sequences.teleport,
+                    force_position_sequence: sequences.force_position,
+                    last_contact: 1,
+                },
+            },
+        ));
+    }
+
+    fn move_to(&mut self, x: f32, y: f32, z: f32) {
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            warn!(target: "net", "Ignoring move_to outside the world");
+            return;
+        }
+        match self
+            .movement
+            .move_to(x, y, z, crate::instant::Instant::now())
+        {
+            Ok(()) => info!(target: "net", "Walking to ({}, {}, {})", x, y, z),
+            Err(e) => warn!(target: "net", "Can't walk to ({}, {}, {}): {}", x, y, z, e),
+        }
+    }
+
+    fn turn_to(&mut self, heading: f32) {
+        if !matches!(self.scene, Scene::InWorld(_)) {
+            warn!(target: "net", "Ignoring turn_to outside the world");
+            return;
+        }
+        match self.movement.turn_to(heading) {
+            Ok(position) => {
+                info!(target: "net", "Turning to heading {}", position.heading);
+                self.send_autonomous_position(position);
+            }
+            Err(e) => warn!(target: "net", "Can't turn to {}: {}", heading, e),
+        }
+    }
+
+    /// Drop partially received messages that have waited longer than the
+    /// fragment timeout. Returns the sequences that were dropped.
+    pub fn sweep_fragments(&mut self, now: crate::instant::Instant) -> Vec<u32> {
+        self.pending_fragments.sweep(now, self.fragment_timeout)
+    }
+
+    fn handle_return_ping(&mut self, sequence: u32) {
+        let Some(rtt) = self.ping.on_reply(sequence, crate::instant::Instant::now()) else {
+            debug!(target: "net", "Ignoring stale or unmatched ReturnPing (sequence {})", sequence);
+            return;
+        };
+
+        let rtt_millis = rtt.as_millis() as u64;
+        debug!(target: "net", "Ping round trip: {} ms", rtt_millis);
+        let _ = self
+            .raw_event_tx
+            .try_send(ClientEvent::System(ClientSystemEvent::PingResult {
+                rtt_millis,
+            }));
+    }
+
+    fn send_text_emote(&mut self, text: String) {
+        info!(target: "net", "Sending text emote: {}", text);
+        self.queue_game_action(crate::client::emote::text_emote_action(text));
+    }
+
+    fn send_stop_movement_command(&mut self, motion: u32, hold_key: u32) {
+        use asheron_rs::enums::HoldKey;
+        use asheron_rs::gameactions::MovementStopMovementCommand;
+
+        let hold_key_enum = HoldKey::try_from(hold_key).unwrap_or(HoldKey::None);
+        info!(target: "net", "Sending stop movement command: motion=0x{:08X}, hold_key={:?}",
+            motion, hold_key_enum);
+        self.queue_game_action(GameActionMessage::MovementStopMovementCommand(
+            MovementStopMovementCommand {
+                motion,
+                hold_key: hold_key_enum,
+            },
+        ));
+    }
+
+    /// Send a TimeSync packet to keep connection alive
+    /// Uses includeSequence=false, incrementSequence=false (sequence will be 0)
+    async fn send_timesync(&mut self) -> Result<(), std::io::Error> {
+        let (client_id, table) = {
+            let connection = self
+                .session
+                .connection
+                .as_ref()
+                .ok_or_else(|| std::io::Error::other("Session not established"))?;
+            (connection.client_id, connection.table)
+        };
+
+        // Prefer our estimate of the server clock; fall back to the Unix
+        // timestamp (seconds since epoch) until the server has synced with us
+        let server_time = self
+            .server_clock
+            .server_time_at(crate::instant::Instant::now());
+        let current_time = server_time
+            .map(|secs| secs.max(0.0) as u64)
+            .unwrap_or_else(|| {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs()
+                }
+                #[cfg(target_arch = "wasm32")]
+                {
+                    (js_sys::Date::now() / 1000.0) as u64
+                }
+            });
+
+        // CRITICAL: Only set recipient_id and iteration if client_id > 0 (matches actestclient line 292-297)
+        // When client_id is 0, these should be 0 (default values)
+        let (recipient_id, iteration) = if client_id > 0 {
+            (client_id, table)
+        } else {
+            (0, 0)
+        };
+
+        // Ask the server to echo a local timestamp back so we can measure RTT
+        let echo_time = self.echo.stamp(crate::instant::Instant::now());
+
+        let packet = C2SPacket {
+            sequence: 0, // Will be set by send_packet
+            flags: PacketHeaderFlags::TIME_SYNC | PacketHeaderFlags::ECHO_REQUEST,
+            checksum: 0,
+            recipient_id,
+            time_since_last_packet: 0,
+            size: 12, // TimeSync u64 + echo request f32
+            iteration,
+            server_switch: None,
+            retransmit_sequences: None,
+            reject_sequences: None,
+            ack_sequence: None,
+            login_request: None,
+            world_login_request: None,
+            connect_response: None,
+            cicmd_command: None,
+            time: Some(current_time),
+            echo_time: Some(echo_time),
+            flow: None,
+            fragments: None,
+        };
+
+        // TimeSync: includeSequence=false, incrementSequence=false (like actestclient line 678)
+        self.send_packet(packet, false, false).await
+    }
+
+    /// Get the client ID
+    pub fn client_id(&self) -> u32 {
+        self.id
+    }
+
+    /// Check if current state has timed out (20s timeout for Connecting and Patching)
+    pub fn check_state_timeout(&mut self) -> bool {
+        const TIMEOUT_DURATION: std::time::Duration = std::time::Duration::from_secs(20);
+
+        if let Some(connecting) = self.scene.as_connecting()
+            && connecting.has_timed_out(TIMEOUT_DURATION)
+        {
+            // Determine if we're in Connecting or Patching phase
+            let phase = if matches!(connecting.patch_progress, PatchingProgress::NotStarted) {
                 "LoginRequest"
             } else {
                 "Patching"
@@ -708,11 +1773,11 @@ impl Client {
             info!(target: "net", "{} timeout - no response after 20s (patch_progress: {:?})", phase, connecting.patch_progress);
 
             // Reconnection behavior on timeout:
-            // - Initial connection attempts (reconnect_attempt_count == 0) always fail permanently
+            // - Initial connection attempts (attempt == 0) always fail permanently
             //   to provide fast feedback when the server is genuinely unavailable
-            // - Subsequent reconnection attempts (reconnect_attempt_count > 0) re-enter Disconnected
+            // - Subsequent reconnection attempts (attempt > 0) re-enter Disconnected
             //   state to retry with exponential backoff
-            if self.reconnect_config.enabled && self.reconnect_attempt_count > 0 {
+            if self.reconnect_config.enabled && self.reconnect_state.attempt() > 0 {
                 info!(target: "net", "Reconnection attempt timed out - re-entering Disconnected state to retry");
                 self.enter_disconnected();
             } else {
@@ -723,7 +1788,7 @@ impl Client {
                     ClientError::PatchingTimeout
                 };
 
-                self.scene = Scene::Error(ErrorScene::new(error, false));
+                self.transition_to_error(error, false);
 
                 // Emit authentication failed system event
                 let _ = self.raw_event_tx.try_send(ClientEvent::System(
@@ -737,12 +1802,16 @@ impl Client {
         false
     }
 
-    /// Check if it's time to retry in current state (2s retry interval)
+    /// Check if it's time to resend the LoginRequest (2s retry interval).
+    /// Stops once the server answers with a ConnectRequest or the attempt
+    /// cap is reached.
     pub fn should_retry(&self) -> bool {
         const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
 
         if let Some(connecting) = self.scene.as_connecting() {
-            connecting.should_retry(RETRY_INTERVAL)
+            connecting.awaiting_connect_request()
+                && connecting.login_attempts < self.max_login_attempts
+                && connecting.should_retry(RETRY_INTERVAL)
         } else {
             false
         }
@@ -755,101 +1824,137 @@ impl Client {
         }
     }
 
-    /// Check if client should attempt to reconnect
-    pub fn should_reconnect(&mut self) -> bool {
-        if let Some(reconnect_at) = self.reconnect_at {
-            // Check if we've reached the reconnect time
-            return crate::instant::Instant::now() >= reconnect_at;
-        }
-        false
+    /// Round-trip time to the server, averaged over recent TimeSync echoes
+    pub fn rtt(&self) -> Option<std::time::Duration> {
+        self.echo.smoothed_rtt()
     }
 
-    /// Initiate reconnection attempt
-    /// Returns true if reconnection is being attempted, false if disabled or max retries reached
-    /// Transitions the client to Connecting state with an appropriate backoff delay
-    pub fn start_reconnection(&mut self) -> bool {
-        if !self.reconnect_config.enabled {
-            info!(target: "net", "Reconnection disabled, entering Error state");
-            self.scene = Scene::Error(ErrorScene::new(
-                ClientError::ConnectionFailed(
-                    "Connection lost and reconnection disabled".to_string(),
-                ),
-                false,
-            ));
-            return false;
-        }
-
-        // Check if we've exceeded max retry attempts
-        if !self
-            .reconnect_config
-            .should_attempt_reconnect(self.reconnect_attempt_count)
-        {
-            error!(
-                target: "net",
-                "Max reconnection attempts reached ({}), entering Error state",
-                self.reconnect_config.max_attempts
-            );
-            self.scene = Scene::Error(ErrorScene::new(
-                ClientError::ConnectionFailed(format!(
-                    "Max reconnection attempts ({}) reached",
-                    self.reconnect_config.max_attempts
-                )),
-                false,
-            ));
-            return false;
+    /// Packet counters and the smoothed round-trip time
+    pub fn stats(&self) -> ConnectionStats {
+        ConnectionStats {
+            packets_sent: self.packets_sent,
+            packets_received: self.packets_received,
+            unacked_sends: self.unacked_send_count,
+            reconnect_attempts: self.reconnect_attempts,
+            rtt_millis: self.rtt().map(|rtt| rtt.as_millis() as u64),
+            unknown_opcodes: self.unknown_opcodes.count(),
         }
+    }
 
-        let delay = self
-            .reconnect_config
-            .delay_for_attempt(self.reconnect_attempt_count);
-
-        info!(
-            target: "net",
-            "Starting reconnection attempt {} (waiting {:?} before reconnecting)",
-            self.reconnect_attempt_count, delay
-        );
+    /// Emit a ConnectionStats event with the current [`Client::stats`]
+    pub fn publish_stats(&self) {
+        let _ =
+            self.raw_event_tx
+                .try_send(ClientEvent::System(ClientSystemEvent::ConnectionStats(
+                    self.stats(),
+                )));
+    }
 
-        // Emit reconnection event
-        let delay_secs: u64 = delay.as_secs();
-        let _ = self
-            .raw_event_tx
-            .try_send(ClientEvent::System(ClientSystemEvent::Reconnecting {
-                attempt: self.reconnect_attempt_count,
-                delay_secs,
-            }));
+    /// Current position in the reconnection cycle
+    pub fn reconnect_state(&self) -> ReconnectState {
+        self.reconnect_state
+    }
 
-        // Transition back to Connecting scene for reconnection attempt
-        self.scene = Scene::Connecting(ConnectingScene::new());
+    /// Advance the reconnection state machine to `now`
+    ///
+    /// On [`ReconnectAction::Reconnect`] the client is back in the Connecting scene and
+    /// the caller should send a LoginRequest. On [`ReconnectAction::GiveUp`] the client
+    /// is in the Error scene and the caller should stop.
+    pub fn tick_reconnect(&mut self, now: crate::instant::Instant) -> ReconnectAction {
+        let action = self.reconnect_state.tick(&self.reconnect_config, now);
+
+        match &action {
+            ReconnectAction::None => {}
+            ReconnectAction::GiveUp(failure) => {
+                match failure {
+                    ReconnectFailure::Disabled => {
+                        info!(target: "net", "Reconnection disabled, entering Error state");
+                    }
+                    ReconnectFailure::MaxAttemptsReached(max) => {
+                        error!(
+                            target: "net",
+                            "Max reconnection attempts reached ({}), entering Error state",
+                            max
+                        );
+                    }
+                }
+                self.transition_to_error(ClientError::ConnectionFailed(failure.to_string()), false);
+            }
+            ReconnectAction::Reconnect { attempt, delay } => {
+                self.reconnect_attempts += 1;
+                info!(
+                    target: "net",
+                    "Starting reconnection attempt {} (waiting {:?} before reconnecting)",
+                    attempt, delay
+                );
 
-        // Clear reconnect_at since we're now reconnecting
-        self.reconnect_at = None;
+                let _ = self.raw_event_tx.try_send(ClientEvent::System(
+                    ClientSystemEvent::Reconnecting {
+                        attempt: *attempt,
+                        delay_secs: delay.as_secs(),
+                    },
+                ));
 
-        // Update timing to account for backoff delay
-        if let Some(connecting) = self.scene.as_connecting_mut() {
-            connecting.last_retry_at = crate::instant::Instant::now() + delay; // Don't retry until after the backoff delay
+                // Transition back to Connecting scene for reconnection attempt
+                let mut connecting = ConnectingScene::new();
+                connecting.last_retry_at = now + *delay; // Don't retry until after the backoff delay
+                let from = self.scene.name();
+                self.scene = Scene::Connecting(connecting);
+                self.emit_scene_changed(from);
+            }
         }
 
-        true
+        action
     }
 
     /// Enter disconnected state and prepare for potential reconnection
     pub fn enter_disconnected(&mut self) {
-        // Increment attempt counter (stored separately from state to survive transitions)
-        self.reconnect_attempt_count += 1;
-
-        let delay = self
-            .reconnect_config
-            .delay_for_attempt(self.reconnect_attempt_count);
+        let (attempt, delay) = self
+            .reconnect_state
+            .on_disconnected(&self.reconnect_config, crate::instant::Instant::now());
 
         info!(
             target: "net",
             "Connection lost - waiting for reconnection (attempt {}, next retry in {:?})",
-            self.reconnect_attempt_count, delay
+            attempt, delay
         );
 
-        // Set reconnect_at for later checking
-        self.reconnect_at = Some(crate::instant::Instant::now() + delay);
+        self.reset_connection();
+        // A resume login cut short by the disconnect counts as a failure
+        if let Some(target) = self.resume_target.as_mut()
+            && target.fail()
+        {
+            warn!(target: "net", "Connection lost while resuming as {}", target.character_name);
+        }
 
+        // Emit disconnected event
+        let _ = self
+            .raw_event_tx
+            .try_send(ClientEvent::System(ClientSystemEvent::Disconnected {
+                will_reconnect: self.reconnect_config.enabled,
+                reconnect_attempt: attempt,
+                delay_secs: delay.as_secs(),
+            }));
+    }
+
+    /// Disconnect if the server has sent nothing for longer than the idle
+    /// timeout. Checked every tick, so a dead connection is noticed even when
+    /// nothing is being sent. Returns true if the connection was dropped.
+    pub fn check_idle_timeout(&mut self, now: crate::instant::Instant) -> bool {
+        if self.session.connection.is_none() || !self.keepalive.is_idle(now) {
+            return false;
+        }
+        error!(
+            target: "net",
+            "Haven't received any packet from server in {}s - connection appears dead",
+            self.keepalive.silence(now).unwrap_or_default().as_secs()
+        );
+        self.enter_disconnected();
+        true
+    }
+
+    /// Forget the current connection so the next LoginRequest starts a fresh one
+    fn reset_connection(&mut self) {
         // Clear session state
         self.session.connection = None;
         self.pending_fragments.clear();
@@ -859,18 +1964,132 @@ impl Client {
         self.recv_count = 0;
         self.last_ack_sent = 0;
         self.unacked_send_count = 0;
-        self.last_receive_time = None;
+        self.sent_packets.clear();
+        self.keepalive.reset();
         self.fragment_sequence = 1;
         self.next_game_action_sequence = 0;
+        self.ping.reset();
+        self.server_clock.reset();
+        // Closing the waiters tells them the login can no longer finish
+        self.login_waiters.clear();
+        self.character_list_waiters.clear();
+        self.drop_chat_lines();
+    }
 
-        // Emit disconnected event
+    /// Forget paced chat lines that haven't gone out yet; they were meant for
+    /// the world the character just left
+    fn drop_chat_lines(&mut self) {
+        let queued = self.outgoing_message_queue.len();
+        self.outgoing_message_queue
+            .retain(|msg| !matches!(msg.content, OutgoingMessageContent::ChatLine(_)));
+        self.chat_pacer = ChatPacer::new();
+
+        let dropped = queued - self.outgoing_message_queue.len();
+        if dropped > 0 {
+            debug!(target: "net", "Dropped {} queued chat line(s)", dropped);
+        }
+    }
+
+    /// Whether the character list that just arrived should log back into the
+    /// character that was in the world before the connection dropped
+    pub(crate) fn should_resume_in_world(&self) -> bool {
+        self.reconnect_config.resume_in_world && self.resume_target.is_some()
+    }
+
+    /// Create the configured character for an account that has none
+    ///
+    /// Returns false when no creation is configured or it was already tried
+    /// this session. The login follows once the server verifies the character.
+    pub(crate) fn start_character_creation(&mut self, account: String) -> bool {
+        let Some(mut char_gen) = self.character_creation.take() else {
+            return false;
+        };
+        if let Some(name) = &self.character {
+            char_gen.name = name.clone();
+        }
+
+        info!(target: "net", "Account {} has no characters, creating {}", account, char_gen.name);
+        self.outgoing_message_queue.push_back(
+            OutgoingMessage::new(OutgoingMessageContent::CharacterCreationAce(
+                account.clone(),
+                char_gen,
+            ))
+            .with_delay_ms(UI_DELAY_MS),
+        );
+        self.creating_character = Some((account, crate::instant::Instant::now()));
+        self.transition_to_char_create();
+        true
+    }
+
+    /// Give up on the character being created for an empty account, going back
+    /// to character select and reporting the failed login
+    pub(crate) fn fail_character_creation(&mut self, reason: &str) {
+        let Some((account, _)) = self.creating_character.take() else {
+            return;
+        };
+        error!(target: "net", "Character creation for account {} failed: {}", account, reason);
+        self.transition_to_char_select(self.known_characters.clone());
         let _ = self
             .raw_event_tx
-            .try_send(ClientEvent::System(ClientSystemEvent::Disconnected {
-                will_reconnect: self.reconnect_config.enabled,
-                reconnect_attempt: self.reconnect_attempt_count,
-                delay_secs: delay.as_secs(),
+            .try_send(ClientEvent::Game(GameEvent::LoginFailed {
+                reason: reason.to_string(),
+            }));
+    }
+
+    /// Give up on character creation the server hasn't answered within
+    /// [`crate::client::CHARACTER_CREATION_TIMEOUT`]. Checked every tick.
+    /// Returns true if creation was abandoned.
+    pub fn check_character_creation_timeout(&mut self, now: crate::instant::Instant) -> bool {
+        let Some((_, started_at)) = &self.creating_character else {
+            return false;
+        };
+        if now.duration_since(*started_at) < crate::client::CHARACTER_CREATION_TIMEOUT {
+            return false;
+        }
+        self.fail_character_creation("Character creation timed out");
+        true
+    }
+
+    /// Queue a login back into the resume target
+    ///
+    /// Returns false, leaving the client at character select, once the resume
+    /// has failed [`crate::client::MAX_RESUME_ATTEMPTS`] times or the character
+    /// can no longer be logged into.
+    pub(crate) fn resume_login(&mut self, account: String) -> bool {
+        let Some(target) = self.resume_target.as_mut() else {
+            return false;
+        };
+
+        let available = self
+            .known_characters
+            .iter()
+            .any(|c| c.character_id.0 == target.character_id && c.seconds_greyed_out == 0);
+        let attempt = if available { target.begin() } else { None };
+
+        let Some(attempt) = attempt else {
+            warn!(target: "net", "Not resuming as {} - staying at character select", target.character_name);
+            self.resume_target = None;
+            return false;
+        };
+
+        info!(target: "net", "Resuming as {} (ID: {}, attempt {})", target.character_name, target.character_id, attempt);
+        let character_id = target.character_id;
+        let character_name = target.character_name.clone();
+
+        let _ = self
+            .raw_event_tx
+            .try_send(ClientEvent::System(ClientSystemEvent::Resuming {
+                character_id: character_id.into(),
+                character_name: character_name.clone(),
+                attempt,
             }));
+
+        self.pending_auto_login = Some(gromnie_events::SimpleClientAction::LoginCharacter {
+            character_id: character_id.into(),
+            character_name,
+            account,
+        });
+        true
     }
 
     /// Get cached DDD response for retries
@@ -904,7 +2123,7 @@ impl Client {
                 } => {
                     info!(target: "events", "Auto-login: Logging in as character {} (ID: {})", character_name, character_id);
                     if let Err(e) =
-                        self.attempt_character_login(character_id, character_name, account)
+                        self.attempt_character_login(character_id.get(), character_name, account)
                     {
                         error!(target: "events", "Failed to attempt auto-login: {}", e);
                     }
@@ -922,10 +2141,24 @@ impl Client {
                 gromnie_events::SimpleClientAction::Disconnect => {
                     info!(target: "events", "Action: Disconnecting");
                     // Disconnect action - transition to Error state
-                    self.scene = Scene::Error(ErrorScene::new(
+                    self.transition_to_error(
                         ClientError::ConnectionFailed("Disconnected by client action".to_string()),
                         true, // Can retry
-                    ));
+                    );
+                }
+                gromnie_events::SimpleClientAction::RetryConnection => {
+                    if self.scene.can_retry() {
+                        info!(target: "events", "Action: Retrying connection");
+                        self.retry_from_error();
+                    } else {
+                        warn!(target: "events", "Ignoring retry: the client can't retry from {:?}", self.scene);
+                    }
+                }
+                gromnie_events::SimpleClientAction::Logout => {
+                    debug!(target: "events", "Action: Logging out");
+                    if let Err(e) = self.logout() {
+                        warn!(target: "events", "Failed to log out: {}", e);
+                    }
                 }
                 gromnie_events::SimpleClientAction::LoginCharacter {
                     character_id,
@@ -934,7 +2167,7 @@ impl Client {
                 } => {
                     debug!(target: "events", "Action: Logging in as character {} (ID: {})", character_name, character_id);
                     if let Err(e) =
-                        self.attempt_character_login(character_id, character_name, account)
+                        self.attempt_character_login(character_id.get(), character_name, account)
                     {
                         error!(target: "events", "Failed to attempt character login: {}", e);
                     }
@@ -947,6 +2180,13 @@ impl Client {
                     debug!(target: "events", "Action: Sending chat say: {}", message);
                     self.send_chat_say(message);
                 }
+                gromnie_events::SimpleClientAction::SendChatLines {
+                    lines,
+                    interval_millis,
+                } => {
+                    debug!(target: "events", "Action: Sending {} chat lines {}ms apart", lines.len(), interval_millis);
+                    self.send_chat_lines(lines, std::time::Duration::from_millis(interval_millis));
+                }
                 gromnie_events::SimpleClientAction::SendChatTell {
                     recipient_name,
                     message,
@@ -954,12 +2194,19 @@ impl Client {
                     debug!(target: "events", "Action: Sending tell to {}: {}", recipient_name, message);
                     self.send_chat_tell(recipient_name, message);
                 }
+                gromnie_events::SimpleClientAction::Squelch { name } => {
+                    debug!(target: "events", "Action: Squelch {}", name);
+                    self.squelch(&name);
+                }
+                gromnie_events::SimpleClientAction::Unsquelch { name } => {
+                    debug!(target: "events", "Action: Unsquelch {}", name);
+                    self.unsquelch(&name);
+                }
                 gromnie_events::SimpleClientAction::ReloadScripts { script_dir } => {
                     debug!(target: "events", "Action: Reloading scripts from {:?}", script_dir);
-                    // Note: This action is handled by ScriptRunner, not here
-                    // The client just forwards it via the event channel
-                    // We shouldn't see this here, but handle it gracefully
-                    warn!(target: "events", "ReloadScripts action received in Client - this should be handled by ScriptRunner");
+                    // The runner routes this to the script consumer when
+                    // scripting is enabled, so there's nothing to reload here
+                    warn!(target: "events", "ReloadScripts action received in Client - scripting is not enabled");
                 }
                 gromnie_events::SimpleClientAction::LogScriptMessage { script_id, message } => {
                     info!(target: "script", "[{}] {}", script_id, message);
@@ -972,10 +2219,127 @@ impl Client {
                     debug!(target: "events", "Action: DoMovementCommand motion=0x{:08X}", motion);
                     self.send_do_movement_command(motion, speed, hold_key);
                 }
+                gromnie_events::SimpleClientAction::MoveTo { x, y, z } => {
+                    debug!(target: "events", "Action: MoveTo ({}, {}, {})", x, y, z);
+                    self.move_to(x, y, z);
+                }
+                gromnie_events::SimpleClientAction::TurnTo { heading } => {
+                    debug!(target: "events", "Action: TurnTo {}", heading);
+                    self.turn_to(heading);
+                }
+                gromnie_events::SimpleClientAction::JoinChannel { channel } => {
+                    debug!(target: "events", "Action: JoinChannel 0x{:08X}", channel);
+                    self.send_join_channel(channel);
+                }
+                gromnie_events::SimpleClientAction::LeaveChannel { channel } => {
+                    debug!(target: "events", "Action: LeaveChannel 0x{:08X}", channel);
+                    self.send_leave_channel(channel);
+                }
                 gromnie_events::SimpleClientAction::StopMovementCommand { motion, hold_key } => {
                     debug!(target: "events", "Action: StopMovementCommand motion=0x{:08X}", motion);
                     self.send_stop_movement_command(motion, hold_key);
                 }
+                gromnie_events::SimpleClientAction::UseItem { object_id } => {
+                    debug!(target: "events", "Action: UseItem 0x{:08X}", object_id);
+                    self.send_use_item(object_id.get());
+                }
+                gromnie_events::SimpleClientAction::DropItem { object_id } => {
+                    debug!(target: "events", "Action: DropItem 0x{:08X}", object_id);
+                    self.send_drop_item(object_id.get());
+                }
+                gromnie_events::SimpleClientAction::DoEmote { emote } => {
+                    debug!(target: "events", "Action: DoEmote {:?}", emote);
+                    self.send_emote(emote);
+                }
+                gromnie_events::SimpleClientAction::SendEmote { emote_id } => {
+                    match gromnie_events::EmoteKind::from_motion_command(emote_id) {
+                        Some(emote) => {
+                            debug!(target: "events", "Action: SendEmote {:?}", emote);
+                            self.send_emote(emote);
+                        }
+                        None => {
+                            warn!(target: "events", "Action: SendEmote with unknown emote id 0x{:08X}, not sending", emote_id);
+                        }
+                    }
+                }
+                gromnie_events::SimpleClientAction::SendTextEmote { text } => {
+                    debug!(target: "events", "Action: SendTextEmote");
+                    self.send_text_emote(text);
+                }
+                gromnie_events::SimpleClientAction::QueryHealth { object_id } => {
+                    debug!(target: "events", "Action: QueryHealth 0x{:08X}", object_id);
+                    self.send_query_health(object_id.get());
+                }
+                gromnie_events::SimpleClientAction::Appraise { object_id } => {
+                    debug!(target: "events", "Action: Appraise 0x{:08X}", object_id);
+                    self.send_appraise(object_id.get());
+                }
+                gromnie_events::SimpleClientAction::OpenContainer { container_id } => {
+                    debug!(target: "events", "Action: OpenContainer 0x{:08X}", container_id);
+                    self.send_open_container(container_id.get());
+                }
+                gromnie_events::SimpleClientAction::RespondConfirmation { context_id, accept } => {
+                    debug!(target: "events", "Action: RespondConfirmation context={} accept={}", context_id, accept);
+                    if let Err(e) = self.send_confirmation_response(context_id, accept) {
+                        warn!(target: "events", "Not answering confirmation {}: {}", context_id, e);
+                    }
+                }
+                gromnie_events::SimpleClientAction::FellowshipCreate { name } => {
+                    debug!(target: "events", "Action: FellowshipCreate {}", name);
+                    self.send_fellowship_create(name);
+                }
+                gromnie_events::SimpleClientAction::FellowshipInvite { object_id } => {
+                    debug!(target: "events", "Action: FellowshipInvite 0x{:08X}", object_id);
+                    self.send_fellowship_invite(object_id.get());
+                }
+                gromnie_events::SimpleClientAction::FellowshipDismiss { object_id } => {
+                    debug!(target: "events", "Action: FellowshipDismiss 0x{:08X}", object_id);
+                    self.send_fellowship_dismiss(object_id.get());
+                }
+                gromnie_events::SimpleClientAction::FellowshipQuit => {
+                    debug!(target: "events", "Action: FellowshipQuit");
+                    self.send_fellowship_quit();
+                }
+                gromnie_events::SimpleClientAction::TradeAdd { item_id, slot } => {
+                    debug!(target: "events", "Action: TradeAdd 0x{:08X} at {}", item_id, slot);
+                    self.send_trade_add(item_id.get(), slot);
+                }
+                gromnie_events::SimpleClientAction::TradeRemove { item_id } => {
+                    debug!(target: "events", "Action: TradeRemove 0x{:08X}", item_id);
+                    self.send_trade_remove(item_id.get());
+                }
+                gromnie_events::SimpleClientAction::TradeAccept => {
+                    debug!(target: "events", "Action: TradeAccept");
+                    self.send_trade_accept();
+                }
+                gromnie_events::SimpleClientAction::TradeDecline => {
+                    debug!(target: "events", "Action: TradeDecline");
+                    self.send_trade_decline();
+                }
+                gromnie_events::SimpleClientAction::TradeReset => {
+                    debug!(target: "events", "Action: TradeReset");
+                    self.send_trade_reset();
+                }
+                gromnie_events::SimpleClientAction::BuyItem {
+                    vendor_id,
+                    item_id,
+                    quantity,
+                } => {
+                    debug!(target: "events", "Action: BuyItem 0x{:08X} from 0x{:08X}", item_id, vendor_id);
+                    self.send_vendor_transaction(true, vendor_id.get(), item_id.get(), quantity);
+                }
+                gromnie_events::SimpleClientAction::SellItem {
+                    vendor_id,
+                    item_id,
+                    quantity,
+                } => {
+                    debug!(target: "events", "Action: SellItem 0x{:08X} to 0x{:08X}", item_id, vendor_id);
+                    self.send_vendor_transaction(false, vendor_id.get(), item_id.get(), quantity);
+                }
+                gromnie_events::SimpleClientAction::SendRawGameAction { opcode, payload } => {
+                    debug!(target: "events", "Action: SendRawGameAction 0x{:04X} ({} bytes)", opcode, payload.len());
+                    self.send_raw_game_action(opcode, payload);
+                }
             }
         }
     }
@@ -1004,6 +2368,16 @@ impl Client {
                 info!(target: "outgoing_msg", "→ LoginSendEnterWorld");
                 self.send_enter_world_internal(enter_world).await
             }
+            OutgoingMessageContent::LogOffCharacter => {
+                info!(target: "outgoing_msg", "→ 0xF653 LoginLogOffCharacter");
+                self.send_log_off_character_internal().await
+            }
+            OutgoingMessageContent::ChatLine(line) => {
+                info!(target: "outgoing_msg", "→ ChatLine");
+                // Queues the game action, which goes out in this same pass
+                self.send_chat_say(line);
+                Ok(())
+            }
             OutgoingMessageContent::GameAction(message_data) => {
                 info!(target: "outgoing_msg", "→ GameAction ({}bytes)", message_data.len());
                 self.send_fragmented_message(message_data, FragmentGroup::Object)
@@ -1012,7 +2386,7 @@ impl Client {
         }
     }
 
-    /// Send a message wrapped in a BlobFragment
+    /// Send a message wrapped in one or more BlobFragments
     async fn send_fragmented_message(
         &mut self,
         message_data: Vec<u8>,
@@ -1020,21 +2394,27 @@ impl Client {
     ) -> Result<(), std::io::Error> {
         info!(target: "net", "send_fragmented_message: message_data len={}, group={:?}", message_data.len(), group);
 
-        // Get current fragment sequence and increment
+        if let Some(trace) = &self.message_trace {
+            trace.record_sent(self.id, &message_data);
+        }
+
+        // One fragment sequence per logical message, shared by all its fragments
         let frag_sequence = self.fragment_sequence;
         self.fragment_sequence += 1;
 
-        // Create BlobFragment structure
-        let fragment_size = (FRAGMENT_HEADER_SIZE + message_data.len()) as u16;
-        let blob_fragment = BlobFragments {
-            sequence: frag_sequence,
-            id: 0x80000000, // Object ID (0x80000000 for game messages)
-            count: 1,       // Single fragment
-            size: fragment_size,
-            index: 0, // First (and only) fragment
-            group,
-            data: message_data,
-        };
+        for blob_fragment in fragments::split_message(frag_sequence, group, &message_data) {
+            self.send_blob_fragment(blob_fragment).await?;
+        }
+        Ok(())
+    }
+
+    /// Send a single BlobFragment in its own packet
+    async fn send_blob_fragment(
+        &mut self,
+        blob_fragment: BlobFragments,
+    ) -> Result<(), std::io::Error> {
+        let frag_sequence = blob_fragment.sequence;
+        let frag_index = blob_fragment.index;
 
         // Extract session values
         let (client_id, table) = {
@@ -1135,14 +2515,17 @@ impl Client {
         let total_checksum = header_checksum.wrapping_add(fragment_checksum);
         buffer[CHECKSUM_OFFSET..CHECKSUM_OFFSET + 4].copy_from_slice(&total_checksum.to_le_bytes());
 
-        debug!(target: "net", "Sending fragmented message: seq={}, frag_seq={}, size={}, checksum=0x{:08X}",
-            packet_sequence, frag_sequence, buffer.len(), total_checksum);
+        debug!(target: "net", "Sending fragmented message: seq={}, frag_seq={}, frag_index={}, size={}, checksum=0x{:08X}",
+            packet_sequence, frag_sequence, frag_index, buffer.len(), total_checksum);
 
-        // After connection, all packets with Id > 0 go to the login channel
-        debug!(target: "net", "Sending fragmented message to login channel");
+        self.sent_packets.record(packet_sequence, &buffer);
+
+        let dest_channel = self.port_routing.channel_for(&self.session.state, false);
+        debug!(target: "net", "Sending fragmented message to {:?} channel", dest_channel);
         self.transport
-            .send(&self.server, TransportChannel::Login, buffer)
+            .send(&self.server, dest_channel, buffer)
             .await?;
+        self.packets_sent += 1;
         Ok(())
     }
 
@@ -1215,6 +2598,23 @@ impl Client {
             .await
     }
 
+    /// Send character logoff (0xF653)
+    /// Server will respond with CharacterLogOff (0xF653)
+    async fn send_log_off_character_internal(&mut self) -> Result<(), std::io::Error> {
+        info!(target: "net", "Sending Login_LogOffCharacter (0xF653)");
+
+        let mut message_data = Vec::new();
+        {
+            let mut cursor = Cursor::new(&mut message_data);
+            C2SMessage::LoginLogOffCharacter(LoginLogOffCharacter {})
+                .write(&mut cursor)
+                .map_err(|e| std::io::Error::other(format!("Write error: {}", e)))?;
+        }
+
+        self.send_fragmented_message(message_data, FragmentGroup::Object)
+            .await
+    }
+
     /// Send character login (enter world) with character ID - Step 3 of character login
     /// This is sent after receiving CharacterEnterWorldServerReady (0xF7DF)
     async fn send_enter_world_internal(
@@ -1241,6 +2641,16 @@ impl Client {
     fn handle_message(&mut self, message: RawMessage) {
         debug!(target: "net", "Received message: {} (0x{:08X})", message.message_type, message.opcode);
 
+        if let Some(trace) = &self.message_trace {
+            trace.record(
+                self.id,
+                TraceDirection::Received,
+                message.opcode,
+                &message.message_type.to_string(),
+                &message.data,
+            );
+        }
+
         let event_tx = self.raw_event_tx.clone();
 
         // Otherwise try to parse as S2CMessage
@@ -1259,9 +2669,10 @@ impl Client {
                             match u32::read(&mut cursor) {
                                 Ok(event_opcode) => match GameEventType::try_from(event_opcode) {
                                     Ok(event_type) => self.handle_game_event(event_type, message),
-                                    Err(_) => {
-                                        debug!(target: "net", "Unknown game event opcode: 0x{:04X}", event_opcode)
-                                    }
+                                    Err(_) => self.report_unknown_message(
+                                        UnknownOpcode::GameEvent(event_opcode),
+                                        &message,
+                                    ),
                                 },
                                 Err(e) => {
                                     error!(target: "net", "Failed to read game event opcode: {}", e)
@@ -1289,12 +2700,23 @@ impl Client {
                         )
                         .ok();
                     }
+                    S2CMessage::LoginWorldInfo => {
+                        dispatch_message::<asheron_rs::messages::s2c::LoginWorldInfo, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
                     S2CMessage::CharacterCharGenVerificationResponse => {
-                        dispatch_message::<
+                        // Only a successful creation decodes; rejections (name
+                        // taken, banned, ...) carry no character
+                        if dispatch_message::<
                             asheron_rs::messages::s2c::CharacterCharGenVerificationResponse,
                             _,
                         >(self, message, &event_tx)
-                        .ok();
+                        .is_err()
+                        {
+                            self.fail_character_creation("Character creation was rejected");
+                        }
                     }
                     S2CMessage::LoginEnterGameServerReady => {
                         self.handle_enter_game_server_ready(message)
@@ -1311,32 +2733,96 @@ impl Client {
                         )
                         .ok();
                     }
+                    S2CMessage::ItemServerSaysRemove => {
+                        dispatch_message::<asheron_rs::messages::s2c::ItemServerSaysRemove, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
+                    S2CMessage::ItemUpdateStackSize => {
+                        dispatch_message::<asheron_rs::messages::s2c::ItemUpdateStackSize, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
                     S2CMessage::CommunicationTextboxString => self.handle_chat_message(message),
+                    S2CMessage::CombatHandlePlayerDeathEvent => {
+                        dispatch_message::<
+                            asheron_rs::messages::s2c::CombatHandlePlayerDeathEvent,
+                            _,
+                        >(self, message, &event_tx)
+                        .ok();
+                    }
                     S2CMessage::CommunicationHearSpeech => {
                         dispatch_message::<asheron_rs::messages::s2c::CommunicationHearSpeech, _>(
                             self, message, &event_tx,
                         )
                         .ok();
                     }
-                    S2CMessage::CommunicationHearRangedSpeech => {
+                    S2CMessage::CommunicationHearRangedSpeech => {
+                        dispatch_message::<
+                            asheron_rs::messages::s2c::CommunicationHearRangedSpeech,
+                            _,
+                        >(self, message, &event_tx)
+                        .ok();
+                    }
+                    S2CMessage::CharacterCharacterError => {
+                        dispatch_message::<asheron_rs::messages::s2c::CharacterCharacterError, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
+                    S2CMessage::QualitiesPrivateUpdateInt => {
+                        dispatch_message::<asheron_rs::messages::s2c::QualitiesPrivateUpdateInt, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
+                    S2CMessage::QualitiesPrivateUpdateFloat => {
+                        dispatch_message::<asheron_rs::messages::s2c::QualitiesPrivateUpdateFloat, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
+                    S2CMessage::QualitiesPrivateUpdateAttribute2ndLevel => {
                         dispatch_message::<
-                            asheron_rs::messages::s2c::CommunicationHearRangedSpeech,
+                            asheron_rs::messages::s2c::QualitiesPrivateUpdateAttribute2ndLevel,
                             _,
                         >(self, message, &event_tx)
                         .ok();
                     }
-                    S2CMessage::CharacterCharacterError => {
-                        dispatch_message::<asheron_rs::messages::s2c::CharacterCharacterError, _>(
+                    S2CMessage::QualitiesPrivateUpdateAttribute => {
+                        dispatch_message::<
+                            asheron_rs::messages::s2c::QualitiesPrivateUpdateAttribute,
+                            _,
+                        >(self, message, &event_tx)
+                        .ok();
+                    }
+                    S2CMessage::QualitiesPrivateUpdateSkill => {
+                        dispatch_message::<asheron_rs::messages::s2c::QualitiesPrivateUpdateSkill, _>(
                             self, message, &event_tx,
                         )
                         .ok();
                     }
-                    S2CMessage::QualitiesPrivateUpdateInt => {
-                        dispatch_message::<asheron_rs::messages::s2c::QualitiesPrivateUpdateInt, _>(
+                    S2CMessage::QualitiesUpdateInt => {
+                        dispatch_message::<asheron_rs::messages::s2c::QualitiesUpdateInt, _>(
+                            self, message, &event_tx,
+                        )
+                        .ok();
+                    }
+                    S2CMessage::QualitiesUpdateFloat => {
+                        dispatch_message::<asheron_rs::messages::s2c::QualitiesUpdateFloat, _>(
                             self, message, &event_tx,
                         )
                         .ok();
                     }
+                    S2CMessage::QualitiesUpdateAttribute2ndLevel => {
+                        dispatch_message::<
+                            asheron_rs::messages::s2c::QualitiesUpdateAttribute2ndLevel,
+                            _,
+                        >(self, message, &event_tx)
+                        .ok();
+                    }
                     S2CMessage::ItemSetState => {
                         dispatch_message::<asheron_rs::messages::s2c::ItemSetState, _>(
                             self, message, &event_tx,
@@ -1375,12 +2861,42 @@ impl Client {
                     }
                 }
             }
-            Err(_) => {
-                info!(target: "net", "Unknown message opcode: 0x{:08X}. This indicates something unexpected is happening.", message.opcode);
-            }
+            Err(_) => self.report_unknown_message(UnknownOpcode::Message(message.opcode), &message),
         }
     }
 
+    /// Log a message we can't parse (loudly only the first time its opcode
+    /// is seen this session) and pass its bytes on for the debug view
+    fn report_unknown_message(&mut self, opcode: UnknownOpcode, message: &RawMessage) {
+        let first_seen = self.unknown_opcodes.record(opcode);
+        let game_event_opcode = match opcode {
+            UnknownOpcode::Message(opcode) => {
+                if first_seen {
+                    info!(target: "net", "Unknown message opcode: 0x{:08X}. This indicates something unexpected is happening.", opcode);
+                } else {
+                    debug!(target: "net", "Unknown message opcode: 0x{:08X}", opcode);
+                }
+                None
+            }
+            UnknownOpcode::GameEvent(event_opcode) => {
+                if first_seen {
+                    info!(target: "net", "Unknown game event opcode: 0x{:04X}", event_opcode);
+                } else {
+                    debug!(target: "net", "Unknown game event opcode: 0x{:04X}", event_opcode);
+                }
+                Some(event_opcode)
+            }
+        };
+
+        let _ =
+            self.raw_event_tx
+                .try_send(ClientEvent::System(ClientSystemEvent::UnknownMessage {
+                    opcode: message.opcode,
+                    game_event_opcode,
+                    data: message.data.clone(),
+                }));
+    }
+
     /// Handle OrderedGameEvent (0xF7B0) messages
     fn handle_game_event(&mut self, event_type: GameEventType, message: RawMessage) {
         info!(target: "net", "Processing OrderedGameEvent message, data len={}", message.data.len());
@@ -1412,150 +2928,418 @@ impl Client {
                 error!(target: "net", "Failed to read sequence from OrderedGameEvent: {}", e);
                 return;
             }
-        };
-
-        let event_tx = self.raw_event_tx.clone();
-        match event_type {
-            GameEventType::CommunicationHearDirectSpeech => {
-                dispatch_game_event::<CommunicationHearDirectSpeech, _, _>(
+        };
+
+        let event_tx = self.raw_event_tx.clone();
+        match event_type {
+            GameEventType::CommunicationHearDirectSpeech => {
+                dispatch_game_event::<CommunicationHearDirectSpeech, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    hear_direct_speech_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::CommunicationTransientString => {
+                dispatch_game_event::<CommunicationTransientString, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    transient_string_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::CommunicationChannelBroadcast => {
+                dispatch_game_event::<CommunicationChannelBroadcast, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    channel_broadcast_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeRegisterTrade => {
+                dispatch_game_event::<TradeRegisterTrade, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_register_trade_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeOpenTrade => {
+                dispatch_game_event::<TradeOpenTrade, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_open_trade_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeCloseTrade => {
+                dispatch_game_event::<TradeCloseTrade, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_close_trade_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeAddToTrade => {
+                dispatch_game_event::<TradeAddToTrade, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_add_to_trade_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeRemoveFromTrade => {
+                dispatch_game_event::<TradeRemoveFromTrade, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_remove_from_trade_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeAcceptTrade => {
+                dispatch_game_event::<TradeAcceptTradeEvent, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_accept_trade_event_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeDeclineTrade => {
+                dispatch_game_event::<TradeDeclineTradeEvent, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_decline_trade_event_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeResetTrade => {
+                dispatch_game_event::<TradeResetTradeEvent, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_reset_trade_event_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::TradeTradeFailure => {
+                dispatch_game_event::<TradeTradeFailure, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    trade_failure_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::MagicUpdateEnchantment => {
+                dispatch_game_event::<MagicUpdateEnchantment, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    magic_update_enchantment_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::MagicRemoveEnchantment => {
+                dispatch_game_event::<MagicRemoveEnchantment, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    magic_remove_enchantment_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::CharacterConfirmationRequest => {
+                dispatch_game_event::<CharacterConfirmationRequest, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    character_confirmation_request_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::MagicUpdateSpell => {
+                dispatch_game_event::<MagicUpdateSpell, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    magic_update_spell_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::LoginPlayerDescription => {
+                dispatch_game_event::<LoginPlayerDescription, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    player_description_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::CommunicationWeenieError => {
+                dispatch_game_event::<CommunicationWeenieError, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    weenie_error_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::CommunicationWeenieErrorWithString => {
+                dispatch_game_event::<CommunicationWeenieErrorWithString, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    weenie_error_with_string_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::ItemSetAppraiseInfo => {
+                dispatch_game_event::<ItemSetAppraiseInfo, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    appraise_info_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::FellowshipFullUpdate => {
+                dispatch_game_event::<FellowshipFullUpdate, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    fellowship_full_update_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::FellowshipUpdateFellow => {
+                dispatch_game_event::<FellowshipUpdateFellow, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    fellowship_update_fellow_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::FellowshipQuit => {
+                dispatch_game_event::<FellowshipQuit, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    fellowship_quit_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::FellowshipDisband => {
+                dispatch_game_event::<FellowshipDisband, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    hear_direct_speech_to_game_event_msg,
+                    fellowship_disband_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::CommunicationTransientString => {
-                dispatch_game_event::<CommunicationTransientString, _, _>(
+            GameEventType::AllegianceAllegianceUpdate => {
+                dispatch_game_event::<AllegianceAllegianceUpdate, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    transient_string_to_game_event_msg,
+                    allegiance_update_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeRegisterTrade => {
-                dispatch_game_event::<TradeRegisterTrade, _, _>(
+            GameEventType::AllegianceAllegianceInfoResponseEvent => {
+                dispatch_game_event::<AllegianceAllegianceInfoResponseEvent, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_register_trade_to_game_event_msg,
+                    allegiance_info_response_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeOpenTrade => {
-                dispatch_game_event::<TradeOpenTrade, _, _>(
+            GameEventType::AllegianceAllegianceLoginNotificationEvent => {
+                dispatch_game_event::<AllegianceAllegianceLoginNotificationEvent, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_open_trade_to_game_event_msg,
+                    allegiance_login_notification_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeCloseTrade => {
-                dispatch_game_event::<TradeCloseTrade, _, _>(
+            GameEventType::VendorVendorInfo => {
+                dispatch_game_event::<VendorVendorInfo, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_close_trade_to_game_event_msg,
+                    vendor_info_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeAddToTrade => {
-                dispatch_game_event::<TradeAddToTrade, _, _>(
+            GameEventType::CombatQueryHealthResponse => {
+                dispatch_game_event::<CombatQueryHealthResponse, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_add_to_trade_to_game_event_msg,
+                    query_health_response_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeRemoveFromTrade => {
-                dispatch_game_event::<TradeRemoveFromTrade, _, _>(
+            GameEventType::CombatHandleAttackerNotificationEvent => {
+                dispatch_game_event::<CombatHandleAttackerNotificationEvent, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_remove_from_trade_to_game_event_msg,
+                    attacker_notification_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeAcceptTrade => {
-                dispatch_game_event::<TradeAcceptTradeEvent, _, _>(
+            GameEventType::CombatHandleDefenderNotificationEvent => {
+                dispatch_game_event::<CombatHandleDefenderNotificationEvent, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_accept_trade_event_to_game_event_msg,
+                    defender_notification_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeDeclineTrade => {
-                dispatch_game_event::<TradeDeclineTradeEvent, _, _>(
+            GameEventType::CombatHandleEvasionAttackerNotificationEvent => {
+                dispatch_game_event::<CombatHandleEvasionAttackerNotificationEvent, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_decline_trade_event_to_game_event_msg,
+                    evasion_attacker_notification_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeResetTrade => {
-                dispatch_game_event::<TradeResetTradeEvent, _, _>(
+            GameEventType::CombatHandleEvasionDefenderNotificationEvent => {
+                dispatch_game_event::<CombatHandleEvasionDefenderNotificationEvent, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_reset_trade_event_to_game_event_msg,
+                    evasion_defender_notification_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::TradeTradeFailure => {
-                dispatch_game_event::<TradeTradeFailure, _, _>(
+            GameEventType::CombatHandleVictimNotificationEventSelf => {
+                dispatch_game_event::<CombatHandleVictimNotificationEventSelf, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    trade_failure_to_game_event_msg,
+                    victim_notification_self_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::MagicUpdateEnchantment => {
-                dispatch_game_event::<MagicUpdateEnchantment, _, _>(
+            GameEventType::CombatHandleVictimNotificationEventOther => {
+                dispatch_game_event::<CombatHandleVictimNotificationEventOther, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    magic_update_enchantment_to_game_event_msg,
+                    victim_notification_other_to_game_event_msg,
                 )
                 .ok();
             }
-            GameEventType::MagicRemoveEnchantment => {
-                dispatch_game_event::<MagicRemoveEnchantment, _, _>(
+            GameEventType::CharacterReturnPing => {
+                // No payload; the event's sequence orders replies
+                self.handle_return_ping(sequence);
+            }
+            GameEventType::ItemOnViewContents => {
+                dispatch_game_event::<ItemOnViewContents, _, _>(
                     self,
                     &mut cursor,
                     &event_tx,
                     object_id,
                     sequence,
-                    magic_remove_enchantment_to_game_event_msg,
+                    view_contents_to_game_event_msg,
+                )
+                .ok();
+            }
+            GameEventType::CommunicationSetSquelchDB => {
+                dispatch_game_event::<CommunicationSetSquelchDB, _, _>(
+                    self,
+                    &mut cursor,
+                    &event_tx,
+                    object_id,
+                    sequence,
+                    squelch_db_to_game_event_msg,
                 )
                 .ok();
             }
@@ -1640,51 +3424,31 @@ impl Client {
     /// Handle a fragment received from the server
     fn handle_fragment(&mut self, blob_fragment: asheron_rs::types::BlobFragments) {
         let sequence = blob_fragment.sequence;
-        let index = blob_fragment.index as usize;
-        let count = blob_fragment.count;
 
-        // Calculate the actual fragment data size (size includes the fragment header overhead)
-        // In C#: fragLength = fragHeader.Size - FragmentHeader.SizeOf
-        // FragmentHeader.SizeOf = 16 bytes (sequence:4 + id:4 + count:2 + size:2 + index:2 + group:2)
-        let chunk_size = blob_fragment.data.len();
-
-        // Get or create Fragment for this sequence
-        let fragment = self
+        // Wait until every fragment of this sequence has arrived
+        let Some(data) = self
             .pending_fragments
-            .entry(sequence)
-            .or_insert_with(|| Fragment::new(sequence, count));
-
-        // Set size and group metadata
-        fragment.set_fragment_info(blob_fragment.size, blob_fragment.group as u16);
-
-        // Add this chunk to the fragment
-        fragment.add_chunk(&blob_fragment.data, index, chunk_size);
-
-        // Check if fragment is complete
-        if fragment.is_complete() {
-            // Get the reassembled data
-            let data = fragment.get_data();
+            .add(&blob_fragment, crate::instant::Instant::now())
+        else {
+            return;
+        };
 
-            // Parse the reassembled data as an AC protocol message
-            match RawMessage::from_fragment(data.to_vec(), sequence, blob_fragment.id) {
-                Ok(message) => {
-                    // Add to message queue for processing
-                    self.message_queue.push_back(message);
-                }
-                Err(e) => {
-                    error!(target: "net", "Error parsing message from fragment {}: {}", sequence, e);
-                }
+        // Parse the reassembled data as an AC protocol message
+        match RawMessage::from_fragment(data, sequence, blob_fragment.id) {
+            Ok(message) => {
+                // Add to message queue for processing
+                self.message_queue.push_back(message);
+            }
+            Err(e) => {
+                error!(target: "net", "Error parsing message from fragment {}: {}", sequence, e);
             }
-
-            // Clean up metadata and remove from pending
-            fragment.cleanup();
-            self.pending_fragments.remove(&sequence);
         }
     }
 
     pub async fn process_packet(&mut self, buffer: &[u8], size: usize, peer: &SocketAddr) {
         // Track last receive time for disconnect detection
-        self.last_receive_time = Some(crate::instant::Instant::now());
+        self.keepalive.on_received(crate::instant::Instant::now());
+        self.packets_received += 1;
 
         // Pull out TransitHeader first and inspect
         let mut cursor = std::io::Cursor::new(buffer);
@@ -1692,6 +3456,28 @@ impl Client {
 
         debug!(target: "net", "Received {} bytes from {}", size, peer);
 
+        // Fragments carry messages, so check them before anything acts on the packet
+        if packet.flags.contains(PacketHeaderFlags::BLOB_FRAGMENTS) {
+            let keys = self
+                .session
+                .connection
+                .as_mut()
+                .map(|connection| &mut connection.recv_keys);
+            match check_received_checksum(&buffer[..size], packet.flags, keys) {
+                ChecksumCheck::Valid => {}
+                ChecksumCheck::Unchecked => {
+                    debug!(target: "net", "Could not verify checksum of packet seq={}", packet.sequence);
+                }
+                ChecksumCheck::Invalid if self.strict_checksums => {
+                    warn!(target: "net", "Dropping packet seq={} with bad checksum", packet.sequence);
+                    return;
+                }
+                ChecksumCheck::Invalid => {
+                    warn!(target: "net", "Packet seq={} has bad checksum; processing anyway", packet.sequence);
+                }
+            }
+        }
+
         // Track server's packet sequence (for ACKing back to server)
         // Only update if this is a sequenced packet (sequence > 0) and it's newer than what we've seen
         if packet.sequence > 0 && packet.sequence > self.recv_count {
@@ -1735,6 +3521,7 @@ impl Client {
                 send_generator: std::sync::Mutex::new(CryptoSystem::new(
                     connect_req_packet.incoming_seed,
                 )), // Client->Server seed
+                recv_keys: ReceiveKeys::new(connect_req_packet.outgoing_seed), // Server->Client seed
             });
             self.session
                 .transition_to(SessionState::AuthConnectResponse);
@@ -1791,9 +3578,27 @@ impl Client {
             debug!(target: "net", "📨 Received ACK from server for our seq={} (packet.seq={}, recv_count={})",
                 acked_seq, packet.sequence, self.recv_count);
 
-            // Server is acknowledging our packets - we could track this to resend unacked packets
-            // For now, we just note that we received the ACK
-            // TODO: Track last_acked_by_server for retransmission logic
+            // Everything up to acked_seq arrived, so it no longer needs to be kept for resending
+            self.sent_packets.acknowledge(acked_seq);
+        }
+
+        if flags.intersects(
+            PacketHeaderFlags::REQUEST_RETRANSMIT | PacketHeaderFlags::REJECT_RETRANSMIT,
+        ) {
+            let mut cursor = Cursor::new(&buffer[..size]);
+            match S2CPacket::read(&mut cursor) {
+                Ok(s2c_packet) => {
+                    if let Some(sequences) = s2c_packet.retransmit_sequences {
+                        self.retransmit_packets(&sequences.list).await;
+                    }
+                    if let Some(sequences) = s2c_packet.reject_sequences {
+                        warn!(target: "net", "Server rejected retransmission of sequences {:?}", sequences.list);
+                    }
+                }
+                Err(e) => {
+                    error!(target: "net", "Error parsing retransmit request: {}", e);
+                }
+            }
         }
 
         if flags.contains(PacketHeaderFlags::TIME_SYNC) {
@@ -1814,7 +3619,33 @@ impl Client {
             debug!(target: "net", "⏰ Received TIME_SYNC from server: time={:.3}, seq={}, recv_count={}",
                 server_time, packet.sequence, self.recv_count);
 
-            // TODO: Store server time if needed for future use
+            self.server_clock
+                .on_sync(server_time, crate::instant::Instant::now());
+        }
+
+        if flags.contains(PacketHeaderFlags::ECHO_RESPONSE) {
+            // The server reflects the echo_time from one of our TimeSyncs
+            match echo_response_offset(flags)
+                .and_then(|offset| buffer[..size].get(offset..offset + 4))
+            {
+                Some(bytes) => {
+                    let echo_time = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                    match self.echo.on_echo(echo_time, crate::instant::Instant::now()) {
+                        Some(rtt) => debug!(
+                            target: "net",
+                            "Echo round trip: {} ms (average {} ms)",
+                            rtt.as_millis(),
+                            self.echo.smoothed_rtt().unwrap_or(rtt).as_millis()
+                        ),
+                        None => {
+                            debug!(target: "net", "Ignoring unexpected echo_time {}", echo_time)
+                        }
+                    }
+                }
+                None => {
+                    debug!(target: "net", "Could not locate ECHO_RESPONSE in packet with flags {:?}", flags)
+                }
+            }
         }
 
         if flags.contains(PacketHeaderFlags::BLOB_FRAGMENTS) {
@@ -1934,10 +3765,27 @@ impl Client {
         // Increment send_count for LoginRequest (matches actestclient)
         self.send_count += 1;
 
-        // Send to login channel
+        // Send to the auth-phase channel (login port by default)
+        let dest_channel = self.port_routing.channel_for(&self.session.state, false);
         self.transport
-            .send(&self.server, TransportChannel::Login, buffer)
+            .send(&self.server, dest_channel, buffer)
             .await?;
+        self.packets_sent += 1;
+
+        if let Some(connecting) = self.scene.as_connecting_mut() {
+            let attempt = connecting.on_login_request_sent();
+            if attempt > 1 {
+                info!(
+                    target: "net",
+                    "Retrying LoginRequest ({}/{})",
+                    attempt, self.max_login_attempts
+                );
+                self.emit_system_event(ClientSystemEvent::LoginRetry {
+                    attempt,
+                    max_attempts: self.max_login_attempts,
+                });
+            }
+        }
 
         // Update progress to LoginRequestSent (33%)
         if let Some(connecting) = self.scene.as_connecting_mut()
@@ -2007,8 +3855,23 @@ impl Client {
     /// Transition to Connecting scene
     pub fn transition_to_connecting(&mut self) {
         self.session = ClientSession::new(SessionState::AuthLoginRequest);
+        self.known_spells.clear();
+        self.character_sheet.clear();
+        self.object_health.clear();
+        self.open_container_requests.clear();
+        self.objects.clear();
+        self.allegiance.clear();
+        self.movement.clear();
+        self.pending_confirmations.clear();
+        self.ping.reset();
+        self.server_clock.reset();
+        self.unknown_opcodes.clear();
+        self.login_waiters.clear();
+        self.character_list_waiters.clear();
+        self.drop_chat_lines();
+        let from = self.scene.name();
         self.scene = Scene::Connecting(ConnectingScene::new());
-        self.emit_scene_changed();
+        self.emit_scene_changed(from);
     }
 
     /// Update connecting progress within the Connecting scene
@@ -2039,11 +3902,13 @@ impl Client {
         characters: Vec<asheron_rs::types::CharacterIdentity>,
     ) {
         // session.state should already be AuthConnected
+        self.drop_chat_lines();
+        let from = self.scene.name();
         self.scene = Scene::CharacterSelect(CharacterSelectScene::new(
             self.account.name.clone(),
             characters,
         ));
-        self.emit_scene_changed();
+        self.emit_scene_changed(from);
     }
 
     /// Begin entering world process (called when EnterWorldRequest is sent)
@@ -2070,32 +3935,48 @@ impl Client {
     /// Transition to InWorld scene
     pub fn transition_to_in_world(&mut self, character_id: u32, character_name: String) {
         self.session.transition_to(SessionState::WorldConnected);
+        let from = self.scene.name();
         self.scene = Scene::InWorld(InWorldScene::new(character_id, character_name));
-        self.emit_scene_changed();
+        self.emit_scene_changed(from);
     }
 
     /// Transition to CharacterCreate scene
     pub fn transition_to_char_create(&mut self) {
         // session.state stays AuthConnected
+        let from = self.scene.name();
         self.scene = Scene::CharacterCreate(CharacterCreateScene::new());
-        self.emit_scene_changed();
+        self.emit_scene_changed(from);
     }
 
     /// Transition to Error scene
     pub fn transition_to_error(&mut self, error: ClientError, can_retry: bool) {
+        self.login_waiters.clear();
+        self.character_list_waiters.clear();
+        let from = self.scene.name();
         self.scene = Scene::Error(ErrorScene::new(error, can_retry));
-        self.emit_scene_changed();
+        self.emit_scene_changed(from);
     }
 
     /// Retry from error scene (transition back to Connecting)
+    ///
+    /// The connection starts over as if it had been dropped, so the runner
+    /// sends a fresh LoginRequest on its next retry tick.
     pub fn retry_from_error(&mut self) {
         if self.scene.can_retry() {
+            self.reset_connection();
             self.transition_to_connecting();
         }
     }
 
-    /// Emit scene changed event
-    fn emit_scene_changed(&self) {
+    /// Emit scene changed events after leaving the scene named `from`
+    fn emit_scene_changed(&self, from: &str) {
+        let _ = self.raw_event_tx.try_send(ClientEvent::State(
+            crate::client::ClientStateEvent::StateTransition {
+                from: from.to_string(),
+                to: self.scene.name().to_string(),
+            },
+        ));
+
         // Emit appropriate event based on new scene
         match &self.scene {
             Scene::Connecting(_) => {
@@ -2113,9 +3994,18 @@ impl Client {
                     .raw_event_tx
                     .try_send(ClientEvent::State(crate::client::ClientStateEvent::InWorld));
             }
-            Scene::Error(_) => {
-                let _ = self.raw_event_tx.try_send(ClientEvent::State(
-                    crate::client::ClientStateEvent::CharacterError,
+            Scene::Error(scene) => {
+                if matches!(scene.error, ClientError::CharacterError(_)) {
+                    let _ = self.raw_event_tx.try_send(ClientEvent::State(
+                        crate::client::ClientStateEvent::CharacterError,
+                    ));
+                }
+                let _ = self.raw_event_tx.try_send(ClientEvent::System(
+                    ClientSystemEvent::ConnectionError {
+                        kind: scene.error.kind(),
+                        message: scene.error.to_string(),
+                        can_retry: scene.can_retry,
+                    },
                 ));
             }
             Scene::CharacterCreate(_) => {
@@ -2137,6 +4027,14 @@ impl GameEventHandler<asheron_rs::gameevents::CommunicationHearDirectSpeech> for
         &mut self,
         event: asheron_rs::gameevents::CommunicationHearDirectSpeech,
     ) -> Option<GameEvent> {
+        if self
+            .squelch
+            .is_squelched(&event.sender_name, event.sender_id.0)
+        {
+            debug!(target: "net", "Dropping tell from squelched {}", event.sender_name);
+            return None;
+        }
+
         let chat_text = format!("{} tells you, \"{}\"", event.sender_name, event.message);
         let message_type = event.type_ as u32;
 
@@ -2149,6 +4047,47 @@ impl GameEventHandler<asheron_rs::gameevents::CommunicationHearDirectSpeech> for
     }
 }
 
+/// Handle Communication_SetSquelchDB, the account's server-side squelches
+/// sent at login. Characters are keyed by object ID.
+impl GameEventHandler<CommunicationSetSquelchDB> for Client {
+    fn handle(&mut self, event: CommunicationSetSquelchDB) -> Option<GameEvent> {
+        let entries = event
+            .squelch_db
+            .character_hash
+            .into_iter()
+            .map(|(object_id, info)| (object_id.0, info.name));
+        self.squelch.set_server_entries(entries);
+        debug!(target: "net", "Server squelch list: {:?}", self.squelch.names());
+        self.emit_squelch_list();
+        None
+    }
+}
+
+/// Handle Item_OnViewContents, the item list of a container that was opened
+impl GameEventHandler<ItemOnViewContents> for Client {
+    fn handle(&mut self, event: ItemOnViewContents) -> Option<GameEvent> {
+        let container_id = event.container_id.0;
+        let items = view_contents_items(&event);
+        debug!(target: "net", "Container 0x{:08X} has {} items", container_id, items.len());
+
+        // Scripts subscribe to the S2C form of this message (304)
+        let _ =
+            self.raw_event_tx
+                .try_send(ClientEvent::Protocol(gromnie_events::ProtocolEvent::S2C(
+                    gromnie_events::S2CEvent::ItemOnViewContents {
+                        container_id,
+                        items: items.clone(),
+                    },
+                )));
+        self.report_container_contents(container_id, &items);
+
+        Some(GameEvent::ItemOnViewContents {
+            container_id: container_id.into(),
+            items: items.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
 /// Handle Communication_TransientString game events
 impl GameEventHandler<asheron_rs::gameevents::CommunicationTransientString> for Client {
     fn handle(
@@ -2165,6 +4104,57 @@ impl GameEventHandler<asheron_rs::gameevents::CommunicationTransientString> for
     }
 }
 
+/// Handle Communication_ChannelBroadcast game events
+impl GameEventHandler<CommunicationChannelBroadcast> for Client {
+    fn handle(&mut self, event: CommunicationChannelBroadcast) -> Option<GameEvent> {
+        // Broadcasts don't carry the sender's object ID, so only names match
+        if self.squelch.contains_name(&event.sender_name) {
+            debug!(target: "net", "Dropping channel message from squelched {}", event.sender_name);
+            return None;
+        }
+
+        let channel = event.channel.bits();
+        info!(
+            target: "net",
+            "Channel 0x{:08X}: {}: {}",
+            channel, event.sender_name, event.message
+        );
+
+        Some(GameEvent::ChannelMessage {
+            channel,
+            sender_name: event.sender_name,
+            message: event.message,
+        })
+    }
+}
+
+/// Handle Communication_WeenieError game events
+impl GameEventHandler<CommunicationWeenieError> for Client {
+    fn handle(&mut self, event: CommunicationWeenieError) -> Option<GameEvent> {
+        let error_code = event.type_ as u32;
+        let kind = gromnie_events::ActionErrorKind::from_code(error_code);
+        info!(target: "net", "Action failed: {:?} (0x{:04X})", kind, error_code);
+
+        Some(GameEvent::ActionError {
+            error_code,
+            message: kind.description().unwrap_or_default().to_string(),
+        })
+    }
+}
+
+/// Handle Communication_WeenieErrorWithString game events
+impl GameEventHandler<CommunicationWeenieErrorWithString> for Client {
+    fn handle(&mut self, event: CommunicationWeenieErrorWithString) -> Option<GameEvent> {
+        let error_code = event.type_ as u32;
+        info!(target: "net", "Action failed: 0x{:04X} \"{}\"", error_code, event.text);
+
+        Some(GameEvent::ActionError {
+            error_code,
+            message: event.text,
+        })
+    }
+}
+
 // ============================================================================
 // Trade game event handlers
 // ============================================================================
@@ -2177,6 +4167,8 @@ impl GameEventHandler<TradeRegisterTrade> for Client {
             initiator_id: event.initiator_id.0,
             partner_id: event.partner_id.0,
             stamp: event.stamp,
+            items: Vec::new(),
+            resets_in_flight: 0,
         });
         None
     }
@@ -2184,8 +4176,17 @@ impl GameEventHandler<TradeRegisterTrade> for Client {
 
 impl GameEventHandler<TradeOpenTrade> for Client {
     fn handle(&mut self, event: TradeOpenTrade) -> Option<GameEvent> {
-        info!(target: "net", "Trade window opened: object_id=0x{:08X}", event.object_id.0);
-        None
+        let partner_id = event.object_id.0;
+        let partner_name = self
+            .objects
+            .get(partner_id)
+            .map(|partner| partner.name.clone())
+            .unwrap_or_default();
+        info!(target: "net", "Trade window opened with {} (0x{:08X})", partner_name, partner_id);
+        Some(GameEvent::TradeOpened {
+            partner_id: partner_id.into(),
+            partner_name,
+        })
     }
 }
 
@@ -2199,8 +4200,23 @@ impl GameEventHandler<TradeCloseTrade> for Client {
 
 impl GameEventHandler<TradeAddToTrade> for Client {
     fn handle(&mut self, event: TradeAddToTrade) -> Option<GameEvent> {
-        info!(target: "net", "Item added to trade: item_id=0x{:08X}", event.object_id.0);
-        None
+        let item_id = event.object_id.0;
+        info!(target: "net", "Item added to trade: item_id=0x{:08X}", item_id);
+        let name = self
+            .objects
+            .get(item_id)
+            .map(|item| item.name.clone())
+            .unwrap_or_default();
+        // Our own adds are recorded before the server echoes them back
+        let own_item = self
+            .pending_trade
+            .as_ref()
+            .is_some_and(|trade| trade.contains(item_id));
+        Some(GameEvent::TradeItemAdded {
+            item_id: item_id.into(),
+            name,
+            own_item,
+        })
     }
 }
 
@@ -2229,6 +4245,14 @@ impl GameEventHandler<TradeDeclineTradeEvent> for Client {
 impl GameEventHandler<TradeResetTradeEvent> for Client {
     fn handle(&mut self, _event: TradeResetTradeEvent) -> Option<GameEvent> {
         info!(target: "net", "Trade reset");
+        if let Some(trade) = self.pending_trade.as_mut() {
+            // Our own reset from a remove is followed by the re-added items
+            if trade.resets_in_flight > 0 {
+                trade.resets_in_flight -= 1;
+            } else {
+                trade.items.clear();
+            }
+        }
         None
     }
 }
@@ -2258,3 +4282,312 @@ impl GameEventHandler<MagicRemoveEnchantment> for Client {
         None
     }
 }
+
+impl GameEventHandler<MagicUpdateSpell> for Client {
+    fn handle(&mut self, event: MagicUpdateSpell) -> Option<GameEvent> {
+        info!(target: "net", "Spell added to spellbook: spell_id={}", event.spell_id.id.0);
+        self.known_spells.insert(event.spell_id.id.0 as u32);
+        None
+    }
+}
+
+// ============================================================================
+// Character game event handlers
+// ============================================================================
+
+impl GameEventHandler<LoginPlayerDescription> for Client {
+    fn handle(&mut self, event: LoginPlayerDescription) -> Option<GameEvent> {
+        let mut sheet = CharacterSheet::new();
+        sheet.load(
+            Default::default(),
+            event
+                .qualities
+                .skills
+                .iter()
+                .flatten()
+                .map(|(skill_id, skill)| skill_value(*skill_id as u32, skill)),
+        );
+        if let Some(cache) = &event.qualities.attributes {
+            let primaries = [
+                &cache.strength,
+                &cache.endurance,
+                &cache.quickness,
+                &cache.coordination,
+                &cache.focus,
+                &cache.self_,
+            ];
+            for (attribute_id, info) in (1..).zip(primaries) {
+                if let Some(info) = info {
+                    sheet.update_attribute(attribute_id, attribute_value(info));
+                }
+            }
+        }
+
+        self.load_spell_book(
+            event
+                .qualities
+                .spell_book
+                .iter()
+                .flatten()
+                .map(|(spell_id, _)| spell_id.id.0 as u32),
+        );
+
+        info!(target: "net", "Player description received: {} skills, {} spells", sheet.skills.len(), self.known_spells.len());
+        self.character_sheet = sheet;
+        None
+    }
+}
+
+// ============================================================================
+// Item game event handlers
+// ============================================================================
+
+impl GameEventHandler<ItemSetAppraiseInfo> for Client {
+    fn handle(&mut self, event: ItemSetAppraiseInfo) -> Option<GameEvent> {
+        let object_id = event.object_id.0;
+        let int_properties: Vec<(String, i64)> = event
+            .int_properties
+            .iter()
+            .flatten()
+            .map(|(key, value)| (property_int_name(*key).to_string(), *value as i64))
+            .collect();
+        debug!(target: "net", "Appraisal of 0x{:08X}: success={}, {} int properties",
+            object_id, event.success, int_properties.len());
+
+        Some(GameEvent::AppraiseResult {
+            object_id: object_id.into(),
+            success: event.success,
+            int_properties,
+        })
+    }
+}
+
+// ============================================================================
+// Fellowship game event handlers
+// ============================================================================
+
+impl GameEventHandler<FellowshipFullUpdate> for Client {
+    fn handle(&mut self, event: FellowshipFullUpdate) -> Option<GameEvent> {
+        let fellowship = event.fellowship;
+        let members: Vec<gromnie_events::FellowMember> = (&fellowship.fellows)
+            .into_iter()
+            .map(|(id, fellow)| fellow_member(id.0, fellow))
+            .collect();
+        debug!(target: "net", "Fellowship \"{}\": {} members", fellowship.name, members.len());
+
+        Some(GameEvent::FellowshipUpdated {
+            name: fellowship.name,
+            leader_id: fellowship.leader_id.0.into(),
+            members,
+        })
+    }
+}
+
+impl GameEventHandler<FellowshipUpdateFellow> for Client {
+    fn handle(&mut self, event: FellowshipUpdateFellow) -> Option<GameEvent> {
+        Some(GameEvent::FellowshipMemberUpdated {
+            member: fellow_member(event.fellow_id.0, &event.fellow),
+        })
+    }
+}
+
+impl GameEventHandler<FellowshipQuit> for Client {
+    fn handle(&mut self, event: FellowshipQuit) -> Option<GameEvent> {
+        debug!(target: "net", "0x{:08X} left the fellowship", event.fellow_id.0);
+        Some(GameEvent::FellowshipMemberLeft {
+            object_id: event.fellow_id.0.into(),
+        })
+    }
+}
+
+impl GameEventHandler<FellowshipDisband> for Client {
+    fn handle(&mut self, _event: FellowshipDisband) -> Option<GameEvent> {
+        debug!(target: "net", "Fellowship disbanded");
+        Some(GameEvent::FellowshipDisbanded)
+    }
+}
+
+// ============================================================================
+// Vendor game event handlers
+// ============================================================================
+
+impl GameEventHandler<VendorVendorInfo> for Client {
+    fn handle(&mut self, event: VendorVendorInfo) -> Option<GameEvent> {
+        let vendor_id = event.shopkeeper_id.0;
+        let items: Vec<gromnie_events::VendorItem> = event
+            .items
+            .list
+            .iter()
+            .map(vendor_item_from_profile)
+            .collect();
+        debug!(target: "net", "Vendor 0x{:08X} has {} items", vendor_id, items.len());
+
+        Some(GameEvent::VendorInfo {
+            vendor_id: vendor_id.into(),
+            items,
+        })
+    }
+}
+
+// ============================================================================
+// Allegiance game event handlers
+// ============================================================================
+
+impl GameEventHandler<AllegianceAllegianceUpdate> for Client {
+    fn handle(&mut self, event: AllegianceAllegianceUpdate) -> Option<GameEvent> {
+        let hierarchy = &event.profile.hierarchy;
+        let members = allegiance_members(hierarchy);
+        debug!(target: "net", "Allegiance \"{}\": {} members, rank {}",
+            hierarchy.allegiance_name, members.len(), event.rank);
+
+        self.allegiance.update(&hierarchy.allegiance_name, &members);
+        Some(GameEvent::AllegianceUpdated {
+            name: hierarchy.allegiance_name.clone(),
+            rank: event.rank,
+            members,
+        })
+    }
+}
+
+impl GameEventHandler<AllegianceAllegianceInfoResponseEvent> for Client {
+    fn handle(&mut self, event: AllegianceAllegianceInfoResponseEvent) -> Option<GameEvent> {
+        let hierarchy = &event.profile.hierarchy;
+        Some(GameEvent::AllegianceInfoReceived {
+            target_id: event.target_id.0.into(),
+            name: hierarchy.allegiance_name.clone(),
+            members: allegiance_members(hierarchy),
+        })
+    }
+}
+
+impl GameEventHandler<AllegianceAllegianceLoginNotificationEvent> for Client {
+    fn handle(&mut self, event: AllegianceAllegianceLoginNotificationEvent) -> Option<GameEvent> {
+        let object_id = event.character_id.0;
+        // The notification only has the id, so name the member from what we know
+        let name = self
+            .allegiance
+            .member(object_id)
+            .map(|member| member.name.clone())
+            .or_else(|| {
+                self.objects
+                    .get(object_id)
+                    .map(|object| object.name.clone())
+            })
+            .unwrap_or_default();
+        debug!(target: "net", "Allegiance member {} (0x{:08X}) logged {}",
+            name, object_id, if event.is_logged_in { "in" } else { "out" });
+
+        Some(GameEvent::AllegianceMemberLogin {
+            object_id: object_id.into(),
+            name,
+            logged_in: event.is_logged_in,
+        })
+    }
+}
+
+// ============================================================================
+// Combat game event handlers
+// ============================================================================
+
+impl GameEventHandler<CombatQueryHealthResponse> for Client {
+    fn handle(&mut self, event: CombatQueryHealthResponse) -> Option<GameEvent> {
+        let object_id = event.object_id.0;
+        // The server sends health as a fraction of maximum
+        let health_percent = (event.health * 100.0).clamp(0.0, 100.0);
+        debug!(target: "net", "Health of 0x{:08X}: {:.0}%", object_id, health_percent);
+
+        self.object_health.insert(object_id, health_percent);
+        Some(GameEvent::TargetHealth {
+            object_id: object_id.into(),
+            health_percent,
+        })
+    }
+}
+
+impl GameEventHandler<CombatHandleAttackerNotificationEvent> for Client {
+    fn handle(&mut self, event: CombatHandleAttackerNotificationEvent) -> Option<GameEvent> {
+        debug!(target: "net", "Hit {} for {} damage{}", event.defender_name,
+            event.damage_amount, if event.critical { " (critical)" } else { "" });
+        Some(GameEvent::DamageDealt {
+            defender_name: event.defender_name,
+            damage_type: event.damage_type.bits(),
+            damage_amount: event.damage_amount,
+            damage_percent: event.damage_percent as f32,
+            critical: event.critical,
+        })
+    }
+}
+
+impl GameEventHandler<CombatHandleDefenderNotificationEvent> for Client {
+    fn handle(&mut self, event: CombatHandleDefenderNotificationEvent) -> Option<GameEvent> {
+        debug!(target: "net", "{} hit us for {} damage{}", event.attacker_name,
+            event.damage_amount, if event.critical { " (critical)" } else { "" });
+        Some(GameEvent::DamageTaken {
+            attacker_name: event.attacker_name,
+            damage_type: event.damage_type.bits(),
+            damage_amount: event.damage_amount,
+            damage_percent: event.damage_percent as f32,
+            critical: event.critical,
+        })
+    }
+}
+
+impl GameEventHandler<CombatHandleEvasionAttackerNotificationEvent> for Client {
+    fn handle(&mut self, event: CombatHandleEvasionAttackerNotificationEvent) -> Option<GameEvent> {
+        debug!(target: "net", "{} evaded our attack", event.defender_name);
+        Some(GameEvent::AttackEvaded {
+            defender_name: event.defender_name,
+        })
+    }
+}
+
+impl GameEventHandler<CombatHandleEvasionDefenderNotificationEvent> for Client {
+    fn handle(&mut self, event: CombatHandleEvasionDefenderNotificationEvent) -> Option<GameEvent> {
+        debug!(target: "net", "Evaded an attack from {}", event.attacker_name);
+        Some(GameEvent::AttackAvoided {
+            attacker_name: event.attacker_name,
+        })
+    }
+}
+
+impl GameEventHandler<CombatHandleVictimNotificationEventSelf> for Client {
+    fn handle(&mut self, event: CombatHandleVictimNotificationEventSelf) -> Option<GameEvent> {
+        info!(target: "net", "Killed: {}", event.message);
+        Some(GameEvent::CombatKill {
+            message: event.message,
+            own_death: true,
+        })
+    }
+}
+
+impl GameEventHandler<CombatHandleVictimNotificationEventOther> for Client {
+    fn handle(&mut self, event: CombatHandleVictimNotificationEventOther) -> Option<GameEvent> {
+        info!(target: "net", "Kill: {}", event.message);
+        Some(GameEvent::CombatKill {
+            message: event.message,
+            own_death: false,
+        })
+    }
+}
+
+// ============================================================================
+// Confirmation game event handlers
+// ============================================================================
+
+impl GameEventHandler<CharacterConfirmationRequest> for Client {
+    fn handle(&mut self, event: CharacterConfirmationRequest) -> Option<GameEvent> {
+        info!(target: "net", "Confirmation request: type={:?}, context={}, text={}",
+            event.confirmation_type, event.context_id, event.text);
+        let confirmation_type = event.confirmation_type as u32;
+        self.pending_confirmations.insert(PendingConfirmation {
+            confirmation_type,
+            context_id: event.context_id,
+            message: event.text.clone(),
+        });
+        Some(GameEvent::ConfirmationRequest {
+            confirmation_type,
+            context_id: event.context_id,
+            message: event.text,
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::VecDeque;
+
+/// Most prompts remembered at once; the oldest is forgotten beyond this
+const MAX_PENDING_CONFIRMATIONS: usize = 16;
+
+/// ConfirmationType of a fellowship invite
+pub const CONFIRMATION_TYPE_FELLOWSHIP: u32 = 4;
+
+/// A yes/no prompt from the server (CharacterConfirmationRequest) waiting for an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingConfirmation {
+    /// ConfirmationType, e.g. [`CONFIRMATION_TYPE_FELLOWSHIP`]
+    pub confirmation_type: u32,
+    pub context_id: u32,
+    pub message: String,
+}
+
+/// Confirmation prompts that haven't been answered yet
+///
+/// Several can be outstanding at once (an allegiance request can arrive
+/// while a fellowship invite is open), so answers are matched to prompts by
+/// context id. Prompts are kept in the order they arrived.
+#[derive(Debug, Default)]
+pub struct PendingConfirmations {
+    pending: VecDeque<PendingConfirmation>,
+}
+
+impl PendingConfirmations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a prompt, replacing one with the same context id
+    pub fn insert(&mut self, confirmation: PendingConfirmation) {
+        self.take(confirmation.context_id);
+        if self.pending.len() == MAX_PENDING_CONFIRMATIONS {
+            self.pending.pop_front();
+        }
+        self.pending.push_back(confirmation);
+    }
+
+    /// The prompt with `context_id`, if it's still unanswered
+    pub fn get(&self, context_id: u32) -> Option<&PendingConfirmation> {
+        self.pending.iter().find(|c| c.context_id == context_id)
+    }
+
+    /// Forget the prompt with `context_id` once it's answered, returning it
+    pub fn take(&mut self, context_id: u32) -> Option<PendingConfirmation> {
+        let index = self
+            .pending
+            .iter()
+            .position(|c| c.context_id == context_id)?;
+        self.pending.remove(index)
+    }
+
+    /// The most recent unanswered prompt of `confirmation_type`
+    pub fn latest(&self, confirmation_type: u32) -> Option<&PendingConfirmation> {
+        self.pending
+            .iter()
+            .rev()
+            .find(|c| c.confirmation_type == confirmation_type)
+    }
+
+    /// Forget all prompts (logout or new connection)
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt(confirmation_type: u32, context_id: u32) -> PendingConfirmation {
+        PendingConfirmation {
+            confirmation_type,
+            context_id,
+            message: format!("prompt {}", context_id),
+        }
+    }
+
+    #[test]
+    fn test_answers_match_by_context_id() {
+        let mut confirmations = PendingConfirmations::new();
+        confirmations.insert(prompt(CONFIRMATION_TYPE_FELLOWSHIP, 7));
+        confirmations.insert(prompt(1, 8)); // swear allegiance
+
+        assert_eq!(confirmations.get(8).unwrap().confirmation_type, 1);
+        assert_eq!(
+            confirmations.take(7),
+            Some(prompt(CONFIRMATION_TYPE_FELLOWSHIP, 7))
+        );
+        // Each prompt can only be answered once
+        assert_eq!(confirmations.take(7), None);
+        assert!(confirmations.get(8).is_some());
+    }
+
+    #[test]
+    fn test_latest_of_type_and_capacity() {
+        let mut confirmations = PendingConfirmations::new();
+        for context_id in 0..MAX_PENDING_CONFIRMATIONS as u32 + 2 {
+            confirmations.insert(prompt(CONFIRMATION_TYPE_FELLOWSHIP, context_id));
+        }
+        confirmations.insert(prompt(1, 100));
+
+        // The oldest prompts were forgotten to make room
+        assert!(confirmations.get(0).is_none());
+        assert!(confirmations.get(2).is_none());
+        assert_eq!(
+            confirmations
+                .latest(CONFIRMATION_TYPE_FELLOWSHIP)
+                .unwrap()
+                .context_id,
+            MAX_PENDING_CONFIRMATIONS as u32 + 1
+        );
+        assert!(confirmations.latest(2).is_none());
+    }
+}
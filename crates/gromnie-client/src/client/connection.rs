@@ -55,3 +55,33 @@ impl ServerInfo {
         self.resolve_addr(self.world_port).await
     }
 }
+
+/// Server/world identity learned while connecting
+///
+/// Fields are filled in as the relevant messages arrive, so any of the
+/// optional values may still be `None` early in the login flow.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorldInfo {
+    /// World name from Login_WorldInfo
+    pub world_name: Option<String>,
+    /// Name-rule language from DDDInterrogation
+    pub language: Option<String>,
+    /// Server region from DDDInterrogation
+    pub region: Option<String>,
+    /// Product id from DDDInterrogation
+    pub product: Option<String>,
+    /// Host the client connected to
+    pub server_host: String,
+    /// Login port the client connected to
+    pub server_port: u16,
+}
+
+impl WorldInfo {
+    pub fn new(server: &ServerInfo) -> Self {
+        WorldInfo {
+            server_host: server.host.clone(),
+            server_port: server.login_port,
+            ..Default::default()
+        }
+    }
+}
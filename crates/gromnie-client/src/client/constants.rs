@@ -4,10 +4,27 @@ pub const PACKET_HEADER_SIZE: usize = 20;
 pub const CHECKSUM_OFFSET: usize = 8;
 pub const CHECKSUM_PLACEHOLDER: u32 = 0xbadd70dd;
 pub const FRAGMENT_HEADER_SIZE: usize = 16; // sequence(4) + id(4) + count(2) + size(2) + index(2) + group(2)
+/// Largest fragment payload that fits in one packet (464 byte fragment minus its header)
+pub const MAX_FRAGMENT_DATA_SIZE: usize = 448;
+
+/// Opcode of the C2S message that wraps every game action
+pub const ORDERED_GAME_ACTION_OPCODE: u32 = 0xF7B1;
 
 // UI delay for connection flow to make progress visible (1 second)
 pub const UI_DELAY_MS: u64 = 1000;
 
+/// LoginRequests sent before giving up on the server answering, the first
+/// one included; at the 2s retry interval this stays inside the 20s timeout
+pub const DEFAULT_MAX_LOGIN_ATTEMPTS: u32 = 5;
+
+/// Longest a shutdown waits for queued messages to go out, delayed ones
+/// included, before the connection is dropped
+pub const SHUTDOWN_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// How long the server has to verify a character created for an empty
+/// account before the client gives up and stays at character select
+pub const CHARACTER_CREATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 /// DDD Interrogation Response - indicates client is up-to-date with all DAT files
 /// Format: [Opcode (0xF7E6), Language (1), CAllIterationList count (0)]
 ///
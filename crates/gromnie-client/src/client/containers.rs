@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::instant::Instant;
+
+/// How long to wait for a container's contents after asking to open it
+pub const OPEN_CONTAINER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Containers we asked to open and are waiting on ItemOnViewContents for
+///
+/// The server also sends contents for containers opened some other way (or
+/// when a request was answered late), so responses are matched by container
+/// id and requests older than [`OPEN_CONTAINER_TIMEOUT`] are dropped.
+#[derive(Debug, Default)]
+pub struct OpenContainerRequests {
+    /// Container id -> when the open was requested
+    requested: HashMap<u32, Instant>,
+}
+
+impl OpenContainerRequests {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a request to open `container_id` at `now`
+    pub fn request(&mut self, container_id: u32, now: Instant) {
+        self.expire(now);
+        self.requested.insert(container_id, now);
+    }
+
+    /// Match contents for `container_id` received at `now` to a request.
+    /// Returns true, and forgets the request, if one was still outstanding.
+    pub fn take(&mut self, container_id: u32, now: Instant) -> bool {
+        self.expire(now);
+        self.requested.remove(&container_id).is_some()
+    }
+
+    /// Forget requests that have waited longer than the timeout, returning their ids
+    pub fn expire(&mut self, now: Instant) -> Vec<u32> {
+        let mut expired = Vec::new();
+        self.requested.retain(|&container_id, &mut requested_at| {
+            let stale = now.duration_since(requested_at) > OPEN_CONTAINER_TIMEOUT;
+            if stale {
+                expired.push(container_id);
+            }
+            !stale
+        });
+        expired
+    }
+
+    /// Forget all requests (logout or new connection)
+    pub fn clear(&mut self) {
+        self.requested.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_requested_container_once() {
+        let start = Instant::now();
+        let mut requests = OpenContainerRequests::new();
+        requests.request(0x5000_0001, start);
+
+        // Contents of a container nobody asked for don't match
+        assert!(!requests.take(0x5000_0002, start));
+        assert!(requests.take(0x5000_0001, start + Duration::from_secs(1)));
+        assert!(!requests.take(0x5000_0001, start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_stale_requests_expire() {
+        let start = Instant::now();
+        let mut requests = OpenContainerRequests::new();
+        requests.request(0x5000_0001, start);
+        requests.request(0x5000_0002, start + Duration::from_secs(8));
+
+        let later = start + OPEN_CONTAINER_TIMEOUT + Duration::from_secs(1);
+        assert_eq!(requests.expire(later), vec![0x5000_0001]);
+        assert!(!requests.take(0x5000_0001, later));
+        assert!(requests.take(0x5000_0002, later));
+    }
+}
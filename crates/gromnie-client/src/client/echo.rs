@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use asheron_rs::enums::PacketHeaderFlags;
+
+use crate::client::constants::PACKET_HEADER_SIZE;
+use crate::instant::Instant;
+
+/// Echoes older than this are ignored (stale, or from before a clock restart)
+const MAX_ECHO_AGE: Duration = Duration::from_secs(60);
+/// Weight of each new sample in the smoothed round-trip time, so roughly the
+/// last eight echoes contribute
+const RTT_SMOOTHING: f32 = 0.125;
+
+/// Measures round-trip time from the echo_time the server reflects back
+///
+/// Outgoing TimeSync packets carry an ECHO_REQUEST stamped with seconds since
+/// the tracker was created. The server copies that value into the
+/// ECHO_RESPONSE of a later packet, so the difference from the current stamp
+/// is the round trip, including however long the server held the echo.
+/// Samples are smoothed with an exponentially weighted moving average so one
+/// slow echo doesn't make the readout jump.
+#[derive(Debug)]
+pub struct EchoTracker {
+    start: Instant,
+    smoothed_rtt: Option<Duration>,
+}
+
+impl EchoTracker {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            start: now,
+            smoothed_rtt: None,
+        }
+    }
+
+    /// The echo_time to send with an ECHO_REQUEST at `now`
+    pub fn stamp(&self, now: Instant) -> f32 {
+        now.duration_since(self.start).as_secs_f32()
+    }
+
+    /// Record an ECHO_RESPONSE carrying `echo_time`, received at `now`.
+    /// Returns the round-trip time, or None if the echo doesn't make sense.
+    pub fn on_echo(&mut self, echo_time: f32, now: Instant) -> Option<Duration> {
+        let elapsed = self.stamp(now) - echo_time;
+        if !echo_time.is_finite()
+            || echo_time < 0.0
+            || !(0.0..=MAX_ECHO_AGE.as_secs_f32()).contains(&elapsed)
+        {
+            return None;
+        }
+        let rtt = Duration::from_secs_f32(elapsed);
+        self.smoothed_rtt = Some(match self.smoothed_rtt {
+            Some(smoothed) => smoothed.mul_f32(1.0 - RTT_SMOOTHING) + rtt.mul_f32(RTT_SMOOTHING),
+            None => rtt,
+        });
+        Some(rtt)
+    }
+
+    /// Round-trip time averaged over recent echoes
+    pub fn smoothed_rtt(&self) -> Option<Duration> {
+        self.smoothed_rtt
+    }
+}
+
+/// Offset of the ECHO_RESPONSE optional header in a server packet with `flags`
+///
+/// Optional headers follow the packet header in flag order. Returns None if a
+/// variable-length header comes first, since its size isn't known without
+/// parsing it.
+pub fn echo_response_offset(flags: PacketHeaderFlags) -> Option<usize> {
+    if flags.intersects(
+        PacketHeaderFlags::REQUEST_RETRANSMIT
+            | PacketHeaderFlags::REJECT_RETRANSMIT
+            | PacketHeaderFlags::LOGIN_REQUEST
+            | PacketHeaderFlags::CONNECT_REQUEST,
+    ) {
+        return None;
+    }
+
+    let fixed_headers = [
+        (PacketHeaderFlags::SERVER_SWITCH, 8),
+        (PacketHeaderFlags::ACK_SEQUENCE, 4),
+        (PacketHeaderFlags::WORLD_LOGIN_REQUEST, 8),
+        (PacketHeaderFlags::CONNECT_RESPONSE, 8),
+        (PacketHeaderFlags::CICMDCOMMAND, 8),
+        (PacketHeaderFlags::TIME_SYNC, 8),
+        (PacketHeaderFlags::ECHO_REQUEST, 4),
+    ];
+    Some(
+        PACKET_HEADER_SIZE
+            + fixed_headers
+                .iter()
+                .filter(|(flag, _)| flags.contains(*flag))
+                .map(|(_, size)| size)
+                .sum::<usize>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_echo_round_trip() {
+        let start = Instant::now();
+        let mut echo = EchoTracker::new(start);
+        assert_eq!(echo.smoothed_rtt(), None);
+
+        let sent = start + Duration::from_secs(10);
+        let stamp = echo.stamp(sent);
+        let rtt = echo
+            .on_echo(stamp, sent + Duration::from_millis(150))
+            .unwrap();
+        assert!((rtt.as_secs_f32() - 0.15).abs() < 0.01);
+        assert_eq!(echo.smoothed_rtt(), Some(rtt));
+
+        // Echoes from the future or far in the past are ignored
+        assert_eq!(echo.on_echo(stamp + 5.0, sent), None);
+        assert_eq!(echo.on_echo(stamp, sent + Duration::from_secs(120)), None);
+        assert_eq!(echo.on_echo(f32::NAN, sent), None);
+        assert_eq!(echo.smoothed_rtt(), Some(rtt));
+    }
+
+    #[test]
+    fn test_smoothed_rtt() {
+        let start = Instant::now();
+        let mut echo = EchoTracker::new(start);
+        let echo_after = |echo: &mut EchoTracker, sent_secs: u64, rtt_millis: u64| {
+            let sent = start + Duration::from_secs(sent_secs);
+            let stamp = echo.stamp(sent);
+            echo.on_echo(stamp, sent + Duration::from_millis(rtt_millis))
+                .unwrap()
+        };
+
+        // The first sample is taken as is
+        echo_after(&mut echo, 1, 100);
+        // One spike only moves the average an eighth of the way
+        assert_eq!(echo_after(&mut echo, 2, 900).as_millis(), 900);
+        let smoothed = echo.smoothed_rtt().unwrap().as_millis();
+        assert!((195..=205).contains(&smoothed), "{}", smoothed);
+
+        // Steady samples pull it back down
+        for i in 0..40 {
+            echo_after(&mut echo, 3 + i, 100);
+        }
+        let smoothed = echo.smoothed_rtt().unwrap().as_millis();
+        assert!((99..=102).contains(&smoothed), "{}", smoothed);
+    }
+
+    #[test]
+    fn test_echo_response_offset() {
+        assert_eq!(
+            echo_response_offset(PacketHeaderFlags::ECHO_RESPONSE),
+            Some(PACKET_HEADER_SIZE)
+        );
+        assert_eq!(
+            echo_response_offset(
+                PacketHeaderFlags::ACK_SEQUENCE
+                    | PacketHeaderFlags::TIME_SYNC
+                    | PacketHeaderFlags::ECHO_RESPONSE
+            ),
+            Some(PACKET_HEADER_SIZE + 12)
+        );
+        assert_eq!(
+            echo_response_offset(
+                PacketHeaderFlags::REQUEST_RETRANSMIT | PacketHeaderFlags::ECHO_RESPONSE
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use asheron_rs::enums::HoldKey;
+use asheron_rs::gameactions::{CommunicationEmote, MovementDoMovementCommand};
+use asheron_rs::message::GameActionMessage;
+use gromnie_events::EmoteKind;
+
+/// Build the game action that plays a predefined emote animation
+pub fn emote_action(emote: EmoteKind) -> GameActionMessage {
+    GameActionMessage::MovementDoMovementCommand(MovementDoMovementCommand {
+        motion: emote.motion_command(),
+        speed: 1.0,
+        hold_key: HoldKey::None,
+    })
+}
+
+/// Build the game action for a custom "/e" style emote
+pub fn text_emote_action(text: String) -> GameActionMessage {
+    GameActionMessage::CommunicationEmote(CommunicationEmote { message: text })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use asheron_rs::message::C2SMessage;
+    use asheron_rs::writers::ACWritable;
+    use byteorder::{ByteOrder, LittleEndian};
+
+    use super::*;
+
+    const OPCODE_ORDERED_GAME_ACTION: u32 = 0xF7B1;
+    const ACTION_COMMUNICATION_EMOTE: u32 = 0x01DF;
+
+    fn serialize(sequence: u32, action: GameActionMessage) -> Vec<u8> {
+        let mut data = Vec::new();
+        C2SMessage::OrderedGameAction { sequence, action }
+            .write(&mut Cursor::new(&mut data))
+            .expect("write failed");
+        data
+    }
+
+    #[test]
+    fn test_text_emote_serialization() {
+        let data = serialize(7, text_emote_action("waves hello".to_string()));
+
+        assert_eq!(
+            LittleEndian::read_u32(&data[0..4]),
+            OPCODE_ORDERED_GAME_ACTION
+        );
+        assert_eq!(LittleEndian::read_u32(&data[4..8]), 7);
+        assert_eq!(
+            LittleEndian::read_u32(&data[8..12]),
+            ACTION_COMMUNICATION_EMOTE
+        );
+
+        // String16L: u16 length, bytes, padded to a 4-byte boundary
+        let len = LittleEndian::read_u16(&data[12..14]) as usize;
+        assert_eq!(len, "waves hello".len());
+        assert_eq!(&data[14..14 + len], b"waves hello");
+        assert_eq!(data.len() % 4, 0);
+    }
+
+    #[test]
+    fn test_emote_uses_motion_command() {
+        let GameActionMessage::MovementDoMovementCommand(cmd) = emote_action(EmoteKind::Wave)
+        else {
+            panic!("Expected MovementDoMovementCommand");
+        };
+        assert_eq!(cmd.motion, 0x1300_0087);
+        assert_eq!(cmd.speed, 1.0);
+    }
+
+    #[test]
+    fn test_emote_ids_round_trip() {
+        for emote in EmoteKind::ALL {
+            assert_eq!(
+                EmoteKind::from_motion_command(emote.motion_command()),
+                Some(emote)
+            );
+        }
+        assert_eq!(EmoteKind::from_motion_command(0x87), None);
+        assert_eq!(EmoteKind::from_motion_command(0x4100_0003), None);
+    }
+}
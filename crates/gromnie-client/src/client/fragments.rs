@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use asheron_rs::enums::FragmentGroup;
+use asheron_rs::network::Fragment;
+use asheron_rs::types::BlobFragments;
+use tracing::{debug, warn};
+
+use super::constants::{FRAGMENT_HEADER_SIZE, MAX_FRAGMENT_DATA_SIZE};
+use crate::instant::Instant;
+
+/// How long a partially received message waits for its missing fragments
+/// before it's dropped
+pub const DEFAULT_FRAGMENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Object ID the server expects on fragments carrying game messages
+const GAME_MESSAGE_FRAGMENT_ID: u32 = 0x80000000;
+
+/// Split an outgoing message into fragments that each fit in one packet
+///
+/// Every fragment shares `sequence`, so the server reassembles them into a
+/// single message by `index`. An empty message still produces one fragment.
+pub(super) fn split_message(
+    sequence: u32,
+    group: FragmentGroup,
+    message_data: &[u8],
+) -> Vec<BlobFragments> {
+    let chunks: Vec<&[u8]> = if message_data.is_empty() {
+        vec![message_data]
+    } else {
+        message_data.chunks(MAX_FRAGMENT_DATA_SIZE).collect()
+    };
+    let count = chunks.len() as u16;
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, chunk)| BlobFragments {
+            sequence,
+            id: GAME_MESSAGE_FRAGMENT_ID,
+            count,
+            size: (FRAGMENT_HEADER_SIZE + chunk.len()) as u16,
+            index: index as u16,
+            group,
+            data: chunk.to_vec(),
+        })
+        .collect()
+}
+
+/// A received message still missing some of its fragments
+struct PendingMessage {
+    fragment: Fragment,
+    /// Which fragment indices have already been added
+    received: Vec<bool>,
+    /// When the first fragment arrived
+    started_at: Instant,
+}
+
+/// Reassembles received fragments into messages, keyed by fragment sequence
+///
+/// Retransmits can deliver the same fragment more than once, so each index is
+/// only added the first time it arrives. Fragments whose index is out of range
+/// for their count are dropped. A message whose last fragment is lost never
+/// completes, so [`FragmentReassembler::sweep`] drops messages that have been
+/// waiting too long.
+#[derive(Default)]
+pub(super) struct FragmentReassembler {
+    pending: HashMap<u32, PendingMessage>,
+}
+
+impl FragmentReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a received fragment. Returns the message data once every fragment
+    /// of its sequence has arrived.
+    pub fn add(&mut self, blob_fragment: &BlobFragments, now: Instant) -> Option<Vec<u8>> {
+        let sequence = blob_fragment.sequence;
+        let index = blob_fragment.index as usize;
+        let count = blob_fragment.count;
+
+        if index >= count as usize {
+            warn!(target: "net", "Dropping fragment {} of sequence {}: count is only {}", index, sequence, count);
+            return None;
+        }
+
+        let pending = self
+            .pending
+            .entry(sequence)
+            .or_insert_with(|| PendingMessage {
+                fragment: Fragment::new(sequence, count),
+                received: vec![false; count as usize],
+                started_at: now,
+            });
+
+        if pending.received.len() != count as usize {
+            warn!(target: "net", "Dropping fragment {} of sequence {}: count {} doesn't match earlier fragments ({})",
+                index, sequence, count, pending.received.len());
+            return None;
+        }
+        if pending.received[index] {
+            debug!(target: "net", "Ignoring duplicate fragment {} of sequence {}", index, sequence);
+            return None;
+        }
+        pending.received[index] = true;
+
+        // Size includes the fragment header; the chunk is just the data
+        pending
+            .fragment
+            .set_fragment_info(blob_fragment.size, blob_fragment.group as u16);
+        pending
+            .fragment
+            .add_chunk(&blob_fragment.data, index, blob_fragment.data.len());
+
+        if !pending.fragment.is_complete() {
+            return None;
+        }
+
+        let mut pending = self.pending.remove(&sequence)?;
+        let data = pending.fragment.get_data().to_vec();
+        pending.fragment.cleanup();
+        Some(data)
+    }
+
+    /// Drop messages whose first fragment arrived more than `timeout` ago,
+    /// returning their sequences
+    pub fn sweep(&mut self, now: Instant, timeout: Duration) -> Vec<u32> {
+        let mut dropped: Vec<u32> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.started_at) > timeout)
+            .map(|(sequence, _)| *sequence)
+            .collect();
+        dropped.sort_unstable();
+
+        for sequence in &dropped {
+            if let Some(mut pending) = self.pending.remove(sequence) {
+                let missing = pending.received.iter().filter(|r| !**r).count();
+                warn!(target: "net", "Dropping incomplete message (sequence {}): {} of {} fragments never arrived",
+                    sequence, missing, pending.received.len());
+                pending.fragment.cleanup();
+            }
+        }
+        dropped
+    }
+
+    /// Drop every partially received message
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_message_reassembles() {
+        let payload: Vec<u8> = (0..2048).map(|i| (i % 251) as u8).collect();
+        let fragments = split_message(7, FragmentGroup::Object, &payload);
+
+        assert_eq!(
+            fragments.len(),
+            payload.len().div_ceil(MAX_FRAGMENT_DATA_SIZE)
+        );
+        let mut reassembled = Vec::new();
+        for (i, fragment) in fragments.iter().enumerate() {
+            assert_eq!(fragment.sequence, 7);
+            assert_eq!(fragment.count as usize, fragments.len());
+            assert_eq!(fragment.index as usize, i);
+            assert!(fragment.data.len() <= MAX_FRAGMENT_DATA_SIZE);
+            assert_eq!(
+                fragment.size as usize,
+                FRAGMENT_HEADER_SIZE + fragment.data.len()
+            );
+            reassembled.extend_from_slice(&fragment.data);
+        }
+        assert_eq!(reassembled, payload);
+    }
+
+    #[test]
+    fn test_small_message_is_one_fragment() {
+        let fragments = split_message(1, FragmentGroup::Object, &[1, 2, 3]);
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].count, 1);
+        assert_eq!(fragments[0].index, 0);
+    }
+
+    #[test]
+    fn test_duplicate_fragment_is_ignored() {
+        let payload: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+        let fragments = split_message(9, FragmentGroup::Object, &payload);
+        assert!(fragments.len() > 1);
+
+        let now = Instant::now();
+        let mut reassembler = FragmentReassembler::new();
+        assert_eq!(reassembler.add(&fragments[0], now), None);
+
+        // A retransmitted copy of the same index must not overwrite the first
+        let mut duplicate = fragments[0].clone();
+        duplicate.data = vec![0xFF; duplicate.data.len()];
+        assert_eq!(reassembler.add(&duplicate, now), None);
+
+        let mut reassembled = None;
+        for fragment in &fragments[1..] {
+            reassembled = reassembler.add(fragment, now);
+        }
+        assert_eq!(reassembled, Some(payload));
+    }
+
+    #[test]
+    fn test_out_of_range_index_is_dropped() {
+        let mut fragment = split_message(3, FragmentGroup::Object, &[1, 2, 3]).remove(0);
+        fragment.index = fragment.count;
+
+        let mut reassembler = FragmentReassembler::new();
+        assert_eq!(reassembler.add(&fragment, Instant::now()), None);
+        assert!(reassembler.pending.is_empty());
+    }
+
+    #[test]
+    fn test_sweep_drops_stale_partial_messages() {
+        let payload: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+        let stale = split_message(4, FragmentGroup::Object, &payload);
+        let fresh = split_message(5, FragmentGroup::Object, &payload);
+
+        let start = Instant::now();
+        let mut reassembler = FragmentReassembler::new();
+        // The final fragment of sequence 4 is lost
+        assert_eq!(reassembler.add(&stale[0], start), None);
+        let later = start + Duration::from_secs(20);
+        assert_eq!(reassembler.add(&fresh[0], later), None);
+
+        let timeout = Duration::from_secs(30);
+        assert!(reassembler.sweep(later, timeout).is_empty());
+        assert_eq!(
+            reassembler.sweep(start + Duration::from_secs(31), timeout),
+            [4]
+        );
+        assert!(!reassembler.pending.contains_key(&4));
+
+        // The newer message is kept and still completes
+        let mut reassembled = None;
+        for fragment in &fresh[1..] {
+            reassembled = reassembler.add(fragment, later);
+        }
+        assert_eq!(reassembled, Some(payload));
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use crate::instant::Instant;
+
+/// How often a TimeSync keep-alive is sent, well within the server's timeout
+/// window (ACE defaults to 60s in the world but can be as low as 10s)
+pub const DEFAULT_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(5);
+/// How long the server may go without sending anything before the
+/// connection is considered dead
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Decides when to send keep-alives and when a silent server counts as gone
+///
+/// Idle detection only starts once something has been received, so a server
+/// that never answers is left to the connecting scene's own timeouts.
+#[derive(Debug)]
+pub struct KeepAlive {
+    interval: Duration,
+    idle_timeout: Duration,
+    last_sent: Option<Instant>,
+    last_received: Option<Instant>,
+}
+
+impl Default for KeepAlive {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEEPALIVE_INTERVAL, DEFAULT_IDLE_TIMEOUT)
+    }
+}
+
+impl KeepAlive {
+    pub fn new(interval: Duration, idle_timeout: Duration) -> Self {
+        Self {
+            interval,
+            idle_timeout,
+            last_sent: None,
+            last_received: None,
+        }
+    }
+
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    pub fn set_idle_timeout(&mut self, idle_timeout: Duration) {
+        self.idle_timeout = idle_timeout;
+    }
+
+    /// Whether a keep-alive should be sent at `now`
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.last_sent
+            .is_none_or(|sent| now.duration_since(sent) >= self.interval)
+    }
+
+    /// Record that a keep-alive was sent at `now`
+    pub fn on_sent(&mut self, now: Instant) {
+        self.last_sent = Some(now);
+    }
+
+    /// Record that a packet arrived from the server at `now`
+    pub fn on_received(&mut self, now: Instant) {
+        self.last_received = Some(now);
+    }
+
+    /// How long it's been since the server last sent anything
+    pub fn silence(&self, now: Instant) -> Option<Duration> {
+        self.last_received
+            .map(|received| now.duration_since(received))
+    }
+
+    /// Whether the server has been silent for longer than the idle timeout
+    pub fn is_idle(&self, now: Instant) -> bool {
+        self.silence(now)
+            .is_some_and(|silence| silence > self.idle_timeout)
+    }
+
+    /// Forget when the server was last heard from (new connection)
+    pub fn reset(&mut self) {
+        self.last_received = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keepalive_due_at_interval() {
+        let start = Instant::now();
+        let mut keepalive = KeepAlive::new(Duration::from_secs(10), DEFAULT_IDLE_TIMEOUT);
+        assert!(keepalive.is_due(start));
+
+        keepalive.on_sent(start);
+        assert!(!keepalive.is_due(start + Duration::from_secs(9)));
+        assert!(keepalive.is_due(start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_idle_after_no_inbound_traffic() {
+        let start = Instant::now();
+        let mut keepalive = KeepAlive::new(Duration::from_secs(5), Duration::from_secs(20));
+        // Nothing received yet: not idle however long it's been
+        assert!(!keepalive.is_idle(start + Duration::from_secs(120)));
+
+        keepalive.on_received(start);
+        // Keep sending with no replies; sends don't count as traffic
+        for secs in (5..=20).step_by(5) {
+            let now = start + Duration::from_secs(secs);
+            assert!(keepalive.is_due(now));
+            keepalive.on_sent(now);
+            assert!(!keepalive.is_idle(now), "idle after {}s", secs);
+        }
+        assert!(keepalive.is_idle(start + Duration::from_secs(21)));
+
+        // Any packet from the server starts the clock again
+        keepalive.on_received(start + Duration::from_secs(21));
+        assert!(!keepalive.is_idle(start + Duration::from_secs(30)));
+
+        keepalive.reset();
+        assert!(!keepalive.is_idle(start + Duration::from_secs(300)));
+    }
+}
@@ -4,16 +4,22 @@
 //! Each handler focuses on business logic only - parsing and error handling
 //! are centralized in the message_handler module.
 
-use tracing::{error, info, warn};
+use std::borrow::Cow;
+
+use tracing::{debug, error, info, warn};
 
 use crate::client::Client;
+use crate::client::character_sheet::{attribute_value, skill_value};
 use crate::client::constants::UI_DELAY_MS;
 use crate::client::message_handler::MessageHandler;
 use crate::client::messages::{OutgoingMessage, OutgoingMessageContent};
+use crate::client::movement::{PlayerPosition, PositionSequences};
+use crate::client::objects::KnownObject;
+use crate::client::property_names::{property_int_name, variant_name};
 use crate::client::protocol_conversions::ToProtocolEvent;
 use crate::client::scene::ClientError;
 use crate::client::{ClientEvent, GameEvent};
-use gromnie_events::ProtocolEvent;
+use gromnie_events::{ObjectId, ProtocolEvent, S2CEvent};
 
 /// Handle LoginCreatePlayer messages
 impl MessageHandler<asheron_rs::messages::s2c::LoginCreatePlayer> for Client {
@@ -50,7 +56,9 @@ impl MessageHandler<asheron_rs::messages::s2c::LoginCreatePlayer> for Client {
             warn!(target: "net", "LoginCreatePlayer received but not in CharacterSelect with entering_world state");
         }
 
-        Some(GameEvent::CreatePlayer { character_id })
+        Some(GameEvent::CreatePlayer {
+            character_id: character_id.into(),
+        })
     }
 }
 /// Handle ItemCreateObject messages
@@ -64,6 +72,44 @@ impl MessageHandler<asheron_rs::messages::s2c::ItemCreateObject> for Client {
 
         info!(target: "net", "Object created in world: {} (ID: 0x{:08X})", object_name, object_id);
 
+        let position = create_obj
+            .physics_description
+            .position
+            .as_ref()
+            .map(|pos| PlayerPosition {
+                landcell: pos.landcell.0,
+                x: pos.frame.origin.x,
+                y: pos.frame.origin.y,
+                z: pos.frame.origin.z,
+                heading: PlayerPosition::heading_from_rotation(
+                    pos.frame.orientation.w,
+                    pos.frame.orientation.z,
+                ),
+            });
+        let burden = create_obj.weenie_description.burden.unwrap_or(0) as u32;
+        let value = create_obj.weenie_description.value.unwrap_or(0);
+        let items_capacity = create_obj
+            .weenie_description
+            .items_capacity
+            .map(|c| c as u32);
+        let container_capacity = create_obj
+            .weenie_description
+            .container_capacity
+            .map(|c| c as u32);
+
+        self.objects.insert(KnownObject {
+            id: object_id,
+            name: object_name.clone(),
+            item_type: format!("{:?}", create_obj.weenie_description.type_),
+            container_id: create_obj.weenie_description.container_id.map(|id| id.0),
+            stack_size: create_obj.weenie_description.stack_size.map(|s| s as u32),
+            burden,
+            value,
+            items_capacity,
+            container_capacity,
+            position,
+        });
+
         // Emit protocol event
         let protocol_event = ProtocolEvent::S2C(create_obj.to_protocol_event());
         let _ = self
@@ -71,20 +117,24 @@ impl MessageHandler<asheron_rs::messages::s2c::ItemCreateObject> for Client {
             .try_send(ClientEvent::Protocol(protocol_event));
 
         Some(GameEvent::ItemCreateObject {
-            object_id,
+            object_id: object_id.into(),
             name: object_name,
             item_type: format!("{:?}", create_obj.weenie_description.type_),
-            container_id: create_obj.weenie_description.container_id.map(|id| id.0),
-            burden: create_obj.weenie_description.burden.unwrap_or(0) as u32,
-            value: create_obj.weenie_description.value.unwrap_or(0),
-            items_capacity: create_obj
+            container_id: create_obj
                 .weenie_description
-                .items_capacity
-                .map(|c| c as u32),
-            container_capacity: create_obj
+                .container_id
+                .map(|id| id.0.into()),
+            burden,
+            value,
+            items_capacity,
+            container_capacity,
+            stack_size: create_obj.weenie_description.stack_size.map(|s| s as u32),
+            wielder_id: create_obj
                 .weenie_description
-                .container_capacity
-                .map(|c| c as u32),
+                .wielder_id
+                .map(|id| id.0.into()),
+            equipped_location: create_obj.weenie_description.location.map(|loc| loc.bits()),
+            position,
         })
     }
 }
@@ -95,6 +145,14 @@ impl MessageHandler<asheron_rs::messages::s2c::CommunicationHearSpeech> for Clie
         &mut self,
         speech: asheron_rs::messages::s2c::CommunicationHearSpeech,
     ) -> Option<GameEvent> {
+        if self
+            .squelch
+            .is_squelched(&speech.sender_name, speech.sender_id.0)
+        {
+            debug!(target: "net", "Dropping speech from squelched {}", speech.sender_name);
+            return None;
+        }
+
         let chat_text = format!("{} says, \"{}\"", speech.sender_name, speech.message);
         let message_type = speech.type_.clone() as u32;
 
@@ -119,6 +177,14 @@ impl MessageHandler<asheron_rs::messages::s2c::CommunicationHearRangedSpeech> fo
         &mut self,
         speech: asheron_rs::messages::s2c::CommunicationHearRangedSpeech,
     ) -> Option<GameEvent> {
+        if self
+            .squelch
+            .is_squelched(&speech.sender_name, speech.sender_id.0)
+        {
+            debug!(target: "net", "Dropping speech from squelched {}", speech.sender_name);
+            return None;
+        }
+
         let chat_text = format!("{} says, \"{}\"", speech.sender_name, speech.message);
         let message_type = speech.type_.clone() as u32;
 
@@ -154,10 +220,28 @@ impl MessageHandler<asheron_rs::messages::s2c::CharacterCharacterError> for Clie
             .raw_event_tx
             .try_send(ClientEvent::Protocol(protocol_event));
 
+        self.resolve_login_waiters(Err(error_message.clone()));
+
+        // A failed resume login goes back to character select to try again
+        let resume_account = self
+            .scene
+            .as_character_select()
+            .and_then(|char_select| char_select.entering_world.as_ref())
+            .map(|entering| entering.account.clone());
+        let resume_failed = error_code != 0x0004
+            && self
+                .resume_target
+                .as_mut()
+                .is_some_and(|target| target.fail());
+
         // ServerCrash (0x0004) means the server is going down - trigger reconnection
         if error_code == 0x0004 {
             warn!(target: "net", "ServerCrash received - entering Disconnected state for reconnection");
             self.enter_disconnected();
+        } else if resume_failed && let Some(account) = resume_account {
+            warn!(target: "net", "Resume login failed: {}", error_message);
+            self.transition_to_char_select(self.known_characters.clone());
+            self.resume_login(account);
         } else {
             // Other character errors are fatal - transition to Error scene
             self.transition_to_error(
@@ -211,6 +295,7 @@ impl MessageHandler<asheron_rs::messages::s2c::LoginLoginCharacterSet> for Clien
 
         // Store the character list for future reference
         self.known_characters = characters.clone();
+        self.resolve_character_list_waiters();
 
         // Transition from Patching to CharSelect scene
         self.transition_to_char_select(characters.clone());
@@ -226,16 +311,29 @@ impl MessageHandler<asheron_rs::messages::s2c::LoginLoginCharacterSet> for Clien
         self.ddd_response = None;
 
         // Reset reconnect attempt counter on successful connection
-        if self.reconnect_attempt_count > 0 {
+        let previous_attempts = self.reconnect_state.on_connected();
+        if previous_attempts > 0 {
             info!(target: "net", "Connection successful - resetting reconnect attempt counter from {} to 0",
-                self.reconnect_attempt_count);
-            self.reconnect_attempt_count = 0;
+                previous_attempts);
         }
 
         info!(target: "net", "Scene transition: Connecting (Patching) -> CharacterSelect");
 
+        // Log back into the world after a reconnect, ahead of any configured auto-login
+        let resumed = previous_attempts > 0
+            && self.should_resume_in_world()
+            && self.resume_login(char_list.account.clone());
+
+        // An empty account gets the configured character, logged into once created
+        let creating = !resumed
+            && self.known_characters.is_empty()
+            && self.start_character_creation(char_list.account.clone());
+
         // Check if auto-login is configured
-        if let Some(ref char_name) = self.character {
+        if !resumed
+            && !creating
+            && let Some(ref char_name) = self.character
+        {
             // Find the character in the list
             let found_char = self
                 .known_characters
@@ -248,7 +346,7 @@ impl MessageHandler<asheron_rs::messages::s2c::LoginLoginCharacterSet> for Clien
                 // Store the pending auto-login action to be processed in the main loop
                 self.pending_auto_login =
                     Some(gromnie_events::SimpleClientAction::LoginCharacter {
-                        character_id: character.character_id.0,
+                        character_id: character.character_id.0.into(),
                         character_name: character.name.clone(),
                         account: char_list.account.clone(),
                     });
@@ -297,6 +395,10 @@ impl MessageHandler<asheron_rs::messages::s2c::DDDInterrogationMessage> for Clie
         info!(target: "net", "Received DDD Interrogation - Language: {}, Region: {}, Product: {}",
             ddd_msg.name_rule_language, ddd_msg.servers_region, ddd_msg.product_id);
 
+        self.world_info.language = Some(ddd_msg.name_rule_language.to_string());
+        self.world_info.region = Some(ddd_msg.servers_region.to_string());
+        self.world_info.product = Some(ddd_msg.product_id.to_string());
+
         // Emit protocol event
         let protocol_event = ProtocolEvent::S2C(ddd_msg.to_protocol_event());
         let _ = self
@@ -341,6 +443,21 @@ impl MessageHandler<asheron_rs::messages::s2c::CharacterCharGenVerificationRespo
             .raw_event_tx
             .try_send(ClientEvent::Protocol(protocol_event));
 
+        let character = created_character(&response);
+        info!(target: "net", "Character created: {} (ID: {:?})", character.name, character.character_id);
+        self.known_characters.push(character.clone());
+
+        // Finish creating the character for an empty account: back to
+        // character select, then log in
+        if let Some((account, _)) = self.creating_character.take() {
+            self.transition_to_char_select(self.known_characters.clone());
+            self.pending_auto_login = Some(gromnie_events::SimpleClientAction::LoginCharacter {
+                character_id: character.character_id.0.into(),
+                character_name: character.name.clone(),
+                account,
+            });
+        }
+
         // Delay emitting CharacterListReceived event
         let game_event = GameEvent::CharacterListReceived {
             account: String::new(),
@@ -362,6 +479,38 @@ impl MessageHandler<asheron_rs::messages::s2c::CharacterCharGenVerificationRespo
     }
 }
 
+/// The character a char-gen verification response reports as created
+///
+/// Only the OK response type has a body, so it's the only one that decodes;
+/// rejections are handled where decoding fails.
+fn created_character(
+    response: &asheron_rs::messages::s2c::CharacterCharGenVerificationResponse,
+) -> asheron_rs::types::CharacterIdentity {
+    match response {
+        asheron_rs::messages::s2c::CharacterCharGenVerificationResponse::Type1(created) => {
+            asheron_rs::types::CharacterIdentity {
+                character_id: created.character_id.clone(),
+                name: created.name.clone(),
+                seconds_greyed_out: 0,
+            }
+        }
+    }
+}
+
+/// Handle LoginWorldInfo messages
+impl MessageHandler<asheron_rs::messages::s2c::LoginWorldInfo> for Client {
+    fn handle(
+        &mut self,
+        world_msg: asheron_rs::messages::s2c::LoginWorldInfo,
+    ) -> Option<GameEvent> {
+        info!(target: "net", "World info: {} ({}/{} connections)",
+            world_msg.world_name, world_msg.connections, world_msg.max_connections);
+
+        self.world_info.world_name = Some(world_msg.world_name.clone());
+        None
+    }
+}
+
 /// Handle ItemSetState messages
 impl MessageHandler<asheron_rs::messages::s2c::ItemSetState> for Client {
     fn handle(&mut self, state_msg: asheron_rs::messages::s2c::ItemSetState) -> Option<GameEvent> {
@@ -372,7 +521,7 @@ impl MessageHandler<asheron_rs::messages::s2c::ItemSetState> for Client {
 
         // For now, we'll just emit as a generic state property update
         Some(GameEvent::ItemSetState {
-            object_id,
+            object_id: object_id.into(),
             property_name: "State".to_string(),
             value: 0, // State is a bitfield, not a simple int
         })
@@ -385,22 +534,155 @@ impl MessageHandler<asheron_rs::messages::s2c::QualitiesPrivateUpdateInt> for Cl
         &mut self,
         quality_msg: asheron_rs::messages::s2c::QualitiesPrivateUpdateInt,
     ) -> Option<GameEvent> {
-        let property_name = format!("{:?}", quality_msg.key);
+        let property_key = quality_msg.key as u32;
+        let property_name = property_int_name(quality_msg.key);
         let value = quality_msg.value;
 
         info!(target: "net", "QualitiesPrivateUpdateInt: Property {} = {}", property_name, value);
+        self.character_sheet.set_int(property_name, value as i64);
+
+        // Built here rather than with ToProtocolEvent so the name is looked up once
+        let protocol_event = ProtocolEvent::S2C(S2CEvent::QualitiesPrivateUpdateInt {
+            key: property_key,
+            property: Cow::Borrowed(property_name),
+            value,
+        });
+        let _ = self
+            .raw_event_tx
+            .try_send(ClientEvent::Protocol(protocol_event));
 
-        // This is a global quality update, not tied to a specific object
-        // For now we'll emit with object_id 0 (or handle this differently)
-        // In reality, this might update the player or a specific object
         Some(GameEvent::QualitiesPrivateUpdateInt {
-            object_id: 0, // TODO: determine which object this applies to
-            property_name,
+            object_id: self.player_object_id(),
+            property_key,
+            property_name: Cow::Borrowed(property_name),
             value,
         })
     }
 }
 
+/// Handle QualitiesPrivateUpdateFloat messages
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesPrivateUpdateFloat> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesPrivateUpdateFloat,
+    ) -> Option<GameEvent> {
+        let property_name = variant_name(&update.key);
+        debug!(target: "net", "QualitiesPrivateUpdateFloat: Property {} = {}", property_name, update.value);
+        self.character_sheet.set_float(property_name, update.value);
+
+        Some(GameEvent::QualitiesUpdateFloat {
+            object_id: self.player_object_id(),
+            property_name: Cow::Borrowed(property_name),
+            value: update.value,
+        })
+    }
+}
+
+/// Handle QualitiesPrivateUpdateAttribute2ndLevel messages (current health, stamina or mana)
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesPrivateUpdateAttribute2ndLevel> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesPrivateUpdateAttribute2ndLevel,
+    ) -> Option<GameEvent> {
+        let vital_name = variant_name(&update.key);
+        debug!(target: "net", "QualitiesPrivateUpdateAttribute2ndLevel: {} = {}", vital_name, update.value);
+        self.character_sheet
+            .set_int(vital_name, update.value as i64);
+
+        Some(GameEvent::VitalUpdated {
+            object_id: self.player_object_id(),
+            vital_name: Cow::Borrowed(vital_name),
+            value: update.value,
+        })
+    }
+}
+
+/// Handle QualitiesUpdateInt messages (an integer quality on another object)
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesUpdateInt> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesUpdateInt,
+    ) -> Option<GameEvent> {
+        let object_id = update.object_id.0;
+        let property_name = property_int_name(update.key);
+        debug!(target: "net", "QualitiesUpdateInt: Object 0x{:08X} {} = {}", object_id, property_name, update.value);
+
+        Some(GameEvent::QualitiesUpdateInt {
+            object_id: object_id.into(),
+            property_key: update.key as u32,
+            property_name: Cow::Borrowed(property_name),
+            value: update.value,
+        })
+    }
+}
+
+/// Handle QualitiesUpdateFloat messages (a float quality on another object)
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesUpdateFloat> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesUpdateFloat,
+    ) -> Option<GameEvent> {
+        let object_id = update.object_id.0;
+        let property_name = variant_name(&update.key);
+        debug!(target: "net", "QualitiesUpdateFloat: Object 0x{:08X} {} = {}", object_id, property_name, update.value);
+
+        Some(GameEvent::QualitiesUpdateFloat {
+            object_id: object_id.into(),
+            property_name: Cow::Borrowed(property_name),
+            value: update.value,
+        })
+    }
+}
+
+/// Handle QualitiesUpdateAttribute2ndLevel messages (another object's current health, stamina or mana)
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesUpdateAttribute2ndLevel> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesUpdateAttribute2ndLevel,
+    ) -> Option<GameEvent> {
+        let object_id = update.object_id.0;
+        let vital_name = variant_name(&update.key);
+        debug!(target: "net", "QualitiesUpdateAttribute2ndLevel: Object 0x{:08X} {} = {}", object_id, vital_name, update.value);
+
+        Some(GameEvent::VitalUpdated {
+            object_id: object_id.into(),
+            vital_name: Cow::Borrowed(vital_name),
+            value: update.value,
+        })
+    }
+}
+
+/// Handle QualitiesPrivateUpdateAttribute messages
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesPrivateUpdateAttribute> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesPrivateUpdateAttribute,
+    ) -> Option<GameEvent> {
+        let attribute_id = update.key as u32;
+        let value = attribute_value(&update.value);
+        info!(target: "net", "QualitiesPrivateUpdateAttribute: {:?} = {}", update.key, value.base());
+
+        if !self.character_sheet.update_attribute(attribute_id, value) {
+            warn!(target: "net", "Unknown attribute id {}", attribute_id);
+        }
+        None
+    }
+}
+
+/// Handle QualitiesPrivateUpdateSkill messages
+impl MessageHandler<asheron_rs::messages::s2c::QualitiesPrivateUpdateSkill> for Client {
+    fn handle(
+        &mut self,
+        update: asheron_rs::messages::s2c::QualitiesPrivateUpdateSkill,
+    ) -> Option<GameEvent> {
+        let skill = skill_value(update.key as u32, &update.value);
+        info!(target: "net", "QualitiesPrivateUpdateSkill: {:?} = {:?}", update.key, skill);
+
+        self.character_sheet.update_skill(skill);
+        None
+    }
+}
+
 /// Handle ItemDeleteObject messages
 impl MessageHandler<asheron_rs::messages::s2c::ItemDeleteObject> for Client {
     fn handle(
@@ -410,6 +692,8 @@ impl MessageHandler<asheron_rs::messages::s2c::ItemDeleteObject> for Client {
         let object_id = delete_obj.object_id.0;
 
         info!(target: "net", "Object deleted from world: 0x{:08X}", object_id);
+        self.object_health.remove(&object_id);
+        self.objects.remove(object_id);
 
         // Emit protocol event
         let protocol_event = ProtocolEvent::S2C(delete_obj.to_protocol_event());
@@ -417,7 +701,141 @@ impl MessageHandler<asheron_rs::messages::s2c::ItemDeleteObject> for Client {
             .raw_event_tx
             .try_send(ClientEvent::Protocol(protocol_event));
 
-        Some(GameEvent::ItemDeleteObject { object_id })
+        Some(GameEvent::ItemDeleteObject {
+            object_id: object_id.into(),
+        })
+    }
+}
+
+/// Handle ItemServerSaysRemove messages (0x0024)
+///
+/// The server uses this to take an object away without a full delete, e.g.
+/// when it leaves view. Consumers see it the same as an ItemDeleteObject.
+impl MessageHandler<asheron_rs::messages::s2c::ItemServerSaysRemove> for Client {
+    fn handle(
+        &mut self,
+        msg: asheron_rs::messages::s2c::ItemServerSaysRemove,
+    ) -> Option<GameEvent> {
+        let object_id = msg.object_id.0;
+        debug!(target: "net", "Server removed object 0x{:08X}", object_id);
+        self.objects.remove(object_id);
+        self.object_health.remove(&object_id);
+
+        Some(GameEvent::ItemDeleteObject {
+            object_id: object_id.into(),
+        })
+    }
+}
+
+/// Handle ItemUpdateStackSize messages (0x0197)
+///
+/// The known object's stack is updated in place. A stack that reaches zero is
+/// gone, so it's then removed the same way as ItemServerSaysRemove.
+impl MessageHandler<asheron_rs::messages::s2c::ItemUpdateStackSize> for Client {
+    fn handle(&mut self, msg: asheron_rs::messages::s2c::ItemUpdateStackSize) -> Option<GameEvent> {
+        let object_id = msg.object_id.0;
+        debug!(target: "net", "Stack size of 0x{:08X} is now {} (value {})", object_id, msg.amount, msg.new_value);
+
+        let previous = self.objects.set_stack_size(object_id, msg.amount);
+        let event = GameEvent::ItemUpdateStackSize {
+            object_id: object_id.into(),
+            name: previous
+                .as_ref()
+                .map(|object| object.name.clone())
+                .unwrap_or_default(),
+            previous_stack_size: previous.and_then(|object| object.stack_size),
+            stack_size: msg.amount,
+            value: msg.new_value,
+        };
+        if msg.amount > 0 {
+            return Some(event);
+        }
+
+        debug!(target: "net", "Stack 0x{:08X} is empty, removing it", object_id);
+        let _ = self.raw_event_tx.try_send(ClientEvent::Game(event));
+        self.objects.remove(object_id);
+        self.object_health.remove(&object_id);
+
+        Some(GameEvent::ItemDeleteObject {
+            object_id: object_id.into(),
+        })
+    }
+}
+
+/// Handle CombatHandlePlayerDeathEvent messages (0x019E)
+impl MessageHandler<asheron_rs::messages::s2c::CombatHandlePlayerDeathEvent> for Client {
+    fn handle(
+        &mut self,
+        msg: asheron_rs::messages::s2c::CombatHandlePlayerDeathEvent,
+    ) -> Option<GameEvent> {
+        let victim_id = msg.killed_id.0;
+        let killer_id = msg.killer_id.0;
+        info!(target: "net", "Player death: 0x{:08X} killed by 0x{:08X}: {}", victim_id, killer_id, msg.message);
+
+        self.object_health.remove(&victim_id);
+
+        Some(GameEvent::PlayerDeath {
+            victim_id: victim_id.into(),
+            killer_id: killer_id.into(),
+            message: msg.message,
+        })
+    }
+}
+
+/// Position from a position update; updates that leave out the orientation
+/// keep `fallback_heading`
+fn unpack_position(pos: &asheron_rs::types::PositionPack, fallback_heading: f32) -> PlayerPosition {
+    let heading = match (pos.w_quat, pos.z_quat) {
+        (Some(w), Some(z)) => PlayerPosition::heading_from_rotation(w, z),
+        _ => fallback_heading,
+    };
+    PlayerPosition {
+        landcell: pos.origin.landcell.0,
+        x: pos.origin.location.x,
+        y: pos.origin.location.y,
+        z: pos.origin.location.z,
+        heading,
+    }
+}
+
+impl Client {
+    /// Object the private quality updates apply to: the character in the world,
+    /// or 0 before there is one
+    fn player_object_id(&self) -> ObjectId {
+        self.scene
+            .as_in_world()
+            .map_or(ObjectId(0), |in_world| in_world.character_id.into())
+    }
+
+    /// Record a position update and report where the object is now
+    fn object_moved(&mut self, object_id: u32, pos: &asheron_rs::types::PositionPack) -> GameEvent {
+        self.record_player_position(object_id, pos);
+        let position = unpack_position(pos, 0.0);
+        self.objects.set_position(object_id, position);
+        GameEvent::ObjectMoved {
+            object_id: object_id.into(),
+            position,
+        }
+    }
+
+    /// Record a position update if it's for the character in the world
+    fn record_player_position(&mut self, object_id: u32, pos: &asheron_rs::types::PositionPack) {
+        let Some(in_world) = self.scene.as_in_world() else {
+            return;
+        };
+        if in_world.character_id != object_id {
+            return;
+        }
+
+        let heading = self.movement.position().map_or(0.0, |p| p.heading);
+        self.movement.on_server_position(
+            unpack_position(pos, heading),
+            PositionSequences {
+                instance: pos.object_instance_sequence,
+                teleport: pos.object_teleport_sequence,
+                force_position: pos.object_force_position_sequence,
+            },
+        );
     }
 }
 
@@ -435,12 +853,14 @@ impl MessageHandler<asheron_rs::messages::s2c::MovementPositionEvent> for Client
             msg.position.origin.location.y,
             msg.position.origin.location.z);
 
+        let moved = self.object_moved(object_id, &msg.position);
+
         let protocol_event = ProtocolEvent::S2C(msg.to_protocol_event());
         let _ = self
             .raw_event_tx
             .try_send(ClientEvent::Protocol(protocol_event));
 
-        None
+        Some(moved)
     }
 }
 
@@ -458,12 +878,14 @@ impl MessageHandler<asheron_rs::messages::s2c::MovementPositionAndMovementEvent>
             msg.position.origin.location.y,
             msg.position.origin.location.z);
 
+        let moved = self.object_moved(object_id, &msg.position);
+
         let protocol_event = ProtocolEvent::S2C(msg.to_protocol_event());
         let _ = self
             .raw_event_tx
             .try_send(ClientEvent::Protocol(protocol_event));
 
-        None
+        Some(moved)
     }
 }
 
@@ -494,6 +916,8 @@ impl MessageHandler<asheron_rs::messages::s2c::EffectsPlayerTeleport> for Client
     ) -> Option<GameEvent> {
         info!(target: "net", "Player teleport effect (seq {})", msg.object_teleport_sequence);
 
+        self.movement.on_teleport(msg.object_teleport_sequence);
+
         let protocol_event = ProtocolEvent::S2C(msg.to_protocol_event());
         let _ = self
             .raw_event_tx
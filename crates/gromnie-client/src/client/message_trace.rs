@@ -0,0 +1,266 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::client::constants::ORDERED_GAME_ACTION_OPCODE;
+
+/// Direction of a traced message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceDirection {
+    Sent,
+    Received,
+}
+
+/// One line of the trace file
+#[derive(Serialize)]
+struct TraceRecord<'a> {
+    client_id: u32,
+    direction: TraceDirection,
+    timestamp: String,
+    opcode: String,
+    description: &'a str,
+    data: String,
+}
+
+/// One line of a trace file, as read back by [`read_trace`]
+#[derive(Deserialize)]
+struct TraceLine {
+    client_id: u32,
+    direction: TraceDirection,
+    timestamp: String,
+    data: String,
+}
+
+/// A message read back from a trace file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub client_id: u32,
+    pub direction: TraceDirection,
+    pub timestamp: DateTime<Utc>,
+    /// Message bytes, including the opcode
+    pub data: Vec<u8>,
+}
+
+/// Read every record of a trace written by [`MessageTrace`], in file order
+///
+/// Blank lines are skipped. A malformed line fails the whole read with its
+/// line number, since replaying a trace with gaps would be misleading.
+pub fn read_trace(reader: impl BufRead) -> io::Result<Vec<TraceEntry>> {
+    let mut entries = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let invalid = |reason: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", index + 1, reason),
+            )
+        };
+
+        let record: TraceLine = serde_json::from_str(&line).map_err(|e| invalid(e.to_string()))?;
+        let timestamp = DateTime::parse_from_rfc3339(&record.timestamp)
+            .map_err(|e| invalid(format!("invalid timestamp: {}", e)))?
+            .with_timezone(&Utc);
+        let data = from_hex(&record.data).ok_or_else(|| invalid("invalid hex data".into()))?;
+        entries.push(TraceEntry {
+            client_id: record.client_id,
+            direction: record.direction,
+            timestamp,
+            data,
+        });
+    }
+    Ok(entries)
+}
+
+/// Writes every sent and received message as newline-delimited JSON
+///
+/// Each line holds the client ID, direction, RFC 3339 timestamp, opcode,
+/// a short description and the message bytes (including the opcode) as hex.
+/// Clones share one buffered writer, so several clients can trace to the
+/// same file and be told apart by `client_id`. Call [`MessageTrace::flush`]
+/// on shutdown; the buffer is also flushed when the last clone is dropped.
+#[derive(Clone)]
+pub struct MessageTrace {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl MessageTrace {
+    /// Create (or truncate) the trace file at `path`
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::from_writer(BufWriter::new(file)))
+    }
+
+    /// Trace to any writer
+    pub fn from_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+        }
+    }
+
+    /// Append one message to the trace
+    pub fn record(
+        &self,
+        client_id: u32,
+        direction: TraceDirection,
+        opcode: u32,
+        description: &str,
+        data: &[u8],
+    ) {
+        let record = TraceRecord {
+            client_id,
+            direction,
+            timestamp: Utc::now().to_rfc3339(),
+            opcode: format!("0x{:04X}", opcode),
+            description,
+            data: to_hex(data),
+        };
+
+        let mut writer = self.writer.lock().expect("message trace poisoned");
+        let result = serde_json::to_writer(&mut *writer, &record)
+            .map_err(io::Error::from)
+            .and_then(|()| writer.write_all(b"\n"));
+        if let Err(e) = result {
+            warn!(target: "net", "Failed to write message trace: {}", e);
+        }
+    }
+
+    /// Record an outgoing message, reading the opcode from its first four bytes
+    pub fn record_sent(&self, client_id: u32, data: &[u8]) {
+        let opcode = read_u32(data, 0).unwrap_or(0);
+        let description = match (opcode, read_u32(data, 8)) {
+            // Opcode, then sequence, then the action type
+            (ORDERED_GAME_ACTION_OPCODE, Some(action)) => format!("GameAction 0x{:04X}", action),
+            _ => String::new(),
+        };
+        self.record(client_id, TraceDirection::Sent, opcode, &description, data);
+    }
+
+    /// Write any buffered records to the underlying writer
+    pub fn flush(&self) -> io::Result<()> {
+        self.writer.lock().expect("message trace poisoned").flush()
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn to_hex(data: &[u8]) -> String {
+    use std::fmt::Write as _;
+
+    let mut hex = String::with_capacity(data.len() * 2);
+    for byte in data {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_records_are_ndjson() {
+        let buf = SharedBuf::default();
+        let trace = MessageTrace::from_writer(buf.clone());
+
+        trace.record(
+            3,
+            TraceDirection::Received,
+            0xF7B0,
+            "OrderedGameEvent",
+            &[0xB0, 0xF7, 0x00, 0x00],
+        );
+        // Game action: opcode, sequence, action type
+        trace.record_sent(4, &[0xB1, 0xF7, 0, 0, 1, 0, 0, 0, 0x36, 0x00, 0, 0]);
+        trace.flush().unwrap();
+
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+
+        assert_eq!(lines[0]["client_id"], 3);
+        assert_eq!(lines[0]["direction"], "received");
+        assert_eq!(lines[0]["opcode"], "0xF7B0");
+        assert_eq!(lines[0]["description"], "OrderedGameEvent");
+        assert_eq!(lines[0]["data"], "b0f70000");
+
+        assert_eq!(lines[1]["client_id"], 4);
+        assert_eq!(lines[1]["direction"], "sent");
+        assert_eq!(lines[1]["opcode"], "0xF7B1");
+        assert_eq!(lines[1]["description"], "GameAction 0x0036");
+    }
+
+    #[test]
+    fn test_read_trace_round_trips_records() {
+        let buf = SharedBuf::default();
+        let trace = MessageTrace::from_writer(buf.clone());
+        trace.record(
+            3,
+            TraceDirection::Received,
+            0xF658,
+            "CharacterList",
+            &[0x58, 0xF6, 0x00, 0x00, 0x2A],
+        );
+        trace.record_sent(3, &[0xB1, 0xF7, 0, 0]);
+        trace.flush().unwrap();
+
+        let output = buf.0.lock().unwrap().clone();
+        let entries = read_trace(output.as_slice()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].client_id, 3);
+        assert_eq!(entries[0].direction, TraceDirection::Received);
+        assert_eq!(entries[0].data, [0x58, 0xF6, 0x00, 0x00, 0x2A]);
+        assert_eq!(entries[1].direction, TraceDirection::Sent);
+        assert!(entries[0].timestamp <= entries[1].timestamp);
+    }
+
+    #[test]
+    fn test_read_trace_reports_bad_line() {
+        let input = concat!(
+            r#"{"client_id":0,"direction":"received","timestamp":"2024-01-01T00:00:00Z","opcode":"0xF7B0","description":"","data":"b0f7"}"#,
+            "\n\n",
+            r#"{"client_id":0,"direction":"received","timestamp":"2024-01-01T00:00:01Z","opcode":"0xF7B0","description":"","data":"b0f"}"#,
+            "\n",
+        );
+        let err = read_trace(input.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 3:"), "{}", err);
+    }
+}
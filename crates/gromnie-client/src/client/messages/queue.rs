@@ -12,9 +12,14 @@ pub enum OutgoingMessageContent {
     EnterWorldRequest,
     // Character login - sent when selecting a character to enter the game world (after server ready)
     EnterWorld(LoginSendEnterWorld),
+    // Character logout - sent when leaving the game world for character select
+    LogOffCharacter,
     // GameAction message (raw bytes including opcode)
     // Used for DDD response and other messages that need custom serialization
     GameAction(Vec<u8>),
+    // Chat line paced by `SendChatLines`; built into a game action when it's
+    // due so its sequence number follows whatever was sent in the meantime
+    ChatLine(String),
 }
 
 /// Struct for outgoing messages that may have attributes like delay, queue, etc.
@@ -46,6 +51,12 @@ impl OutgoingMessage {
         self
     }
 
+    /// Send the message no earlier than `deadline`
+    pub fn at(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
     /// Check if this message is ready to be sent
     pub fn is_ready(&self) -> bool {
         match self.deadline {
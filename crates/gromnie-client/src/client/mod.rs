@@ -1,9 +1,21 @@
 // Re-export main types
 pub use self::client::Client;
-pub use self::connection::ServerInfo;
-pub use self::constants::UI_DELAY_MS;
+pub use self::confirmations::{CONFIRMATION_TYPE_FELLOWSHIP, PendingConfirmation};
+pub use self::connection::{ServerInfo, WorldInfo};
+pub use self::constants::{
+    CHARACTER_CREATION_TIMEOUT, DEFAULT_MAX_LOGIN_ATTEMPTS, SHUTDOWN_DRAIN_TIMEOUT, UI_DELAY_MS,
+};
+pub use self::containers::OPEN_CONTAINER_TIMEOUT;
+pub use self::fragments::DEFAULT_FRAGMENT_TIMEOUT;
+pub use self::keepalive::{DEFAULT_IDLE_TIMEOUT, DEFAULT_KEEPALIVE_INTERVAL};
+pub use self::message_trace::{MessageTrace, TraceDirection, TraceEntry, read_trace};
 pub use self::messages::{OutgoingMessage, OutgoingMessageContent};
+pub use self::movement::{PlayerPosition, WALK_SPEED, check_in_landblock};
 pub use self::protocol::{C2SPacketExt, CustomLoginRequest};
+pub use self::reconnect::{
+    MAX_RESUME_ATTEMPTS, ReconnectAction, ReconnectFailure, ReconnectState, ResumeTarget,
+};
+pub use self::retransmit::{DEFAULT_RETRANSMIT_BUFFER_SIZE, SentPacketBuffer};
 pub use self::scene::{
     CharacterCreateScene, CharacterSelectScene, ClientError, ConnectingProgress, ConnectingScene,
     EnteringWorldState, ErrorScene, InWorldScene, PatchingProgress, Scene,
@@ -12,23 +24,43 @@ pub use self::session::{Account, ClientSession, ConnectionState, SessionState};
 
 // Re-export event types from gromnie-events for compatibility
 pub use gromnie_events::{
-    ClientEvent, ClientStateEvent, ClientSystemEvent, SimpleClientAction,
+    ClientEvent, ClientStateEvent, ClientSystemEvent, ConnectionStats, SimpleClientAction,
     SimpleGameEvent as GameEvent,
 };
 // Re-export internal types
 pub use types::ClientAction;
 
 pub mod ace_protocol;
+mod allegiance;
+pub mod character_sheet;
+pub mod chat;
+mod checksum;
 #[allow(clippy::module_inception)]
 mod client;
+mod confirmations;
 mod connection;
 mod constants;
+mod containers;
+mod echo;
+pub mod emote;
+mod fragments;
 pub mod game_event_handler;
+mod keepalive;
 pub mod message_handler;
 mod message_handlers;
+mod message_trace;
 mod messages;
+mod movement;
+pub mod objects;
+mod ping;
+pub mod property_names;
 mod protocol;
 mod protocol_conversions;
+mod reconnect;
+mod retransmit;
 mod scene;
+mod server_time;
 mod session;
+mod squelch;
 pub mod types;
+mod unknown_opcodes;
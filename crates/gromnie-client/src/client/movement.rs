@@ -0,0 +1,281 @@
+use std::time::Duration;
+
+use crate::instant::Instant;
+
+pub use gromnie_events::PlayerPosition;
+
+/// How fast `move_to` walks the character, in meters per second
+pub const WALK_SPEED: f32 = 3.0;
+/// How often a position update is sent while walking
+const STEP_INTERVAL: Duration = Duration::from_millis(500);
+/// Close enough to the destination to stop walking, in meters
+const ARRIVAL_DISTANCE: f32 = 0.5;
+/// Width of an outdoor cell in meters; a landblock is 8x8 cells
+const OUTDOOR_CELL_SIZE: f32 = 24.0;
+
+/// Heading from `from` toward (x, y)
+fn heading_to(from: &PlayerPosition, x: f32, y: f32) -> f32 {
+    normalize_heading((x - from.x).atan2(y - from.y).to_degrees())
+}
+
+fn distance_to_point(from: &PlayerPosition, x: f32, y: f32, z: f32) -> f32 {
+    ((x - from.x).powi(2) + (y - from.y).powi(2) + (z - from.z).powi(2)).sqrt()
+}
+
+/// Move `from` to (x, y, z) in the same landblock, switching outdoor cells as
+/// the coordinates cross them. Dungeon and building cells are kept as is.
+fn moved_to(from: &PlayerPosition, x: f32, y: f32, z: f32) -> PlayerPosition {
+    let cell = from.landcell & 0xFFFF;
+    let landcell = if (1..=0x40).contains(&cell) {
+        let column = (x / OUTDOOR_CELL_SIZE).clamp(0.0, 7.0) as u32;
+        let row = (y / OUTDOOR_CELL_SIZE).clamp(0.0, 7.0) as u32;
+        (from.landcell & 0xFFFF_0000) | (column * 8 + row + 1)
+    } else {
+        from.landcell
+    };
+    PlayerPosition {
+        landcell,
+        x,
+        y,
+        z,
+        heading: from.heading,
+    }
+}
+
+/// Local coordinates have to fall inside a landblock, 0-192 on both axes
+pub fn check_in_landblock(x: f32, y: f32) -> Result<(), String> {
+    let inside = 0.0..=PlayerPosition::LANDBLOCK_SIZE;
+    if !inside.contains(&x) || !inside.contains(&y) {
+        return Err(format!(
+            "({}, {}) is outside the landblock (0-{})",
+            x,
+            y,
+            PlayerPosition::LANDBLOCK_SIZE
+        ));
+    }
+    Ok(())
+}
+
+fn normalize_heading(heading: f32) -> f32 {
+    heading.rem_euclid(360.0)
+}
+
+/// Sequence numbers the server stamps on the character's position updates;
+/// a position sent by the client has to echo them or the server ignores it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PositionSequences {
+    pub instance: u16,
+    pub teleport: u16,
+    pub force_position: u16,
+}
+
+/// The character's position and a destination it's walking toward
+///
+/// The server is authoritative: every position it sends replaces the one
+/// here. Walking is a straight line at [`WALK_SPEED`] with no pathfinding,
+/// so anything in the way stops the character short.
+#[derive(Debug, Default)]
+pub struct PlayerMovement {
+    position: Option<PlayerPosition>,
+    sequences: PositionSequences,
+    destination: Option<(f32, f32, f32)>,
+    last_step: Option<Instant>,
+}
+
+impl PlayerMovement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The character's last known position
+    pub fn position(&self) -> Option<PlayerPosition> {
+        self.position
+    }
+
+    pub fn sequences(&self) -> PositionSequences {
+        self.sequences
+    }
+
+    /// Record a position the server sent for the character. A forced move
+    /// (teleport or a server correction) cancels walking.
+    pub fn on_server_position(&mut self, position: PlayerPosition, sequences: PositionSequences) {
+        if sequences.teleport != self.sequences.teleport
+            || sequences.force_position != self.sequences.force_position
+        {
+            self.destination = None;
+        }
+        self.position = Some(position);
+        self.sequences = sequences;
+    }
+
+    /// Record a teleport; walking stops until the new position arrives
+    pub fn on_teleport(&mut self, teleport_sequence: u16) {
+        self.sequences.teleport = teleport_sequence;
+        self.destination = None;
+    }
+
+    /// Start walking toward (x, y, z) in the current landblock; x and y have
+    /// to be inside it
+    pub fn move_to(&mut self, x: f32, y: f32, z: f32, now: Instant) -> Result<(), String> {
+        if self.position.is_none() {
+            return Err("the character's position isn't known yet".to_string());
+        }
+        check_in_landblock(x, y)?;
+        self.destination = Some((x, y, z));
+        self.last_step = Some(now);
+        Ok(())
+    }
+
+    /// Face `heading` (degrees clockwise from north), stopping any walk.
+    /// Returns the position to send.
+    pub fn turn_to(&mut self, heading: f32) -> Result<PlayerPosition, String> {
+        let Some(position) = self.position.as_mut() else {
+            return Err("the character's position isn't known yet".to_string());
+        };
+        position.heading = normalize_heading(heading);
+        self.destination = None;
+        Ok(*position)
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.destination.is_some()
+    }
+
+    /// Walk toward the destination for the time since the last step.
+    /// Returns the position to send when an update is due.
+    pub fn step(&mut self, now: Instant) -> Option<PlayerPosition> {
+        let (x, y, z) = self.destination?;
+        let position = self.position?;
+        let last_step = self.last_step.unwrap_or(now);
+        let elapsed = now.duration_since(last_step);
+        if elapsed < STEP_INTERVAL {
+            return None;
+        }
+
+        let remaining = distance_to_point(&position, x, y, z);
+        let travel = WALK_SPEED * elapsed.as_secs_f32();
+        let mut next = if remaining <= travel.max(ARRIVAL_DISTANCE) {
+            self.destination = None;
+            moved_to(&position, x, y, z)
+        } else {
+            let t = travel / remaining;
+            moved_to(
+                &position,
+                position.x + (x - position.x) * t,
+                position.y + (y - position.y) * t,
+                position.z + (z - position.z) * t,
+            )
+        };
+        if remaining > ARRIVAL_DISTANCE {
+            next.heading = heading_to(&position, x, y);
+        }
+
+        self.position = Some(next);
+        self.last_step = Some(now);
+        Some(next)
+    }
+
+    /// Forget the position and any walk (logout or new connection)
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(x: f32, y: f32) -> PlayerPosition {
+        PlayerPosition {
+            landcell: 0xA9B4_0001,
+            x,
+            y,
+            z: 0.0,
+            heading: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_walks_toward_destination_and_stops() {
+        let start = Instant::now();
+        let mut movement = PlayerMovement::new();
+        assert!(movement.move_to(10.0, 0.0, 0.0, start).is_err());
+
+        movement.on_server_position(at(2.0, 2.0), PositionSequences::default());
+        movement.move_to(2.0, 8.0, 0.0, start).unwrap();
+
+        // Updates are only sent every STEP_INTERVAL
+        assert_eq!(movement.step(start + Duration::from_millis(100)), None);
+
+        let step = movement.step(start + Duration::from_secs(1)).unwrap();
+        assert!((step.y - (2.0 + WALK_SPEED)).abs() < 0.01);
+        assert!(step.heading.abs() < 0.01, "walking north: {}", step.heading);
+        assert!(movement.is_moving());
+
+        let arrived = movement.step(start + Duration::from_secs(3)).unwrap();
+        assert_eq!((arrived.x, arrived.y), (2.0, 8.0));
+        assert!(!movement.is_moving());
+        assert_eq!(movement.step(start + Duration::from_secs(4)), None);
+    }
+
+    #[test]
+    fn test_destination_outside_landblock_is_rejected() {
+        let start = Instant::now();
+        let mut movement = PlayerMovement::new();
+        movement.on_server_position(at(20.0, 20.0), PositionSequences::default());
+
+        assert!(movement.move_to(-1.0, 20.0, 0.0, start).is_err());
+        assert!(movement.move_to(20.0, 192.5, 0.0, start).is_err());
+        assert!(!movement.is_moving());
+
+        movement.move_to(192.0, 0.0, 0.0, start).unwrap();
+        let arrived = movement.step(start + Duration::from_secs(120)).unwrap();
+        // Column and row stay inside the landblock at its edge
+        assert_eq!(arrived.landcell, 0xA9B4_0039);
+    }
+
+    #[test]
+    fn test_crossing_outdoor_cells_updates_landcell() {
+        let start = Instant::now();
+        let mut movement = PlayerMovement::new();
+        movement.on_server_position(at(20.0, 20.0), PositionSequences::default());
+        movement.move_to(30.0, 50.0, 0.0, start).unwrap();
+
+        let arrived = movement.step(start + Duration::from_secs(30)).unwrap();
+        // Column 1, row 2
+        assert_eq!(arrived.landcell, 0xA9B4_000B);
+    }
+
+    #[test]
+    fn test_turn_and_heading_round_trip() {
+        let mut movement = PlayerMovement::new();
+        movement.on_server_position(at(0.0, 0.0), PositionSequences::default());
+        movement.move_to(5.0, 5.0, 0.0, Instant::now()).unwrap();
+
+        let turned = movement.turn_to(-90.0).unwrap();
+        assert_eq!(turned.heading, 270.0);
+        assert!(!movement.is_moving());
+
+        let (w, _, _, z) = turned.rotation();
+        let heading = PlayerPosition::heading_from_rotation(w, z);
+        assert!((heading - 270.0).abs() < 0.01, "{}", heading);
+    }
+
+    #[test]
+    fn test_forced_position_cancels_walk() {
+        let mut movement = PlayerMovement::new();
+        movement.on_server_position(at(0.0, 0.0), PositionSequences::default());
+        movement.move_to(50.0, 0.0, 0.0, Instant::now()).unwrap();
+
+        // A routine update keeps walking
+        movement.on_server_position(at(1.0, 0.0), PositionSequences::default());
+        assert!(movement.is_moving());
+
+        let forced = PositionSequences {
+            force_position: 1,
+            ..Default::default()
+        };
+        movement.on_server_position(at(0.0, 0.0), forced);
+        assert!(!movement.is_moving());
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use gromnie_events::PlayerPosition;
+
+/// An object the server has told the client about
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnownObject {
+    pub id: u32,
+    pub name: String,
+    /// Item type as reported in the object's weenie description (e.g. "Corpse")
+    pub item_type: String,
+    /// Container holding the object, or None if it is lying in the world
+    pub container_id: Option<u32>,
+    /// Number of items in the stack, for stackable items
+    pub stack_size: Option<u32>,
+    pub burden: u32,
+    pub value: u32,
+    pub items_capacity: Option<u32>,
+    pub container_capacity: Option<u32>,
+    /// Where the object is, if the server has said; kept up to date by
+    /// movement updates
+    pub position: Option<PlayerPosition>,
+}
+
+/// Objects created by the server and not yet removed, keyed by object ID
+///
+/// Filled from ItemCreateObject and pruned by ItemDeleteObject and
+/// ItemServerSaysRemove, so it reflects what this client currently knows
+/// about its surroundings and inventory. Stack sizes follow
+/// ItemUpdateStackSize and positions follow movement updates.
+#[derive(Debug, Default)]
+pub struct ObjectTable {
+    objects: BTreeMap<u32, KnownObject>,
+}
+
+impl ObjectTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace an object
+    pub fn insert(&mut self, object: KnownObject) {
+        self.objects.insert(object.id, object);
+    }
+
+    /// Forget an object, returning it if it was known
+    pub fn remove(&mut self, object_id: u32) -> Option<KnownObject> {
+        self.objects.remove(&object_id)
+    }
+
+    /// Set a known object's stack size, returning the object as it was
+    pub fn set_stack_size(&mut self, object_id: u32, stack_size: u32) -> Option<KnownObject> {
+        let object = self.objects.get_mut(&object_id)?;
+        let previous = object.clone();
+        object.stack_size = Some(stack_size);
+        Some(previous)
+    }
+
+    /// Move a known object; unknown objects aren't added
+    pub fn set_position(&mut self, object_id: u32, position: PlayerPosition) {
+        if let Some(object) = self.objects.get_mut(&object_id) {
+            object.position = Some(position);
+        }
+    }
+
+    pub fn get(&self, object_id: u32) -> Option<&KnownObject> {
+        self.objects.get(&object_id)
+    }
+
+    /// Whether `object_id` is in `owner_id`'s inventory, directly or inside
+    /// one of its packs
+    pub fn is_carried_by(&self, object_id: u32, owner_id: u32) -> bool {
+        let mut current = object_id;
+        // Bounded by the table size so a container loop can't spin forever
+        for _ in 0..=self.objects.len() {
+            match self.get(current).and_then(|object| object.container_id) {
+                Some(container_id) if container_id == owner_id => return true,
+                Some(container_id) => current = container_id,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// All known objects in object ID order
+    pub fn iter(&self) -> impl Iterator<Item = &KnownObject> {
+        self.objects.values()
+    }
+
+    /// Objects lying in the world (not in a container) in the given landblock,
+    /// in object ID order
+    pub fn in_landblock(&self, landblock: u32) -> impl Iterator<Item = &KnownObject> {
+        self.objects.values().filter(move |object| {
+            object.container_id.is_none()
+                && object
+                    .position
+                    .is_some_and(|position| position.landblock() == landblock)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.objects.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(id: u32, name: &str) -> KnownObject {
+        KnownObject {
+            id,
+            name: name.to_string(),
+            item_type: "Corpse".to_string(),
+            container_id: None,
+            stack_size: None,
+            burden: 0,
+            value: 0,
+            items_capacity: None,
+            container_capacity: None,
+            position: None,
+        }
+    }
+
+    fn at(landcell: u32) -> Option<PlayerPosition> {
+        Some(PlayerPosition {
+            landcell,
+            x: 10.0,
+            y: 20.0,
+            z: 0.0,
+            heading: 0.0,
+        })
+    }
+
+    #[test]
+    fn test_insert_replace_and_remove() {
+        let mut table = ObjectTable::new();
+        table.insert(object(2, "Corpse of Drudge"));
+        table.insert(object(1, "Corpse of Banderling"));
+        table.insert(object(2, "Corpse of Drudge Sneaker"));
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(2).unwrap().name, "Corpse of Drudge Sneaker");
+
+        let ids: Vec<u32> = table.iter().map(|o| o.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+
+        assert!(table.remove(1).is_some());
+        assert!(table.remove(1).is_none());
+        table.clear();
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_set_stack_size() {
+        let mut table = ObjectTable::new();
+        table.insert(KnownObject {
+            stack_size: Some(250),
+            ..object(7, "Arrow")
+        });
+
+        let previous = table.set_stack_size(7, 200).unwrap();
+        assert_eq!(previous.stack_size, Some(250));
+        assert_eq!(table.get(7).unwrap().stack_size, Some(200));
+        // Unknown objects aren't added
+        assert!(table.set_stack_size(8, 10).is_none());
+        assert!(table.get(8).is_none());
+    }
+
+    #[test]
+    fn test_in_landblock() {
+        let mut table = ObjectTable::new();
+        table.insert(KnownObject {
+            position: at(0xA9B4_0017),
+            ..object(1, "Corpse of Drudge")
+        });
+        table.insert(KnownObject {
+            position: at(0xA9B5_0001),
+            ..object(2, "Corpse of Banderling")
+        });
+        table.insert(KnownObject {
+            container_id: Some(1),
+            position: at(0xA9B4_0017),
+            ..object(3, "Pyreal")
+        });
+        table.insert(object(4, "Corpse of Mosswart"));
+
+        let ids: Vec<u32> = table.in_landblock(0xA9B4).map(|o| o.id).collect();
+        assert_eq!(ids, vec![1]);
+
+        // Moving into another landblock takes the object along
+        table.set_position(1, at(0xA9B5_0002).unwrap());
+        table.set_position(9, at(0xA9B4_0017).unwrap());
+        let ids: Vec<u32> = table.in_landblock(0xA9B5).map(|o| o.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(table.in_landblock(0xA9B4).count(), 0);
+        assert!(table.get(9).is_none());
+    }
+
+    #[test]
+    fn test_is_carried_by() {
+        let mut table = ObjectTable::new();
+        let in_container = |id, container_id| KnownObject {
+            container_id: Some(container_id),
+            ..object(id, "Pyreal")
+        };
+        // 10 is the character, 20 a pack it carries
+        table.insert(in_container(20, 10));
+        table.insert(in_container(21, 10));
+        table.insert(in_container(22, 20));
+        table.insert(in_container(30, 99));
+        table.insert(object(40, "Pyreal"));
+
+        assert!(table.is_carried_by(21, 10));
+        assert!(table.is_carried_by(22, 10));
+        assert!(!table.is_carried_by(30, 10));
+        assert!(!table.is_carried_by(40, 10));
+        assert!(!table.is_carried_by(50, 10));
+
+        // A container loop ends the search instead of spinning
+        table.insert(in_container(60, 61));
+        table.insert(in_container(61, 60));
+        assert!(!table.is_carried_by(60, 10));
+    }
+}
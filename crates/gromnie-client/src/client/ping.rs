@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::instant::Instant;
+
+/// How often to ping the server while in the world
+pub const PING_INTERVAL: Duration = Duration::from_secs(30);
+/// Pings older than this are assumed lost and no longer matched against replies
+const PING_TIMEOUT: Duration = Duration::from_secs(60);
+/// Upper bound on unanswered pings kept for matching
+const MAX_OUTSTANDING_PINGS: usize = 4;
+
+/// Tracks application-level pings (Character_PingRequest / Character_ReturnPing)
+///
+/// Replies carry no payload, so they are matched to requests in send order.
+/// The reply's game event sequence is used to drop duplicates and replies
+/// that arrive after a newer one has already been processed.
+#[derive(Debug, Default)]
+pub struct PingTracker {
+    /// Send times of unanswered pings, oldest first
+    outstanding: VecDeque<Instant>,
+    last_sent: Option<Instant>,
+    last_reply_sequence: Option<u32>,
+    last_rtt: Option<Duration>,
+}
+
+impl PingTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a new ping should be sent at `now`
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.last_sent
+            .is_none_or(|sent| now.duration_since(sent) >= PING_INTERVAL)
+    }
+
+    /// Record that a ping was sent at `now`
+    pub fn on_sent(&mut self, now: Instant) {
+        if self.outstanding.len() == MAX_OUTSTANDING_PINGS {
+            self.outstanding.pop_front();
+        }
+        self.outstanding.push_back(now);
+        self.last_sent = Some(now);
+    }
+
+    /// Record a reply with the given game event sequence.
+    /// Returns the round-trip time, or None if the reply is stale or unmatched.
+    pub fn on_reply(&mut self, sequence: u32, now: Instant) -> Option<Duration> {
+        if self
+            .last_reply_sequence
+            .is_some_and(|last| sequence <= last)
+        {
+            return None;
+        }
+        self.last_reply_sequence = Some(sequence);
+
+        while let Some(sent) = self.outstanding.pop_front() {
+            let rtt = now.duration_since(sent);
+            if rtt <= PING_TIMEOUT {
+                self.last_rtt = Some(rtt);
+                return Some(rtt);
+            }
+        }
+        None
+    }
+
+    /// Most recently measured round-trip time
+    pub fn last_rtt(&self) -> Option<Duration> {
+        self.last_rtt
+    }
+
+    /// Forget outstanding pings and reply ordering (new connection).
+    /// The last measured RTT is kept for display.
+    pub fn reset(&mut self) {
+        self.outstanding.clear();
+        self.last_sent = None;
+        self.last_reply_sequence = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let start = Instant::now();
+        let mut tracker = PingTracker::new();
+        assert!(tracker.is_due(start));
+
+        tracker.on_sent(start);
+        assert!(!tracker.is_due(start));
+
+        let rtt = tracker.on_reply(5, start + Duration::from_millis(120));
+        assert_eq!(rtt, Some(Duration::from_millis(120)));
+        assert_eq!(tracker.last_rtt(), Some(Duration::from_millis(120)));
+        assert!(tracker.is_due(start + PING_INTERVAL));
+    }
+
+    #[test]
+    fn test_stale_and_duplicate_replies_ignored() {
+        let start = Instant::now();
+        let mut tracker = PingTracker::new();
+        tracker.on_sent(start);
+        tracker.on_sent(start + Duration::from_millis(10));
+
+        assert!(
+            tracker
+                .on_reply(7, start + Duration::from_millis(50))
+                .is_some()
+        );
+        // Same or older sequence: duplicate or reordered, not a new measurement
+        assert_eq!(tracker.on_reply(7, start + Duration::from_millis(60)), None);
+        assert_eq!(tracker.on_reply(6, start + Duration::from_millis(60)), None);
+        assert_eq!(
+            tracker.on_reply(8, start + Duration::from_millis(70)),
+            Some(Duration::from_millis(60))
+        );
+    }
+
+    #[test]
+    fn test_timed_out_pings_skipped() {
+        let start = Instant::now();
+        let mut tracker = PingTracker::new();
+        tracker.on_sent(start);
+        let second = start + PING_TIMEOUT;
+        tracker.on_sent(second);
+
+        let rtt = tracker.on_reply(1, second + Duration::from_millis(30));
+        assert_eq!(rtt, Some(Duration::from_millis(30)));
+    }
+}
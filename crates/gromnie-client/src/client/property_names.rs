@@ -0,0 +1,215 @@
+use std::fmt;
+
+use asheron_rs::enums::PropertyInt;
+use serde::Serialize;
+use serde::ser::{self, Impossible};
+
+/// Display name for an integer property key
+pub fn property_int_name(key: PropertyInt) -> &'static str {
+    variant_name(&key)
+}
+
+/// Variant name of a unit-only protocol enum such as a property or vital key
+///
+/// The name comes from the enum's derived `Serialize` impl, so every variant
+/// is covered and nothing is formatted or allocated. Falls back to "Unknown"
+/// for values that don't serialize as a unit variant.
+pub fn variant_name<K: Serialize>(key: &K) -> &'static str {
+    key.serialize(VariantName).unwrap_or("Unknown")
+}
+
+/// Serializer that accepts only a unit enum variant and returns its name
+struct VariantName;
+
+#[derive(Debug)]
+struct NotAUnitVariant;
+
+impl fmt::Display for NotAUnitVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not a unit enum variant")
+    }
+}
+
+impl std::error::Error for NotAUnitVariant {}
+
+impl ser::Error for NotAUnitVariant {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        NotAUnitVariant
+    }
+}
+
+type Rejected = Impossible<&'static str, NotAUnitVariant>;
+
+impl ser::Serializer for VariantName {
+    type Ok = &'static str;
+    type Error = NotAUnitVariant;
+    type SerializeSeq = Rejected;
+    type SerializeTuple = Rejected;
+    type SerializeTupleStruct = Rejected;
+    type SerializeTupleVariant = Rejected;
+    type SerializeMap = Rejected;
+    type SerializeStruct = Rejected;
+    type SerializeStructVariant = Rejected;
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(NotAUnitVariant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_match_variants() {
+        assert_eq!(property_int_name(PropertyInt::StackSize), "StackSize");
+        assert_eq!(
+            property_int_name(PropertyInt::Level),
+            format!("{:?}", PropertyInt::Level)
+        );
+        assert_eq!(variant_name(&42u32), "Unknown");
+    }
+}
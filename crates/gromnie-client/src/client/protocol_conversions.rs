@@ -99,15 +99,6 @@ impl ToProtocolEvent for asheron_rs::messages::s2c::ItemSetState {
     }
 }
 
-impl ToProtocolEvent for asheron_rs::messages::s2c::QualitiesPrivateUpdateInt {
-    fn to_protocol_event(&self) -> S2CEvent {
-        S2CEvent::QualitiesPrivateUpdateInt {
-            property: format!("{:?}", self.key),
-            value: self.value,
-        }
-    }
-}
-
 impl ToProtocolEvent for asheron_rs::messages::s2c::ItemDeleteObject {
     fn to_protocol_event(&self) -> S2CEvent {
         S2CEvent::ItemDeleteObject {
@@ -223,6 +214,36 @@ pub fn transient_string_to_game_event_msg(
     }
 }
 
+pub fn channel_broadcast_to_game_event_msg(
+    event: asheron_rs::gameevents::CommunicationChannelBroadcast,
+) -> GameEventMsg {
+    GameEventMsg::ChannelBroadcast {
+        channel: event.channel.bits(),
+        sender_name: event.sender_name,
+        message: event.message,
+    }
+}
+
+pub fn squelch_db_to_game_event_msg(
+    _event: asheron_rs::gameevents::CommunicationSetSquelchDB,
+) -> GameEventMsg {
+    GameEventMsg::SquelchDb
+}
+
+// ============================================================================
+// Confirmation game event conversions
+// ============================================================================
+
+pub fn character_confirmation_request_to_game_event_msg(
+    event: asheron_rs::gameevents::CharacterConfirmationRequest,
+) -> GameEventMsg {
+    GameEventMsg::ConfirmationRequest {
+        confirmation_type: event.confirmation_type as u32,
+        context_id: event.context_id,
+        message: event.text,
+    }
+}
+
 // ============================================================================
 // Trade game event conversions
 // ============================================================================
@@ -317,6 +338,287 @@ pub fn magic_remove_enchantment_to_game_event_msg(
     }
 }
 
+pub fn magic_update_spell_to_game_event_msg(
+    event: asheron_rs::gameevents::MagicUpdateSpell,
+) -> GameEventMsg {
+    GameEventMsg::SpellUpdated {
+        spell_id: event.spell_id.id.0 as u32,
+    }
+}
+
+pub fn player_description_to_game_event_msg(
+    _event: asheron_rs::gameevents::LoginPlayerDescription,
+) -> GameEventMsg {
+    GameEventMsg::PlayerDescription
+}
+
+// ============================================================================
+// Item game event conversions
+// ============================================================================
+
+pub fn appraise_info_to_game_event_msg(
+    event: asheron_rs::gameevents::ItemSetAppraiseInfo,
+) -> GameEventMsg {
+    GameEventMsg::AppraiseInfo {
+        object_id: event.object_id.0,
+        success: event.success,
+    }
+}
+
+pub fn view_contents_to_game_event_msg(
+    event: asheron_rs::gameevents::ItemOnViewContents,
+) -> GameEventMsg {
+    GameEventMsg::ItemOnViewContents {
+        container_id: event.container_id.0,
+        items: view_contents_items(&event),
+    }
+}
+
+/// Object IDs of the items in a container's contents
+pub fn view_contents_items(event: &asheron_rs::gameevents::ItemOnViewContents) -> Vec<u32> {
+    event
+        .items
+        .list
+        .iter()
+        .map(|profile| profile.object_id.0)
+        .collect()
+}
+
+// ============================================================================
+// Vendor game event conversions
+// ============================================================================
+
+/// Stock amount ACE sends for items a vendor never runs out of
+const VENDOR_UNLIMITED_STOCK: u32 = 0x00FF_FFFF;
+
+pub fn vendor_info_to_game_event_msg(
+    event: asheron_rs::gameevents::VendorVendorInfo,
+) -> GameEventMsg {
+    GameEventMsg::VendorInfo {
+        vendor_id: event.shopkeeper_id.0,
+        item_count: event.items.list.len() as u32,
+    }
+}
+
+/// Convert one entry of a vendor's stock
+///
+/// The low 24 bits of the packed amount are the stock count; the high byte
+/// is the weenie description type.
+pub fn vendor_item_from_profile(
+    item: &asheron_rs::types::ItemProfile,
+) -> gromnie_events::VendorItem {
+    let amount = item.packed_amount & VENDOR_UNLIMITED_STOCK;
+    gromnie_events::VendorItem {
+        object_id: item.object_id.0.into(),
+        name: item.weenie_description.name.clone(),
+        stock: (amount != VENDOR_UNLIMITED_STOCK).then_some(amount),
+    }
+}
+
+// ============================================================================
+// Fellowship game event conversions
+// ============================================================================
+
+pub fn fellowship_full_update_to_game_event_msg(
+    event: asheron_rs::gameevents::FellowshipFullUpdate,
+) -> GameEventMsg {
+    GameEventMsg::FellowshipFullUpdate {
+        name: event.fellowship.name.clone(),
+        leader_id: event.fellowship.leader_id.0,
+        member_count: (&event.fellowship.fellows).into_iter().count() as u32,
+    }
+}
+
+pub fn fellowship_update_fellow_to_game_event_msg(
+    event: asheron_rs::gameevents::FellowshipUpdateFellow,
+) -> GameEventMsg {
+    GameEventMsg::FellowshipUpdateFellow {
+        fellow_id: event.fellow_id.0,
+    }
+}
+
+pub fn fellowship_quit_to_game_event_msg(
+    event: asheron_rs::gameevents::FellowshipQuit,
+) -> GameEventMsg {
+    GameEventMsg::FellowshipQuit {
+        fellow_id: event.fellow_id.0,
+    }
+}
+
+pub fn fellowship_disband_to_game_event_msg(
+    _event: asheron_rs::gameevents::FellowshipDisband,
+) -> GameEventMsg {
+    GameEventMsg::FellowshipDisband
+}
+
+/// Convert one fellowship member's entry
+pub fn fellow_member(
+    object_id: u32,
+    fellow: &asheron_rs::types::Fellow,
+) -> gromnie_events::FellowMember {
+    gromnie_events::FellowMember {
+        object_id: object_id.into(),
+        name: fellow.name.clone(),
+        level: fellow.level,
+        health: fellow.current_health,
+        max_health: fellow.max_health,
+        stamina: fellow.current_stamina,
+        max_stamina: fellow.max_stamina,
+        mana: fellow.current_mana,
+        max_mana: fellow.max_mana,
+    }
+}
+
+// ============================================================================
+// Allegiance game event conversions
+// ============================================================================
+
+pub fn allegiance_update_to_game_event_msg(
+    event: asheron_rs::gameevents::AllegianceAllegianceUpdate,
+) -> GameEventMsg {
+    GameEventMsg::AllegianceUpdate {
+        rank: event.rank,
+        allegiance_name: event.profile.hierarchy.allegiance_name.clone(),
+        member_count: event.profile.hierarchy.records.len() as u32,
+    }
+}
+
+pub fn allegiance_info_response_to_game_event_msg(
+    event: asheron_rs::gameevents::AllegianceAllegianceInfoResponseEvent,
+) -> GameEventMsg {
+    GameEventMsg::AllegianceInfoResponse {
+        target_id: event.target_id.0,
+        allegiance_name: event.profile.hierarchy.allegiance_name.clone(),
+        member_count: event.profile.hierarchy.records.len() as u32,
+    }
+}
+
+pub fn allegiance_login_notification_to_game_event_msg(
+    event: asheron_rs::gameevents::AllegianceAllegianceLoginNotificationEvent,
+) -> GameEventMsg {
+    GameEventMsg::AllegianceLoginNotification {
+        character_id: event.character_id.0,
+        logged_in: event.is_logged_in,
+    }
+}
+
+/// Convert an allegiance hierarchy's records, marking its officers
+pub fn allegiance_members(
+    hierarchy: &asheron_rs::types::AllegianceHierarchy,
+) -> Vec<gromnie_events::AllegianceMember> {
+    let officers: std::collections::HashMap<u32, u32> = (&hierarchy.officers)
+        .into_iter()
+        .map(|(id, level)| (id.0, *level as u32))
+        .collect();
+    hierarchy
+        .records
+        .iter()
+        .map(|record| {
+            let data = &record.allegiance_data;
+            let object_id = data.character_id.0;
+            gromnie_events::AllegianceMember {
+                object_id: object_id.into(),
+                name: data.name.clone(),
+                rank: data.rank as u32,
+                level: data.level,
+                // The monarch's record has no parent
+                patron_id: (record.tree_parent.0 != 0).then(|| record.tree_parent.0.into()),
+                officer_level: officers.get(&object_id).copied().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// Combat game event conversions
+// ============================================================================
+
+pub fn query_health_response_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatQueryHealthResponse,
+) -> GameEventMsg {
+    GameEventMsg::QueryHealthResponse {
+        object_id: event.object_id.0,
+        health: event.health,
+    }
+}
+
+pub fn attacker_notification_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatHandleAttackerNotificationEvent,
+) -> GameEventMsg {
+    GameEventMsg::AttackerNotification {
+        defender_name: event.defender_name,
+        damage_type: event.damage_type.bits(),
+        damage_amount: event.damage_amount,
+        damage_percent: event.damage_percent as f32,
+        critical: event.critical,
+    }
+}
+
+pub fn defender_notification_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatHandleDefenderNotificationEvent,
+) -> GameEventMsg {
+    GameEventMsg::DefenderNotification {
+        attacker_name: event.attacker_name,
+        damage_type: event.damage_type.bits(),
+        damage_amount: event.damage_amount,
+        damage_percent: event.damage_percent as f32,
+        critical: event.critical,
+    }
+}
+
+pub fn evasion_attacker_notification_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatHandleEvasionAttackerNotificationEvent,
+) -> GameEventMsg {
+    GameEventMsg::EvasionAttackerNotification {
+        defender_name: event.defender_name,
+    }
+}
+
+pub fn evasion_defender_notification_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatHandleEvasionDefenderNotificationEvent,
+) -> GameEventMsg {
+    GameEventMsg::EvasionDefenderNotification {
+        attacker_name: event.attacker_name,
+    }
+}
+
+pub fn victim_notification_self_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatHandleVictimNotificationEventSelf,
+) -> GameEventMsg {
+    GameEventMsg::VictimNotificationSelf {
+        message: event.message,
+    }
+}
+
+pub fn victim_notification_other_to_game_event_msg(
+    event: asheron_rs::gameevents::CombatHandleVictimNotificationEventOther,
+) -> GameEventMsg {
+    GameEventMsg::VictimNotificationOther {
+        message: event.message,
+    }
+}
+
+// ============================================================================
+// Error game event conversions
+// ============================================================================
+
+pub fn weenie_error_to_game_event_msg(
+    event: asheron_rs::gameevents::CommunicationWeenieError,
+) -> GameEventMsg {
+    GameEventMsg::WeenieError {
+        error_code: event.type_ as u32,
+    }
+}
+
+pub fn weenie_error_with_string_to_game_event_msg(
+    event: asheron_rs::gameevents::CommunicationWeenieErrorWithString,
+) -> GameEventMsg {
+    GameEventMsg::WeenieErrorWithString {
+        error_code: event.type_ as u32,
+        text: event.text,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use gromnie_events::{GameEventMsg, S2CEvent};
@@ -418,6 +720,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_channel_broadcast_game_event_conversion() {
+        let event = asheron_rs::gameevents::CommunicationChannelBroadcast {
+            channel: asheron_rs::enums::Channel::from_bits_retain(0x0800),
+            sender_name: "Sentinel Sam".to_string(),
+            message: "Server restart in 5 minutes".to_string(),
+        };
+
+        match channel_broadcast_to_game_event_msg(event) {
+            GameEventMsg::ChannelBroadcast {
+                channel,
+                sender_name,
+                message,
+            } => {
+                assert_eq!(channel, 0x0800);
+                assert_eq!(sender_name, "Sentinel Sam");
+                assert_eq!(message, "Server restart in 5 minutes");
+            }
+            _ => panic!("Expected ChannelBroadcast variant"),
+        }
+    }
+
     /// Test delete_pending mapping from seconds_greyed_out
     /// This is a critical test that verifies the character deletion status mapping
     #[test]
@@ -0,0 +1,278 @@
+use std::time::Duration;
+
+use crate::config::ReconnectConfig;
+use crate::instant::Instant;
+
+/// Where the client is in the reconnection cycle
+///
+/// Transitions:
+///
+/// | From                 | Trigger                         | To                   |
+/// |----------------------|---------------------------------|----------------------|
+/// | any but `Exhausted`  | connection lost / timed out     | `WaitingToReconnect` |
+/// | `WaitingToReconnect` | `at` reached, attempt allowed   | `Reconnecting`       |
+/// | `WaitingToReconnect` | `at` reached, disabled or max   | `Exhausted`          |
+/// | `Reconnecting`       | character list received         | `Idle`               |
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ReconnectState {
+    /// Connected, or still on the initial connection attempt
+    #[default]
+    Idle,
+    /// Connection was lost; waiting for the backoff delay to expire
+    WaitingToReconnect { at: Instant, attempt: u32 },
+    /// A reconnection attempt is in flight
+    Reconnecting { attempt: u32 },
+    /// Reconnection is disabled or the attempt limit was reached
+    Exhausted,
+}
+
+/// What the caller should do after [`ReconnectState::tick`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReconnectAction {
+    /// Nothing to do yet
+    None,
+    /// Start a new connection attempt once `delay` has passed
+    Reconnect { attempt: u32, delay: Duration },
+    /// Stop trying and surface the error
+    GiveUp(ReconnectFailure),
+}
+
+/// Why reconnection was abandoned
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReconnectFailure {
+    Disabled,
+    MaxAttemptsReached(u32),
+}
+
+impl std::fmt::Display for ReconnectFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReconnectFailure::Disabled => write!(f, "Connection lost and reconnection disabled"),
+            ReconnectFailure::MaxAttemptsReached(max) => {
+                write!(f, "Max reconnection attempts ({}) reached", max)
+            }
+        }
+    }
+}
+
+impl ReconnectState {
+    /// Number of reconnection attempts made since the last successful connection
+    pub fn attempt(&self) -> u32 {
+        match self {
+            ReconnectState::Idle | ReconnectState::Exhausted => 0,
+            ReconnectState::WaitingToReconnect { attempt, .. }
+            | ReconnectState::Reconnecting { attempt } => *attempt,
+        }
+    }
+
+    /// When the next attempt is due, if one is scheduled
+    pub fn reconnect_at(&self) -> Option<Instant> {
+        match self {
+            ReconnectState::WaitingToReconnect { at, .. } => Some(*at),
+            _ => None,
+        }
+    }
+
+    /// Record a lost connection and schedule the next attempt.
+    /// Returns the attempt number and the backoff delay before it.
+    pub fn on_disconnected(&mut self, config: &ReconnectConfig, now: Instant) -> (u32, Duration) {
+        let attempt = self.attempt() + 1;
+        let delay = config.delay_before_attempt(attempt);
+        *self = ReconnectState::WaitingToReconnect {
+            at: now + delay,
+            attempt,
+        };
+        (attempt, delay)
+    }
+
+    /// Record a successful connection. Returns the attempt count that was cleared.
+    pub fn on_connected(&mut self) -> u32 {
+        let attempt = self.attempt();
+        *self = ReconnectState::Idle;
+        attempt
+    }
+
+    /// Advance the state machine to `now` and return the next action
+    pub fn tick(&mut self, config: &ReconnectConfig, now: Instant) -> ReconnectAction {
+        let ReconnectState::WaitingToReconnect { at, attempt } = *self else {
+            return ReconnectAction::None;
+        };
+
+        if now < at {
+            return ReconnectAction::None;
+        }
+
+        if !config.enabled {
+            *self = ReconnectState::Exhausted;
+            return ReconnectAction::GiveUp(ReconnectFailure::Disabled);
+        }
+
+        if !config.should_attempt_reconnect(attempt) {
+            *self = ReconnectState::Exhausted;
+            return ReconnectAction::GiveUp(ReconnectFailure::MaxAttemptsReached(
+                config.max_attempts,
+            ));
+        }
+
+        *self = ReconnectState::Reconnecting { attempt };
+        ReconnectAction::Reconnect {
+            attempt,
+            delay: config.delay_before_attempt(attempt),
+        }
+    }
+}
+
+/// Failed attempts to log back into the world after which the client gives up
+/// and stays at character select
+pub const MAX_RESUME_ATTEMPTS: u32 = 2;
+
+/// The character to log back into after a reconnect, when
+/// [`ReconnectConfig::resume_in_world`] is set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResumeTarget {
+    pub character_id: u32,
+    pub character_name: String,
+    /// Resume logins that failed since the character was last in the world
+    failures: u32,
+    /// Whether a resume login is in flight
+    in_progress: bool,
+}
+
+impl ResumeTarget {
+    /// Track a character that just entered the world
+    pub fn new(character_id: u32, character_name: String) -> Self {
+        Self {
+            character_id,
+            character_name,
+            failures: 0,
+            in_progress: false,
+        }
+    }
+
+    /// Start a resume login. Returns the attempt number, or `None` once
+    /// [`MAX_RESUME_ATTEMPTS`] attempts have failed.
+    pub fn begin(&mut self) -> Option<u32> {
+        if self.failures >= MAX_RESUME_ATTEMPTS {
+            return None;
+        }
+        self.in_progress = true;
+        Some(self.failures + 1)
+    }
+
+    /// Record that the login in flight failed. Returns false if no resume
+    /// login was in flight.
+    pub fn fail(&mut self) -> bool {
+        if !self.in_progress {
+            return false;
+        }
+        self.in_progress = false;
+        self.failures += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled(max_attempts: u32) -> ReconnectConfig {
+        ReconnectConfig {
+            enabled: true,
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_waits_until_backoff_expires() {
+        let config = enabled(0);
+        let now = Instant::now();
+        let mut state = ReconnectState::Idle;
+
+        let (attempt, delay) = state.on_disconnected(&config, now);
+        assert_eq!(attempt, 1);
+        assert_eq!(delay, config.delay_before_attempt(1));
+
+        assert_eq!(state.tick(&config, now), ReconnectAction::None);
+        assert_eq!(
+            state.tick(&config, now + delay),
+            ReconnectAction::Reconnect { attempt: 1, delay }
+        );
+        assert_eq!(state, ReconnectState::Reconnecting { attempt: 1 });
+    }
+
+    #[test]
+    fn test_first_attempt_uses_initial_delay() {
+        let config = ReconnectConfig {
+            initial_reconnect_delay_secs: Some(5),
+            ..enabled(0)
+        };
+        let now = Instant::now();
+        let mut state = ReconnectState::Idle;
+
+        let (_, delay) = state.on_disconnected(&config, now);
+        assert_eq!(delay, Duration::from_secs(5));
+        assert_eq!(state.reconnect_at(), Some(now + Duration::from_secs(5)));
+
+        // A failed first attempt falls back to the backoff schedule
+        let (attempt, delay) = state.on_disconnected(&config, now);
+        assert_eq!(attempt, 2);
+        assert_eq!(delay, config.delay_for_attempt(2));
+    }
+
+    #[test]
+    fn test_failed_attempts_back_off() {
+        let config = enabled(0);
+        let now = Instant::now();
+        let mut state = ReconnectState::Reconnecting { attempt: 1 };
+
+        let (attempt, delay) = state.on_disconnected(&config, now);
+        assert_eq!(attempt, 2);
+        assert_eq!(state.reconnect_at(), Some(now + delay));
+
+        assert_eq!(state.on_connected(), 2);
+        assert_eq!(state, ReconnectState::Idle);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let config = enabled(1);
+        let now = Instant::now();
+        let mut state = ReconnectState::Idle;
+
+        let (_, delay) = state.on_disconnected(&config, now);
+        assert_eq!(
+            state.tick(&config, now + delay),
+            ReconnectAction::GiveUp(ReconnectFailure::MaxAttemptsReached(1))
+        );
+        assert_eq!(state, ReconnectState::Exhausted);
+        assert_eq!(state.tick(&config, now + delay), ReconnectAction::None);
+    }
+
+    #[test]
+    fn test_gives_up_when_disabled() {
+        let config = ReconnectConfig::default();
+        let now = Instant::now();
+        let mut state = ReconnectState::Idle;
+
+        let (_, delay) = state.on_disconnected(&config, now);
+        assert_eq!(
+            state.tick(&config, now + delay),
+            ReconnectAction::GiveUp(ReconnectFailure::Disabled)
+        );
+    }
+
+    #[test]
+    fn test_resume_gives_up_after_two_failures() {
+        let mut target = ResumeTarget::new(1, "Bob".to_string());
+
+        // Failures only count while a resume login is in flight
+        assert!(!target.fail());
+
+        assert_eq!(target.begin(), Some(1));
+        assert!(target.fail());
+        assert_eq!(target.begin(), Some(2));
+        assert!(target.fail());
+        assert_eq!(target.begin(), None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+/// Default number of sent packets kept for retransmission
+pub const DEFAULT_RETRANSMIT_BUFFER_SIZE: usize = 64;
+
+/// The last few sequenced packets sent to the server, kept so they can be
+/// resent when the server asks for them with REQUEST_RETRANSMIT
+///
+/// Packets are stored exactly as sent. Anything the server has acknowledged is
+/// dropped, and once the buffer is full the oldest packet is evicted.
+#[derive(Debug)]
+pub struct SentPacketBuffer {
+    capacity: usize,
+    /// (sequence, serialized packet), oldest first
+    packets: VecDeque<(u32, Vec<u8>)>,
+    last_acked: u32,
+}
+
+impl Default for SentPacketBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_RETRANSMIT_BUFFER_SIZE)
+    }
+}
+
+impl SentPacketBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            packets: VecDeque::with_capacity(capacity),
+            last_acked: 0,
+        }
+    }
+
+    /// Change how many packets are kept, evicting the oldest if needed
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.packets.len() > capacity {
+            self.packets.pop_front();
+        }
+    }
+
+    /// Remember a packet that was just sent with `sequence`
+    pub fn record(&mut self, sequence: u32, bytes: &[u8]) {
+        if self.capacity == 0 || sequence <= self.last_acked {
+            return;
+        }
+        if self.packets.len() == self.capacity {
+            self.packets.pop_front();
+        }
+        self.packets.push_back((sequence, bytes.to_vec()));
+    }
+
+    /// The server has received everything up to and including `sequence`
+    pub fn acknowledge(&mut self, sequence: u32) {
+        self.last_acked = self.last_acked.max(sequence);
+        while self
+            .packets
+            .front()
+            .is_some_and(|(seq, _)| *seq <= self.last_acked)
+        {
+            self.packets.pop_front();
+        }
+    }
+
+    /// Highest sequence the server has acknowledged
+    pub fn last_acked(&self) -> u32 {
+        self.last_acked
+    }
+
+    /// Bytes of a buffered packet, if it is still held
+    pub fn get(&self, sequence: u32) -> Option<&[u8]> {
+        self.packets
+            .iter()
+            .find(|(seq, _)| *seq == sequence)
+            .map(|(_, bytes)| bytes.as_slice())
+    }
+
+    /// Number of buffered packets
+    pub fn len(&self) -> usize {
+        self.packets.len()
+    }
+
+    /// Whether no packets are buffered
+    pub fn is_empty(&self) -> bool {
+        self.packets.is_empty()
+    }
+
+    /// Forget everything, e.g. when the connection is reset
+    pub fn clear(&mut self) {
+        self.packets.clear();
+        self.last_acked = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retransmit_request() {
+        let mut buffer = SentPacketBuffer::new(3);
+        for seq in 1..=5 {
+            buffer.record(seq, &[seq as u8; 4]);
+        }
+        assert_eq!(buffer.len(), 3);
+
+        // The server asks for 2 (already evicted) and 4
+        assert_eq!(buffer.get(2), None);
+        assert_eq!(buffer.get(4), Some([4u8; 4].as_slice()));
+
+        buffer.acknowledge(4);
+        assert_eq!(buffer.get(4), None);
+        assert_eq!(buffer.get(5), Some([5u8; 4].as_slice()));
+        assert_eq!(buffer.last_acked(), 4);
+
+        // Stale acks don't move backwards
+        buffer.acknowledge(1);
+        assert_eq!(buffer.last_acked(), 4);
+        assert_eq!(buffer.len(), 1);
+    }
+}
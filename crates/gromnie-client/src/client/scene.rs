@@ -54,6 +54,8 @@ pub struct ConnectingScene {
     pub patch_progress: PatchingProgress,
     pub started_at: crate::instant::Instant,
     pub last_retry_at: crate::instant::Instant,
+    /// LoginRequests sent so far in this attempt, the first one included
+    pub login_attempts: u32,
 }
 
 #[derive(Clone, Debug)]
@@ -103,6 +105,7 @@ impl ConnectingScene {
             patch_progress: PatchingProgress::NotStarted,
             started_at: now,
             last_retry_at: now,
+            login_attempts: 0,
         }
     }
 
@@ -113,6 +116,7 @@ impl ConnectingScene {
         self.patch_progress = PatchingProgress::NotStarted;
         self.started_at = now;
         self.last_retry_at = now;
+        self.login_attempts = 0;
     }
 
     /// Check if this connecting attempt has timed out (20s default)
@@ -129,6 +133,21 @@ impl ConnectingScene {
     pub fn update_retry_time(&mut self) {
         self.last_retry_at = crate::instant::Instant::now();
     }
+
+    /// Record that a LoginRequest was sent. Returns the attempt number.
+    pub fn on_login_request_sent(&mut self) -> u32 {
+        self.login_attempts += 1;
+        self.update_retry_time();
+        self.login_attempts
+    }
+
+    /// Whether the server has answered the LoginRequest yet
+    pub fn awaiting_connect_request(&self) -> bool {
+        matches!(
+            self.connect_progress,
+            ConnectingProgress::Initial | ConnectingProgress::LoginRequestSent
+        )
+    }
 }
 
 impl CharacterSelectScene {
@@ -200,7 +219,46 @@ impl ErrorScene {
     }
 }
 
+impl ClientError {
+    /// Whether the server went quiet, as opposed to turning the client away
+    pub fn kind(&self) -> gromnie_events::ConnectionErrorKind {
+        match self {
+            ClientError::LoginTimeout | ClientError::PatchingTimeout => {
+                gromnie_events::ConnectionErrorKind::Timeout
+            }
+            ClientError::CharacterError(_)
+            | ClientError::ConnectionFailed(_)
+            | ClientError::PatchingFailed(_) => gromnie_events::ConnectionErrorKind::Rejected,
+        }
+    }
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::CharacterError(error) => write!(f, "Character error: {:?}", error),
+            ClientError::ConnectionFailed(reason) => write!(f, "Connection failed: {}", reason),
+            ClientError::PatchingFailed(reason) => write!(f, "Patching failed: {}", reason),
+            ClientError::LoginTimeout => write!(f, "Timed out waiting for the login server"),
+            ClientError::PatchingTimeout => {
+                write!(f, "Timed out waiting for the server to finish patching")
+            }
+        }
+    }
+}
+
 impl Scene {
+    /// Name of the scene, as reported in state transitions
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scene::Connecting(_) => "Connecting",
+            Scene::CharacterSelect(_) => "CharacterSelect",
+            Scene::CharacterCreate(_) => "CharacterCreate",
+            Scene::InWorld(_) => "InWorld",
+            Scene::Error(_) => "Error",
+        }
+    }
+
     /// Get a reference to the connecting scene if this is a Connecting scene
     pub fn as_connecting(&self) -> Option<&ConnectingScene> {
         match self {
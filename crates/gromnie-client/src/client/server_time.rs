@@ -0,0 +1,96 @@
+use crate::instant::Instant;
+
+/// Weight given to each new TIME_SYNC sample once a baseline exists
+const OFFSET_SMOOTHING: f64 = 0.2;
+/// Samples further than this from the estimate (in seconds) replace it outright,
+/// e.g. after the server restarts or the client connects to a different world
+const RESYNC_THRESHOLD_SECS: f64 = 60.0;
+
+/// Estimates the server clock from TIME_SYNC packets
+///
+/// The server time is kept as an offset from a local monotonic baseline, so
+/// the estimate keeps advancing between syncs. The first sync sets the offset
+/// directly; later syncs move it part of the way toward each new sample so
+/// that network jitter doesn't make the clock jump back and forth.
+#[derive(Debug, Default)]
+pub struct ServerClock {
+    /// Local time of the first sync, and the server time minus seconds since then
+    baseline: Option<(Instant, f64)>,
+}
+
+impl ServerClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a TIME_SYNC carrying `server_time` (seconds) received at `now`
+    pub fn on_sync(&mut self, server_time: f64, now: Instant) {
+        if !server_time.is_finite() {
+            return;
+        }
+
+        match &mut self.baseline {
+            Some((start, offset)) => {
+                let sample = server_time - now.duration_since(*start).as_secs_f64();
+                let error = sample - *offset;
+                if error.abs() > RESYNC_THRESHOLD_SECS {
+                    *offset = sample;
+                } else {
+                    *offset += error * OFFSET_SMOOTHING;
+                }
+            }
+            None => self.baseline = Some((now, server_time)),
+        }
+    }
+
+    /// Estimated server time in seconds at `now`, once a sync has been received
+    pub fn server_time_at(&self, now: Instant) -> Option<f64> {
+        self.baseline
+            .map(|(start, offset)| offset + now.duration_since(start).as_secs_f64())
+    }
+
+    /// Forget the baseline (new connection)
+    pub fn reset(&mut self) {
+        self.baseline = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_first_sync_sets_baseline() {
+        let start = Instant::now();
+        let mut clock = ServerClock::new();
+        assert_eq!(clock.server_time_at(start), None);
+
+        clock.on_sync(1000.0, start);
+        assert_eq!(clock.server_time_at(start), Some(1000.0));
+        let later = clock
+            .server_time_at(start + Duration::from_secs(5))
+            .unwrap();
+        assert!((later - 1005.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_later_syncs_are_smoothed() {
+        let start = Instant::now();
+        let mut clock = ServerClock::new();
+        clock.on_sync(1000.0, start);
+
+        // Server says one second more than the estimate: move part of the way
+        let now = start + Duration::from_secs(10);
+        clock.on_sync(1011.0, now);
+        let estimate = clock.server_time_at(now).unwrap();
+        assert!(estimate > 1010.0 && estimate < 1011.0);
+
+        // A sample far off the estimate replaces it
+        clock.on_sync(5000.0, now);
+        assert_eq!(clock.server_time_at(now), Some(5000.0));
+
+        clock.reset();
+        assert_eq!(clock.server_time_at(now), None);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::instant::Instant;
 use std::sync::Mutex;
 
-use crate::crypto::crypto_system::CryptoSystem;
+use crate::crypto::crypto_system::{CryptoSystem, ReceiveKeys};
 
 /// Session state received from the server's ConnectRequest packet
 /// This is now used internally within ClientSession
@@ -11,6 +11,7 @@ pub struct ConnectionState {
     pub client_id: u16,
     pub table: u16, // Table/iteration value from packet header
     pub send_generator: Mutex<CryptoSystem>, // Client->Server checksum encryption (initialized from seed_c2s)
+    pub recv_keys: ReceiveKeys, // Server->Client checksum validation (initialized from seed_s2c)
 }
 
 impl Clone for ConnectionState {
@@ -20,6 +21,7 @@ impl Clone for ConnectionState {
             client_id: self.client_id,
             table: self.table,
             send_generator: Mutex::new(self.send_generator.lock().unwrap().clone()),
+            recv_keys: self.recv_keys.clone(),
         }
     }
 }
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// What's saved to an account's squelch file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SquelchFile {
+    #[serde(default)]
+    names: Vec<String>,
+}
+
+/// Players whose chat is dropped before it reaches consumers
+///
+/// Names match case-insensitively. Entries from the server's squelch
+/// database (Communication_SetSquelchDB) also match by object ID, but are
+/// resent at every login so only names added here are saved to the file.
+#[derive(Debug, Default)]
+pub struct SquelchList {
+    /// Lowercased name -> name as it was added
+    names: BTreeMap<String, String>,
+    /// Object ID -> name, from the server's squelch database
+    server: BTreeMap<u32, String>,
+    /// Where added names are saved, if anywhere
+    file: Option<PathBuf>,
+}
+
+impl SquelchList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load names saved to `path`; later changes are saved back to it. A
+    /// missing file is an empty list.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let path = path.into();
+        let saved = match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str::<SquelchFile>(&content)
+                .map_err(|e| format!("{}: {}", path.display(), e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => SquelchFile::default(),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+
+        let mut list = Self {
+            file: Some(path),
+            ..Self::default()
+        };
+        for name in saved.names {
+            list.insert_name(&name);
+        }
+        Ok(list)
+    }
+
+    /// Squelch a player by name. Returns false if already squelched.
+    pub fn add(&mut self, name: &str) -> bool {
+        if !self.insert_name(name) {
+            return false;
+        }
+        self.save();
+        true
+    }
+
+    /// Stop squelching a player, including a server entry with that name.
+    /// Returns false if they weren't squelched.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let key = name_key(name);
+        let removed_name = self.names.remove(&key).is_some();
+        let server_count = self.server.len();
+        self.server
+            .retain(|_, server_name| name_key(server_name) != key);
+        let removed_server = self.server.len() != server_count;
+
+        if removed_name {
+            self.save();
+        }
+        removed_name || removed_server
+    }
+
+    /// Replace the entries from the server's squelch database
+    pub fn set_server_entries(&mut self, entries: impl IntoIterator<Item = (u32, String)>) {
+        self.server = entries.into_iter().collect();
+    }
+
+    /// Whether chat from this sender should be dropped
+    pub fn is_squelched(&self, sender_name: &str, sender_id: u32) -> bool {
+        self.server.contains_key(&sender_id) || self.names.contains_key(&name_key(sender_name))
+    }
+
+    /// Whether `name` is on the list, as an added name or a server entry
+    pub fn contains_name(&self, name: &str) -> bool {
+        let key = name_key(name);
+        self.names.contains_key(&key)
+            || self
+                .server
+                .values()
+                .any(|server_name| name_key(server_name) == key)
+    }
+
+    /// Every squelched name, sorted and without duplicates
+    pub fn names(&self) -> Vec<String> {
+        let mut names: BTreeMap<String, &str> = self
+            .server
+            .values()
+            .map(|name| (name_key(name), name.as_str()))
+            .collect();
+        names.extend(
+            self.names
+                .iter()
+                .map(|(key, name)| (key.clone(), name.as_str())),
+        );
+        names.into_values().map(str::to_string).collect()
+    }
+
+    fn insert_name(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        let key = name_key(name);
+        if name.is_empty() || self.names.contains_key(&key) {
+            return false;
+        }
+        self.names.insert(key, name.to_string());
+        true
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.file else {
+            return;
+        };
+        let saved = SquelchFile {
+            names: self.names.values().cloned().collect(),
+        };
+        if let Err(e) = write_file(path, &saved) {
+            warn!(target: "net", "Failed to save squelch list to {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Names are compared trimmed and lowercased
+fn name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn write_file(path: &Path, saved: &SquelchFile) -> Result<(), String> {
+    let content = toml::to_string(saved).map_err(|e| e.to_string())?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_match_case_insensitively() {
+        let mut list = SquelchList::new();
+        assert!(list.add("Spammer"));
+        assert!(!list.add(" spammer "));
+        assert!(!list.add(""));
+
+        assert!(list.is_squelched("SPAMMER", 0x5000_0001));
+        assert!(!list.is_squelched("Friend", 0x5000_0002));
+
+        list.set_server_entries([(0x5000_0003, "Griefer".to_string())]);
+        // Server entries match by ID even if the name is different
+        assert!(list.is_squelched("Griefer the Renamed", 0x5000_0003));
+        assert_eq!(list.names(), vec!["Griefer", "Spammer"]);
+        assert!(list.contains_name(" GRIEFER"));
+        assert!(list.contains_name("spammer"));
+        assert!(!list.contains_name("Friend"));
+
+        assert!(list.remove("griefer"));
+        assert!(!list.is_squelched("Griefer", 0x5000_0003));
+        assert!(list.remove("Spammer"));
+        assert!(!list.remove("Spammer"));
+        assert!(list.names().is_empty());
+    }
+
+    #[test]
+    fn test_added_names_are_saved() {
+        let dir = std::env::temp_dir().join(format!("gromnie-squelch-{}", std::process::id()));
+        let path = dir.join("account.toml");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut list = SquelchList::load(&path).unwrap();
+        assert!(list.names().is_empty());
+        list.add("Spammer");
+        list.add("Pest");
+        list.remove("Pest");
+        list.set_server_entries([(0x5000_0003, "Griefer".to_string())]);
+
+        // Only names added locally come back
+        let reloaded = SquelchList::load(&path).unwrap();
+        assert_eq!(reloaded.names(), vec!["Spammer"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
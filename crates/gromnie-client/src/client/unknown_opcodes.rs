@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+/// A message the client has no parser for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnknownOpcode {
+    /// Top-level message opcode that isn't an S2CMessage
+    Message(u32),
+    /// Event opcode inside an OrderedGameEvent (0xF7B0) that isn't a GameEventType
+    GameEvent(u32),
+}
+
+/// Unknown opcodes seen this session, so each is only logged loudly once
+#[derive(Debug, Default)]
+pub struct UnknownOpcodes {
+    seen: HashSet<UnknownOpcode>,
+}
+
+impl UnknownOpcodes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a sighting, returning true the first time `opcode` is seen
+    pub fn record(&mut self, opcode: UnknownOpcode) -> bool {
+        self.seen.insert(opcode)
+    }
+
+    /// Number of distinct unknown opcodes seen
+    pub fn count(&self) -> u32 {
+        self.seen.len() as u32
+    }
+
+    /// Forget everything seen (new connection)
+    pub fn clear(&mut self) {
+        self.seen.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_distinct_opcodes() {
+        let mut unknown = UnknownOpcodes::new();
+        assert!(unknown.record(UnknownOpcode::Message(0xF7B5)));
+        assert!(!unknown.record(UnknownOpcode::Message(0xF7B5)));
+        // The same number as a game event opcode is a different message
+        assert!(unknown.record(UnknownOpcode::GameEvent(0xF7B5)));
+        assert_eq!(unknown.count(), 2);
+
+        unknown.clear();
+        assert_eq!(unknown.count(), 0);
+        assert!(unknown.record(UnknownOpcode::Message(0xF7B5)));
+    }
+}
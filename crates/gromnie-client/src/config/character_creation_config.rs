@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::paths::ProjectPaths;
+
+/// Character creation for empty accounts, from the `[character_creation]`
+/// section of config.toml
+///
+/// ```toml
+/// [character_creation]
+/// enabled = true
+/// template = "templates/mage.toml"
+/// ```
+///
+/// When enabled and an account has no characters, one is created and logged
+/// into. It is built from `template` (see `CharacterBuilder::from_template`),
+/// or from the default build when there is no template, and named after the
+/// account's `character` when that is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CharacterCreationConfig {
+    /// Whether to create a character for accounts that have none
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Character template to build from; relative paths are under the config
+    /// directory
+    #[serde(default)]
+    pub template: Option<PathBuf>,
+}
+
+impl CharacterCreationConfig {
+    /// Location of the template, if one is set
+    pub fn template_path(&self) -> Option<PathBuf> {
+        let template = self.template.as_ref()?;
+        if template.is_absolute() {
+            return Some(template.clone());
+        }
+        Some(
+            ProjectPaths::new("gromnie")
+                .map(|paths| paths.config_dir().join(template))
+                .unwrap_or_else(|| template.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GromnieConfig;
+
+    #[test]
+    fn test_missing_section_disables_creation() {
+        let config: GromnieConfig = toml::from_str("").unwrap();
+        assert!(!config.character_creation.enabled);
+        assert_eq!(config.character_creation.template_path(), None);
+    }
+
+    #[test]
+    fn test_absolute_template_path_is_kept() {
+        let config: GromnieConfig = toml::from_str(
+            r#"
+            [character_creation]
+            enabled = true
+            template = "/srv/gromnie/mage.toml"
+            "#,
+        )
+        .unwrap();
+        assert!(config.character_creation.enabled);
+        assert_eq!(
+            config.character_creation.template_path(),
+            Some(PathBuf::from("/srv/gromnie/mage.toml"))
+        );
+    }
+}
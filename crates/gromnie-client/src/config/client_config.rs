@@ -1,3 +1,5 @@
+use std::net::SocketAddr;
+
 // ============================================================================
 
 /// Configuration for running a client
@@ -9,8 +11,32 @@ pub struct ClientConfig {
     pub password: String,
     /// Enable automatic reconnection with exponential backoff
     pub reconnect: bool,
+    /// Fixed wait before the first reconnect attempt (None = use the backoff delay)
+    pub initial_reconnect_delay_secs: Option<u64>,
+    /// After reconnecting, log back into the character that was in the world
+    pub resume_in_world: bool,
+    /// Drop received packets whose checksum doesn't verify
+    pub strict_checksums: bool,
+    /// How long a partially received message waits for its missing fragments
+    /// (None = `DEFAULT_FRAGMENT_TIMEOUT`)
+    pub fragment_timeout_secs: Option<u64>,
+    /// Seconds between TimeSync keep-alives (None = `DEFAULT_KEEPALIVE_INTERVAL`)
+    pub keepalive_interval_secs: Option<u64>,
+    /// Seconds without a packet from the server before disconnecting
+    /// (None = `DEFAULT_IDLE_TIMEOUT`)
+    pub idle_timeout_secs: Option<u64>,
+    /// LoginRequests sent before giving up on a retry
+    /// (None = `DEFAULT_MAX_LOGIN_ATTEMPTS`)
+    pub max_login_attempts: Option<u32>,
     /// Optional character name to auto-login with after receiving character list
     pub character_name: Option<String>,
+    /// Which server port each session phase sends to
+    pub port_routing: super::PortRouting,
+    /// Local address to bind the client socket to (None = ephemeral port)
+    pub bind_addr: Option<SocketAddr>,
+    /// Server-specific reconnect settings merged over the global ones;
+    /// `reconnect` above still decides whether to reconnect at all
+    pub reconnect_override: Option<super::ReconnectOverride>,
 }
 
 impl ClientConfig {
@@ -22,7 +48,17 @@ impl ClientConfig {
             account_name,
             password,
             reconnect: false,
+            initial_reconnect_delay_secs: None,
+            resume_in_world: false,
+            strict_checksums: false,
+            fragment_timeout_secs: None,
+            keepalive_interval_secs: None,
+            idle_timeout_secs: None,
+            max_login_attempts: None,
             character_name: None,
+            port_routing: super::PortRouting::default(),
+            bind_addr: None,
+            reconnect_override: None,
         }
     }
 
@@ -32,9 +68,76 @@ impl ClientConfig {
         self
     }
 
+    /// Set a fixed wait before the first reconnect attempt
+    pub fn with_initial_reconnect_delay_secs(mut self, secs: Option<u64>) -> Self {
+        self.initial_reconnect_delay_secs = secs;
+        self
+    }
+
+    /// Log back into the world after reconnecting instead of stopping at
+    /// character select
+    pub fn with_resume_in_world(mut self, resume_in_world: bool) -> Self {
+        self.resume_in_world = resume_in_world;
+        self
+    }
+
+    /// Drop received packets whose checksum doesn't verify instead of only
+    /// logging them
+    pub fn with_strict_checksums(mut self, strict_checksums: bool) -> Self {
+        self.strict_checksums = strict_checksums;
+        self
+    }
+
+    /// Set how long a partially received message waits for its missing
+    /// fragments before it's dropped
+    pub fn with_fragment_timeout_secs(mut self, secs: Option<u64>) -> Self {
+        self.fragment_timeout_secs = secs;
+        self
+    }
+
+    /// Set how often a TimeSync keep-alive is sent
+    pub fn with_keepalive_interval_secs(mut self, secs: Option<u64>) -> Self {
+        self.keepalive_interval_secs = secs;
+        self
+    }
+
+    /// Set how long the server may stay silent before the client disconnects
+    pub fn with_idle_timeout_secs(mut self, secs: Option<u64>) -> Self {
+        self.idle_timeout_secs = secs;
+        self
+    }
+
+    /// Set how many LoginRequests are sent before the client stops retrying
+    pub fn with_max_login_attempts(mut self, attempts: Option<u32>) -> Self {
+        self.max_login_attempts = attempts;
+        self
+    }
+
     /// Set the character name for auto-login
     pub fn with_character_name(mut self, character_name: String) -> Self {
         self.character_name = Some(character_name);
         self
     }
+
+    /// Set the destination-port policy (usually from `ServerConfig::routing`)
+    pub fn with_port_routing(mut self, port_routing: super::PortRouting) -> Self {
+        self.port_routing = port_routing;
+        self
+    }
+
+    /// Set the local address to bind to (usually from `ServerConfig::bind_addr`)
+    pub fn with_bind_addr(mut self, bind_addr: Option<SocketAddr>) -> Self {
+        self.bind_addr = bind_addr;
+        self
+    }
+
+    /// Set reconnect settings that replace the global ones (usually from
+    /// `ServerConfig::reconnect`)
+    pub fn with_reconnect_override(
+        mut self,
+        reconnect_override: Option<super::ReconnectOverride>,
+    ) -> Self {
+        self.reconnect_override = reconnect_override;
+        self
+    }
 }
@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Event database settings, from the `[database]` section of config.toml
+///
+/// ```toml
+/// [database]
+/// path = "/home/me/gromnie/events.db"
+/// ```
+///
+/// When the section is present, every event is recorded to the SQLite file at
+/// `path`, which is created along with its schema if it doesn't exist yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseConfig {
+    /// SQLite file events are written to
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GromnieConfig;
+
+    #[test]
+    fn test_missing_section_disables_database() {
+        let config: GromnieConfig = toml::from_str("").unwrap();
+        assert_eq!(config.database, None);
+    }
+
+    #[test]
+    fn test_database_path() {
+        let config: GromnieConfig = toml::from_str(
+            r#"
+            [database]
+            path = "events.db"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.database,
+            Some(DatabaseConfig {
+                path: PathBuf::from("events.db")
+            })
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of event the Discord consumer can relay to its channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiscordEventCategory {
+    /// In-game chat (`ChatMessageReceived`, `ChannelMessage`)
+    Chat,
+    /// Character login (`LoginSucceeded`)
+    Login,
+    /// Player deaths (`PlayerDeath`)
+    Deaths,
+    /// Errors reported by the server or client (`CharacterError`,
+    /// `ActionError`, `LoginFailed`, `AuthenticationFailed`, `ConnectionError`)
+    Errors,
+    /// Connection lifecycle (`Disconnected`, `Reconnecting`, `Shutdown`)
+    System,
+}
+
+/// Discord relay settings, from the `[discord]` section of config.toml
+///
+/// ```toml
+/// [discord]
+/// relay = ["chat", "deaths"]
+/// ```
+///
+/// Events whose category is not in `relay` are not posted to Discord; they
+/// are still logged as usual. Without a `[discord]` section, chat and system
+/// events are relayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscordConfig {
+    /// Categories of events to post to the Discord channel
+    #[serde(default = "default_relay")]
+    pub relay: Vec<DiscordEventCategory>,
+}
+
+fn default_relay() -> Vec<DiscordEventCategory> {
+    vec![DiscordEventCategory::Chat, DiscordEventCategory::System]
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        Self {
+            relay: default_relay(),
+        }
+    }
+}
+
+impl DiscordConfig {
+    /// Whether events in `category` should be posted to Discord
+    pub fn relays(&self, category: DiscordEventCategory) -> bool {
+        self.relay.contains(&category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GromnieConfig;
+
+    #[test]
+    fn test_missing_section_relays_chat_and_system() {
+        let config: GromnieConfig = toml::from_str("").unwrap();
+        assert!(config.discord.relays(DiscordEventCategory::Chat));
+        assert!(config.discord.relays(DiscordEventCategory::System));
+        assert!(!config.discord.relays(DiscordEventCategory::Login));
+    }
+
+    #[test]
+    fn test_relay_allowlist() {
+        let config: GromnieConfig = toml::from_str(
+            r#"
+            [discord]
+            relay = ["chat", "deaths"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.discord.relay,
+            vec![DiscordEventCategory::Chat, DiscordEventCategory::Deaths]
+        );
+        assert!(!config.discord.relays(DiscordEventCategory::System));
+    }
+}
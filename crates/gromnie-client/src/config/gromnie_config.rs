@@ -1,17 +1,30 @@
-use std::{collections::BTreeMap, fs, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::config::{
-    account_config::AccountConfig, paths::ProjectPaths, scripting_config::ScriptingConfig,
+    account_config::AccountConfig,
+    character_creation_config::CharacterCreationConfig,
+    database_config::DatabaseConfig,
+    discord_config::DiscordConfig,
+    paths::ProjectPaths,
+    scripting_config::ScriptingConfig,
     server_config::ServerConfig,
+    validation::{self, ConfigIssue, ConfigReport},
 };
 
 #[derive(Debug)]
 pub enum ConfigLoadError {
     NotFound,
+    /// Not valid TOML
     ParseError(String),
+    /// Valid TOML, but settings have the wrong type or are missing
+    Invalid(Vec<ConfigIssue>),
     IoError(String),
 }
 
@@ -20,6 +33,16 @@ impl std::fmt::Display for ConfigLoadError {
         match self {
             ConfigLoadError::NotFound => write!(f, "Config file not found"),
             ConfigLoadError::ParseError(msg) => write!(f, "Failed to parse config: {}", msg),
+            ConfigLoadError::Invalid(issues) => {
+                write!(f, "Invalid config: ")?;
+                for (i, issue) in issues.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", issue)?;
+                }
+                Ok(())
+            }
             ConfigLoadError::IoError(msg) => write!(f, "IO error reading config: {}", msg),
         }
     }
@@ -38,12 +61,63 @@ pub struct GromnieConfig {
     #[serde(default)]
     pub scripting: ScriptingConfig,
 
+    /// Which events the Discord bot relays to its channel
+    #[serde(default)]
+    pub discord: DiscordConfig,
+
+    /// SQLite file to record events to (unset = events aren't recorded)
+    #[serde(default)]
+    pub database: Option<DatabaseConfig>,
+
+    /// Emit every event as a structured `tracing` span under the
+    /// `gromnie::events` target, for OTLP and similar subscribers
+    #[serde(default)]
+    pub trace_events: bool,
+
+    /// Create a character for accounts that have none
+    #[serde(default)]
+    pub character_creation: CharacterCreationConfig,
+
     /// Enable automatic reconnection with exponential backoff
     #[serde(default)]
     pub reconnect: bool,
+
+    /// Fixed wait in seconds before the first reconnect attempt (unset = use the backoff delay)
+    #[serde(default)]
+    pub initial_reconnect_delay_secs: Option<u64>,
+
+    /// After reconnecting, log back into the character that was in the world
+    /// instead of stopping at character select
+    #[serde(default)]
+    pub resume_in_world: bool,
+
+    /// Drop received packets whose checksum doesn't verify instead of only
+    /// logging them
+    #[serde(default)]
+    pub strict_checksums: bool,
+
+    /// Seconds a partially received message waits for its missing fragments
+    /// before it's dropped (unset = 30)
+    #[serde(default)]
+    pub fragment_timeout_secs: Option<u64>,
+
+    /// Seconds between TimeSync keep-alives (unset = 5)
+    #[serde(default)]
+    pub keepalive_interval_secs: Option<u64>,
+
+    /// Seconds without hearing from the server before the connection is
+    /// treated as lost (unset = 60)
+    #[serde(default)]
+    pub idle_timeout_secs: Option<u64>,
+
+    /// LoginRequests sent, 2s apart, before waiting out the login timeout
+    /// (unset = 5)
+    #[serde(default)]
+    pub max_login_attempts: Option<u32>,
 }
 
 impl GromnieConfig {
+    /// Location of config.toml, under `GROMNIE_CONFIG_DIR` when that is set
     pub fn config_path() -> PathBuf {
         let proj_paths =
             ProjectPaths::new("gromnie").expect("Failed to determine config directory");
@@ -52,17 +126,43 @@ impl GromnieConfig {
 
     pub fn load() -> Result<Self, ConfigLoadError> {
         let path = Self::config_path();
+        let content = Self::read(&path)?;
+        let config = Self::parse(&content)?;
+        info!("Loaded config from {}", path.display());
+        Ok(config)
+    }
 
+    /// Read a config file without parsing it
+    pub fn read(path: &Path) -> Result<String, ConfigLoadError> {
         if !path.exists() {
             return Err(ConfigLoadError::NotFound);
         }
+        fs::read_to_string(path).map_err(|e| ConfigLoadError::IoError(e.to_string()))
+    }
 
-        let content =
-            fs::read_to_string(&path).map_err(|e| ConfigLoadError::IoError(e.to_string()))?;
-        let config =
-            toml::from_str(&content).map_err(|e| ConfigLoadError::ParseError(e.to_string()))?;
-        info!("Loaded config from {}", path.display());
-        Ok(config)
+    /// Parse config.toml contents, reporting every invalid setting by name
+    ///
+    /// Unknown settings are logged and otherwise ignored.
+    pub fn parse(content: &str) -> Result<Self, ConfigLoadError> {
+        let report = Self::check(content)?;
+        for issue in &report.warnings {
+            warn!("Config: {}", issue);
+        }
+        if !report.is_valid() {
+            return Err(ConfigLoadError::Invalid(report.errors));
+        }
+        toml::from_str(content).map_err(|e| ConfigLoadError::ParseError(e.to_string()))
+    }
+
+    /// Check config.toml contents without loading them
+    ///
+    /// Fails only if the contents aren't TOML at all; problems with
+    /// individual settings are listed in the report.
+    pub fn check(content: &str) -> Result<ConfigReport, ConfigLoadError> {
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|e| ConfigLoadError::ParseError(e.to_string()))?;
+        Ok(validation::validate(&table))
     }
 
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -1,12 +1,22 @@
 pub mod account_config;
+pub mod character_creation_config;
 pub mod client_config;
+pub mod database_config;
+pub mod discord_config;
 pub mod gromnie_config;
 pub mod paths;
 pub mod scripting_config;
 pub mod server_config;
+pub mod validation;
 
 pub use account_config::AccountConfig;
+pub use character_creation_config::CharacterCreationConfig;
 pub use client_config::ClientConfig;
+pub use database_config::DatabaseConfig;
+pub use discord_config::{DiscordConfig, DiscordEventCategory};
 pub use gromnie_config::{ConfigLoadError, GromnieConfig};
-pub use paths::ProjectPaths;
-pub use server_config::{ReconnectConfig, ServerConfig};
+pub use paths::{ProjectPaths, UnwritableDirError};
+pub use server_config::{
+    PortRouting, PortTarget, ReconnectConfig, ReconnectOverride, ServerConfig,
+};
+pub use validation::{ConfigIssue, ConfigReport};
@@ -1,4 +1,6 @@
-use std::path::PathBuf;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
 
 /// Platform-specific path handling that follows XDG Base Directory spec on Unix-like systems
 /// and Windows conventions on Windows.
@@ -12,22 +14,57 @@ use std::path::PathBuf;
 /// - Config: %APPDATA%\{name}
 /// - Data: %APPDATA%\{name}
 /// - Cache: %LOCALAPPDATA%\{name}
+///
+/// Setting `{NAME}_CONFIG_DIR` (`GROMNIE_CONFIG_DIR` for gromnie) replaces both
+/// the config and data directories with that one directory, so config, scripts
+/// and script data can all be pointed somewhere writable.
 pub struct ProjectPaths {
     name: String,
+    dir_override: Option<PathBuf>,
 }
 
 impl ProjectPaths {
     /// Create a new ProjectPaths instance for the given application name.
     pub fn new(name: &str) -> Option<Self> {
-        // Verify we can get home directory before creating
-        get_home_dir()?;
+        let dir_override = std::env::var_os(override_env_var(name))
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+
+        // Verify we can get home directory before creating, unless it isn't needed
+        if dir_override.is_none() {
+            get_home_dir()?;
+        }
         Some(ProjectPaths {
             name: name.to_string(),
+            dir_override,
         })
     }
 
+    /// Name of the environment variable that overrides the config/data directory
+    pub fn override_env_var(&self) -> String {
+        override_env_var(&self.name)
+    }
+
+    /// Create the config directory if needed and check that files can be written to it.
+    ///
+    /// Returns the directory on success. The error names the directory and the
+    /// override variable so it can be acted on directly.
+    pub fn ensure_config_dir_writable(&self) -> Result<PathBuf, UnwritableDirError> {
+        let dir = self.config_dir();
+        ensure_writable(&dir).map_err(|source| UnwritableDirError {
+            dir: dir.clone(),
+            env_var: self.override_env_var(),
+            source,
+        })?;
+        Ok(dir)
+    }
+
     /// Get the configuration directory path.
     pub fn config_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.dir_override {
+            return dir.clone();
+        }
+
         #[cfg(target_os = "windows")]
         {
             get_windows_appdata()
@@ -43,6 +80,10 @@ impl ProjectPaths {
 
     /// Get the data directory path.
     pub fn data_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.dir_override {
+            return dir.clone();
+        }
+
         #[cfg(target_os = "windows")]
         {
             get_windows_appdata()
@@ -72,6 +113,47 @@ impl ProjectPaths {
     }
 }
 
+/// A config directory that can't be created or written to
+#[derive(Debug)]
+pub struct UnwritableDirError {
+    pub dir: PathBuf,
+    pub env_var: String,
+    pub source: io::Error,
+}
+
+impl fmt::Display for UnwritableDirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "config dir {} is not writable ({}); set {} or fix permissions",
+            self.dir.display(),
+            self.source,
+            self.env_var
+        )
+    }
+}
+
+impl std::error::Error for UnwritableDirError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn override_env_var(name: &str) -> String {
+    format!("{}_CONFIG_DIR", name.to_uppercase())
+}
+
+/// Create `dir` and probe it with a throwaway file.
+///
+/// `create_dir_all` succeeds on an existing read-only directory, so the probe
+/// is what catches read-only mounts.
+fn ensure_writable(dir: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let probe = dir.join(format!(".write-test-{}", std::process::id()));
+    std::fs::write(&probe, b"")?;
+    std::fs::remove_file(&probe)
+}
+
 /// Get the home directory, respecting HOME and USERPROFILE environment variables.
 fn get_home_dir() -> Option<PathBuf> {
     std::env::var("HOME")
@@ -148,6 +230,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dir_override_replaces_config_and_data() {
+        let dir = std::env::temp_dir().join(format!("gromnie-paths-{}", std::process::id()));
+        let paths = ProjectPaths {
+            name: "gromnie".to_string(),
+            dir_override: Some(dir.clone()),
+        };
+        assert_eq!(paths.config_dir(), dir);
+        assert_eq!(paths.data_dir(), dir);
+        assert_eq!(paths.override_env_var(), "GROMNIE_CONFIG_DIR");
+
+        assert_eq!(paths.ensure_config_dir_writable().unwrap(), dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     #[cfg(not(target_os = "windows"))]
     fn test_xdg_config_dir_uses_config_path() {
@@ -1,6 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use super::paths::ProjectPaths;
 
@@ -26,6 +27,15 @@ pub struct ScriptingConfig {
     #[serde(default = "default_hot_reload_interval")]
     pub hot_reload_interval_ms: u64,
 
+    /// How many times a second scripts' `on_tick` runs, 1 to 100 (default: 20)
+    ///
+    /// Timers and scheduled actions are also checked on each tick, so a
+    /// faster rate makes them fire closer to their due time. Every tick calls
+    /// into every loaded script, so CPU use grows with the rate; idle bots can
+    /// go as low as 1.
+    #[serde(default = "default_tick_hz", deserialize_with = "deserialize_tick_hz")]
+    pub tick_hz: u32,
+
     /// Script execution timeout in milliseconds (default: 100ms)
     ///
     /// Each script's event handler and tick function will be terminated if it
@@ -33,6 +43,55 @@ pub struct ScriptingConfig {
     /// the entire scripting system. Set to 0 to disable timeouts (not recommended).
     #[serde(default = "default_script_timeout")]
     pub script_timeout_ms: u64,
+
+    /// Fuel each script callback may burn (default: 100,000,000)
+    ///
+    /// Fuel roughly counts WASM instructions. A callback that runs out, e.g.
+    /// because of an infinite loop, is stopped and the script is unloaded;
+    /// other scripts keep running. Unlike `script_timeout_ms`, this also stops
+    /// scripts that never yield. Set to 0 to disable the limit.
+    #[serde(default = "default_script_fuel_limit")]
+    pub script_fuel_limit: u64,
+
+    /// Number of recent events kept for scripts to fetch with `recent-events`
+    /// (default: 200)
+    ///
+    /// Lets a script loaded or reloaded mid-session catch up on earlier events
+    /// such as the character list. Protocol events are not kept. Set to 0 to
+    /// disable the history.
+    #[serde(default = "default_event_history_size")]
+    pub event_history_size: usize,
+
+    /// Let scripts send arbitrary game actions with `send-game-action`
+    /// (default: false)
+    ///
+    /// The payload isn't checked, so a buggy script can put malformed packets
+    /// on the wire. Only enable this for scripts you trust.
+    #[serde(default)]
+    pub allow_raw_actions: bool,
+
+    /// Save each script's persistent state to disk as it changes and restore
+    /// it on startup (default: false, so state only survives reloads)
+    #[serde(default)]
+    pub persist_state_to_disk: bool,
+
+    /// Also write each script's `log()` messages to its own file under
+    /// `script-logs/` in the config directory (default: false)
+    #[serde(default)]
+    pub log_to_file: bool,
+
+    /// Size in KiB a script's log file may reach before it is rotated to
+    /// `<id>.log.1` (default: 1024)
+    #[serde(default = "default_log_file_max_kb")]
+    pub log_file_max_kb: u64,
+
+    /// Only load scripts with these IDs (takes precedence over `disabled_scripts`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled_scripts: Option<Vec<String>>,
+
+    /// Never load scripts with these IDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_scripts: Vec<String>,
 }
 
 fn default_hot_reload() -> bool {
@@ -43,10 +102,43 @@ fn default_hot_reload_interval() -> u64 {
     1000
 }
 
+/// Slowest allowed `tick_hz`
+pub const MIN_TICK_HZ: u32 = 1;
+
+/// Fastest allowed `tick_hz`
+pub const MAX_TICK_HZ: u32 = 100;
+
+fn default_tick_hz() -> u32 {
+    20
+}
+
+fn deserialize_tick_hz<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let tick_hz = u32::deserialize(deserializer)?;
+    if !(MIN_TICK_HZ..=MAX_TICK_HZ).contains(&tick_hz) {
+        return Err(serde::de::Error::custom(format!(
+            "tick_hz must be between {} and {}, got {}",
+            MIN_TICK_HZ, MAX_TICK_HZ, tick_hz
+        )));
+    }
+    Ok(tick_hz)
+}
+
 fn default_script_timeout() -> u64 {
     100
 }
 
+fn default_script_fuel_limit() -> u64 {
+    100_000_000
+}
+
+fn default_event_history_size() -> usize {
+    200
+}
+
+fn default_log_file_max_kb() -> u64 {
+    1024
+}
+
 impl Default for ScriptingConfig {
     fn default() -> Self {
         Self {
@@ -55,7 +147,16 @@ impl Default for ScriptingConfig {
             config: HashMap::new(),
             hot_reload: true,
             hot_reload_interval_ms: 1000,
+            tick_hz: default_tick_hz(),
             script_timeout_ms: 100,
+            script_fuel_limit: default_script_fuel_limit(),
+            event_history_size: default_event_history_size(),
+            allow_raw_actions: false,
+            persist_state_to_disk: false,
+            log_to_file: false,
+            log_file_max_kb: default_log_file_max_kb(),
+            enabled_scripts: None,
+            disabled_scripts: Vec::new(),
         }
     }
 }
@@ -69,4 +170,153 @@ impl ScriptingConfig {
                 .unwrap_or_else(|| PathBuf::from(".scripts"))
         })
     }
+
+    /// Time between script ticks, from `tick_hz` (clamped to 1-100 Hz)
+    pub fn tick_interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.tick_hz.clamp(MIN_TICK_HZ, MAX_TICK_HZ) as f64)
+    }
+
+    /// Allowlist/denylist of script IDs to load
+    pub fn script_selection(&self) -> ScriptSelection {
+        ScriptSelection {
+            allow: self.enabled_scripts.clone(),
+            deny: self.disabled_scripts.clone(),
+        }
+    }
+
+    /// Directory for persisted script state (one file per script ID),
+    /// namespaced by client ID like [`ScriptingConfig::storage_dir`]
+    pub fn state_dir(&self, client_id: u32) -> PathBuf {
+        ProjectPaths::new("gromnie")
+            .map(|p| p.config_dir().join("script-state"))
+            .unwrap_or_else(|| PathBuf::from(".script-state"))
+            .join(format!("client-{}", client_id))
+    }
+
+    /// Directory for per-script log files when `log_to_file` is on,
+    /// namespaced by client ID like [`ScriptingConfig::storage_dir`]
+    pub fn log_dir(&self, client_id: u32) -> PathBuf {
+        ProjectPaths::new("gromnie")
+            .map(|p| p.config_dir().join("script-logs"))
+            .unwrap_or_else(|| PathBuf::from(".script-logs"))
+            .join(format!("client-{}", client_id))
+    }
+
+    /// Directory for script key/value storage, namespaced by client ID so
+    /// clients running the same script keep separate files
+    pub fn storage_dir(&self, client_id: u32) -> PathBuf {
+        ProjectPaths::new("gromnie")
+            .map(|p| p.config_dir().join("script-data"))
+            .unwrap_or_else(|| PathBuf::from(".script-data"))
+            .join(format!("client-{}", client_id))
+    }
+}
+
+/// Which scripts to load by ID, from `enabled_scripts` and `disabled_scripts`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptSelection {
+    /// If set, only these scripts load and `deny` is ignored
+    pub allow: Option<Vec<String>>,
+    pub deny: Vec<String>,
+}
+
+impl ScriptSelection {
+    /// Why the script should be skipped, or None if it should load
+    pub fn skip_reason(&self, script_id: &str) -> Option<&'static str> {
+        match &self.allow {
+            Some(allow) if !allow.iter().any(|id| id == script_id) => {
+                Some("not in enabled_scripts")
+            }
+            Some(_) => None,
+            None if self.deny.iter().any(|id| id == script_id) => Some("in disabled_scripts"),
+            None => None,
+        }
+    }
+
+    /// IDs in the allowlist that none of the `found` scripts reported
+    pub fn missing_enabled<'a>(&'a self, found: &[&str]) -> Vec<&'a str> {
+        self.allow
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .filter(|id| !found.contains(id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_selection() {
+        // Default: everything loads
+        let config = ScriptingConfig::default();
+        assert_eq!(config.script_selection().skip_reason("auto_login"), None);
+
+        // Denylist
+        let config: ScriptingConfig = toml::from_str(r#"disabled_scripts = ["spammer"]"#).unwrap();
+        let selection = config.script_selection();
+        assert_eq!(selection.skip_reason("auto_login"), None);
+        assert_eq!(
+            selection.skip_reason("spammer"),
+            Some("in disabled_scripts")
+        );
+
+        // Allowlist wins over the denylist
+        let config: ScriptingConfig = toml::from_str(
+            r#"
+            enabled_scripts = ["auto_login"]
+            disabled_scripts = ["auto_login", "spammer"]
+            "#,
+        )
+        .unwrap();
+        let selection = config.script_selection();
+        assert_eq!(selection.skip_reason("auto_login"), None);
+        assert_eq!(
+            selection.skip_reason("spammer"),
+            Some("not in enabled_scripts")
+        );
+        assert_eq!(
+            selection.skip_reason("other"),
+            Some("not in enabled_scripts")
+        );
+    }
+
+    #[test]
+    fn test_tick_hz() {
+        let config = ScriptingConfig::default();
+        assert_eq!(config.tick_hz, 20);
+        assert_eq!(config.tick_interval(), Duration::from_millis(50));
+
+        let config: ScriptingConfig = toml::from_str("tick_hz = 100").unwrap();
+        assert_eq!(config.tick_interval(), Duration::from_millis(10));
+        let config: ScriptingConfig = toml::from_str("tick_hz = 1").unwrap();
+        assert_eq!(config.tick_interval(), Duration::from_secs(1));
+
+        for out_of_range in ["tick_hz = 0", "tick_hz = 101"] {
+            let err = toml::from_str::<ScriptingConfig>(out_of_range).unwrap_err();
+            assert!(err.to_string().contains("between 1 and 100"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_missing_enabled_scripts() {
+        let config: ScriptingConfig =
+            toml::from_str(r#"enabled_scripts = ["auto_login", "buffer"]"#).unwrap();
+        let selection = config.script_selection();
+        assert_eq!(
+            selection.missing_enabled(&["auto_login", "spammer"]),
+            vec!["buffer"]
+        );
+        assert!(
+            selection
+                .missing_enabled(&["auto_login", "buffer"])
+                .is_empty()
+        );
+
+        // Nothing is required without an allowlist
+        let selection = ScriptingConfig::default().script_selection();
+        assert!(selection.missing_enabled(&[]).is_empty());
+    }
 }
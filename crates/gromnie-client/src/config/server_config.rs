@@ -1,5 +1,11 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
+use crate::client::SessionState;
+use crate::transport::TransportChannel;
+
 /// Configuration for client reconnection with exponential backoff
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -18,6 +24,29 @@ pub struct ReconnectConfig {
 
     /// Exponential backoff multiplier - each failure multiplies delay by this amount (default: 2)
     pub backoff_multiplier: f64,
+
+    /// Fixed wait before the first reconnect attempt, in seconds. Later attempts
+    /// still follow the backoff schedule. `None` uses the backoff delay for the
+    /// first attempt too (default).
+    pub initial_reconnect_delay_secs: Option<u64>,
+
+    /// Number of recently sent packets kept so they can be resent when the
+    /// server requests a retransmit (default: 64, 0 disables retransmission)
+    pub retransmit_buffer_size: usize,
+
+    /// Randomly vary each backoff delay by up to this fraction either way, so
+    /// many clients dropped at once don't retry in lockstep. Clamped to 0..=1
+    /// (default: 0.0, no jitter)
+    pub jitter_fraction: f64,
+
+    /// Seed for the jitter RNG, making delays reproducible. `None` seeds from
+    /// entropy, which is what you want when running several clients (default)
+    pub jitter_seed: Option<u64>,
+
+    /// After reconnecting, log back into the character that was in the world
+    /// when the connection dropped instead of stopping at character select.
+    /// Gives up after two failed logins (default: false)
+    pub resume_in_world: bool,
 }
 
 impl Default for ReconnectConfig {
@@ -28,6 +57,11 @@ impl Default for ReconnectConfig {
             initial_delay_secs: 10,
             max_delay_secs: 600,
             backoff_multiplier: 2.0,
+            initial_reconnect_delay_secs: None,
+            retransmit_buffer_size: crate::client::DEFAULT_RETRANSMIT_BUFFER_SIZE,
+            jitter_fraction: 0.0,
+            jitter_seed: None,
+            resume_in_world: false,
         }
     }
 }
@@ -38,7 +72,30 @@ impl ReconnectConfig {
         let delay_secs = (self.initial_delay_secs as f64
             * self.backoff_multiplier.powi(attempt as i32))
         .min(self.max_delay_secs as f64) as u64;
-        std::time::Duration::from_secs(delay_secs)
+
+        let jitter = self.jitter_fraction.clamp(0.0, 1.0);
+        if jitter == 0.0 || delay_secs == 0 {
+            return std::time::Duration::from_secs(delay_secs);
+        }
+
+        // Seeded per attempt so a fixed seed gives a fixed schedule
+        let mut rng = match self.jitter_seed {
+            Some(seed) => rand::rngs::StdRng::seed_from_u64(seed.wrapping_add(attempt as u64)),
+            None => rand::rngs::StdRng::from_entropy(),
+        };
+        let factor = 1.0 + rng.gen_range(-jitter..=jitter);
+        // Cap again so jitter never pushes the delay past the maximum
+        std::time::Duration::from_secs_f64(
+            (delay_secs as f64 * factor).min(self.max_delay_secs as f64),
+        )
+    }
+
+    /// Delay to wait before reconnect attempt `attempt` (1 = first attempt after a disconnect)
+    pub fn delay_before_attempt(&self, attempt: u32) -> std::time::Duration {
+        match self.initial_reconnect_delay_secs {
+            Some(secs) if attempt <= 1 => std::time::Duration::from_secs(secs),
+            _ => self.delay_for_attempt(attempt),
+        }
     }
 
     /// Check if we should attempt reconnection based on max attempts
@@ -49,6 +106,133 @@ impl ReconnectConfig {
             attempt < self.max_attempts
         }
     }
+
+    /// This config with every field set in `overrides` replaced
+    pub fn with_override(&self, overrides: &ReconnectOverride) -> ReconnectConfig {
+        ReconnectConfig {
+            enabled: overrides.enabled.unwrap_or(self.enabled),
+            max_attempts: overrides.max_attempts.unwrap_or(self.max_attempts),
+            initial_delay_secs: overrides
+                .initial_delay_secs
+                .unwrap_or(self.initial_delay_secs),
+            max_delay_secs: overrides.max_delay_secs.unwrap_or(self.max_delay_secs),
+            backoff_multiplier: overrides
+                .backoff_multiplier
+                .unwrap_or(self.backoff_multiplier),
+            initial_reconnect_delay_secs: overrides
+                .initial_reconnect_delay_secs
+                .or(self.initial_reconnect_delay_secs),
+            retransmit_buffer_size: overrides
+                .retransmit_buffer_size
+                .unwrap_or(self.retransmit_buffer_size),
+            jitter_fraction: overrides.jitter_fraction.unwrap_or(self.jitter_fraction),
+            jitter_seed: overrides.jitter_seed.or(self.jitter_seed),
+            resume_in_world: overrides.resume_in_world.unwrap_or(self.resume_in_world),
+        }
+    }
+}
+
+/// Per-server changes to [`ReconnectConfig`], set as `[servers.<name>.reconnect]`
+///
+/// Each field that is set replaces the global value when connecting to that
+/// server; unset fields inherit it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReconnectOverride {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_delay_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_multiplier: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_reconnect_delay_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retransmit_buffer_size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jitter_fraction: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jitter_seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume_in_world: Option<bool>,
+}
+
+/// Server port a packet is sent to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortTarget {
+    /// The configured server port (9000 by default)
+    Login,
+    /// The port directly after the login port (9001 by default)
+    World,
+}
+
+impl From<PortTarget> for TransportChannel {
+    fn from(target: PortTarget) -> Self {
+        match target {
+            PortTarget::Login => TransportChannel::Login,
+            PortTarget::World => TransportChannel::World,
+        }
+    }
+}
+
+/// Destination-port policy for outgoing packets, chosen by session phase
+///
+/// ACE-style servers listen on a login port and a world port (login + 1). The
+/// client picks the destination from the current `SessionState`:
+///
+/// | Phase              | Session states                                         | Default |
+/// |--------------------|--------------------------------------------------------|---------|
+/// | `connect_response` | the ConnectResponse handshake packet, in any state      | world   |
+/// | `auth`             | `AuthLoginRequest`, `AuthConnectResponse`, `AuthConnected` | login   |
+/// | `world`            | `WorldConnected`, `TerminationStarted`                 | login   |
+///
+/// The defaults match ACE. Servers that expect traffic to move to the world port
+/// after entering the world should set `world = "world"`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PortRouting {
+    /// Target for the ConnectResponse handshake packet
+    pub connect_response: PortTarget,
+    /// Target for traffic before entering the world (login, patching, character select)
+    pub auth: PortTarget,
+    /// Target for traffic after entering the world
+    pub world: PortTarget,
+}
+
+impl Default for PortRouting {
+    fn default() -> Self {
+        Self {
+            connect_response: PortTarget::World,
+            auth: PortTarget::Login,
+            world: PortTarget::Login,
+        }
+    }
+}
+
+impl PortRouting {
+    /// Pick the destination for a packet sent in the given session state
+    pub fn target_for(&self, state: &SessionState, is_connect_response: bool) -> PortTarget {
+        if is_connect_response {
+            return self.connect_response;
+        }
+
+        match state {
+            SessionState::AuthLoginRequest
+            | SessionState::AuthConnectResponse
+            | SessionState::AuthConnected => self.auth,
+            SessionState::WorldConnected | SessionState::TerminationStarted => self.world,
+        }
+    }
+
+    /// Same as `target_for`, as a transport channel
+    pub fn channel_for(&self, state: &SessionState, is_connect_response: bool) -> TransportChannel {
+        self.target_for(state, is_connect_response).into()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,14 +240,252 @@ pub struct ServerConfig {
     pub host: String,
     #[serde(default = "default_port")]
     pub port: u16,
+    /// Which port each session phase sends to (defaults to ACE behavior)
+    #[serde(default)]
+    pub routing: PortRouting,
+    /// Local interface to send from (default: all interfaces)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_address: Option<IpAddr>,
+    /// Local UDP port to send from (default: an ephemeral port picked by the OS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_port: Option<u16>,
+    /// Reconnect settings for this server that replace the global ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconnect: Option<ReconnectOverride>,
 }
 
 fn default_port() -> u16 {
     9000
 }
 
+impl ServerConfig {
+    /// Local address to bind the client socket to, or `None` to use an
+    /// ephemeral port on all interfaces
+    pub fn bind_addr(&self) -> Option<SocketAddr> {
+        if self.bind_address.is_none() && self.bind_port.is_none() {
+            return None;
+        }
+        let ip = self
+            .bind_address
+            .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        Some(SocketAddr::new(ip, self.bind_port.unwrap_or(0)))
+    }
+
+    /// Whether to reconnect to this server, given the global `reconnect` setting
+    pub fn reconnect_enabled(&self, global: bool) -> bool {
+        self.reconnect
+            .as_ref()
+            .and_then(|overrides| overrides.enabled)
+            .unwrap_or(global)
+    }
+}
+
 impl std::fmt::Display for ServerConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}:{}", self.host, self.port)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jittered_delay_never_exceeds_max() {
+        for seed in 0..50 {
+            let config = ReconnectConfig {
+                jitter_fraction: 0.5,
+                jitter_seed: Some(seed),
+                ..Default::default()
+            };
+            // Far past the point where the backoff reaches the cap
+            let delay = config.delay_for_attempt(20).as_secs_f64();
+            let max = config.max_delay_secs as f64;
+            assert!(
+                delay >= max * 0.5 && delay <= max,
+                "seed {} delay {} outside {}..={}",
+                seed,
+                delay,
+                max * 0.5,
+                max
+            );
+        }
+    }
+
+    #[test]
+    fn test_jittered_delay_stays_in_envelope() {
+        let config = ReconnectConfig {
+            jitter_fraction: 0.25,
+            ..Default::default()
+        };
+
+        let mut distinct = std::collections::HashSet::new();
+        for seed in 0..50 {
+            let config = ReconnectConfig {
+                jitter_seed: Some(seed),
+                ..config.clone()
+            };
+            for attempt in 0..4 {
+                let base = ReconnectConfig::default()
+                    .delay_for_attempt(attempt)
+                    .as_secs_f64();
+                let delay = config.delay_for_attempt(attempt).as_secs_f64();
+                assert!(
+                    delay >= base * 0.75 && delay <= base * 1.25,
+                    "attempt {} delay {} outside {} +/- 25%",
+                    attempt,
+                    delay,
+                    base
+                );
+                distinct.insert(delay.to_bits());
+            }
+            // Same seed, same schedule
+            assert_eq!(config.delay_for_attempt(2), config.delay_for_attempt(2));
+        }
+        assert!(distinct.len() > 4, "jitter should vary the delays");
+
+        // Out of range fractions are clamped to 1, so delays stay within 0..=2x
+        let wild = ReconnectConfig {
+            jitter_fraction: 5.0,
+            jitter_seed: Some(7),
+            ..Default::default()
+        };
+        assert!(wild.delay_for_attempt(0).as_secs_f64() <= 20.0);
+        assert_eq!(
+            ReconnectConfig::default().delay_for_attempt(0),
+            std::time::Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_default_routing_matches_ace() {
+        let routing = PortRouting::default();
+
+        assert_eq!(
+            routing.target_for(&SessionState::AuthConnectResponse, true),
+            PortTarget::World
+        );
+        assert_eq!(
+            routing.target_for(&SessionState::AuthLoginRequest, false),
+            PortTarget::Login
+        );
+        assert_eq!(
+            routing.target_for(&SessionState::WorldConnected, false),
+            PortTarget::Login
+        );
+    }
+
+    #[test]
+    fn test_routing_from_server_config() {
+        let server: ServerConfig = toml::from_str(
+            r#"
+            host = "localhost"
+
+            [routing]
+            world = "world"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(server.port, 9000);
+        assert_eq!(
+            server
+                .routing
+                .target_for(&SessionState::AuthConnected, false),
+            PortTarget::Login
+        );
+        assert_eq!(
+            server
+                .routing
+                .target_for(&SessionState::WorldConnected, false),
+            PortTarget::World
+        );
+    }
+
+    #[test]
+    fn test_bind_addr_from_server_config() {
+        let server: ServerConfig = toml::from_str(r#"host = "localhost""#).unwrap();
+        assert_eq!(server.bind_addr(), None);
+
+        let server: ServerConfig = toml::from_str(
+            r#"
+            host = "localhost"
+            bind_port = 50000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(server.bind_addr(), Some("0.0.0.0:50000".parse().unwrap()));
+
+        let server: ServerConfig = toml::from_str(
+            r#"
+            host = "localhost"
+            bind_address = "192.168.1.20"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(server.bind_addr(), Some("192.168.1.20:0".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_partial_reconnect_override() {
+        let server: ServerConfig = toml::from_str(
+            r#"
+            host = "localhost"
+
+            [reconnect]
+            enabled = true
+            initial_delay_secs = 1
+            max_delay_secs = 5
+            "#,
+        )
+        .unwrap();
+        let overrides = server.reconnect.as_ref().unwrap();
+
+        let global = ReconnectConfig {
+            max_attempts: 3,
+            jitter_fraction: 0.5,
+            initial_reconnect_delay_secs: Some(30),
+            ..Default::default()
+        };
+        let merged = global.with_override(overrides);
+        assert!(merged.enabled);
+        assert_eq!(merged.initial_delay_secs, 1);
+        assert_eq!(merged.max_delay_secs, 5);
+        // Everything else is inherited
+        assert_eq!(merged.max_attempts, 3);
+        assert_eq!(merged.jitter_fraction, 0.5);
+        assert_eq!(merged.initial_reconnect_delay_secs, Some(30));
+        assert_eq!(merged.backoff_multiplier, global.backoff_multiplier);
+        assert_eq!(merged.resume_in_world, global.resume_in_world);
+
+        assert!(server.reconnect_enabled(false));
+    }
+
+    #[test]
+    fn test_empty_reconnect_override_changes_nothing() {
+        let server: ServerConfig = toml::from_str(r#"host = "localhost""#).unwrap();
+        assert_eq!(server.reconnect, None);
+        assert!(!server.reconnect_enabled(false));
+        assert!(server.reconnect_enabled(true));
+
+        let global = ReconnectConfig {
+            enabled: true,
+            max_attempts: 7,
+            ..Default::default()
+        };
+        let merged = global.with_override(&ReconnectOverride::default());
+        assert!(merged.enabled);
+        assert_eq!(merged.max_attempts, 7);
+        assert_eq!(merged.initial_delay_secs, global.initial_delay_secs);
+
+        // An override can turn reconnecting off for one server
+        let server: ServerConfig = toml::from_str(
+            r#"
+            host = "remote.example.com"
+            reconnect = { enabled = false }
+            "#,
+        )
+        .unwrap();
+        assert!(!server.reconnect_enabled(true));
+    }
+}
@@ -0,0 +1,421 @@
+use toml::{Table, Value};
+
+use super::scripting_config::{MAX_TICK_HZ, MIN_TICK_HZ};
+
+/// What a config value has to look like
+enum Expect {
+    Bool,
+    String,
+    /// Integer from `min`, up to `max` when there is one
+    Integer {
+        min: i64,
+        max: Option<i64>,
+    },
+    IpAddr,
+    /// Integer or float, zero or more
+    Number,
+    /// One of these strings
+    OneOf(&'static [&'static str]),
+    /// List of strings
+    Strings,
+    /// List of strings, each one of these
+    ListOf(&'static [&'static str]),
+    /// Table with these fields
+    Table(&'static [Field]),
+    /// Table of named entries (`[servers.<name>]`), each with these fields
+    Entries(&'static [Field]),
+    /// Free-form table, e.g. per-script settings
+    AnyTable,
+}
+
+impl Expect {
+    const NON_NEGATIVE: Expect = Expect::Integer { min: 0, max: None };
+
+    /// How to describe the expected value after "must be"
+    fn describe(&self) -> String {
+        match self {
+            Expect::Bool => "true or false".to_string(),
+            Expect::String => "a string".to_string(),
+            Expect::Integer { min: 0, max: None } => "a non-negative integer".to_string(),
+            Expect::Integer { min, max: None } => format!("an integer of at least {}", min),
+            Expect::Integer {
+                min,
+                max: Some(max),
+            } => format!("an integer {}-{}", min, max),
+            Expect::IpAddr => "an IP address".to_string(),
+            Expect::Number => "a non-negative number".to_string(),
+            Expect::OneOf(choices) => format!("one of {}", quoted(choices)),
+            Expect::Strings => "a list of strings".to_string(),
+            Expect::ListOf(choices) => format!("a list of {}", quoted(choices)),
+            Expect::Table(_) | Expect::Entries(_) | Expect::AnyTable => "a table".to_string(),
+        }
+    }
+}
+
+fn quoted(choices: &[&str]) -> String {
+    choices
+        .iter()
+        .map(|choice| format!("\"{}\"", choice))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A known config setting
+struct Field {
+    name: &'static str,
+    expect: Expect,
+    required: bool,
+}
+
+const fn optional(name: &'static str, expect: Expect) -> Field {
+    Field {
+        name,
+        expect,
+        required: false,
+    }
+}
+
+const fn required(name: &'static str, expect: Expect) -> Field {
+    Field {
+        name,
+        expect,
+        required: true,
+    }
+}
+
+const PORT_TARGETS: &[&str] = &["login", "world"];
+
+const ROUTING_FIELDS: &[Field] = &[
+    optional("connect_response", Expect::OneOf(PORT_TARGETS)),
+    optional("auth", Expect::OneOf(PORT_TARGETS)),
+    optional("world", Expect::OneOf(PORT_TARGETS)),
+];
+
+const RECONNECT_FIELDS: &[Field] = &[
+    optional("enabled", Expect::Bool),
+    optional("max_attempts", Expect::NON_NEGATIVE),
+    optional("initial_delay_secs", Expect::NON_NEGATIVE),
+    optional("max_delay_secs", Expect::NON_NEGATIVE),
+    optional("backoff_multiplier", Expect::Number),
+    optional("initial_reconnect_delay_secs", Expect::NON_NEGATIVE),
+    optional("retransmit_buffer_size", Expect::NON_NEGATIVE),
+    optional("jitter_fraction", Expect::Number),
+    optional("jitter_seed", Expect::NON_NEGATIVE),
+    optional("resume_in_world", Expect::Bool),
+];
+
+const SERVER_FIELDS: &[Field] = &[
+    required("host", Expect::String),
+    optional(
+        "port",
+        Expect::Integer {
+            min: 1,
+            max: Some(65535),
+        },
+    ),
+    optional("routing", Expect::Table(ROUTING_FIELDS)),
+    optional("bind_address", Expect::IpAddr),
+    optional(
+        "bind_port",
+        Expect::Integer {
+            min: 0,
+            max: Some(65535),
+        },
+    ),
+    optional("reconnect", Expect::Table(RECONNECT_FIELDS)),
+];
+
+const ACCOUNT_FIELDS: &[Field] = &[
+    required("username", Expect::String),
+    required("password", Expect::String),
+    optional("character", Expect::String),
+];
+
+const SCRIPTING_FIELDS: &[Field] = &[
+    optional("enabled", Expect::Bool),
+    optional("script_dir", Expect::String),
+    optional("config", Expect::AnyTable),
+    optional("hot_reload", Expect::Bool),
+    optional("hot_reload_interval_ms", Expect::NON_NEGATIVE),
+    // Same range the loader accepts; anything outside it fails to parse
+    optional(
+        "tick_hz",
+        Expect::Integer {
+            min: MIN_TICK_HZ as i64,
+            max: Some(MAX_TICK_HZ as i64),
+        },
+    ),
+    optional("script_timeout_ms", Expect::NON_NEGATIVE),
+    optional("script_fuel_limit", Expect::NON_NEGATIVE),
+    optional("event_history_size", Expect::NON_NEGATIVE),
+    optional("allow_raw_actions", Expect::Bool),
+    optional("persist_state_to_disk", Expect::Bool),
+    optional("log_to_file", Expect::Bool),
+    optional("log_file_max_kb", Expect::Integer { min: 1, max: None }),
+    optional("enabled_scripts", Expect::Strings),
+    optional("disabled_scripts", Expect::Strings),
+];
+
+const DISCORD_FIELDS: &[Field] = &[optional(
+    "relay",
+    Expect::ListOf(&["chat", "login", "deaths", "errors", "system"]),
+)];
+
+const DATABASE_FIELDS: &[Field] = &[required("path", Expect::String)];
+
+const CHARACTER_CREATION_FIELDS: &[Field] = &[
+    optional("enabled", Expect::Bool),
+    optional("template", Expect::String),
+];
+
+const CONFIG_FIELDS: &[Field] = &[
+    optional("servers", Expect::Entries(SERVER_FIELDS)),
+    optional("accounts", Expect::Entries(ACCOUNT_FIELDS)),
+    optional("scripting", Expect::Table(SCRIPTING_FIELDS)),
+    optional("discord", Expect::Table(DISCORD_FIELDS)),
+    optional("database", Expect::Table(DATABASE_FIELDS)),
+    optional("trace_events", Expect::Bool),
+    optional(
+        "character_creation",
+        Expect::Table(CHARACTER_CREATION_FIELDS),
+    ),
+    optional("reconnect", Expect::Bool),
+    optional("initial_reconnect_delay_secs", Expect::NON_NEGATIVE),
+    optional("resume_in_world", Expect::Bool),
+    optional("strict_checksums", Expect::Bool),
+    optional(
+        "fragment_timeout_secs",
+        Expect::Integer { min: 1, max: None },
+    ),
+    optional(
+        "keepalive_interval_secs",
+        Expect::Integer { min: 1, max: None },
+    ),
+    optional("idle_timeout_secs", Expect::Integer { min: 1, max: None }),
+    optional("max_login_attempts", Expect::Integer { min: 1, max: None }),
+];
+
+/// A problem with one setting in config.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// Dotted path to the setting, e.g. `servers.local.port`
+    pub field: String,
+    /// What's wrong, phrased to follow the field name
+    pub problem: String,
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.field, self.problem)
+    }
+}
+
+/// Result of checking a config against the settings gromnie knows about
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConfigReport {
+    /// Settings with the wrong type or value, or missing; the config won't load
+    pub errors: Vec<ConfigIssue>,
+    /// Settings gromnie doesn't know, most likely typos; they're ignored
+    pub warnings: Vec<ConfigIssue>,
+}
+
+impl ConfigReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Check every setting in a parsed config.toml, collecting all problems
+pub fn validate(config: &Table) -> ConfigReport {
+    let mut report = ConfigReport::default();
+    check_table(config, CONFIG_FIELDS, "", &mut report);
+    report
+}
+
+fn check_table(table: &Table, fields: &[Field], prefix: &str, report: &mut ConfigReport) {
+    for field in fields {
+        let path = format!("{}{}", prefix, field.name);
+        match table.get(field.name) {
+            Some(value) => check_value(value, &field.expect, &path, report),
+            None if field.required => report.errors.push(ConfigIssue {
+                field: path,
+                problem: format!("is required and must be {}", field.expect.describe()),
+            }),
+            None => {}
+        }
+    }
+
+    for key in table.keys() {
+        if !fields.iter().any(|field| field.name == key) {
+            report.warnings.push(ConfigIssue {
+                field: format!("{}{}", prefix, key),
+                problem: "is not a known setting".to_string(),
+            });
+        }
+    }
+}
+
+fn check_value(value: &Value, expect: &Expect, path: &str, report: &mut ConfigReport) {
+    let valid = match (expect, value) {
+        (Expect::Bool, Value::Boolean(_)) => true,
+        (Expect::String, Value::String(_)) => true,
+        (Expect::Integer { min, max }, Value::Integer(n)) => {
+            *n >= *min && max.is_none_or(|max| *n <= max)
+        }
+        (Expect::IpAddr, Value::String(s)) => s.parse::<std::net::IpAddr>().is_ok(),
+        (Expect::Number, Value::Integer(n)) => *n >= 0,
+        (Expect::Number, Value::Float(f)) => *f >= 0.0,
+        (Expect::OneOf(choices), Value::String(s)) => choices.contains(&s.as_str()),
+        (Expect::Strings, Value::Array(items)) => items.iter().all(Value::is_str),
+        (Expect::ListOf(choices), Value::Array(items)) => items
+            .iter()
+            .all(|item| item.as_str().is_some_and(|s| choices.contains(&s))),
+        (Expect::Table(fields), Value::Table(table)) => {
+            check_table(table, fields, &format!("{}.", path), report);
+            true
+        }
+        (Expect::Entries(fields), Value::Table(entries)) => {
+            for (name, entry) in entries {
+                let entry_path = format!("{}.{}", path, name);
+                match entry {
+                    Value::Table(table) => {
+                        check_table(table, fields, &format!("{}.", entry_path), report)
+                    }
+                    _ => report.errors.push(ConfigIssue {
+                        field: entry_path,
+                        problem: "must be a table".to_string(),
+                    }),
+                }
+            }
+            true
+        }
+        (Expect::AnyTable, Value::Table(_)) => true,
+        _ => false,
+    };
+
+    if !valid {
+        report.errors.push(ConfigIssue {
+            field: path.to_string(),
+            problem: format!("must be {}", expect.describe()),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(toml: &str) -> ConfigReport {
+        validate(&toml.parse::<Table>().unwrap())
+    }
+
+    fn messages(issues: &[ConfigIssue]) -> Vec<String> {
+        issues.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_example_config_is_valid() {
+        let report = check(
+            r#"
+            [servers.local]
+            host = "localhost"
+            port = 9000
+
+            [accounts.default]
+            username = "user"
+            password = "pass"
+
+            [scripting]
+            enabled = true
+            "#,
+        );
+        assert_eq!(report, ConfigReport::default());
+    }
+
+    #[test]
+    fn test_reports_field_and_expected_type() {
+        let report = check(
+            r#"
+            reconnect = "yes"
+
+            [servers.local]
+            port = "9000"
+            routing = { world = "elsewhere" }
+
+            [accounts.default]
+            username = "user"
+            password = "pass"
+
+            [discord]
+            relay = ["chat", "gossip"]
+            "#,
+        );
+        assert_eq!(
+            messages(&report.errors),
+            [
+                "servers.local.host is required and must be a string",
+                "servers.local.port must be an integer 1-65535",
+                "servers.local.routing.world must be one of \"login\", \"world\"",
+                "discord.relay must be a list of \"chat\", \"login\", \"deaths\", \"errors\", \"system\"",
+                "reconnect must be true or false",
+            ]
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_server_reconnect_override() {
+        let report = check(
+            r#"
+            [servers.local]
+            host = "localhost"
+
+            [servers.local.reconnect]
+            enabled = true
+            initial_delay_secs = 1
+            backoff_multiplier = 1.5
+            jitter_fraction = -0.1
+            max_retries = 3
+            "#,
+        );
+        assert_eq!(
+            messages(&report.errors),
+            ["servers.local.reconnect.jitter_fraction must be a non-negative number"]
+        );
+        assert_eq!(
+            messages(&report.warnings),
+            ["servers.local.reconnect.max_retries is not a known setting"]
+        );
+    }
+
+    #[test]
+    fn test_tick_hz_range_matches_loader() {
+        for tick_hz in [0, 101] {
+            let report = check(&format!("[scripting]\ntick_hz = {}", tick_hz));
+            assert_eq!(
+                messages(&report.errors),
+                ["scripting.tick_hz must be an integer 1-100"]
+            );
+        }
+        assert!(check("[scripting]\ntick_hz = 100").is_valid());
+    }
+
+    #[test]
+    fn test_unknown_settings_are_warnings() {
+        let report = check(
+            r#"
+            [servers.local]
+            host = "localhost"
+            prot = 9000
+
+            [scripting.config.my_script]
+            anything = "goes"
+            "#,
+        );
+        assert!(report.is_valid());
+        assert_eq!(
+            messages(&report.warnings),
+            ["servers.local.prot is not a known setting"]
+        );
+    }
+}
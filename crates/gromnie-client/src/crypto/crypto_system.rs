@@ -1,6 +1,16 @@
+use std::collections::VecDeque;
+
 use rand::SeedableRng;
 use rand_isaac::IsaacRng;
 
+/// How far past the newest matched key the server's keys are searched, and
+/// how far behind it a skipped key is kept before being dropped. Lost or
+/// reordered packets mean keys aren't always used in sequence.
+const RECEIVE_KEY_WINDOW: usize = 256;
+/// How many matched keys are remembered, so a retransmitted packet (which
+/// reuses its original key) still validates
+const USED_KEY_HISTORY: usize = 64;
+
 /// Wrapper around ISAAC random number generator for checksum encryption
 /// Mirrors the C# CryptoSystem class used in actestclient
 #[derive(Clone, Debug)]
@@ -36,6 +46,71 @@ impl CryptoSystem {
     }
 }
 
+/// Server->client checksum keys, generated from the server's seed
+///
+/// Each encrypted packet from the server consumes the next key in its ISAAC
+/// sequence, but packets can be dropped or arrive out of order, so a key is
+/// accepted if it is within [`RECEIVE_KEY_WINDOW`] keys of the newest one
+/// matched so far.
+#[derive(Clone, Debug)]
+pub struct ReceiveKeys {
+    generator: CryptoSystem,
+    /// Index in the server's sequence of the next key to generate
+    next_index: u64,
+    /// Highest index matched so far
+    newest_index: u64,
+    /// Unmatched keys with their sequence index, oldest first
+    upcoming: VecDeque<(u64, u32)>,
+    used: VecDeque<u32>,
+}
+
+impl ReceiveKeys {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            generator: CryptoSystem::new(seed),
+            next_index: 0,
+            newest_index: 0,
+            upcoming: VecDeque::with_capacity(RECEIVE_KEY_WINDOW),
+            used: VecDeque::with_capacity(USED_KEY_HISTORY),
+        }
+    }
+
+    /// Accept `key` if the server could have used it, marking it as used
+    pub fn consume(&mut self, key: u32) -> bool {
+        if self.used.contains(&key) {
+            return true;
+        }
+
+        let window = RECEIVE_KEY_WINDOW as u64;
+        while self.next_index < self.newest_index + window {
+            self.upcoming
+                .push_back((self.next_index, self.generator.get_send_key()));
+            self.next_index += 1;
+        }
+        let Some(position) = self.upcoming.iter().position(|&(_, k)| k == key) else {
+            return false;
+        };
+        let (index, _) = self.upcoming.remove(position).unwrap();
+
+        // Keys the server skipped long ago won't be used now, and keeping them
+        // would leave less of the window for keys still to come
+        self.newest_index = self.newest_index.max(index);
+        while self
+            .upcoming
+            .front()
+            .is_some_and(|&(index, _)| index + window < self.newest_index)
+        {
+            self.upcoming.pop_front();
+        }
+
+        if self.used.len() == USED_KEY_HISTORY {
+            self.used.pop_front();
+        }
+        self.used.push_back(key);
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,4 +139,40 @@ mod tests {
         assert_eq!(crypto1.get_send_key(), crypto2.get_send_key());
         assert_eq!(crypto1.get_send_key(), crypto2.get_send_key());
     }
+
+    #[test]
+    fn test_receive_keys_accept_out_of_order_and_repeats() {
+        let mut server = CryptoSystem::new(0x12345678);
+        let first = server.get_send_key();
+        let second = server.get_send_key();
+
+        let mut keys = ReceiveKeys::new(0x12345678);
+        assert!(keys.consume(second));
+        assert!(keys.consume(first));
+        // A retransmission reuses its key
+        assert!(keys.consume(first));
+        assert!(!keys.consume(first ^ 1));
+    }
+
+    #[test]
+    fn test_receive_keys_follow_server_past_skipped_keys() {
+        let mut server = CryptoSystem::new(0x12345678);
+        let sequence: Vec<u32> = (0..4 * RECEIVE_KEY_WINDOW)
+            .map(|_| server.get_send_key())
+            .collect();
+
+        // Every jump stays inside the window, but together they skip far
+        // more keys than it holds
+        let step = RECEIVE_KEY_WINDOW - 1;
+        let mut keys = ReceiveKeys::new(0x12345678);
+        for index in (0..sequence.len()).step_by(step) {
+            assert!(keys.consume(sequence[index]), "key {index} rejected");
+        }
+
+        // Skipped keys far behind the newest one are dropped
+        assert!(!keys.consume(sequence[1]));
+        // ...but recent ones are still accepted
+        let newest = (sequence.len() - 1) / step * step;
+        assert!(keys.consume(sequence[newest - 1]));
+    }
 }
@@ -35,6 +35,19 @@ impl NativeUdpTransport {
         let socket = tokio::net::UdpSocket::bind("0.0.0.0:0").await?;
         Ok(Self { socket })
     }
+
+    /// Bind to a specific local address, e.g. to get a fixed source port for NAT rules
+    pub async fn bind(addr: SocketAddr) -> Result<Self, std::io::Error> {
+        let socket = tokio::net::UdpSocket::bind(addr).await.map_err(|e| {
+            let message = if e.kind() == std::io::ErrorKind::AddrInUse {
+                format!("local UDP address {} is already in use", addr)
+            } else {
+                format!("failed to bind local UDP address {}: {}", addr, e)
+            };
+            std::io::Error::new(e.kind(), message)
+        })?;
+        Ok(Self { socket })
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -59,3 +72,22 @@ impl ClientTransport for NativeUdpTransport {
         Box::pin(async move { self.socket.recv_from(buf).await })
     }
 }
+
+/// Transport that discards everything the client sends and never receives,
+/// for driving a client offline (benchmarks, trace replay)
+pub struct NullTransport;
+
+impl ClientTransport for NullTransport {
+    fn send<'a>(
+        &'a mut self,
+        _server: &'a ServerInfo,
+        _channel: TransportChannel,
+        _bytes: Vec<u8>,
+    ) -> TransportFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn recv<'a>(&'a mut self, _buf: &'a mut [u8]) -> TransportFuture<'a, (usize, SocketAddr)> {
+        Box::pin(std::future::pending())
+    }
+}
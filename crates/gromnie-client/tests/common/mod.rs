@@ -3,6 +3,8 @@ use asheron_rs::packets::c2s_packet::C2SPacket;
 use byteorder::{ByteOrder, LittleEndian};
 use gromnie_client::client::C2SPacketExt;
 
+pub mod stub_server;
+
 // ============================================================================
 // Field Extraction Helpers
 // ============================================================================
@@ -0,0 +1,351 @@
+//! Minimal stub AC server for driving the client through login in tests
+//!
+//! Implements just enough of the protocol to get a client from Connecting to
+//! CharacterSelect without a real server:
+//!
+//! 1. Client sends `LoginRequest` to the login port. The stub replies with a
+//!    `ConnectRequest` (cookie, client id, and fixed ISAAC seeds).
+//! 2. Client sends `ConnectResponse` to the world port. The stub replies with a
+//!    `DDDInterrogation` message in a single `BLOB_FRAGMENTS` packet.
+//! 3. Client sends its first fragment-bearing packet (the DDD interrogation
+//!    response). The stub replies with a canned `LoginCharacterSet`.
+//! 4. Only when started with [`StubServer::start_for_creation`]: the client
+//!    sends `CharacterSendCharGenResult`, and the stub answers with a
+//!    `CharacterCharGenVerificationResponse` for the character it was given.
+//!
+//! Anything else the client sends (ACKs, TimeSync, later game actions) is
+//! ignored. Outgoing checksums are not computed and incoming checksums are not
+//! verified; the client only logs the bad checksums unless `strict_checksums`
+//! is set.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use asheron_rs::enums::PacketHeaderFlags;
+use byteorder::{ByteOrder, LittleEndian};
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+
+const PACKET_HEADER_SIZE: usize = 20;
+const FRAGMENT_HEADER_SIZE: usize = 16;
+
+const OPCODE_DDD_INTERROGATION: u32 = 0xF7E5;
+const OPCODE_LOGIN_CHARACTER_SET: u32 = 0xF658;
+const OPCODE_CHAR_GEN_RESULT: u32 = 0xF656;
+const OPCODE_CHAR_GEN_VERIFICATION: u32 = 0xF643;
+
+/// Optional C2S headers that can come before the fragments, in wire order,
+/// with their sizes; `None` is a counted list of sequence numbers
+const OPTIONAL_HEADERS: [(u32, Option<usize>); 9] = [
+    (0x0000_0100, Some(8)), // ServerSwitch
+    (0x0000_1000, None),    // RequestRetransmit
+    (0x0000_2000, None),    // RejectRetransmit
+    (0x0000_4000, Some(4)), // AckSequence
+    (0x0040_0000, Some(8)), // CICMDCommand
+    (0x0100_0000, Some(8)), // TimeSync
+    (0x0200_0000, Some(4)), // EchoRequest
+    (0x0400_0000, Some(8)), // EchoResponse
+    (0x0800_0000, Some(6)), // Flow
+];
+
+/// Fixed session values handed out in ConnectRequest
+pub const STUB_COOKIE: u64 = 0x0123_4567_89AB_CDEF;
+pub const STUB_CLIENT_ID: u32 = 1;
+const STUB_SERVER_SEED: u32 = 0x1111_1111;
+const STUB_CLIENT_SEED: u32 = 0x2222_2222;
+
+/// A character advertised in the stub's character list
+#[derive(Clone, Debug)]
+pub struct StubCharacter {
+    pub id: u32,
+    pub name: String,
+}
+
+impl StubCharacter {
+    pub fn new(id: u32, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+        }
+    }
+}
+
+/// Handle to a running stub server. The server task is aborted on drop.
+pub struct StubServer {
+    login_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl StubServer {
+    /// Bind login/world sockets on consecutive localhost ports and start serving
+    pub async fn start(account: impl Into<String>, characters: Vec<StubCharacter>) -> Self {
+        let (login, world) = bind_port_pair().await;
+        let login_addr = login.local_addr().expect("login socket has an address");
+
+        let account = account.into();
+        let task = tokio::spawn(async move {
+            serve(Arc::new(login), Arc::new(world), account, characters, None).await;
+        });
+
+        Self { login_addr, task }
+    }
+
+    /// Like [`StubServer::start`] with no characters, but accepting the
+    /// client's character creation and reporting `created` as the result
+    pub async fn start_for_creation(account: impl Into<String>, created: StubCharacter) -> Self {
+        let (login, world) = bind_port_pair().await;
+        let login_addr = login.local_addr().expect("login socket has an address");
+
+        let account = account.into();
+        let task = tokio::spawn(async move {
+            serve(
+                Arc::new(login),
+                Arc::new(world),
+                account,
+                Vec::new(),
+                Some(created),
+            )
+            .await;
+        });
+
+        Self { login_addr, task }
+    }
+
+    /// Address to pass to `Client::new` (`host:login_port`)
+    pub fn address(&self) -> String {
+        self.login_addr.to_string()
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// The client sends to `login_port + 1` for the world port, so the two sockets
+/// must be adjacent. Retry until the OS hands us a usable pair.
+async fn bind_port_pair() -> (UdpSocket, UdpSocket) {
+    for _ in 0..32 {
+        let login = UdpSocket::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind stub login socket");
+        let port = login.local_addr().unwrap().port();
+        if port == u16::MAX {
+            continue;
+        }
+        if let Ok(world) = UdpSocket::bind(("127.0.0.1", port + 1)).await {
+            return (login, world);
+        }
+    }
+    panic!("could not bind adjacent login/world ports for stub server");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    AwaitingLoginRequest,
+    AwaitingConnectResponse,
+    AwaitingDddResponse,
+    AwaitingCharGen,
+    Done,
+}
+
+async fn serve(
+    login: Arc<UdpSocket>,
+    world: Arc<UdpSocket>,
+    account: String,
+    characters: Vec<StubCharacter>,
+    created: Option<StubCharacter>,
+) {
+    let mut phase = Phase::AwaitingLoginRequest;
+    let mut sequence = 0u32;
+    let mut login_buf = [0u8; 1024];
+    let mut world_buf = [0u8; 1024];
+
+    loop {
+        let (from_login, size, peer) = tokio::select! {
+            r = login.recv_from(&mut login_buf) => match r {
+                Ok((size, peer)) => (true, size, peer),
+                Err(_) => return,
+            },
+            r = world.recv_from(&mut world_buf) => match r {
+                Ok((size, peer)) => (false, size, peer),
+                Err(_) => return,
+            },
+        };
+        let buf = if from_login { &login_buf } else { &world_buf };
+
+        if size < PACKET_HEADER_SIZE {
+            continue;
+        }
+        let flags = PacketHeaderFlags::from_bits_truncate(LittleEndian::read_u32(&buf[4..8]));
+
+        let reply = match phase {
+            Phase::AwaitingLoginRequest if flags.contains(PacketHeaderFlags::LOGIN_REQUEST) => {
+                phase = Phase::AwaitingConnectResponse;
+                build_connect_request()
+            }
+            Phase::AwaitingConnectResponse
+                if flags.contains(PacketHeaderFlags::CONNECT_RESPONSE) =>
+            {
+                phase = Phase::AwaitingDddResponse;
+                sequence += 1;
+                build_fragment_packet(sequence, &build_ddd_interrogation())
+            }
+            Phase::AwaitingDddResponse if flags.contains(PacketHeaderFlags::BLOB_FRAGMENTS) => {
+                phase = if created.is_some() {
+                    Phase::AwaitingCharGen
+                } else {
+                    Phase::Done
+                };
+                sequence += 1;
+                build_fragment_packet(sequence, &build_character_set(&account, &characters))
+            }
+            Phase::AwaitingCharGen
+                if message_opcodes(&buf[..size]).contains(&OPCODE_CHAR_GEN_RESULT) =>
+            {
+                phase = Phase::Done;
+                sequence += 1;
+                let created = created.as_ref().expect("only awaited with a character");
+                build_fragment_packet(sequence, &build_char_gen_verification(created))
+            }
+            _ => continue,
+        };
+
+        if login.send_to(&reply, peer).await.is_err() {
+            return;
+        }
+    }
+}
+
+fn write_header(buf: &mut Vec<u8>, sequence: u32, flags: PacketHeaderFlags, size: usize) {
+    buf.extend_from_slice(&sequence.to_le_bytes());
+    buf.extend_from_slice(&flags.bits().to_le_bytes());
+    buf.extend_from_slice(&0u32.to_le_bytes()); // checksum (not computed)
+    buf.extend_from_slice(&0u16.to_le_bytes()); // recipient_id
+    buf.extend_from_slice(&0u16.to_le_bytes()); // time_since_last_packet
+    buf.extend_from_slice(&(size as u16).to_le_bytes());
+    buf.extend_from_slice(&0u16.to_le_bytes()); // iteration (table)
+}
+
+fn build_connect_request() -> Vec<u8> {
+    let mut payload = Vec::with_capacity(32);
+    payload.extend_from_slice(&0f64.to_le_bytes()); // server_time
+    payload.extend_from_slice(&STUB_COOKIE.to_le_bytes());
+    payload.extend_from_slice(&STUB_CLIENT_ID.to_le_bytes()); // net_id
+    payload.extend_from_slice(&STUB_SERVER_SEED.to_le_bytes()); // outgoing_seed
+    payload.extend_from_slice(&STUB_CLIENT_SEED.to_le_bytes()); // incoming_seed
+    payload.extend_from_slice(&0u32.to_le_bytes()); // unknown
+
+    let mut packet = Vec::with_capacity(PACKET_HEADER_SIZE + payload.len());
+    write_header(
+        &mut packet,
+        0,
+        PacketHeaderFlags::CONNECT_REQUEST,
+        payload.len(),
+    );
+    packet.extend_from_slice(&payload);
+    packet
+}
+
+/// Wrap a complete message in a single-fragment BLOB_FRAGMENTS packet
+fn build_fragment_packet(sequence: u32, message: &[u8]) -> Vec<u8> {
+    let fragment_size = FRAGMENT_HEADER_SIZE + message.len();
+
+    let mut packet = Vec::with_capacity(PACKET_HEADER_SIZE + fragment_size);
+    write_header(
+        &mut packet,
+        sequence,
+        PacketHeaderFlags::BLOB_FRAGMENTS,
+        fragment_size,
+    );
+    packet.extend_from_slice(&sequence.to_le_bytes()); // fragment sequence
+    packet.extend_from_slice(&0x8000_0000u32.to_le_bytes()); // fragment id
+    packet.extend_from_slice(&1u16.to_le_bytes()); // count
+    packet.extend_from_slice(&(fragment_size as u16).to_le_bytes());
+    packet.extend_from_slice(&0u16.to_le_bytes()); // index
+    packet.extend_from_slice(&5u16.to_le_bytes()); // group
+    packet.extend_from_slice(message);
+    packet
+}
+
+fn build_ddd_interrogation() -> Vec<u8> {
+    let mut msg = Vec::new();
+    msg.extend_from_slice(&OPCODE_DDD_INTERROGATION.to_le_bytes());
+    msg.extend_from_slice(&1u32.to_le_bytes()); // servers_region
+    msg.extend_from_slice(&1u32.to_le_bytes()); // name_rule_language
+    msg.extend_from_slice(&1u32.to_le_bytes()); // product_id
+    msg.extend_from_slice(&1u32.to_le_bytes()); // supported_languages count
+    msg.extend_from_slice(&1u32.to_le_bytes()); // supported_languages[0]
+    msg
+}
+
+fn build_character_set(account: &str, characters: &[StubCharacter]) -> Vec<u8> {
+    let mut msg = Vec::new();
+    msg.extend_from_slice(&OPCODE_LOGIN_CHARACTER_SET.to_le_bytes());
+    msg.extend_from_slice(&0u32.to_le_bytes()); // status
+    msg.extend_from_slice(&(characters.len() as u32).to_le_bytes());
+    for character in characters {
+        msg.extend_from_slice(&character.id.to_le_bytes());
+        write_string16l(&mut msg, &character.name);
+        msg.extend_from_slice(&0u32.to_le_bytes()); // seconds_greyed_out
+    }
+    msg.extend_from_slice(&0u32.to_le_bytes()); // deleted_characters count
+    msg.extend_from_slice(&11u32.to_le_bytes()); // num_allowed_characters
+    write_string16l(&mut msg, account);
+    msg.extend_from_slice(&1u32.to_le_bytes()); // use_turbine_chat
+    msg.extend_from_slice(&1u32.to_le_bytes()); // has_throneof_destiny
+    msg
+}
+
+fn build_char_gen_verification(character: &StubCharacter) -> Vec<u8> {
+    let mut msg = Vec::new();
+    msg.extend_from_slice(&OPCODE_CHAR_GEN_VERIFICATION.to_le_bytes());
+    msg.extend_from_slice(&1u32.to_le_bytes()); // response_type: OK
+    msg.extend_from_slice(&character.id.to_le_bytes());
+    write_string16l(&mut msg, &character.name);
+    msg.extend_from_slice(&0u32.to_le_bytes()); // seconds_until_deletion
+    msg
+}
+
+/// Opcodes of the messages starting in a client packet's fragments
+fn message_opcodes(packet: &[u8]) -> Vec<u32> {
+    let flags = LittleEndian::read_u32(&packet[4..8]);
+    let mut offset = PACKET_HEADER_SIZE;
+    for (bit, size) in OPTIONAL_HEADERS {
+        if flags & bit == 0 {
+            continue;
+        }
+        offset += match size {
+            Some(size) => size,
+            None if offset + 4 <= packet.len() => {
+                4 + 4 * LittleEndian::read_u32(&packet[offset..]) as usize
+            }
+            None => return Vec::new(),
+        };
+    }
+
+    let mut opcodes = Vec::new();
+    while offset + FRAGMENT_HEADER_SIZE + 4 <= packet.len() {
+        let fragment_size = LittleEndian::read_u16(&packet[offset + 10..]) as usize;
+        let index = LittleEndian::read_u16(&packet[offset + 12..]);
+        if index == 0 {
+            opcodes.push(LittleEndian::read_u32(
+                &packet[offset + FRAGMENT_HEADER_SIZE..],
+            ));
+        }
+        if fragment_size < FRAGMENT_HEADER_SIZE {
+            break;
+        }
+        offset += fragment_size;
+    }
+    opcodes
+}
+
+/// AC `string`: u16 length, bytes, then padding so the whole field is 4-byte aligned
+fn write_string16l(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u16).to_le_bytes());
+    buf.extend_from_slice(s.as_bytes());
+    let written = 2 + s.len();
+    buf.resize(buf.len() + (4 - written % 4) % 4, 0);
+}
@@ -215,6 +215,22 @@ mod scene_tests {
 
     // ============ Scene Enum Tests ============
 
+    #[test]
+    fn test_scene_names() {
+        assert_eq!(
+            Scene::Connecting(ConnectingScene::new()).name(),
+            "Connecting"
+        );
+        assert_eq!(
+            Scene::InWorld(InWorldScene::new(1, "Test".to_string())).name(),
+            "InWorld"
+        );
+        assert_eq!(
+            Scene::Error(ErrorScene::new(ClientError::LoginTimeout, true)).name(),
+            "Error"
+        );
+    }
+
     #[test]
     fn test_scene_as_connecting() {
         let connecting = ConnectingScene::new();
@@ -344,6 +360,25 @@ mod scene_tests {
         }
     }
 
+    #[test]
+    fn test_client_error_kind_and_message() {
+        use gromnie_events::ConnectionErrorKind;
+
+        let timeout = ClientError::LoginTimeout;
+        assert_eq!(timeout.kind(), ConnectionErrorKind::Timeout);
+        assert_eq!(
+            timeout.to_string(),
+            "Timed out waiting for the login server"
+        );
+
+        let failed = ClientError::ConnectionFailed("max reconnection attempts reached".to_string());
+        assert_eq!(failed.kind(), ConnectionErrorKind::Rejected);
+        assert_eq!(
+            failed.to_string(),
+            "Connection failed: max reconnection attempts reached"
+        );
+    }
+
     // ============ EnteringWorldState Tests ============
 
     #[test]
@@ -519,3 +554,74 @@ mod transition_flow_tests {
         assert!(scene.as_connecting().is_some());
     }
 }
+
+/// State events a `Client` sends as it moves between scenes
+#[cfg(test)]
+mod scene_event_tests {
+    use gromnie_client::client::{Client, ClientEvent, ClientStateEvent, ReconnectAction};
+    use gromnie_client::instant::Instant;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    async fn test_client(reconnect: bool) -> (Client, mpsc::Receiver<ClientEvent>) {
+        let (event_tx, event_rx) = mpsc::channel(64);
+        let (client, _action_tx) = Client::new(
+            1,
+            "127.0.0.1:9000".to_string(),
+            "test_user".to_string(),
+            "test_pass".to_string(),
+            None,
+            event_tx,
+            reconnect,
+        )
+        .await;
+        (client, event_rx)
+    }
+
+    fn state_events(event_rx: &mut mpsc::Receiver<ClientEvent>) -> Vec<ClientStateEvent> {
+        std::iter::from_fn(|| event_rx.try_recv().ok())
+            .filter_map(|event| match event {
+                ClientEvent::State(state) => Some(state),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn is_transition(event: &ClientStateEvent, expected_from: &str, expected_to: &str) -> bool {
+        matches!(event, ClientStateEvent::StateTransition { from, to }
+            if from == expected_from && to == expected_to)
+    }
+
+    #[tokio::test]
+    async fn test_login_complete_sends_in_world() {
+        let (mut client, mut event_rx) = test_client(false).await;
+        client.transition_to_char_select(Vec::new());
+        client.begin_entering_world(0x5000_0001, "Stub".to_string());
+        state_events(&mut event_rx);
+
+        client.send_login_complete_notification();
+
+        let states = state_events(&mut event_rx);
+        assert_eq!(states.len(), 2, "{:?}", states);
+        assert!(is_transition(&states[0], "CharacterSelect", "InWorld"));
+        assert!(matches!(states[1], ClientStateEvent::InWorld));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_sends_connecting() {
+        let (mut client, mut event_rx) = test_client(true).await;
+        client.set_initial_reconnect_delay_secs(Some(0));
+        client.set_reconnect_jitter(0.0);
+        client.transition_to_in_world(0x5000_0001, "Stub".to_string());
+        client.enter_disconnected();
+        state_events(&mut event_rx);
+
+        let action = client.tick_reconnect(Instant::now() + Duration::from_secs(1));
+        assert!(matches!(action, ReconnectAction::Reconnect { .. }));
+
+        let states = state_events(&mut event_rx);
+        assert_eq!(states.len(), 2, "{:?}", states);
+        assert!(is_transition(&states[0], "InWorld", "Connecting"));
+        assert!(matches!(states[1], ClientStateEvent::Connecting));
+    }
+}
@@ -0,0 +1,527 @@
+//! End-to-end login flow against the stub server in `common::stub_server`
+//!
+//! Drives a real `Client` over UDP from Connecting to CharacterSelect, the same
+//! way the runner's receive loop does, without needing an AC server.
+
+#[allow(dead_code)]
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use asheron_rs::types::PackableList;
+use common::stub_server::{StubCharacter, StubServer};
+use gromnie_client::client::ace_protocol::{AceCharGenResult, RawSkillAdvancementClass};
+use gromnie_client::client::{
+    Client, ClientEvent, ClientStateEvent, ClientSystemEvent, MessageTrace, Scene, TraceDirection,
+    read_trace,
+};
+use gromnie_events::SimpleClientAction;
+use tokio::sync::mpsc;
+
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Pump the client until it reaches CharacterSelect or `LOGIN_TIMEOUT` expires
+async fn drive_to_character_select(client: &mut Client) {
+    drive_until(client, "CharacterSelect", |client| {
+        matches!(client.scene, Scene::CharacterSelect(_))
+    })
+    .await;
+}
+
+/// Pump the client until `done` holds or `LOGIN_TIMEOUT` expires
+async fn drive_until(client: &mut Client, waiting_for: &str, done: impl Fn(&Client) -> bool) {
+    let deadline = tokio::time::Instant::now() + LOGIN_TIMEOUT;
+    let mut buf = [0u8; 1024];
+
+    while !done(client) {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        assert!(
+            !remaining.is_zero(),
+            "timed out waiting for {} (scene: {:?})",
+            waiting_for,
+            client.scene
+        );
+
+        let Ok(result) =
+            tokio::time::timeout(Duration::from_millis(100), client.recv_packet(&mut buf)).await
+        else {
+            // Nothing arrived; still flush anything that became ready
+            client.send_pending_messages().await.unwrap();
+            continue;
+        };
+        let (size, peer) = result.expect("recv failed");
+
+        client.process_packet(&buf[..size], size, &peer).await;
+        if client.has_messages() {
+            client.process_messages();
+        }
+        client.process_actions();
+        client.send_pending_messages().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_login_reaches_character_select() {
+    let server = StubServer::start(
+        "testaccount",
+        vec![
+            StubCharacter::new(0x5000_0001, "Stub One"),
+            StubCharacter::new(0x5000_0002, "Stub Two"),
+        ],
+    )
+    .await;
+
+    let (event_tx, mut event_rx) = mpsc::channel(256);
+    let (mut client, _action_tx) = Client::new(
+        1,
+        server.address(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        None,
+        event_tx,
+        false,
+    )
+    .await;
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    drive_to_character_select(&mut client).await;
+
+    let Scene::CharacterSelect(scene) = &client.scene else {
+        unreachable!();
+    };
+    let names: Vec<&str> = scene.characters.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["Stub One", "Stub Two"]);
+
+    let info = client.world_info();
+    assert!(
+        info.region.is_some(),
+        "DDD interrogation should populate world info"
+    );
+
+    // The handshake should have surfaced the usual lifecycle events
+    let mut authenticated = false;
+    let mut connected = false;
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            ClientEvent::System(ClientSystemEvent::AuthenticationSucceeded) => authenticated = true,
+            ClientEvent::State(ClientStateEvent::Connected) => connected = true,
+            _ => {}
+        }
+    }
+    assert!(authenticated, "expected AuthenticationSucceeded");
+    assert!(connected, "expected Connected");
+}
+
+/// A plain Aluvian with every skill left untrained
+fn stub_char_gen(name: &str) -> AceCharGenResult {
+    AceCharGenResult {
+        one: 1,
+        heritage_group: 1,
+        gender: 1,
+        eyes_strip: 0,
+        nose_strip: 0,
+        mouth_strip: 0,
+        hair_color: 0,
+        eye_color: 0,
+        hair_style: 0,
+        headgear_style: 0,
+        headgear_color: 0,
+        shirt_style: 0,
+        shirt_color: 0,
+        trousers_style: 0,
+        trousers_color: 0,
+        footwear_style: 0,
+        footwear_color: 0,
+        skin_shade: 0,
+        hair_shade: 0,
+        headgear_shade: 0,
+        shirt_shade: 0,
+        trousers_shade: 0,
+        tootwear_shade: 0,
+        template_num: 0,
+        strength: 10,
+        endurance: 10,
+        coordination: 10,
+        quickness: 10,
+        focus: 10,
+        self_: 10,
+        slot: 0,
+        class_id: 0,
+        skills: PackableList {
+            count: 55,
+            list: vec![RawSkillAdvancementClass(0); 55],
+        },
+        name: name.to_string(),
+        start_area: 0,
+        is_admin: 0,
+        is_envoy: 0,
+        validation: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_empty_account_creates_character_then_logs_in() {
+    let server =
+        StubServer::start_for_creation("testaccount", StubCharacter::new(0x5000_0009, "Fresh"))
+            .await;
+
+    let (event_tx, _event_rx) = mpsc::channel(256);
+    let (mut client, _action_tx) = Client::new(
+        1,
+        server.address(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        Some("Fresh".to_string()),
+        event_tx,
+        false,
+    )
+    .await;
+    client.set_character_creation(Some(stub_char_gen("Fresh")));
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    drive_until(&mut client, "the created character to log in", |client| {
+        client
+            .scene
+            .as_character_select()
+            .is_some_and(|scene| scene.entering_world.is_some())
+    })
+    .await;
+
+    let Scene::CharacterSelect(scene) = &client.scene else {
+        unreachable!();
+    };
+    let entering = scene.entering_world.as_ref().unwrap();
+    assert_eq!(entering.character_id, 0x5000_0009);
+    assert_eq!(entering.character_name, "Fresh");
+    let names: Vec<&str> = scene.characters.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["Fresh"]);
+}
+
+#[tokio::test]
+async fn test_character_list_waiter_resolves_on_login() {
+    let server = StubServer::start(
+        "testaccount",
+        vec![StubCharacter::new(0x5000_0001, "Stub One")],
+    )
+    .await;
+
+    let (event_tx, _event_rx) = mpsc::channel(256);
+    let (mut client, _action_tx) = Client::new(
+        1,
+        server.address(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        None,
+        event_tx,
+        false,
+    )
+    .await;
+
+    // Nothing cached yet, so this waits for the server's list
+    let mut waiting = client
+        .character_list_with_result()
+        .expect("should wait while connecting");
+    assert!(waiting.try_recv().is_err());
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    drive_to_character_select(&mut client).await;
+
+    let characters = waiting.await.expect("waiter should be resolved");
+    assert_eq!(characters.len(), 1);
+    assert_eq!(characters[0].name, "Stub One");
+
+    // Once cached, the list comes back straight away
+    let cached = client
+        .character_list_with_result()
+        .unwrap()
+        .try_recv()
+        .expect("cached list should be ready");
+    assert_eq!(cached[0].name, "Stub One");
+}
+
+#[tokio::test]
+async fn test_drain_sends_queued_messages_before_shutdown() {
+    let server = StubServer::start(
+        "testaccount",
+        vec![StubCharacter::new(0x5000_0001, "Stub One")],
+    )
+    .await;
+
+    let (event_tx, _event_rx) = mpsc::channel(256);
+    let (mut client, _action_tx) = Client::new(
+        1,
+        server.address(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        None,
+        event_tx,
+        false,
+    )
+    .await;
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    drive_to_character_select(&mut client).await;
+
+    client.queue_game_action(asheron_rs::message::GameActionMessage::TradeDeclineTrade(
+        asheron_rs::gameactions::TradeDeclineTrade {},
+    ));
+    let left = tokio::time::timeout(
+        Duration::from_secs(2),
+        client.drain_outgoing_messages(Duration::from_secs(1)),
+    )
+    .await
+    .expect("drain should be bounded")
+    .expect("drain failed");
+    assert_eq!(left, 0);
+    assert!(!client.has_pending_outgoing_messages());
+}
+
+/// Game action type of Communication_Talk
+const COMMUNICATION_TALK: u32 = 0x0015;
+
+/// In-memory trace sink the test can read back
+#[derive(Clone, Default)]
+struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_due_chat_line_is_sent_as_talk() {
+    let server = StubServer::start(
+        "testaccount",
+        vec![StubCharacter::new(0x5000_0001, "Stub One")],
+    )
+    .await;
+
+    let (event_tx, _event_rx) = mpsc::channel(256);
+    let (mut client, action_tx) = Client::new(
+        1,
+        server.address(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        None,
+        event_tx,
+        false,
+    )
+    .await;
+    let buf = SharedBuf::default();
+    client.set_message_trace(Some(MessageTrace::from_writer(buf.clone())));
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    drive_to_character_select(&mut client).await;
+
+    action_tx
+        .send(SimpleClientAction::SendChatLines {
+            lines: vec!["first".to_string(), "second".to_string()],
+            interval_millis: 60_000,
+        })
+        .unwrap();
+    client.process_actions();
+    client.send_pending_messages().await.unwrap();
+
+    // Only the first line is due; it goes out as a Talk action
+    let sent: Vec<_> = read_trace(&buf.0.lock().unwrap()[..])
+        .unwrap()
+        .into_iter()
+        .filter(|entry| entry.direction == TraceDirection::Sent)
+        .collect();
+    let action_type = |data: &[u8]| {
+        data.get(8..12)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    };
+    let talks = sent
+        .iter()
+        .filter(|entry| action_type(&entry.data) == Some(COMMUNICATION_TALK))
+        .count();
+    assert_eq!(talks, 1);
+
+    // The second line is dropped with the connection instead of going out
+    // in the next session
+    client.enter_disconnected();
+    let left = client
+        .drain_outgoing_messages(Duration::ZERO)
+        .await
+        .expect("drain failed");
+    assert_eq!(left, 0);
+}
+
+/// A client at character select that traces what it sends, plus its action sender
+async fn traced_client_at_character_select(
+    server: &StubServer,
+) -> (Client, mpsc::UnboundedSender<SimpleClientAction>, SharedBuf) {
+    let (event_tx, _event_rx) = mpsc::channel(256);
+    let (mut client, action_tx) = Client::new(
+        1,
+        server.address(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        None,
+        event_tx,
+        false,
+    )
+    .await;
+    let buf = SharedBuf::default();
+    client.set_message_trace(Some(MessageTrace::from_writer(buf.clone())));
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    drive_to_character_select(&mut client).await;
+    (client, action_tx, buf)
+}
+
+/// How many Talk actions have gone out so far
+fn sent_talks(buf: &SharedBuf) -> usize {
+    read_trace(&buf.0.lock().unwrap()[..])
+        .unwrap()
+        .into_iter()
+        .filter(|entry| entry.direction == TraceDirection::Sent)
+        .filter(|entry| {
+            entry
+                .data
+                .get(8..12)
+                .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+                == Some(COMMUNICATION_TALK)
+        })
+        .count()
+}
+
+#[tokio::test]
+async fn test_drain_waits_for_message_due_within_timeout() {
+    let server = StubServer::start(
+        "testaccount",
+        vec![StubCharacter::new(0x5000_0001, "Stub One")],
+    )
+    .await;
+    let (mut client, action_tx, buf) = traced_client_at_character_select(&server).await;
+
+    // The second line only becomes due a second after the first
+    action_tx
+        .send(SimpleClientAction::SendChatLines {
+            lines: vec!["first".to_string(), "second".to_string()],
+            interval_millis: 1000,
+        })
+        .unwrap();
+    client.process_actions();
+
+    let left = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.drain_outgoing_messages(Duration::from_secs(3)),
+    )
+    .await
+    .expect("drain should be bounded")
+    .expect("drain failed");
+    assert_eq!(left, 0);
+    assert_eq!(sent_talks(&buf), 2);
+}
+
+#[tokio::test]
+async fn test_drain_drops_message_due_after_timeout() {
+    let server = StubServer::start(
+        "testaccount",
+        vec![StubCharacter::new(0x5000_0001, "Stub One")],
+    )
+    .await;
+    let (mut client, action_tx, buf) = traced_client_at_character_select(&server).await;
+
+    action_tx
+        .send(SimpleClientAction::SendChatLines {
+            lines: vec!["first".to_string(), "second".to_string()],
+            interval_millis: 60_000,
+        })
+        .unwrap();
+    client.process_actions();
+
+    // The second line can't become due in time, so the drain gives up on it
+    // straight away rather than sleeping out the timeout
+    let started = std::time::Instant::now();
+    let left = client
+        .drain_outgoing_messages(Duration::from_secs(2))
+        .await
+        .expect("drain failed");
+    assert_eq!(left, 1);
+    assert_eq!(sent_talks(&buf), 1);
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_login_request_retries_stop_at_cap() {
+    // Bound but never answers, like a server dropping the first packets
+    let silent = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let (event_tx, mut event_rx) = mpsc::channel(100);
+    let (mut client, _action_tx) = Client::new(
+        1,
+        silent.local_addr().unwrap().to_string(),
+        "testaccount".to_string(),
+        "password".to_string(),
+        None,
+        event_tx,
+        false,
+    )
+    .await;
+    client.set_max_login_attempts(3);
+
+    client
+        .do_login()
+        .await
+        .expect("failed to send LoginRequest");
+    assert!(
+        !client.should_retry(),
+        "retries wait for the retry interval"
+    );
+
+    let mut attempts = 1;
+    loop {
+        // Skip the wait between retries
+        if let Some(connecting) = client.scene.as_connecting_mut() {
+            connecting.last_retry_at =
+                gromnie_client::instant::Instant::now() - Duration::from_secs(3);
+        }
+        if !client.should_retry() {
+            break;
+        }
+        client
+            .do_login()
+            .await
+            .expect("failed to resend LoginRequest");
+        attempts += 1;
+    }
+    assert_eq!(attempts, 3);
+
+    let mut retries = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        if let ClientEvent::System(ClientSystemEvent::LoginRetry {
+            attempt,
+            max_attempts,
+        }) = event
+        {
+            retries.push((attempt, max_attempts));
+        }
+    }
+    assert_eq!(retries, [(2, 3), (3, 3)]);
+}
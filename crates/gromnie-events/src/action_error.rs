@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+/// Known WeenieError codes sent when the server rejects an action
+///
+/// Only codes a script is likely to react to are named; everything else is
+/// kept as [`ActionErrorKind::Other`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionErrorKind {
+    /// Target is further away than the action allows
+    TooFarAway,
+    /// Another action is still in progress
+    TooBusy,
+    /// Inventory move/give/drop was not allowed
+    IllegalInventoryTransaction,
+    /// Can't jump while airborne
+    CantJumpInAir,
+    /// Not a valid command
+    InvalidCommand,
+    /// Character is frozen
+    Frozen,
+    /// Character is stuck
+    Stuck,
+    /// Carrying too much
+    TooEncumbered,
+    /// Action was cancelled before completing
+    ActionCancelled,
+    /// The target object no longer exists
+    ObjectGone,
+    /// The target object is unknown
+    NoObject,
+    /// No path to the target
+    CantGetThere,
+    /// Character is dead
+    Dead,
+    /// Charged too far toward the target
+    ChargedTooFar,
+    /// Not enough stamina
+    TooTired,
+    /// Too many queued actions
+    TooManyActions,
+    /// Too loaded down to jump
+    CantJumpLoadedDown,
+    /// Any other error code
+    Other(u32),
+}
+
+impl ActionErrorKind {
+    /// Decode a WeenieError code
+    pub fn from_code(code: u32) -> Self {
+        match code {
+            0x1C => ActionErrorKind::TooFarAway,
+            0x1D => ActionErrorKind::TooBusy,
+            0x20 => ActionErrorKind::IllegalInventoryTransaction,
+            0x24 => ActionErrorKind::CantJumpInAir,
+            0x26 => ActionErrorKind::InvalidCommand,
+            0x28 => ActionErrorKind::Frozen,
+            0x29 => ActionErrorKind::Stuck,
+            0x2A => ActionErrorKind::TooEncumbered,
+            0x36 => ActionErrorKind::ActionCancelled,
+            0x37 => ActionErrorKind::ObjectGone,
+            0x38 => ActionErrorKind::NoObject,
+            0x39 => ActionErrorKind::CantGetThere,
+            0x3A => ActionErrorKind::Dead,
+            0x3D => ActionErrorKind::ChargedTooFar,
+            0x3E => ActionErrorKind::TooTired,
+            0x45 => ActionErrorKind::TooManyActions,
+            0x49 => ActionErrorKind::CantJumpLoadedDown,
+            other => ActionErrorKind::Other(other),
+        }
+    }
+
+    /// Human-readable description, for codes that have one
+    pub fn description(self) -> Option<&'static str> {
+        let text = match self {
+            ActionErrorKind::TooFarAway => "That is too far away",
+            ActionErrorKind::TooBusy => "You're too busy",
+            ActionErrorKind::IllegalInventoryTransaction => "You can't do that with that item",
+            ActionErrorKind::CantJumpInAir => "You can't jump while in the air",
+            ActionErrorKind::InvalidCommand => "That is not a valid command",
+            ActionErrorKind::Frozen => "You are frozen",
+            ActionErrorKind::Stuck => "You are stuck",
+            ActionErrorKind::TooEncumbered => "You are too encumbered",
+            ActionErrorKind::ActionCancelled => "Action cancelled",
+            ActionErrorKind::ObjectGone => "That object is gone",
+            ActionErrorKind::NoObject => "No such object",
+            ActionErrorKind::CantGetThere => "You can't get there from here",
+            ActionErrorKind::Dead => "You are dead",
+            ActionErrorKind::ChargedTooFar => "You charged too far",
+            ActionErrorKind::TooTired => "You are too tired to do that",
+            ActionErrorKind::TooManyActions => "Too many actions",
+            ActionErrorKind::CantJumpLoadedDown => "You are too loaded down to jump",
+            ActionErrorKind::Other(_) => return None,
+        };
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_and_unknown_codes() {
+        assert_eq!(
+            ActionErrorKind::from_code(0x1C),
+            ActionErrorKind::TooFarAway
+        );
+        assert_eq!(
+            ActionErrorKind::from_code(0x1C).description(),
+            Some("That is too far away")
+        );
+        assert_eq!(
+            ActionErrorKind::from_code(0x4F1),
+            ActionErrorKind::Other(0x4F1)
+        );
+        assert_eq!(ActionErrorKind::Other(0x4F1).description(), None);
+    }
+}
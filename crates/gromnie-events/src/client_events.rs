@@ -1,3 +1,5 @@
+use crate::connection_stats::ConnectionStats;
+use crate::ids::CharacterId;
 use crate::protocol_events::ProtocolEvent;
 use crate::simple_game_events::SimpleGameEvent;
 use serde::{Deserialize, Serialize};
@@ -18,11 +20,17 @@ pub enum ClientSystemEvent {
         reason: String,
     },
     ConnectingStarted,
+    /// The server hasn't answered the LoginRequest, so it was sent again
+    /// (`attempt` counts from 1, so the first retry is 2)
+    LoginRetry {
+        attempt: u32,
+        max_attempts: u32,
+    },
     ConnectingDone,
     UpdatingStarted,
     UpdatingDone,
     LoginSucceeded {
-        character_id: u32,
+        character_id: CharacterId,
         character_name: String,
     },
     /// Connection was lost
@@ -36,6 +44,52 @@ pub enum ClientSystemEvent {
         attempt: u32,
         delay_secs: u64,
     },
+    /// Logging back into the character that was in the world before the
+    /// connection dropped (`attempt` counts from 1)
+    Resuming {
+        character_id: CharacterId,
+        character_name: String,
+        attempt: u32,
+    },
+    /// A ping was answered by the server
+    PingResult {
+        rtt_millis: u64,
+    },
+    /// Periodic snapshot of the connection counters
+    ConnectionStats(ConnectionStats),
+    /// A message arrived that the client has no parser for. `data` is the
+    /// whole message, opcode included, for the debug view
+    UnknownMessage {
+        opcode: u32,
+        /// Set when `opcode` is OrderedGameEvent and the nested event is unknown
+        game_event_opcode: Option<u32>,
+        data: Vec<u8>,
+    },
+    /// The client stopped in its error scene. With `can_retry` it waits for
+    /// a `RetryConnection` action; otherwise it has given up
+    ConnectionError {
+        kind: ConnectionErrorKind,
+        message: String,
+        can_retry: bool,
+    },
+    /// A script failed and was unloaded (e.g. it ran out of fuel)
+    ScriptError {
+        script_id: String,
+        message: String,
+    },
+    /// The squelch list was loaded or changed; `names` is the whole list
+    SquelchListChanged {
+        names: Vec<String>,
+    },
+}
+
+/// Why the client ended up in its error scene
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ConnectionErrorKind {
+    /// The server stopped answering (login, patching, or every reconnect attempt)
+    Timeout,
+    /// The server turned us away, e.g. with a character error
+    Rejected,
 }
 
 /// State of the client
@@ -52,4 +106,5 @@ pub enum ClientStateEvent {
     InWorld,
     ExitingWorld,
     CharacterError,
+    StateTransition { from: String, to: String },
 }
@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+/// Counters describing the health of a client's connection to the server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ConnectionStats {
+    /// Packets sent since the client started, including keep-alives and retransmits
+    pub packets_sent: u64,
+    /// Packets received since the client started
+    pub packets_received: u64,
+    /// Sequenced packets sent since the server last sent anything back
+    pub unacked_sends: u32,
+    /// Reconnection attempts made since the client started
+    pub reconnect_attempts: u32,
+    /// Round-trip time averaged over recent TimeSyncs the server echoed back, in milliseconds
+    pub rtt_millis: Option<u64>,
+    /// Distinct message opcodes received this session that the client couldn't parse
+    pub unknown_opcodes: u32,
+}
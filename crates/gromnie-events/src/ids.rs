@@ -0,0 +1,118 @@
+//! Typed object ids for the consumer-facing events and actions.
+//!
+//! `SimpleGameEvent`, `SimpleClientAction` and the login system events use
+//! these; protocol events and the script interface keep plain `u32`s to mirror
+//! the wire format and WIT.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Declare a `u32` newtype for one kind of server object id
+///
+/// Formatting (`{}`, `{:X}`, `{:08X}`) matches the bare `u32`, so ids can be
+/// dropped into existing log messages unchanged.
+macro_rules! object_id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u32);
+
+        impl $name {
+            pub const fn new(id: u32) -> Self {
+                Self(id)
+            }
+
+            pub const fn get(self) -> u32 {
+                self.0
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(id: u32) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+object_id_type!(
+    /// Id of any object in the world (items, creatures, players, ...)
+    ObjectId
+);
+
+object_id_type!(
+    /// Id of a player character
+    CharacterId
+);
+
+object_id_type!(
+    /// Id of an object that holds items (the player, packs, chests, ...)
+    ContainerId
+);
+
+// Characters and containers are objects, so they widen to ObjectId. The other
+// direction has to be explicit: not every object is a character or a container.
+
+impl From<CharacterId> for ObjectId {
+    fn from(id: CharacterId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<ContainerId> for ObjectId {
+    fn from(id: ContainerId) -> Self {
+        Self(id.0)
+    }
+}
+
+/// A character is the top-level container of its own inventory
+impl From<CharacterId> for ContainerId {
+    fn from(id: CharacterId) -> Self {
+        Self(id.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions_and_formatting() {
+        let character = CharacterId::from(0x5000_0001);
+        let object: ObjectId = character.into();
+        let container: ContainerId = character.into();
+        assert_eq!(object.get(), container.get());
+        assert_eq!(u32::from(object), 0x5000_0001);
+
+        assert_eq!(format!("{}", object), "1342177281");
+        assert_eq!(format!("0x{:08X}", ObjectId(0xAB)), "0x000000AB");
+    }
+}
@@ -5,7 +5,10 @@
 use std::collections::HashMap;
 use tokio::sync::mpsc::UnboundedSender;
 
+pub mod action_error;
 pub mod client_events;
+pub mod connection_stats;
+pub mod ids;
 mod instant;
 pub mod protocol_events;
 pub mod script_events;
@@ -16,13 +19,18 @@ pub mod system_events;
 use instant::Instant;
 
 // Re-export key types for convenience
-pub use client_events::{ClientEvent, ClientStateEvent, ClientSystemEvent};
+pub use action_error::ActionErrorKind;
+pub use client_events::{ClientEvent, ClientStateEvent, ClientSystemEvent, ConnectionErrorKind};
+pub use connection_stats::ConnectionStats;
+pub use ids::{CharacterId, ContainerId, ObjectId};
 pub use protocol_events::{
     GameEventMsg, IntoGameEventMsg, OrderedGameEvent, ProtocolEvent, S2CEvent,
 };
 pub use script_events::ScriptEventType;
-pub use simple_client_actions::SimpleClientAction;
-pub use simple_game_events::SimpleGameEvent;
+pub use simple_client_actions::{EmoteKind, SimpleClientAction};
+pub use simple_game_events::{
+    AllegianceMember, FellowMember, PlayerPosition, SimpleGameEvent, VendorItem,
+};
 pub use system_events::SystemEvent;
 
 // ============================================================================
@@ -74,6 +82,8 @@ pub enum EventType {
     Game(SimpleGameEvent),
     State(ClientStateEvent),
     System(SystemEvent),
+    /// Raw protocol event from the server, alongside any game event derived from it
+    Protocol(ProtocolEvent),
 }
 
 // ============================================================================
@@ -145,6 +155,11 @@ impl EventEnvelope {
 pub trait EventConsumer: Send + 'static {
     /// Handle an event envelope
     fn handle_event(&mut self, envelope: EventEnvelope);
+
+    /// Name used when reporting problems with this consumer, such as a panic
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 // ============================================================================
@@ -168,6 +183,36 @@ pub struct ConsumerContext<'a, Config = ()> {
     pub action_tx: UnboundedSender<SimpleClientAction>,
 }
 
+/// How the runner hands events to a consumer
+///
+/// `Sync` consumers are called inline on the event task, in order with every
+/// other sync consumer, so they must return quickly. `Async` consumers get
+/// their own task fed by a bounded channel: they can block or do I/O without
+/// holding up scripts or the UI, but they see each event some time after the
+/// sync consumers do, any actions they send are correspondingly later, and
+/// events are dropped (with a warning) if their channel is full. Each consumer
+/// still receives events in order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeliveryMode {
+    /// Call `handle_event` inline on the event task
+    #[default]
+    Sync,
+    /// Queue events on a channel of this capacity, processed on a separate task
+    Async { capacity: usize },
+}
+
+impl DeliveryMode {
+    /// Default channel capacity for async consumers
+    pub const DEFAULT_ASYNC_CAPACITY: usize = 1024;
+
+    /// Async delivery with the default channel capacity
+    pub const fn async_default() -> Self {
+        DeliveryMode::Async {
+            capacity: Self::DEFAULT_ASYNC_CAPACITY,
+        }
+    }
+}
+
 /// Factory trait for creating event consumers
 ///
 /// This trait allows consumers to be created lazily when the client is ready,
@@ -175,6 +220,11 @@ pub struct ConsumerContext<'a, Config = ()> {
 pub trait ConsumerFactory<Config = ()>: Send + Sync + 'static {
     /// Create a consumer for the given client context
     fn create(&self, ctx: &ConsumerContext<Config>) -> Box<dyn EventConsumer>;
+
+    /// How created consumers should receive events (inline by default)
+    fn delivery_mode(&self) -> DeliveryMode {
+        DeliveryMode::Sync
+    }
 }
 
 // Allow closures to be used as consumer factories
@@ -3,6 +3,8 @@
 //! This module defines Rust types that mirror the WIT protocol event types,
 //! providing full access to the acprotocol event stream with type safety.
 
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
 
 /// Protocol event - mirrors WIT structure
@@ -76,7 +78,10 @@ pub enum S2CEvent {
         state: String,
     },
     QualitiesPrivateUpdateInt {
-        property: String,
+        /// PropertyInt key
+        key: u32,
+        /// Display name of the key
+        property: Cow<'static, str>,
         value: i32,
     },
     /// Movement_PositionEvent (0xF748) - position/motion update for an object
@@ -114,6 +119,54 @@ pub enum S2CEvent {
     },
 }
 
+impl S2CEvent {
+    /// Stable number identifying the variant, used by script event filters
+    ///
+    /// Scripts subscribe to an S2C event with `300 + discriminant`. Numbers
+    /// are never reused; new variants get the next free one.
+    ///
+    /// | # | Variant |
+    /// |---|---------|
+    /// | 0 | `LoginCreatePlayer` |
+    /// | 1 | `LoginCharacterSet` |
+    /// | 2 | `ItemCreateObject` |
+    /// | 3 | `ItemDeleteObject` |
+    /// | 4 | `ItemOnViewContents` |
+    /// | 5 | `PlayerContainersReceived` |
+    /// | 6 | `CharacterError` |
+    /// | 7 | `HearSpeech` |
+    /// | 8 | `HearRangedSpeech` |
+    /// | 9 | `DDDInterrogation` |
+    /// | 10 | `CharGenVerificationResponse` |
+    /// | 11 | `ItemSetState` |
+    /// | 12 | `QualitiesPrivateUpdateInt` |
+    /// | 13 | `MovementPositionEvent` |
+    /// | 14 | `MovementPositionAndMovementEvent` |
+    /// | 15 | `MovementSetObjectMovement` |
+    /// | 16 | `EffectsPlayerTeleport` |
+    pub fn discriminant(&self) -> u32 {
+        match self {
+            S2CEvent::LoginCreatePlayer { .. } => 0,
+            S2CEvent::LoginCharacterSet { .. } => 1,
+            S2CEvent::ItemCreateObject { .. } => 2,
+            S2CEvent::ItemDeleteObject { .. } => 3,
+            S2CEvent::ItemOnViewContents { .. } => 4,
+            S2CEvent::PlayerContainersReceived { .. } => 5,
+            S2CEvent::CharacterError { .. } => 6,
+            S2CEvent::HearSpeech { .. } => 7,
+            S2CEvent::HearRangedSpeech { .. } => 8,
+            S2CEvent::DDDInterrogation { .. } => 9,
+            S2CEvent::CharGenVerificationResponse => 10,
+            S2CEvent::ItemSetState { .. } => 11,
+            S2CEvent::QualitiesPrivateUpdateInt { .. } => 12,
+            S2CEvent::MovementPositionEvent { .. } => 13,
+            S2CEvent::MovementPositionAndMovementEvent { .. } => 14,
+            S2CEvent::MovementSetObjectMovement { .. } => 15,
+            S2CEvent::EffectsPlayerTeleport { .. } => 16,
+        }
+    }
+}
+
 /// Nested game events with OrderedGameEvent metadata
 ///
 /// These are events contained within the OrderedGameEvent wrapper (0xF7B0).
@@ -140,6 +193,16 @@ pub enum GameEventMsg {
     TransientString {
         message: String,
     },
+    /// A message on a chat channel the character is in (CommunicationChannelBroadcast)
+    ChannelBroadcast {
+        /// Channel flag value
+        channel: u32,
+        sender_name: String,
+        message: String,
+    },
+    /// The account's server-side squelches, sent at login; the names are kept
+    /// on the client rather than copied into the event
+    SquelchDb,
 
     // ===== Trading Events =====
     /// Server confirmed trade registration (both players notified)
@@ -173,6 +236,15 @@ pub enum GameEventMsg {
         reason: u32,
     },
 
+    // ===== Confirmation Events =====
+    /// Server is asking for a yes/no answer (fellowship invite, allegiance, etc.)
+    ConfirmationRequest {
+        /// ConfirmationType (4 = fellowship invite)
+        confirmation_type: u32,
+        context_id: u32,
+        message: String,
+    },
+
     // ===== Spell / Enchantment Events =====
     /// An enchantment (buff/debuff) was applied or refreshed on this character
     EnchantmentUpdated {
@@ -185,6 +257,127 @@ pub enum GameEventMsg {
     EnchantmentRemoved {
         spell_id: u32,
     },
+    /// A spell was added to this character's spellbook
+    SpellUpdated {
+        spell_id: u32,
+    },
+
+    // ===== Character Events =====
+    /// Full character description sent on login; the decoded attributes and
+    /// skills are kept on the client rather than copied into the event
+    PlayerDescription,
+
+    // ===== Item Events =====
+    /// Answer to an appraisal request (ItemSetAppraiseInfo)
+    AppraiseInfo {
+        object_id: u32,
+        /// False if the appraisal failed, e.g. the object was out of range
+        success: bool,
+    },
+    /// Items in a container that was opened (ItemOnViewContents)
+    ItemOnViewContents {
+        container_id: u32,
+        items: Vec<u32>,
+    },
+
+    // ===== Vendor Events =====
+    /// A vendor's stock (VendorVendorInfo); the items are in the `VendorInfo` game event
+    VendorInfo {
+        vendor_id: u32,
+        item_count: u32,
+    },
+
+    // ===== Fellowship Events =====
+    /// Full fellowship state (FellowshipFullUpdate)
+    FellowshipFullUpdate {
+        name: String,
+        leader_id: u32,
+        member_count: u32,
+    },
+    /// One member's stats changed or a member joined (FellowshipUpdateFellow)
+    FellowshipUpdateFellow {
+        fellow_id: u32,
+    },
+    /// A member left the fellowship (FellowshipQuit)
+    FellowshipQuit {
+        fellow_id: u32,
+    },
+    /// The fellowship was disbanded (FellowshipDisband)
+    FellowshipDisband,
+
+    // ===== Allegiance Events =====
+    /// The character's allegiance (AllegianceAllegianceUpdate)
+    AllegianceUpdate {
+        rank: u32,
+        allegiance_name: String,
+        member_count: u32,
+    },
+    /// Another player's allegiance (AllegianceAllegianceInfoResponseEvent)
+    AllegianceInfoResponse {
+        target_id: u32,
+        allegiance_name: String,
+        member_count: u32,
+    },
+    /// A member logged in or out (AllegianceAllegianceLoginNotificationEvent)
+    AllegianceLoginNotification {
+        character_id: u32,
+        logged_in: bool,
+    },
+
+    // ===== Combat Events =====
+    /// Answer to a health query (CombatQueryHealthResponse)
+    QueryHealthResponse {
+        object_id: u32,
+        /// Fraction of maximum health, 0.0 to 1.0
+        health: f32,
+    },
+    /// Our attack hit (CombatHandleAttackerNotificationEvent)
+    AttackerNotification {
+        defender_name: String,
+        /// DamageType flags
+        damage_type: u32,
+        damage_amount: u32,
+        /// Damage as a fraction of the defender's maximum health
+        damage_percent: f32,
+        critical: bool,
+    },
+    /// We were hit (CombatHandleDefenderNotificationEvent)
+    DefenderNotification {
+        attacker_name: String,
+        /// DamageType flags
+        damage_type: u32,
+        damage_amount: u32,
+        /// Damage as a fraction of our maximum health
+        damage_percent: f32,
+        critical: bool,
+    },
+    /// The defender evaded our attack (CombatHandleEvasionAttackerNotificationEvent)
+    EvasionAttackerNotification {
+        defender_name: String,
+    },
+    /// We evaded an attack (CombatHandleEvasionDefenderNotificationEvent)
+    EvasionDefenderNotification {
+        attacker_name: String,
+    },
+    /// We died (CombatHandleVictimNotificationEventSelf)
+    VictimNotificationSelf {
+        message: String,
+    },
+    /// We killed something (CombatHandleVictimNotificationEventOther)
+    VictimNotificationOther {
+        message: String,
+    },
+
+    // ===== Error Events =====
+    /// Server rejected an action
+    WeenieError {
+        error_code: u32,
+    },
+    /// Server rejected an action, with text to show (usually a name)
+    WeenieErrorWithString {
+        error_code: u32,
+        text: String,
+    },
 }
 
 // ============================================================================
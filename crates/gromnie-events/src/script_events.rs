@@ -1,5 +1,7 @@
+use serde::Serialize;
+
 /// Types of script-related events
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ScriptEventType {
     Loaded,
     Unloaded,
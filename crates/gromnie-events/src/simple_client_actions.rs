@@ -1,16 +1,33 @@
+use crate::ids::{CharacterId, ContainerId, ObjectId};
+
 /// Simplified versions of GameAction
 #[derive(Debug, Clone)]
 pub enum SimpleClientAction {
     /// Send a chat message to everyone nearby (CommunicationTalk)
     SendChatSay { message: String },
+    /// Send chat lines one after another, each read like `SendChatSay`.
+    /// `interval_millis` apart, but never closer than the client's minimum;
+    /// blank lines are skipped
+    SendChatLines {
+        lines: Vec<String>,
+        interval_millis: u64,
+    },
     /// Send a direct message to a specific player by name (CommunicationTalkDirectByName)
     SendChatTell {
         recipient_name: String,
         message: String,
     },
+    /// Drop chat from a player from now on; kept per account
+    Squelch { name: String },
+    /// Stop dropping a squelched player's chat
+    Unsquelch { name: String },
+    /// Join a chat channel (Communication_AddToChannel); ignored outside the world
+    JoinChannel { channel: u32 },
+    /// Leave a chat channel (Communication_RemoveFromChannel); ignored outside the world
+    LeaveChannel { channel: u32 },
     /// Log in as a specific character
     LoginCharacter {
-        character_id: u32,
+        character_id: CharacterId,
         character_name: String,
         account: String,
     },
@@ -18,6 +35,10 @@ pub enum SimpleClientAction {
     SendLoginComplete,
     /// Disconnect from the server
     Disconnect,
+    /// Log out of the world and return to character select
+    Logout,
+    /// Start connecting again from a retryable error scene
+    RetryConnection,
     /// Reload scripts from the given directory
     ReloadScripts { script_dir: std::path::PathBuf },
     /// Log a message from a script
@@ -38,4 +59,127 @@ pub enum SimpleClientAction {
         /// Hold key modifier (must match)
         hold_key: u32,
     },
+    /// Walk in a straight line toward local coordinates in the current
+    /// landblock (Movement_AutonomousPosition updates); ignored outside the
+    /// world or before the character's position is known
+    MoveTo { x: f32, y: f32, z: f32 },
+    /// Face a heading in degrees clockwise from north, stopping any MoveTo
+    TurnTo { heading: f32 },
+    /// Use an item, e.g. drink a potion or open a container (InventoryUseEvent)
+    UseItem { object_id: ObjectId },
+    /// Drop an item on the ground (InventoryDropItem)
+    DropItem { object_id: ObjectId },
+    /// Play a predefined emote animation
+    DoEmote { emote: EmoteKind },
+    /// Play an emote by its motion command value; unknown ids are logged and dropped
+    SendEmote { emote_id: u32 },
+    /// Send a custom "/e" style emote (CommunicationEmote)
+    SendTextEmote { text: String },
+    /// Ask the server for an object's health (CombatQueryHealth); answered with `TargetHealth`
+    QueryHealth { object_id: ObjectId },
+    /// Ask the server to appraise an object (ItemAppraise); answered with `AppraiseResult`
+    Appraise { object_id: ObjectId },
+    /// Open a container by using it (InventoryUseEvent); answered with `ContainerContents`
+    OpenContainer { container_id: ContainerId },
+    /// Answer a `ConfirmationRequest` prompt (Character_ConfirmationResponse);
+    /// ignored outside the world or if no prompt with `context_id` is open
+    RespondConfirmation { context_id: u32, accept: bool },
+    /// Start a fellowship led by this character, sharing experience
+    /// (Fellowship_Create); ignored outside the world
+    FellowshipCreate { name: String },
+    /// Invite a player into the fellowship (Fellowship_Recruit); ignored
+    /// outside the world or if the player isn't a known object
+    FellowshipInvite { object_id: ObjectId },
+    /// Remove a member from the fellowship; only the leader can
+    /// (Fellowship_Dismiss); ignored outside the world
+    FellowshipDismiss { object_id: ObjectId },
+    /// Leave the fellowship (Fellowship_Quit); ignored outside the world
+    FellowshipQuit,
+    /// Put an item into the open trade at `slot` (Trade_AddToTrade);
+    /// ignored outside the world
+    TradeAdd { item_id: ObjectId, slot: u32 },
+    /// Take an item we added back out of the open trade. There is no message
+    /// for this, so the trade is reset and the other items are added again;
+    /// ignored if the item isn't in the trade
+    TradeRemove { item_id: ObjectId },
+    /// Accept the open trade as it stands (Trade_AcceptTrade); ignored if
+    /// no trade has been registered
+    TradeAccept,
+    /// Decline the open trade (Trade_DeclineTrade)
+    TradeDecline,
+    /// Take every item out of the open trade (Trade_ResetTrade); ignored if
+    /// no trade has been registered
+    TradeReset,
+    /// Buy from a vendor (Vendor_Buy); ignored outside the world
+    BuyItem {
+        vendor_id: ObjectId,
+        item_id: ObjectId,
+        quantity: u32,
+    },
+    /// Sell an inventory item to a vendor (Vendor_Sell); ignored outside the world
+    SellItem {
+        vendor_id: ObjectId,
+        item_id: ObjectId,
+        quantity: u32,
+    },
+    /// Send a game action the client doesn't model: `payload` is everything
+    /// after the action type and is sent unchecked; ignored outside the world
+    SendRawGameAction { opcode: u32, payload: Vec<u8> },
+}
+
+/// Common character emotes, each played as a motion command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmoteKind {
+    BowDeep,
+    ClapHands,
+    Cry,
+    Laugh,
+    Nod,
+    Point,
+    ShakeHead,
+    Shrug,
+    Wave,
+    Salute,
+    Kneel,
+}
+
+impl EmoteKind {
+    /// Every emote, in declaration order
+    pub const ALL: [EmoteKind; 11] = [
+        EmoteKind::BowDeep,
+        EmoteKind::ClapHands,
+        EmoteKind::Cry,
+        EmoteKind::Laugh,
+        EmoteKind::Nod,
+        EmoteKind::Point,
+        EmoteKind::ShakeHead,
+        EmoteKind::Shrug,
+        EmoteKind::Wave,
+        EmoteKind::Salute,
+        EmoteKind::Kneel,
+    ];
+
+    /// Motion command value sent in MovementDoMovementCommand
+    pub fn motion_command(self) -> u32 {
+        match self {
+            EmoteKind::BowDeep => 0x1300_007D,
+            EmoteKind::ClapHands => 0x1300_007E,
+            EmoteKind::Cry => 0x1300_007F,
+            EmoteKind::Laugh => 0x1300_0080,
+            EmoteKind::Nod => 0x1300_0083,
+            EmoteKind::Point => 0x1300_0084,
+            EmoteKind::ShakeHead => 0x1300_0085,
+            EmoteKind::Shrug => 0x1300_0086,
+            EmoteKind::Wave => 0x1300_0087,
+            EmoteKind::Salute => 0x1300_008A,
+            EmoteKind::Kneel => 0x1300_0092,
+        }
+    }
+
+    /// Look up an emote by its motion command value
+    pub fn from_motion_command(motion: u32) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|emote| emote.motion_command() == motion)
+    }
 }
@@ -1,5 +1,9 @@
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
 
+use crate::ids::{CharacterId, ContainerId, ObjectId};
+
 /// Simplified versions of acprotocol GameEvent/OrderedGameEvent
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SimpleGameEvent {
@@ -10,7 +14,7 @@ pub enum SimpleGameEvent {
     },
     /// Character login succeeded - received LoginComplete notification
     LoginSucceeded {
-        character_id: u32,
+        character_id: CharacterId,
         character_name: String,
     },
     /// Character login failed
@@ -27,8 +31,15 @@ pub enum SimpleGameEvent {
         message: String,
         message_type: u32,
     },
+    /// Message on a chat channel the character is in (Communication_ChannelBroadcast)
+    ChannelMessage {
+        channel: u32,
+        /// Empty for the character's own messages echoed back
+        sender_name: String,
+        message: String,
+    },
     CreatePlayer {
-        character_id: u32,
+        character_id: CharacterId,
     },
     /// Progress update for connecting phase
     ConnectingSetProgress {
@@ -40,44 +51,369 @@ pub enum SimpleGameEvent {
     },
     /// Item created with full details
     ItemCreateObject {
-        object_id: u32,
+        object_id: ObjectId,
         name: String,
         item_type: String,
-        container_id: Option<u32>,
+        container_id: Option<ContainerId>,
         burden: u32,
         value: u32,
         items_capacity: Option<u32>,
         container_capacity: Option<u32>,
+        /// Number of items in the stack, for stackable items
+        stack_size: Option<u32>,
+        /// Object wielding this item, if it is equipped
+        wielder_id: Option<ObjectId>,
+        /// EquipMask bits for the slot(s) this item is equipped in
+        equipped_location: Option<u32>,
+        /// Where the object is, if it's out in the world rather than in a
+        /// container or wielded
+        position: Option<PlayerPosition>,
     },
     /// Container contents received
     ItemOnViewContents {
-        container_id: u32,
-        items: Vec<u32>,
+        container_id: ContainerId,
+        items: Vec<ObjectId>,
     },
     /// Player containers received (initial inventory list)
     PlayerContainersReceived {
-        player_id: u32,
-        containers: Vec<u32>,
+        player_id: CharacterId,
+        containers: Vec<ContainerId>,
     },
     /// Item deleted from world
     ItemDeleteObject {
-        object_id: u32,
+        object_id: ObjectId,
+    },
+    /// Stack size of an item changed, e.g. after using part of a stack.
+    /// A stack that reaches zero is followed by an `ItemDeleteObject`.
+    ItemUpdateStackSize {
+        object_id: ObjectId,
+        /// From the object's ItemCreateObject; empty if it wasn't seen
+        name: String,
+        /// Size before the update, if the object was known
+        previous_stack_size: Option<u32>,
+        stack_size: u32,
+        /// Value of the whole stack at its new size
+        value: u32,
+    },
+    /// An object out in the world moved; the heading is 0 if the update
+    /// didn't say which way it faces
+    ObjectMoved {
+        object_id: ObjectId,
+        position: PlayerPosition,
     },
     /// Item moved between containers
     ItemMovedObject {
-        object_id: u32,
-        new_container_id: u32,
+        object_id: ObjectId,
+        new_container_id: ContainerId,
     },
-    /// Quality/property integer updated on an object
+    /// Integer quality updated on the player (Qualities_PrivateUpdateInt)
     QualitiesPrivateUpdateInt {
-        object_id: u32,
-        property_name: String,
+        /// The player's object ID
+        object_id: ObjectId,
+        /// PropertyInt key, for matching by value
+        property_key: u32,
+        /// Display name of the key; always borrowed when produced by the client
+        property_name: Cow<'static, str>,
         value: i32,
     },
+    /// Integer quality updated on another object (Qualities_UpdateInt)
+    QualitiesUpdateInt {
+        object_id: ObjectId,
+        /// PropertyInt key, for matching by value
+        property_key: u32,
+        /// Display name of the key; always borrowed when produced by the client
+        property_name: Cow<'static, str>,
+        value: i32,
+    },
+    /// Float quality updated on the player or another object
+    /// (Qualities_PrivateUpdateFloat / Qualities_UpdateFloat)
+    QualitiesUpdateFloat {
+        object_id: ObjectId,
+        /// PropertyFloat name of the key
+        property_name: Cow<'static, str>,
+        value: f64,
+    },
+    /// Current health, stamina or mana changed on the player or another object
+    /// (Qualities_PrivateUpdateAttribute2ndLevel / Qualities_UpdateAttribute2ndLevel)
+    VitalUpdated {
+        object_id: ObjectId,
+        /// `Health`, `Stamina` or `Mana`
+        vital_name: Cow<'static, str>,
+        value: u32,
+    },
     /// Generic item state update
     ItemSetState {
-        object_id: u32,
+        object_id: ObjectId,
         property_name: String,
         value: i32,
     },
+    /// Server rejected an action (Communication_WeenieError / WeenieErrorWithString)
+    ActionError {
+        /// WeenieError code; see [`crate::ActionErrorKind::from_code`]
+        error_code: u32,
+        /// Text sent with the error, or a description of the code if none was sent
+        message: String,
+    },
+    /// Server reported another object's health, usually in answer to a health query
+    TargetHealth {
+        object_id: ObjectId,
+        /// Health as a percentage of maximum, 0.0 to 100.0
+        health_percent: f32,
+    },
+    /// Contents of a container opened with `OpenContainer`
+    ContainerContents {
+        container_id: ContainerId,
+        items: Vec<ObjectId>,
+    },
+    /// Server is asking a yes/no question (Character_ConfirmationRequest);
+    /// answer it with `RespondConfirmation` and the same `context_id`
+    ConfirmationRequest {
+        /// ConfirmationType, e.g. 1 to swear allegiance or 4 to join a fellowship
+        confirmation_type: u32,
+        context_id: u32,
+        message: String,
+    },
+    /// A trade window opened with another player (Trade_OpenTrade)
+    TradeOpened {
+        partner_id: ObjectId,
+        /// The partner's object name; empty if the client hasn't seen them
+        partner_name: String,
+    },
+    /// An item was put into the open trade by either side (Trade_AddToTrade)
+    TradeItemAdded {
+        item_id: ObjectId,
+        /// The item's object name; empty if the client hasn't seen it
+        name: String,
+        /// True if we put the item in, false if the partner did
+        own_item: bool,
+    },
+    /// Server answered an appraisal request, usually one sent with `Appraise`
+    AppraiseResult {
+        object_id: ObjectId,
+        /// False if the appraisal failed, e.g. the object was out of range
+        success: bool,
+        /// Integer properties revealed by the appraisal, by PropertyInt name
+        int_properties: Vec<(String, i64)>,
+    },
+    /// A player died (the local player or another player in view)
+    PlayerDeath {
+        victim_id: ObjectId,
+        killer_id: ObjectId,
+        /// Death message as sent by the server
+        message: String,
+    },
+    /// A vendor's stock, sent when the player uses a vendor (Vendor_VendorInfo)
+    VendorInfo {
+        vendor_id: ObjectId,
+        items: Vec<VendorItem>,
+    },
+    /// Full state of the character's fellowship (Fellowship_FullUpdate)
+    FellowshipUpdated {
+        name: String,
+        leader_id: ObjectId,
+        members: Vec<FellowMember>,
+    },
+    /// One fellowship member's stats changed, or a member joined (Fellowship_UpdateFellow)
+    FellowshipMemberUpdated {
+        member: FellowMember,
+    },
+    /// A member left the fellowship; the local player if it was this character (Fellowship_Quit)
+    FellowshipMemberLeft {
+        object_id: ObjectId,
+    },
+    /// The fellowship was disbanded (Fellowship_Disband)
+    FellowshipDisbanded,
+    /// Full state of the character's allegiance (Allegiance_AllegianceUpdate)
+    AllegianceUpdated {
+        /// Allegiance name, empty if the monarch hasn't set one
+        name: String,
+        /// The character's rank
+        rank: u32,
+        members: Vec<AllegianceMember>,
+    },
+    /// Another player's allegiance, in answer to an allegiance info request
+    /// (Allegiance_AllegianceInfoResponseEvent)
+    AllegianceInfoReceived {
+        target_id: ObjectId,
+        name: String,
+        members: Vec<AllegianceMember>,
+    },
+    /// An allegiance member logged in or out (Allegiance_AllegianceLoginNotificationEvent)
+    AllegianceMemberLogin {
+        object_id: ObjectId,
+        /// Member name from the last allegiance update, or the object's name if
+        /// they aren't in it; empty if neither is known
+        name: String,
+        logged_in: bool,
+    },
+    /// The local player's attack hit (Combat_HandleAttackerNotificationEvent)
+    DamageDealt {
+        defender_name: String,
+        /// DamageType flags, e.g. 0x1 slash or 0x10 fire
+        damage_type: u32,
+        damage_amount: u32,
+        /// Damage as a fraction of the defender's maximum health
+        damage_percent: f32,
+        critical: bool,
+    },
+    /// The local player was hit (Combat_HandleDefenderNotificationEvent)
+    DamageTaken {
+        attacker_name: String,
+        /// DamageType flags, e.g. 0x1 slash or 0x10 fire
+        damage_type: u32,
+        damage_amount: u32,
+        /// Damage as a fraction of the local player's maximum health
+        damage_percent: f32,
+        critical: bool,
+    },
+    /// The defender evaded the local player's attack (Combat_HandleEvasionAttackerNotificationEvent)
+    AttackEvaded {
+        defender_name: String,
+    },
+    /// The local player evaded an attack (Combat_HandleEvasionDefenderNotificationEvent)
+    AttackAvoided {
+        attacker_name: String,
+    },
+    /// The local player killed something, or was killed
+    /// (Combat_HandleVictimNotificationEventOther / EventSelf). The local
+    /// player's own death also arrives as a PlayerDeath.
+    CombatKill {
+        /// Kill message as sent by the server
+        message: String,
+        /// True if the local player was the one killed
+        own_death: bool,
+    },
+}
+
+/// A fellowship member's level and vitals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FellowMember {
+    pub object_id: ObjectId,
+    pub name: String,
+    pub level: u32,
+    pub health: u32,
+    pub max_health: u32,
+    pub stamina: u32,
+    pub max_stamina: u32,
+    pub mana: u32,
+    pub max_mana: u32,
+}
+
+/// An allegiance member's place in the hierarchy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AllegianceMember {
+    pub object_id: ObjectId,
+    pub name: String,
+    pub rank: u32,
+    pub level: u32,
+    /// Patron, or None for the monarch
+    pub patron_id: Option<ObjectId>,
+    /// AllegianceOfficerLevel, 0 if not an officer
+    pub officer_level: u32,
+}
+
+/// One item a vendor has for sale
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VendorItem {
+    pub object_id: ObjectId,
+    pub name: String,
+    /// Number in stock, or None if the vendor never runs out
+    pub stock: Option<u32>,
+}
+
+/// Where something is: a landcell plus coordinates inside its landblock
+///
+/// Used for the character as well as for every other object in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayerPosition {
+    pub landcell: u32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    /// Degrees clockwise from north, 0-360
+    pub heading: f32,
+}
+
+impl PlayerPosition {
+    /// Width of a landblock in meters
+    pub const LANDBLOCK_SIZE: f32 = 192.0;
+
+    /// Orientation as a quaternion (w, x, y, z): a rotation about the vertical axis
+    pub fn rotation(&self) -> (f32, f32, f32, f32) {
+        let half = -self.heading.to_radians() / 2.0;
+        (half.cos(), 0.0, 0.0, half.sin())
+    }
+
+    /// Heading in degrees clockwise from north for a rotation about the vertical axis
+    pub fn heading_from_rotation(w: f32, z: f32) -> f32 {
+        (-(2.0 * z.atan2(w)).to_degrees()).rem_euclid(360.0)
+    }
+
+    /// Straight-line distance in meters, or None if there's no way to tell,
+    /// e.g. one point is in a dungeon and the other is somewhere else
+    pub fn distance_to(&self, other: &PlayerPosition) -> Option<f32> {
+        let (ax, ay) = self.offset_from(other)?;
+        Some((ax.powi(2) + ay.powi(2) + (self.z - other.z).powi(2)).sqrt())
+    }
+
+    /// (x, y) of this point relative to `other`'s landblock
+    fn offset_from(&self, other: &PlayerPosition) -> Option<(f32, f32)> {
+        let (dx, dy) = (self.x - other.x, self.y - other.y);
+        if self.landblock() == other.landblock() {
+            return Some((dx, dy));
+        }
+        // Outdoor landblocks tile a grid, so points in different ones can
+        // still be compared; indoor cells are only comparable within a landblock
+        if !self.is_outdoors() || !other.is_outdoors() {
+            return None;
+        }
+        let block_x = (self.landcell >> 24) as f32 - (other.landcell >> 24) as f32;
+        let block_y =
+            ((self.landcell >> 16) & 0xFF) as f32 - ((other.landcell >> 16) & 0xFF) as f32;
+        Some((
+            dx + block_x * Self::LANDBLOCK_SIZE,
+            dy + block_y * Self::LANDBLOCK_SIZE,
+        ))
+    }
+
+    /// Landblock part of the landcell, e.g. 0xA9B4 for 0xA9B40017
+    pub fn landblock(&self) -> u32 {
+        self.landcell >> 16
+    }
+
+    /// Outdoor cells are numbered 1-64; indoor cells start at 0x100
+    pub fn is_outdoors(&self) -> bool {
+        (self.landcell & 0xFFFF) < 0x100
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(landcell: u32, x: f32, y: f32) -> PlayerPosition {
+        PlayerPosition {
+            landcell,
+            x,
+            y,
+            z: 0.0,
+            heading: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_distance_across_landblocks() {
+        let here = at(0xA9B4_0001, 10.0, 10.0);
+        assert_eq!(here.distance_to(&at(0xA9B4_0002, 13.0, 14.0)), Some(5.0));
+
+        // One landblock east, just across the border
+        let east = at(0xAAB4_0001, 1.0, 10.0);
+        assert_eq!(here.distance_to(&east), Some(183.0));
+        assert_eq!(east.distance_to(&here), Some(183.0));
+
+        // Dungeon cells only compare within their own landblock
+        let dungeon = at(0x0125_0105, 10.0, 10.0);
+        assert_eq!(here.distance_to(&dungeon), None);
+        assert_eq!(dungeon.distance_to(&at(0x0125_0110, 10.0, 13.0)), Some(3.0));
+    }
 }
@@ -1,4 +1,10 @@
-#[derive(Debug, Clone)]
+use crate::client_events::ConnectionErrorKind;
+use crate::connection_stats::ConnectionStats;
+use crate::ids::CharacterId;
+use crate::script_events::ScriptEventType;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
 pub enum SystemEvent {
     /// Reload scripts (internal use for hot-reload)
     ReloadScripts { script_dir: std::path::PathBuf },
@@ -10,6 +16,12 @@ pub enum SystemEvent {
     AuthenticationFailed { client_id: u32, reason: String },
     /// Client started connecting phase
     ConnectingStarted { client_id: u32 },
+    /// Client resent its LoginRequest because the server hasn't answered
+    LoginRetry {
+        client_id: u32,
+        attempt: u32,
+        max_attempts: u32,
+    },
     /// Client finished connecting phase
     ConnectingDone { client_id: u32 },
     /// Client started updating/patching phase
@@ -18,7 +30,7 @@ pub enum SystemEvent {
     UpdatingDone { client_id: u32 },
     /// Character login succeeded
     LoginSucceeded {
-        character_id: u32,
+        character_id: CharacterId,
         character_name: String,
     },
     /// Client was disconnected from the server
@@ -34,6 +46,45 @@ pub enum SystemEvent {
         attempt: u32,
         delay_secs: u64,
     },
+    /// Client is logging back into the world after reconnecting
+    Resuming {
+        client_id: u32,
+        character_id: CharacterId,
+        character_name: String,
+        attempt: u32,
+    },
+    /// Measured round-trip time of a ping to the server
+    PingResult { client_id: u32, rtt_millis: u64 },
+    /// Periodic snapshot of a client's connection counters
+    ConnectionStats {
+        client_id: u32,
+        stats: ConnectionStats,
+    },
+    /// A client received a message it has no parser for
+    UnknownMessage {
+        client_id: u32,
+        opcode: u32,
+        /// Set when `opcode` is OrderedGameEvent and the nested event is unknown
+        game_event_opcode: Option<u32>,
+        /// Whole message, opcode included
+        data: Vec<u8>,
+    },
+    /// A client stopped in its error scene
+    ConnectionError {
+        client_id: u32,
+        kind: ConnectionErrorKind,
+        message: String,
+        /// Whether the client will reconnect on a `RetryConnection` action
+        can_retry: bool,
+    },
+    /// A client's squelch list was loaded or changed
+    SquelchListChanged { client_id: u32, names: Vec<String> },
+    /// Lifecycle event of a script
+    Script {
+        client_id: u32,
+        script_id: String,
+        event: ScriptEventType,
+    },
     /// System shutdown requested
     Shutdown,
 }
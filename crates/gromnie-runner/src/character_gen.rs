@@ -1,9 +1,145 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use asheron_rs::enums::{Gender, HeritageGroup};
 use asheron_rs::types::PackableList;
+use serde::Deserialize;
 
 use gromnie_client::client::ace_protocol::{
     AceCharGenConfig, AceCharGenResult, RawSkillAdvancementClass,
 };
+use gromnie_client::config::CharacterCreationConfig;
+
+/// Number of skill slots the server expects in a char-gen packet
+const SKILL_COUNT: usize = 55;
+/// Lowest and highest value for a single attribute at creation
+const ATTRIBUTE_RANGE: std::ops::RangeInclusive<u32> = 10..=100;
+/// Attribute points available at creation
+const ATTRIBUTE_POINTS: u32 = 330;
+/// Skill credits available at creation
+const SKILL_CREDITS: u32 = 50;
+
+/// ACE SkillAdvancementClass values
+const SKILL_TRAINED: u32 = 2;
+const SKILL_SPECIALIZED: u32 = 3;
+
+/// Skills that can be trained at creation: (id, template key, credits to
+/// train, credits to specialize including training)
+const SKILL_COSTS: &[(usize, &str, u32, u32)] = &[
+    (6, "melee_defense", 10, 20),
+    (7, "missile_defense", 6, 10),
+    (14, "arcane_lore", 4, 6),
+    (15, "magic_defense", 12, 20),
+    (16, "mana_conversion", 6, 12),
+    (18, "item_tinkering", 4, 8),
+    (19, "assess_person", 2, 4),
+    (20, "deception", 4, 6),
+    (21, "healing", 6, 10),
+    (22, "jump", 0, 4),
+    (23, "lockpick", 6, 10),
+    (24, "run", 0, 4),
+    (27, "assess_creature", 2, 4),
+    (28, "weapon_tinkering", 4, 8),
+    (29, "armor_tinkering", 4, 8),
+    (30, "magic_item_tinkering", 4, 8),
+    (31, "creature_enchantment", 8, 16),
+    (32, "item_enchantment", 8, 16),
+    (33, "life_magic", 12, 20),
+    (34, "war_magic", 16, 28),
+    (35, "leadership", 4, 6),
+    (36, "loyalty", 2, 4),
+    (37, "fletching", 4, 8),
+    (38, "alchemy", 6, 12),
+    (39, "cooking", 4, 8),
+    (41, "two_handed_combat", 8, 16),
+    (43, "void_magic", 16, 28),
+    (44, "heavy_weapons", 6, 12),
+    (45, "light_weapons", 4, 8),
+    (46, "finesse_weapons", 4, 8),
+    (47, "missile_weapons", 6, 12),
+    (48, "shield", 2, 4),
+    (49, "dual_wield", 2, 4),
+    (50, "recklessness", 4, 6),
+    (51, "sneak_attack", 4, 6),
+    (52, "dirty_fighting", 4, 6),
+    (54, "summoning", 8, 12),
+];
+
+/// Why a character template couldn't be used
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateError {
+    #[error("Failed to read template: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse template: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("Unsupported heritage '{0}' (expected Aluvian, Gharundim, Sho or Viamontian)")]
+    UnknownHeritage(String),
+    #[error("Unknown gender '{0}' (expected Male or Female)")]
+    UnknownGender(String),
+    #[error("Attribute {name} is {value}; it must be between 10 and 100")]
+    AttributeOutOfRange { name: &'static str, value: u32 },
+    #[error("Attributes total {total} points; at most 330 are available")]
+    TooManyAttributePoints { total: u32 },
+    #[error("Unknown skill '{0}'")]
+    UnknownSkill(String),
+    #[error("Skills cost {cost} credits; at most 50 are available")]
+    TooManySkillCredits { cost: u32 },
+}
+
+/// How far a skill is raised at creation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SkillTraining {
+    Trained,
+    Specialized,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Attributes {
+    strength: u32,
+    endurance: u32,
+    coordination: u32,
+    quickness: u32,
+    focus: u32,
+    #[serde(rename = "self")]
+    self_: u32,
+}
+
+/// On-disk character template
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CharacterTemplate {
+    name: Option<String>,
+    heritage: String,
+    gender: String,
+    /// Index into the heritage's class templates (0 = custom)
+    #[serde(default)]
+    class: u32,
+    #[serde(default)]
+    start_area: u32,
+    attributes: Attributes,
+    #[serde(default)]
+    skills: BTreeMap<String, SkillTraining>,
+}
+
+fn parse_heritage(name: &str) -> Result<HeritageGroup, TemplateError> {
+    match name.to_ascii_lowercase().as_str() {
+        "aluvian" => Ok(HeritageGroup::Aluvian),
+        "gharundim" => Ok(HeritageGroup::Gharundim),
+        "sho" => Ok(HeritageGroup::Sho),
+        "viamontian" => Ok(HeritageGroup::Viamontian),
+        _ => Err(TemplateError::UnknownHeritage(name.to_string())),
+    }
+}
+
+fn parse_gender(name: &str) -> Result<Gender, TemplateError> {
+    match name.to_ascii_lowercase().as_str() {
+        "male" => Ok(Gender::Male),
+        "female" => Ok(Gender::Female),
+        _ => Err(TemplateError::UnknownGender(name.to_string())),
+    }
+}
 
 /// Builder for creating ACE-compatible characters with sensible defaults
 pub struct CharacterBuilder {
@@ -51,11 +187,11 @@ impl CharacterBuilder {
                     // The server expects exactly 55 skills in SkillAdvancementClass format
                     // ACE defines Inactive = 0, but acprotocol doesn't have it, so we use RawSkillAdvancementClass
                     let mut skills = vec![];
-                    for _ in 0..55 {
+                    for _ in 0..SKILL_COUNT {
                         skills.push(RawSkillAdvancementClass(0));
                     }
                     PackableList {
-                        count: 55,
+                        count: SKILL_COUNT as u32,
                         list: skills,
                     }
                 },
@@ -68,6 +204,118 @@ impl CharacterBuilder {
         }
     }
 
+    /// Load a character template from a TOML file
+    ///
+    /// The template sets heritage, gender, class template, attributes and
+    /// skills; appearance keeps its defaults. Without a `name` in the file,
+    /// the file stem is used. Attribute points and skill credits are checked
+    /// against what character creation allows, so an invalid build fails here
+    /// instead of being rejected by the server.
+    ///
+    /// ```toml
+    /// heritage = "Aluvian"
+    /// gender = "Female"
+    ///
+    /// [attributes]
+    /// strength = 10
+    /// endurance = 40
+    /// coordination = 60
+    /// quickness = 10
+    /// focus = 100
+    /// self = 100
+    ///
+    /// [skills]
+    /// war_magic = "specialized"
+    /// life_magic = "trained"
+    /// ```
+    pub fn from_template(path: impl AsRef<Path>) -> Result<Self, TemplateError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        let default_name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self::from_template_str(&contents, default_name)
+    }
+
+    /// Parse a character template from TOML; see [`CharacterBuilder::from_template`]
+    pub fn from_template_str(contents: &str, default_name: String) -> Result<Self, TemplateError> {
+        let template: CharacterTemplate = toml::from_str(contents)?;
+
+        let attrs = &template.attributes;
+        let attributes = [
+            ("strength", attrs.strength),
+            ("endurance", attrs.endurance),
+            ("coordination", attrs.coordination),
+            ("quickness", attrs.quickness),
+            ("focus", attrs.focus),
+            ("self", attrs.self_),
+        ];
+        for (name, value) in attributes {
+            if !ATTRIBUTE_RANGE.contains(&value) {
+                return Err(TemplateError::AttributeOutOfRange { name, value });
+            }
+        }
+        let total: u32 = attributes.iter().map(|(_, value)| value).sum();
+        if total > ATTRIBUTE_POINTS {
+            return Err(TemplateError::TooManyAttributePoints { total });
+        }
+
+        let mut builder = Self::new(template.name.unwrap_or(default_name))
+            .heritage(parse_heritage(&template.heritage)?)
+            .gender(parse_gender(&template.gender)?)
+            .attributes(
+                attrs.strength,
+                attrs.endurance,
+                attrs.coordination,
+                attrs.quickness,
+                attrs.focus,
+                attrs.self_,
+            );
+        builder.config.template_num = template.class;
+        builder.config.start_area = template.start_area;
+
+        let mut cost = 0;
+        for (skill, training) in &template.skills {
+            let &(id, _, train_cost, specialize_cost) = SKILL_COSTS
+                .iter()
+                .find(|(_, key, _, _)| *key == skill.as_str())
+                .ok_or_else(|| TemplateError::UnknownSkill(skill.clone()))?;
+            let (advancement, skill_cost) = match training {
+                SkillTraining::Trained => (SKILL_TRAINED, train_cost),
+                SkillTraining::Specialized => (SKILL_SPECIALIZED, specialize_cost),
+            };
+            cost += skill_cost;
+            builder.config.skills.list[id] = RawSkillAdvancementClass(advancement);
+        }
+        if cost > SKILL_CREDITS {
+            return Err(TemplateError::TooManySkillCredits { cost });
+        }
+
+        Ok(builder)
+    }
+
+    /// Character to create for an empty account, from `[character_creation]`
+    ///
+    /// `None` when creation is disabled. Without a template this is the
+    /// default build under a test name; the client renames it to its
+    /// auto-login character when it has one.
+    pub fn from_config(config: &CharacterCreationConfig) -> Result<Option<Self>, TemplateError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        match config.template_path() {
+            Some(path) => Self::from_template(path).map(Some),
+            None => Ok(Some(Self::new_test_character())),
+        }
+    }
+
+    /// Set the character's name
+    pub fn name(mut self, name: String) -> Self {
+        self.config.name = name;
+        self
+    }
+
     /// Create a character with a timestamped name for testing
     pub fn new_test_character() -> Self {
         let char_name = format!("TestChar{}", chrono::Utc::now().timestamp() % 10000);
@@ -110,3 +358,88 @@ impl CharacterBuilder {
         AceCharGenResult::from_generic(self.config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAGE: &str = r#"
+heritage = "Aluvian"
+gender = "Female"
+
+[attributes]
+strength = 10
+endurance = 40
+coordination = 60
+quickness = 10
+focus = 100
+self = 100
+
+[skills]
+war_magic = "specialized"
+life_magic = "trained"
+mana_conversion = "trained"
+"#;
+
+    #[test]
+    fn test_template_sets_attributes_and_skills() {
+        let builder = CharacterBuilder::from_template_str(MAGE, "mage1".to_string()).unwrap();
+        let config = &builder.config;
+
+        assert_eq!(config.name, "mage1");
+        assert_eq!(config.focus, 100);
+        assert_eq!(config.skills.list[34].0, SKILL_SPECIALIZED);
+        assert_eq!(config.skills.list[33].0, SKILL_TRAINED);
+        assert_eq!(config.skills.list[6].0, 0);
+    }
+
+    #[test]
+    fn test_config_builds_from_template_or_default() {
+        let disabled = CharacterCreationConfig::default();
+        assert!(CharacterBuilder::from_config(&disabled).unwrap().is_none());
+
+        let default_build = CharacterCreationConfig {
+            enabled: true,
+            template: None,
+        };
+        let builder = CharacterBuilder::from_config(&default_build)
+            .unwrap()
+            .unwrap();
+        assert!(builder.config.name.starts_with("TestChar"));
+
+        let dir = std::env::temp_dir().join(format!("gromnie-chargen-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("mage.toml");
+        std::fs::write(&path, MAGE).unwrap();
+        let from_template = CharacterCreationConfig {
+            enabled: true,
+            template: Some(path),
+        };
+        let builder = CharacterBuilder::from_config(&from_template)
+            .unwrap()
+            .unwrap();
+        assert_eq!(builder.config.name, "mage");
+        assert_eq!(builder.config.self_, 100);
+    }
+
+    #[test]
+    fn test_template_rejects_invalid_builds() {
+        let too_many_points = MAGE.replace("strength = 10", "strength = 100");
+        assert!(matches!(
+            CharacterBuilder::from_template_str(&too_many_points, String::new()),
+            Err(TemplateError::TooManyAttributePoints { total: 420 })
+        ));
+
+        let too_many_credits = format!("{}void_magic = \"specialized\"\n", MAGE);
+        assert!(matches!(
+            CharacterBuilder::from_template_str(&too_many_credits, String::new()),
+            Err(TemplateError::TooManySkillCredits { .. })
+        ));
+
+        let unknown_skill = format!("{}basket_weaving = \"trained\"\n", MAGE);
+        assert!(matches!(
+            CharacterBuilder::from_template_str(&unknown_skill, String::new()),
+            Err(TemplateError::UnknownSkill(skill)) if skill == "basket_weaving"
+        ));
+    }
+}
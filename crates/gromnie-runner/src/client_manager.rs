@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{RwLock, mpsc, watch};
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::client_runner::{
+    ClientConfig, EventBusManager, MultiClientConsumerFactory, TransportFactory,
+    create_client_from_config, run_client_internal,
+};
+use crate::event_wrapper::EventWrapper;
+use gromnie_client::client::{Client, MessageTrace, Scene};
+use gromnie_events::SimpleClientAction;
+
+/// How long `shutdown_all` waits for clients to finish before giving up on them
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Errors from starting a [`ClientManager`]
+#[derive(Debug, thiserror::Error)]
+pub enum ClientManagerError {
+    #[error("Client ID {0} is used by more than one client config")]
+    DuplicateClientId(u32),
+    #[error("Failed to create client {client_id}: {source}")]
+    CreateClient {
+        client_id: u32,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Lifecycle of one managed client's network loop
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClientStatus {
+    /// The network loop is running (connecting, in character select, in world, or reconnecting)
+    Running,
+    /// The network loop exited, e.g. after a disconnect with reconnection disabled
+    Stopped,
+    /// The client task panicked
+    Panicked,
+}
+
+struct ManagedClient {
+    config: ClientConfig,
+    client: Arc<RwLock<Client>>,
+    action_tx: mpsc::UnboundedSender<SimpleClientAction>,
+    status_rx: watch::Receiver<ClientStatus>,
+    task: JoinHandle<()>,
+}
+
+/// Runs a fleet of clients, each with its own account, in one process
+///
+/// Unlike [`run_multi_client`](crate::run_multi_client), which generates
+/// numbered clients for load testing, the manager takes an explicit list of
+/// client configs; [`ClientRunner`](crate::ClientRunner) uses it for
+/// `with_clients` runs with more than one account. Every client gets its own
+/// event bus and consumer, so one client disconnecting or failing leaves the
+/// others running. Clients only stop together when
+/// [`ClientManager::shutdown_all`] is called.
+pub struct ClientManager {
+    clients: BTreeMap<u32, ManagedClient>,
+    shutdown_tx: watch::Sender<bool>,
+}
+
+impl ClientManager {
+    /// Start one client per config, waiting `spawn_interval` between them so
+    /// the server isn't hit with every login at once. Client IDs must be unique.
+    pub async fn start(
+        configs: Vec<ClientConfig>,
+        consumer_factory: Arc<dyn MultiClientConsumerFactory>,
+        transport_factory: Option<TransportFactory>,
+        spawn_interval: Duration,
+        message_trace: Option<MessageTrace>,
+    ) -> Result<Self, ClientManagerError> {
+        check_unique_ids(&configs)?;
+
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let mut clients = BTreeMap::new();
+
+        for (index, config) in configs.into_iter().enumerate() {
+            if index > 0 {
+                tokio::time::sleep(spawn_interval).await;
+            }
+
+            let event_bus_manager = EventBusManager::new(100);
+            let (raw_event_tx, raw_event_rx) = mpsc::channel::<gromnie_events::ClientEvent>(256);
+
+            let event_wrapper = EventWrapper::new(config.id, event_bus_manager.event_bus.clone());
+            tokio::spawn(async move {
+                event_wrapper.run(raw_event_rx).await;
+            });
+            let event_rx = event_bus_manager.subscribe();
+
+            let (mut client, action_tx) =
+                match create_client_from_config(&config, raw_event_tx, transport_factory.as_ref())
+                    .await
+                {
+                    Ok(created) => created,
+                    Err(source) => {
+                        // Stop the clients already started rather than leaving them orphaned
+                        let _ = shutdown_tx.send(true);
+                        return Err(ClientManagerError::CreateClient {
+                            client_id: config.id,
+                            source,
+                        });
+                    }
+                };
+            client.set_message_trace(message_trace.clone());
+            let client = Arc::new(RwLock::new(client));
+
+            let event_consumer =
+                consumer_factory.create_consumer(config.id, &config, action_tx.clone());
+
+            let inner = tokio::spawn(run_client_internal(
+                client.clone(),
+                event_rx,
+                event_consumer,
+                Some(shutdown_rx.clone()),
+            ));
+
+            // Watch the client task so a panic only marks that client as failed
+            let (status_tx, status_rx) = watch::channel(ClientStatus::Running);
+            let client_id = config.id;
+            let task = tokio::spawn(async move {
+                let status = match inner.await {
+                    Ok(()) => ClientStatus::Stopped,
+                    Err(e) if e.is_panic() => {
+                        error!("[Client {}] Task panicked: {:?}", client_id, e);
+                        ClientStatus::Panicked
+                    }
+                    Err(_) => ClientStatus::Stopped,
+                };
+                info!("[Client {}] Stopped ({:?})", client_id, status);
+                let _ = status_tx.send(status);
+            });
+
+            info!(
+                "[Client {}] Started for account {}",
+                config.id, config.account_name
+            );
+            clients.insert(
+                config.id,
+                ManagedClient {
+                    config,
+                    client,
+                    action_tx,
+                    status_rx,
+                    task,
+                },
+            );
+        }
+
+        Ok(Self {
+            clients,
+            shutdown_tx,
+        })
+    }
+
+    /// IDs of all managed clients, in ascending order
+    pub fn client_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.clients.keys().copied()
+    }
+
+    /// Config a client was started with
+    pub fn config(&self, client_id: u32) -> Option<&ClientConfig> {
+        self.clients.get(&client_id).map(|managed| &managed.config)
+    }
+
+    /// Lifecycle status of one client
+    pub fn status(&self, client_id: u32) -> Option<ClientStatus> {
+        self.clients
+            .get(&client_id)
+            .map(|managed| *managed.status_rx.borrow())
+    }
+
+    /// Lifecycle status of every client, in ascending ID order
+    pub fn statuses(&self) -> Vec<(u32, ClientStatus)> {
+        self.clients
+            .iter()
+            .map(|(id, managed)| (*id, *managed.status_rx.borrow()))
+            .collect()
+    }
+
+    /// Current scene of one client (connecting, character select, in world, ...)
+    pub async fn scene(&self, client_id: u32) -> Option<Scene> {
+        let managed = self.clients.get(&client_id)?;
+        Some(managed.client.read().await.scene.clone())
+    }
+
+    /// Send an action to one client. Returns false if the client is unknown or stopped.
+    pub fn send_action(&self, client_id: u32, action: SimpleClientAction) -> bool {
+        self.clients
+            .get(&client_id)
+            .is_some_and(|managed| managed.action_tx.send(action).is_ok())
+    }
+
+    /// Signal every client to stop and wait for them to finish
+    pub async fn shutdown_all(self) {
+        info!("Shutting down {} clients", self.clients.len());
+        let _ = self.shutdown_tx.send(true);
+
+        let wait = async {
+            for (_, managed) in self.clients {
+                let _ = managed.task.await;
+            }
+        };
+        if tokio::time::timeout(SHUTDOWN_TIMEOUT, wait).await.is_err() {
+            warn!("Shutdown timeout reached before every client stopped");
+        }
+    }
+}
+
+fn check_unique_ids(configs: &[ClientConfig]) -> Result<(), ClientManagerError> {
+    let mut seen = BTreeSet::new();
+    for config in configs {
+        if !seen.insert(config.id) {
+            return Err(ClientManagerError::DuplicateClientId(config.id));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client_runner::FnConsumerFactory;
+    use crate::event_consumer::{EventConsumer, LoggingConsumer};
+    use gromnie_client::transport::NullTransport;
+
+    fn config(id: u32, account: &str) -> ClientConfig {
+        ClientConfig::new(
+            id,
+            "127.0.0.1:9000".to_string(),
+            account.to_string(),
+            "password".to_string(),
+        )
+    }
+
+    async fn start_offline(
+        configs: Vec<ClientConfig>,
+    ) -> Result<ClientManager, ClientManagerError> {
+        let consumers = Arc::new(FnConsumerFactory::new(|_, _, action_tx| {
+            Box::new(LoggingConsumer::new(action_tx)) as Box<dyn EventConsumer>
+        }));
+        let transport: TransportFactory =
+            Arc::new(|_: &ClientConfig| Box::new(NullTransport) as Box<_>);
+        ClientManager::start(configs, consumers, Some(transport), Duration::ZERO, None).await
+    }
+
+    #[test]
+    fn test_duplicate_ids_rejected() {
+        assert!(check_unique_ids(&[config(0, "crafter"), config(1, "buffer")]).is_ok());
+
+        let result = check_unique_ids(&[config(0, "crafter"), config(0, "buffer")]);
+        assert!(matches!(
+            result,
+            Err(ClientManagerError::DuplicateClientId(0))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clients_keep_their_config_ids() {
+        let manager = start_offline(vec![config(7, "crafter"), config(3, "buffer")])
+            .await
+            .unwrap();
+
+        assert_eq!(manager.client_ids().collect::<Vec<_>>(), vec![3, 7]);
+        assert_eq!(manager.config(7).unwrap().account_name, "crafter");
+        assert_eq!(manager.config(3).unwrap().account_name, "buffer");
+        assert_eq!(manager.status(7), Some(ClientStatus::Running));
+
+        let action = || SimpleClientAction::LogScriptMessage {
+            script_id: "test".to_string(),
+            message: "hello".to_string(),
+        };
+        assert!(manager.send_action(3, action()));
+        // IDs between the configured ones don't belong to anyone
+        assert!(manager.config(5).is_none());
+        assert_eq!(manager.status(5), None);
+        assert!(!manager.send_action(5, action()));
+
+        manager.shutdown_all().await;
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_ids_start_nothing() {
+        let result = start_offline(vec![config(1, "crafter"), config(1, "buffer")]).await;
+        assert!(matches!(
+            result,
+            Err(ClientManagerError::DuplicateClientId(1))
+        ));
+    }
+}
@@ -7,23 +7,24 @@ use tracing::{error, info};
 use crate::event_bus::{EventBus, EventEnvelope};
 use crate::event_consumer::EventConsumer;
 use crate::event_wrapper::EventWrapper;
-use gromnie_client::client::Client;
-use gromnie_client::transport::ClientTransport;
+use gromnie_client::client::{Client, MessageTrace, ReconnectAction};
+use gromnie_client::transport::{ClientTransport, NativeUdpTransport};
 
 // Re-export ClientConfig from gromnie-client
 pub use gromnie_client::config::ClientConfig;
 
 pub type TransportFactory = Arc<dyn Fn(&ClientConfig) -> Box<dyn ClientTransport> + Send + Sync>;
 
+/// Create a client from its config. Fails if `config.bind_addr` can't be bound.
 pub(crate) async fn create_client_from_config(
     config: &ClientConfig,
     raw_event_tx: mpsc::Sender<gromnie_events::ClientEvent>,
     transport_factory: Option<&TransportFactory>,
-) -> (
+) -> std::io::Result<(
     Client,
     mpsc::UnboundedSender<gromnie_events::SimpleClientAction>,
-) {
-    if let Some(factory) = transport_factory {
+)> {
+    let (mut client, action_tx) = if let Some(factory) = transport_factory {
         Client::new_with_transport(
             config.id,
             config.address.clone(),
@@ -35,6 +36,19 @@ pub(crate) async fn create_client_from_config(
             factory(config),
         )
         .await
+    } else if let Some(bind_addr) = config.bind_addr {
+        let transport = NativeUdpTransport::bind(bind_addr).await?;
+        Client::new_with_transport(
+            config.id,
+            config.address.clone(),
+            config.account_name.clone(),
+            config.password.clone(),
+            config.character_name.clone(),
+            raw_event_tx,
+            config.reconnect,
+            Box::new(transport),
+        )
+        .await
     } else {
         Client::new_with_reconnect(
             config.id,
@@ -46,7 +60,59 @@ pub(crate) async fn create_client_from_config(
             config.reconnect,
         )
         .await
+    };
+
+    client.set_port_routing(config.port_routing.clone());
+    client.set_initial_reconnect_delay_secs(config.initial_reconnect_delay_secs);
+    client.set_resume_in_world(config.resume_in_world);
+    if let Some(overrides) = &config.reconnect_override {
+        // Whether to reconnect was already settled, with command line flags
+        // taking precedence over the server's setting
+        let merged = gromnie_client::config::ReconnectConfig {
+            enabled: config.reconnect,
+            ..client.reconnect_config().with_override(overrides)
+        };
+        client.set_reconnect_config(merged);
     }
+    client.set_strict_checksums(config.strict_checksums);
+    if let Some(secs) = config.fragment_timeout_secs {
+        client.set_fragment_timeout(std::time::Duration::from_secs(secs));
+    }
+    if let Some(secs) = config.keepalive_interval_secs {
+        client.set_keepalive_interval(std::time::Duration::from_secs(secs));
+    }
+    if let Some(secs) = config.idle_timeout_secs {
+        client.set_idle_timeout(std::time::Duration::from_secs(secs));
+    }
+    if let Some(attempts) = config.max_login_attempts {
+        client.set_max_login_attempts(attempts);
+    }
+    if let Some(path) = squelch_file(&config.account_name) {
+        client.set_squelch_file(path);
+    }
+    Ok((client, action_tx))
+}
+
+/// Where an account's squelch list is saved: `squelch/<account>.toml` in the
+/// config directory
+fn squelch_file(account_name: &str) -> Option<std::path::PathBuf> {
+    let paths = gromnie_client::config::ProjectPaths::new("gromnie")?;
+    let file_name: String = account_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    Some(
+        paths
+            .config_dir()
+            .join("squelch")
+            .join(format!("{}.toml", file_name)),
+    )
 }
 
 /// Configuration for running clients - either single or multi-client
@@ -342,7 +408,13 @@ pub async fn run_client<C, F>(
     // Subscribe to the event bus for the consumer
     let event_rx = event_bus_manager.subscribe();
 
-    let (client, action_tx) = create_client_from_config(&config, raw_event_tx, None).await;
+    let (client, action_tx) = match create_client_from_config(&config, raw_event_tx, None).await {
+        Ok(created) => created,
+        Err(e) => {
+            error!("[Client {}] Failed to create client: {}", config.id, e);
+            return;
+        }
+    };
 
     // Wrap client in Arc<RwLock<>> for shared access
     let client = Arc::new(RwLock::new(client));
@@ -379,7 +451,13 @@ pub async fn run_client_with_consumers<F>(
         event_wrapper.run(raw_event_rx).await;
     });
 
-    let (client, action_tx) = create_client_from_config(&config, raw_event_tx, None).await;
+    let (client, action_tx) = match create_client_from_config(&config, raw_event_tx, None).await {
+        Ok(created) => created,
+        Err(e) => {
+            error!("[Client {}] Failed to create client: {}", config.id, e);
+            return;
+        }
+    };
 
     // Wrap client in Arc<RwLock<>> for shared access
     let client = Arc::new(RwLock::new(client));
@@ -405,7 +483,7 @@ pub async fn run_client_with_consumers<F>(
                             info!(target: "events", "Event consumer {} received shutdown signal", idx);
                             break;
                         }
-                        consumer.handle_event(envelope);
+                        crate::event_consumer::deliver(consumer.as_mut(), envelope);
                     }
                     Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
                         error!(target: "events", "Consumer {} lagged, {} messages were skipped", idx, skipped);
@@ -469,7 +547,13 @@ pub async fn run_client_with_action_channel<C, F>(
     // Subscribe to the event bus for the consumer
     let event_rx = event_bus_manager.subscribe();
 
-    let (client, action_tx) = create_client_from_config(&config, raw_event_tx, None).await;
+    let (client, action_tx) = match create_client_from_config(&config, raw_event_tx, None).await {
+        Ok(created) => created,
+        Err(e) => {
+            error!("[Client {}] Failed to create client: {}", config.id, e);
+            return;
+        }
+    };
 
     // Wrap client in Arc<RwLock<>> for shared access
     let client = Arc::new(RwLock::new(client));
@@ -493,18 +577,30 @@ pub async fn run_client_with_action_channel<C, F>(
 /// Internal client runner implementation
 pub(crate) async fn run_client_internal(
     client: Arc<RwLock<Client>>,
-    mut event_rx: broadcast::Receiver<EventEnvelope>,
-    mut event_consumer: Box<dyn EventConsumer>,
+    event_rx: broadcast::Receiver<EventEnvelope>,
+    event_consumer: Box<dyn EventConsumer>,
     shutdown_rx: Option<tokio::sync::watch::Receiver<bool>>,
 ) {
-    // Spawn event handler task
-    let event_task = tokio::spawn(async move {
+    let event_task = spawn_event_handler(event_rx, event_consumer);
+
+    // Run the main client loop
+    run_client_loop(client, shutdown_rx).await;
+
+    finish_event_handler(event_task).await;
+}
+
+/// Spawn the task that feeds every event on the bus to `event_consumer`
+pub(crate) fn spawn_event_handler(
+    mut event_rx: broadcast::Receiver<EventEnvelope>,
+    mut event_consumer: Box<dyn EventConsumer>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
         info!(target: "events", "Event handler task started");
 
         loop {
             match event_rx.recv().await {
                 Ok(envelope) => {
-                    event_consumer.handle_event(envelope);
+                    crate::event_consumer::deliver(event_consumer.as_mut(), envelope);
                 }
                 Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
                     error!(target: "events", "Event receiver lagged, {} messages were skipped", skipped);
@@ -517,11 +613,11 @@ pub(crate) async fn run_client_internal(
         }
 
         info!(target: "events", "Event handler task stopped");
-    });
-
-    // Run the main client loop
-    run_client_loop(client, shutdown_rx).await;
+    })
+}
 
+/// Give the event handler task a moment to drain before shutting down
+pub(crate) async fn finish_event_handler(event_task: tokio::task::JoinHandle<()>) {
     // Wait for event handler task to finish with a timeout
     info!(target: "events", "Waiting for event handler task to finish");
     let timeout = tokio::time::Duration::from_secs(1);
@@ -538,6 +634,37 @@ pub(crate) async fn run_client_internal(
     }
 }
 
+/// Action channel that publishes script reloads on the event bus, where the
+/// script consumer handles them, and passes every other action to the client
+pub(crate) fn forward_reloads_to_bus(
+    action_tx: mpsc::UnboundedSender<gromnie_events::SimpleClientAction>,
+    event_sender: crate::event_bus::EventSender,
+) -> mpsc::UnboundedSender<gromnie_events::SimpleClientAction> {
+    use gromnie_events::{EventSource, SimpleClientAction, SystemEvent};
+
+    let (proxy_tx, mut proxy_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(action) = proxy_rx.recv().await {
+            match action {
+                SimpleClientAction::ReloadScripts { script_dir } => {
+                    event_sender.publish(EventEnvelope::system_event(
+                        SystemEvent::ReloadScripts { script_dir },
+                        0, // client_id (use 0 for system-wide events)
+                        0, // sequence
+                        EventSource::System,
+                    ));
+                }
+                action => {
+                    if action_tx.send(action).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    proxy_tx
+}
+
 /// Run the main client network loop
 async fn run_client_loop(
     client: Arc<RwLock<Client>>,
@@ -566,11 +693,6 @@ async fn run_client_loop(
 
     // Main network loop
     let mut buf = [0u8; 1024];
-    let mut last_keepalive = tokio::time::Instant::now();
-    // Send keepalive every 5 seconds to stay well within the server's timeout window
-    // (Server timeout is configurable but defaults to 60s for gameplay, could be as low as 10s)
-    let keepalive_interval = tokio::time::Duration::from_secs(5);
-
     // Tick interval for checking retries and timeouts
     let tick_interval = tokio::time::Duration::from_millis(100); // Check every 100ms
     let mut last_tick = tokio::time::Instant::now();
@@ -618,20 +740,64 @@ async fn run_client_loop(
                     break;
                 }
 
-                // Check if we should attempt reconnection (separate from retry logic)
-                if client.write().await.should_reconnect() {
+                // Drop the connection if the server has gone quiet, even if
+                // we haven't tried to send anything
+                client
+                    .write()
+                    .await
+                    .check_idle_timeout(gromnie_client::instant::Instant::now());
+
+                // Stop waiting on a character the server never verified
+                client
+                    .write()
+                    .await
+                    .check_character_creation_timeout(gromnie_client::instant::Instant::now());
+
+                // Advance reconnection state machine (separate from retry logic)
+                {
                     let mut client_guard = client.write().await;
-                    info!("Reconnection timer expired, attempting reconnection...");
-                    if !client_guard.start_reconnection() {
-                        info!("Reconnection not available (max attempts or disabled), exiting loop");
-                        break;
+                    match client_guard.tick_reconnect(gromnie_client::instant::Instant::now()) {
+                        ReconnectAction::None => {}
+                        ReconnectAction::GiveUp(failure) => {
+                            info!("Reconnection not available ({}), exiting loop", failure);
+                            break;
+                        }
+                        ReconnectAction::Reconnect { .. } => {
+                            info!("Reconnection timer expired, attempting reconnection...");
+                            // Send initial LoginRequest for reconnection
+                            if let Err(e) = client_guard.do_login().await {
+                                error!("Failed to send LoginRequest for reconnection: {}", e);
+                            }
+                        }
                     }
-                    // Send initial LoginRequest for reconnection
-                    if let Err(e) = client_guard.do_login().await {
-                        error!("Failed to send LoginRequest for reconnection: {}", e);
+                }
+
+                // Ping the server periodically to measure round-trip time
+                {
+                    let mut client_guard = client.write().await;
+                    if client_guard.tick_ping(gromnie_client::instant::Instant::now())
+                        && let Err(e) = client_guard.send_pending_messages().await
+                    {
+                        error!("Failed to send ping: {}", e);
                     }
                 }
 
+                // Walk toward a script's move_to destination
+                {
+                    let mut client_guard = client.write().await;
+                    if client_guard.tick_movement(gromnie_client::instant::Instant::now())
+                        && let Err(e) = client_guard.send_pending_messages().await
+                    {
+                        error!("Failed to send position update: {}", e);
+                    }
+                }
+
+                // Drop messages whose missing fragments never arrived
+                client
+                    .write()
+                    .await
+                    .sweep_fragments(gromnie_client::instant::Instant::now());
+
                 // Check if we should retry in current state
                 {
                     let mut client_guard = client.write().await;
@@ -639,12 +805,10 @@ async fn run_client_loop(
                         use gromnie_client::client::Scene;
                         match &client_guard.scene {
                             Scene::Connecting(_connecting) => {
-                                info!("Retrying LoginRequest...");
+                                // do_login counts the attempt and restarts the retry timer
                                 if let Err(e) = client_guard.do_login().await {
                                     error!("Failed to send LoginRequest retry: {}", e);
-                                }
-                                if let Some(connecting) = client_guard.scene.as_connecting_mut() {
-                                    connecting.update_retry_time();
+                                    client_guard.update_retry_time();
                                 }
                             }
                             Scene::CharacterSelect(_) => {
@@ -664,12 +828,15 @@ async fn run_client_loop(
                     }
                 }
 
-                // Send keepalive if needed
-                if last_keepalive.elapsed() >= keepalive_interval {
-                    if let Err(e) = client.write().await.send_keepalive().await {
-                        error!("Failed to send keep-alive: {}", e);
+                // Send keepalive if needed, and report connection stats at the same cadence
+                {
+                    let mut client_guard = client.write().await;
+                    if client_guard.keepalive_due(gromnie_client::instant::Instant::now()) {
+                        if let Err(e) = client_guard.send_keepalive().await {
+                            error!("Failed to send keep-alive: {}", e);
+                        }
+                        client_guard.publish_stats();
                     }
-                    last_keepalive = tokio::time::Instant::now();
                 }
             }
             _ = async {
@@ -689,11 +856,43 @@ async fn run_client_loop(
         }
     }
 
+    // Flush anything still queued (e.g. actions sent just before shutdown),
+    // then log the character off so it doesn't linger in the world, all
+    // bounded so a dead connection can't hold up the exit
+    {
+        let mut client_guard = client.write().await;
+        client_guard.process_actions();
+        client_guard.process_game_actions();
+        drain_on_shutdown(&mut client_guard).await;
+        if matches!(
+            client_guard.scene,
+            gromnie_client::client::Scene::InWorld(_)
+        ) {
+            info!("Logging off before shutdown");
+            // Only fails when not in the world, which was just checked
+            let _ = client_guard.logout();
+            drain_on_shutdown(&mut client_guard).await;
+        }
+    }
+
     let client_id = client.read().await.client_id();
     info!("Client {} network loop stopped", client_id);
     info!("Client task shutting down - cleaning up network connections...");
 }
 
+/// Send everything queued, giving up after `SHUTDOWN_DRAIN_TIMEOUT`
+async fn drain_on_shutdown(client: &mut Client) {
+    let drain_timeout = gromnie_client::client::SHUTDOWN_DRAIN_TIMEOUT;
+    match tokio::time::timeout(drain_timeout, client.drain_outgoing_messages(drain_timeout)).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => error!("Failed to flush outgoing messages on shutdown: {}", e),
+        Err(_) => error!(
+            "Gave up flushing outgoing messages after {:?}",
+            drain_timeout
+        ),
+    }
+}
+
 /// Create a new EventBusManager for managing a shared event bus
 pub fn create_event_bus_manager() -> EventBusManager {
     EventBusManager::new(100)
@@ -712,6 +911,8 @@ pub fn create_event_bus_manager() -> EventBusManager {
 /// * `consumer_factory` - Factory for creating consumers per client
 /// * `client_config_generator` - Function to generate client config for each client ID
 /// * `shutdown_rx` - Optional shutdown signal receiver
+/// * `transport_factory` - Optional custom transport for each client
+/// * `message_trace` - Optional trace shared by all clients, flushed on shutdown
 ///
 /// # Returns
 /// Statistics collected during the run
@@ -721,6 +922,7 @@ pub async fn run_multi_client<G>(
     client_config_generator: G,
     shutdown_rx: Option<watch::Receiver<bool>>,
     transport_factory: Option<TransportFactory>,
+    message_trace: Option<MessageTrace>,
 ) -> Arc<MultiClientStats>
 where
     G: Fn(u32) -> ClientConfig + Send + Sync + 'static,
@@ -764,6 +966,7 @@ where
         let mut shutdown_rx = shutdown_rx.clone();
         let spawn_interval = config.spawn_interval_ms;
         let transport_factory = transport_factory.clone();
+        let message_trace = message_trace.clone();
 
         let handle = tokio::spawn(async move {
             // Rate limiting: stagger client connections
@@ -792,9 +995,24 @@ where
             let event_rx = event_bus_manager.subscribe();
 
             // Create the client
-            let (client, action_tx) =
-                create_client_from_config(&client_config, raw_event_tx, transport_factory.as_ref())
-                    .await;
+            let (mut client, action_tx) = match create_client_from_config(
+                &client_config,
+                raw_event_tx,
+                transport_factory.as_ref(),
+            )
+            .await
+            {
+                Ok(created) => created,
+                Err(e) => {
+                    error!(
+                        "[Client {}] Failed to create client: {}",
+                        client_config.id, e
+                    );
+                    stats.task_failures.fetch_add(1, Ordering::SeqCst);
+                    return;
+                }
+            };
+            client.set_message_trace(message_trace);
 
             // Wrap client in Arc<RwLock<>> for shared access
             let client = Arc::new(RwLock::new(client));
@@ -860,6 +1078,12 @@ where
         }
     }
 
+    if let Some(trace) = &message_trace
+        && let Err(e) = trace.flush()
+    {
+        error!("Failed to flush message trace: {}", e);
+    }
+
     stats
 }
 
@@ -867,6 +1091,8 @@ where
 pub enum RunResult {
     /// Single client completed
     Single,
+    /// Single client couldn't be created, e.g. its local address couldn't be bound
+    Failed(std::io::Error),
     /// Multi-client run completed with statistics
     Multi(Arc<MultiClientStats>),
 }
@@ -962,7 +1188,13 @@ where
             let event_rx = event_bus_manager.subscribe();
 
             let (client_obj, action_tx) =
-                create_client_from_config(&client, raw_event_tx, None).await;
+                match create_client_from_config(&client, raw_event_tx, None).await {
+                    Ok(created) => created,
+                    Err(e) => {
+                        error!("[Client {}] Failed to create client: {}", client.id, e);
+                        return RunResult::Failed(e);
+                    }
+                };
 
             // Wrap client in Arc<RwLock<>> for shared access
             let client_obj = Arc::new(RwLock::new(client_obj));
@@ -1014,6 +1246,7 @@ where
                         config_gen,
                         shutdown_rx,
                         None,
+                        None,
                     )
                     .await
                 }
@@ -1033,6 +1266,7 @@ where
                         default_gen,
                         shutdown_rx,
                         None,
+                        None,
                     )
                     .await
                 }
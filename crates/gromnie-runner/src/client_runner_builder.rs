@@ -5,13 +5,15 @@
 use std::sync::Arc;
 use tokio::sync::{mpsc, watch};
 
-use crate::client_runner::TransportFactory;
+use crate::client_runner::{MultiClientConsumerFactory, MultiClientStats, TransportFactory};
 use crate::event_consumer::EventConsumer;
+use crate::replay::ReplayConfig;
+use gromnie_client::client::MessageTrace;
 use gromnie_events::SimpleClientAction;
 
 // Re-export types
 pub use gromnie_client::config::ClientConfig;
-pub use gromnie_events::{ConsumerContext, ConsumerFactory};
+pub use gromnie_events::{ConsumerContext, ConsumerFactory, DeliveryMode};
 
 /// Client mode - either static configs or dynamic generation
 pub enum ClientMode {
@@ -74,6 +76,8 @@ pub struct ClientRunnerBuilder {
     event_bus_capacity: usize,
     app_config: Option<gromnie_client::config::GromnieConfig>,
     transport_factory: Option<TransportFactory>,
+    message_trace: Option<MessageTrace>,
+    replay: Option<ReplayConfig>,
 }
 
 impl ClientRunnerBuilder {
@@ -87,6 +91,8 @@ impl ClientRunnerBuilder {
             event_bus_capacity: 100,
             app_config: None,
             transport_factory: None,
+            message_trace: None,
+            replay: None,
         }
     }
 
@@ -251,6 +257,24 @@ impl ClientRunnerBuilder {
         self
     }
 
+    /// Record every message each client sends and receives to `trace`.
+    ///
+    /// All clients share the trace; it is flushed when the run finishes.
+    pub fn with_message_trace(mut self, trace: MessageTrace) -> Self {
+        self.message_trace = Some(trace);
+        self
+    }
+
+    /// Replay a recorded trace instead of connecting to a server.
+    ///
+    /// The client gets no socket; received messages from the trace are fed
+    /// through it so consumers and scripts see the recorded events. Actions
+    /// are logged and dropped. Only single-client runs replay.
+    pub fn with_replay(mut self, replay: ReplayConfig) -> Self {
+        self.replay = Some(replay);
+        self
+    }
+
     /// Build the ClientRunner
     ///
     /// If config was not provided via `with_config()`, it will be loaded
@@ -307,17 +331,47 @@ impl ClientRunnerBuilder {
             }
         };
 
+        // Record events when a `[database]` section is configured
+        let mut consumers = self.consumers;
+        #[cfg(feature = "sqlite")]
+        if let Some(ref database) = config.database {
+            match crate::sqlite_consumer::SqliteConsumer::factory(database) {
+                Ok(factory) => consumers.push(Box::new(factory)),
+                Err(e) => tracing::error!(
+                    "Failed to open event database {}: {}",
+                    database.path.display(),
+                    e
+                ),
+            }
+        }
+        #[cfg(not(feature = "sqlite"))]
+        if let Some(ref database) = config.database {
+            tracing::warn!(
+                "Ignoring [database] section ({}): built without the sqlite feature",
+                database.path.display()
+            );
+        }
+
+        // Emit events as tracing spans when `trace_events` is set
+        if config.trace_events {
+            consumers.push(Box::new(
+                crate::event_consumer::TracingConsumer::from_factory(),
+            ));
+        }
+
         // Note: Scripting consumer will be created separately after client creation
         // because it needs Arc<RwLock<Client>> which isn't available in the factory pattern
 
         Ok(ClientRunner {
             mode,
-            consumers: self.consumers,
+            consumers,
             action_channel: self.action_channel,
             shutdown_rx: self.shutdown_rx,
             event_bus_capacity: self.event_bus_capacity,
             app_config: Some(config),
             transport_factory: self.transport_factory,
+            message_trace: self.message_trace,
+            replay: self.replay,
         })
     }
 }
@@ -338,12 +392,16 @@ pub struct ClientRunner {
     pub(crate) event_bus_capacity: usize,
     pub(crate) app_config: Option<gromnie_client::config::GromnieConfig>,
     pub(crate) transport_factory: Option<TransportFactory>,
+    pub(crate) message_trace: Option<MessageTrace>,
+    pub(crate) replay: Option<ReplayConfig>,
 }
 
 /// Result from running clients
 pub enum RunResult {
     /// Single client completed
     Single,
+    /// Single client couldn't be created, e.g. its local address couldn't be bound
+    Failed(std::io::Error),
     /// Multi-client run completed with statistics
     Multi(Arc<crate::client_runner::MultiClientStats>),
 }
@@ -409,24 +467,29 @@ impl ClientRunner {
         // Create event bus
         let event_bus_manager = Arc::new(EventBusManager::new(self.event_bus_capacity));
 
-        // Setup SIGUSR2 handler if scripting is enabled
-        if let Some(ref app_config) = self.app_config
+        // Setup SIGUSR2 handler if scripting is enabled. The handle is held
+        // until the client stops so the handler is removed on shutdown.
+        let _reload_handle = if let Some(ref app_config) = self.app_config
             && app_config.scripting.enabled
         {
             let event_sender = event_bus_manager.create_sender(config.id);
             let script_dir = app_config.scripting.script_dir();
-            gromnie_scripting_host::setup_reload_signal_handler(move || {
-                let reload_event = EventEnvelope::system_event(
-                    SystemEvent::ReloadScripts {
-                        script_dir: script_dir.clone(),
-                    },
-                    0, // client_id (use 0 for system-wide events)
-                    0, // sequence
-                    EventSource::System,
-                );
-                event_sender.publish(reload_event);
-            });
-        }
+            Some(gromnie_scripting_host::setup_reload_signal_handler(
+                move || {
+                    let reload_event = EventEnvelope::system_event(
+                        SystemEvent::ReloadScripts {
+                            script_dir: script_dir.clone(),
+                        },
+                        0, // client_id (use 0 for system-wide events)
+                        0, // sequence
+                        EventSource::System,
+                    );
+                    event_sender.publish(reload_event);
+                },
+            ))
+        } else {
+            None
+        };
 
         // Create raw event channel
         let (raw_event_tx, raw_event_rx) = mpsc::channel::<gromnie_events::ClientEvent>(256);
@@ -440,17 +503,70 @@ impl ClientRunner {
         // Subscribe to the event bus
         let event_rx = event_bus_manager.subscribe();
 
+        // A replay has no server to talk to
+        let transport_factory: Option<TransportFactory> = if self.replay.is_some() {
+            Some(Arc::new(|_: &ClientConfig| {
+                Box::new(gromnie_client::transport::NullTransport) as Box<_>
+            }))
+        } else {
+            self.transport_factory.clone()
+        };
+
         // Create the client
-        let (client, action_tx) = crate::client_runner::create_client_from_config(
+        let (mut client, action_tx) = match crate::client_runner::create_client_from_config(
             &config,
             raw_event_tx,
-            self.transport_factory.as_ref(),
+            transport_factory.as_ref(),
         )
-        .await;
+        .await
+        {
+            Ok(created) => created,
+            Err(e) => {
+                tracing::error!("[Client {}] Failed to create client: {}", config.id, e);
+                return RunResult::Failed(e);
+            }
+        };
+        client.set_message_trace(self.message_trace.clone());
+
+        // Character to create if the account turns out to be empty
+        if let Some(ref app_config) = self.app_config {
+            match crate::character_gen::CharacterBuilder::from_config(
+                &app_config.character_creation,
+            ) {
+                Ok(builder) => {
+                    client.set_character_creation(builder.map(|builder| builder.build()))
+                }
+                Err(e) => tracing::error!(
+                    "[Client {}] Not creating characters for empty accounts: {}",
+                    config.id,
+                    e
+                ),
+            }
+        }
 
         // Wrap client in Arc<RwLock<>> for shared access
         let client = Arc::new(tokio::sync::RwLock::new(client));
 
+        // Nothing can be sent during a replay, so actions only get logged
+        let action_tx = if self.replay.is_some() {
+            crate::replay::logged_action_channel(config.id)
+        } else {
+            action_tx
+        };
+
+        // Script reloads asked for by consumers (e.g. the TUI) go to the
+        // script consumer rather than the client
+        let action_tx = if let Some(ref app_config) = self.app_config
+            && app_config.scripting.enabled
+        {
+            crate::client_runner::forward_reloads_to_bus(
+                action_tx,
+                event_bus_manager.create_sender(config.id),
+            )
+        } else {
+            action_tx
+        };
+
         // Send action_tx back if requested (for TUI)
         if let Some(ref sender) = self.action_channel {
             let _ = sender.send(action_tx.clone());
@@ -469,7 +585,12 @@ impl ClientRunner {
         let mut consumers: Vec<Box<dyn EventConsumer>> = self
             .consumers
             .iter()
-            .map(|factory| factory.create(&ctx))
+            .map(|factory| {
+                crate::event_consumer::with_delivery_mode(
+                    factory.create(&ctx),
+                    factory.delivery_mode(),
+                )
+            })
             .collect();
 
         // Add scripting consumer if enabled (needs Arc<RwLock<Client>>)
@@ -491,14 +612,26 @@ impl ClientRunner {
             Box::new(crate::event_consumer::CompositeConsumer::new(consumers))
         };
 
-        // Run the client
-        crate::client_runner::run_client_internal(
-            client,
-            event_rx,
-            event_consumer,
-            self.shutdown_rx,
-        )
-        .await;
+        // Run the client, or feed it the trace
+        if let Some(replay) = self.replay {
+            let event_task = crate::client_runner::spawn_event_handler(event_rx, event_consumer);
+            crate::replay::run_replay_loop(client, replay, self.shutdown_rx).await;
+            crate::client_runner::finish_event_handler(event_task).await;
+        } else {
+            crate::client_runner::run_client_internal(
+                client,
+                event_rx,
+                event_consumer,
+                self.shutdown_rx,
+            )
+            .await;
+        }
+
+        if let Some(trace) = &self.message_trace
+            && let Err(e) = trace.flush()
+        {
+            tracing::error!("Failed to flush message trace: {}", e);
+        }
 
         RunResult::Single
     }
@@ -512,7 +645,7 @@ impl ClientRunner {
         shared_event_bus: bool,
         generator: Box<dyn Fn(u32) -> ClientConfig + Send + Sync>,
     ) -> RunResult {
-        use crate::client_runner::{MultiClientConfig, MultiClientConsumerFactory};
+        use crate::client_runner::MultiClientConfig;
 
         let multi_config = MultiClientConfig {
             server_address,
@@ -521,39 +654,6 @@ impl ClientRunner {
             shared_event_bus,
         };
 
-        // Create a factory adapter
-        struct FactoryAdapter {
-            consumers: Arc<Vec<Box<dyn ConsumerFactory>>>,
-        }
-
-        impl MultiClientConsumerFactory for FactoryAdapter {
-            fn create_consumer(
-                &self,
-                client_id: u32,
-                _client_config: &ClientConfig,
-                action_tx: mpsc::UnboundedSender<SimpleClientAction>,
-            ) -> Box<dyn EventConsumer> {
-                // Use () as config type to avoid circular dependencies
-                let ctx = ConsumerContext {
-                    client_id,
-                    client_config: &(),
-                    action_tx: action_tx.clone(),
-                };
-
-                let consumers: Vec<Box<dyn EventConsumer>> = self
-                    .consumers
-                    .iter()
-                    .map(|factory| factory.create(&ctx))
-                    .collect();
-
-                if consumers.len() == 1 {
-                    consumers.into_iter().next().unwrap()
-                } else {
-                    Box::new(crate::event_consumer::CompositeConsumer::new(consumers))
-                }
-            }
-        }
-
         let factory = Arc::new(FactoryAdapter {
             consumers: Arc::new(self.consumers),
         });
@@ -564,6 +664,7 @@ impl ClientRunner {
             generator,
             self.shutdown_rx,
             self.transport_factory,
+            self.message_trace,
         )
         .await;
 
@@ -571,12 +672,22 @@ impl ClientRunner {
     }
 
     /// Run multiple clients with static configs (internal)
+    ///
+    /// Each account gets its own event bus through a [`ClientManager`], so one
+    /// client disconnecting leaves the rest running. A shared event bus needs
+    /// the load-testing runner instead.
+    ///
+    /// [`ClientManager`]: crate::ClientManager
     async fn run_static(
         self,
         configs: Vec<ClientConfig>,
         spawn_interval_ms: u64,
         shared_event_bus: bool,
     ) -> RunResult {
+        if !shared_event_bus {
+            return self.run_managed(configs, spawn_interval_ms).await;
+        }
+
         let num_clients = configs.len() as u32;
         let server_address = configs
             .first()
@@ -606,4 +717,118 @@ impl ClientRunner {
         )
         .await
     }
+
+    /// Run a fleet of configured clients under a [`ClientManager`] (internal)
+    ///
+    /// [`ClientManager`]: crate::ClientManager
+    async fn run_managed(self, configs: Vec<ClientConfig>, spawn_interval_ms: u64) -> RunResult {
+        use std::sync::atomic::Ordering;
+
+        let stats = Arc::new(MultiClientStats::default());
+        stats
+            .attempted
+            .store(configs.len() as u32, Ordering::SeqCst);
+
+        let factory = Arc::new(FactoryAdapter {
+            consumers: Arc::new(self.consumers),
+        });
+        let manager = match crate::ClientManager::start(
+            configs,
+            factory,
+            self.transport_factory,
+            std::time::Duration::from_millis(spawn_interval_ms),
+            self.message_trace.clone(),
+        )
+        .await
+        {
+            Ok(manager) => manager,
+            Err(e) => {
+                tracing::error!("Failed to start clients: {}", e);
+                stats.task_failures.fetch_add(1, Ordering::SeqCst);
+                return RunResult::Multi(stats);
+            }
+        };
+        stats
+            .spawned
+            .store(manager.client_ids().count() as u32, Ordering::SeqCst);
+        tracing::info!("All clients started, waiting for events...");
+
+        // Wait for Ctrl+C or external shutdown
+        let external_shutdown = async {
+            match self.shutdown_rx {
+                Some(mut rx) => {
+                    while !*rx.borrow_and_update() {
+                        if rx.changed().await.is_err() {
+                            std::future::pending::<()>().await;
+                        }
+                    }
+                }
+                None => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {
+                tracing::info!("Received Ctrl+C, shutting down all clients...");
+            }
+            _ = external_shutdown => {
+                tracing::info!("Shutdown requested, shutting down all clients...");
+            }
+        }
+
+        let panicked = manager
+            .statuses()
+            .into_iter()
+            .filter(|(_, status)| *status == crate::ClientStatus::Panicked)
+            .count();
+        stats
+            .task_failures
+            .fetch_add(panicked as u32, Ordering::SeqCst);
+        manager.shutdown_all().await;
+
+        if let Some(trace) = &self.message_trace
+            && let Err(e) = trace.flush()
+        {
+            tracing::error!("Failed to flush message trace: {}", e);
+        }
+
+        RunResult::Multi(stats)
+    }
+}
+
+/// Builds each client's consumers from the builder's factories
+struct FactoryAdapter {
+    consumers: Arc<Vec<Box<dyn ConsumerFactory>>>,
+}
+
+impl MultiClientConsumerFactory for FactoryAdapter {
+    fn create_consumer(
+        &self,
+        client_id: u32,
+        _client_config: &ClientConfig,
+        action_tx: mpsc::UnboundedSender<SimpleClientAction>,
+    ) -> Box<dyn EventConsumer> {
+        // Use () as config type to avoid circular dependencies
+        let ctx = ConsumerContext {
+            client_id,
+            client_config: &(),
+            action_tx: action_tx.clone(),
+        };
+
+        let consumers: Vec<Box<dyn EventConsumer>> = self
+            .consumers
+            .iter()
+            .map(|factory| {
+                crate::event_consumer::with_delivery_mode(
+                    factory.create(&ctx),
+                    factory.delivery_mode(),
+                )
+            })
+            .collect();
+
+        if consumers.len() == 1 {
+            consumers.into_iter().next().unwrap()
+        } else {
+            Box::new(crate::event_consumer::CompositeConsumer::new(consumers))
+        }
+    }
 }
@@ -109,6 +109,9 @@ impl EventHandler for LoggingEventHandler {
             EventType::System(system_event) => {
                 tracing::info!(target: "events", "System Event: {:?}", system_event);
             }
+            EventType::Protocol(protocol_event) => {
+                tracing::trace!(target: "events", "Protocol Event: {:?}", protocol_event);
+            }
         }
     }
 }
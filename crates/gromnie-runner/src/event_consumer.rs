@@ -3,11 +3,12 @@ use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
 use tokio::sync::mpsc::UnboundedSender;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 use crate::client_runner::MultiClientStats;
-use crate::event_bus::{EventEnvelope, EventType, SystemEvent};
-use gromnie_events::{SimpleClientAction, SimpleGameEvent};
+use crate::event_bus::{ClientStateEvent, EventEnvelope, EventType, ScriptEventType, SystemEvent};
+use gromnie_client::config::{DiscordConfig, DiscordEventCategory};
+use gromnie_events::{CharacterId, DeliveryMode, SimpleClientAction, SimpleGameEvent};
 use serenity::http::Http;
 use serenity::model::id::ChannelId;
 
@@ -56,6 +57,21 @@ fn log_common_game_event(event: &GameEvent) -> bool {
             error!(target: "events", "Character error (code {}): {}", error_code, error_message);
             true
         }
+        GameEvent::ActionError {
+            error_code,
+            message,
+        } => {
+            warn!(target: "events", "Action failed (code 0x{:04X}): {}", error_code, message);
+            true
+        }
+        GameEvent::ConfirmationRequest {
+            confirmation_type,
+            context_id,
+            message,
+        } => {
+            info!(target: "events", "Confirmation request (type {}, context {}): {}", confirmation_type, context_id, message);
+            true
+        }
         _ => false,
     }
 }
@@ -93,6 +109,39 @@ fn log_common_system_event(event: &SystemEvent) -> bool {
             info!(target: "events", "Reconnecting (attempt={}, delay={}s)", attempt, delay_secs);
             true
         }
+        SystemEvent::LoginRetry {
+            attempt,
+            max_attempts,
+            ..
+        } => {
+            info!(target: "events", "Retrying login ({}/{})", attempt, max_attempts);
+            true
+        }
+        SystemEvent::Resuming {
+            character_name,
+            attempt,
+            ..
+        } => {
+            info!(target: "events", "Resuming into the world as {} (attempt={})", character_name, attempt);
+            true
+        }
+        SystemEvent::ConnectionError {
+            kind,
+            message,
+            can_retry,
+            ..
+        } => {
+            error!(target: "events", "Connection error ({:?}, can_retry={}): {}", kind, can_retry, message);
+            true
+        }
+        SystemEvent::Script {
+            script_id,
+            event: ScriptEventType::Error { message },
+            ..
+        } => {
+            error!(target: "events", "Script {} failed: {}", script_id, message);
+            true
+        }
         _ => false,
     }
 }
@@ -161,6 +210,10 @@ impl EventConsumer for LoggingConsumer {
                         value,
                         items_capacity: _,
                         container_capacity: _,
+                        stack_size: _,
+                        wielder_id: _,
+                        equipped_location: _,
+                        position: _,
                     } => {
                         info!(target: "events", "ITEM CREATE: {} (ID: {}, Type: {}, Container: {:?}, Burden: {}, Value: {})",
                             name, object_id, item_type, container_id, burden, value);
@@ -180,6 +233,14 @@ impl EventConsumer for LoggingConsumer {
                     GameEvent::ItemDeleteObject { object_id } => {
                         info!(target: "events", "ITEM DELETE: Object ID {}", object_id);
                     }
+                    GameEvent::ItemUpdateStackSize {
+                        object_id,
+                        stack_size,
+                        value,
+                        ..
+                    } => {
+                        info!(target: "events", "ITEM STACK SIZE: Object {} now {} (Value: {})", object_id, stack_size, value);
+                    }
                     GameEvent::ItemMovedObject {
                         object_id,
                         new_container_id,
@@ -190,6 +251,7 @@ impl EventConsumer for LoggingConsumer {
                         object_id,
                         property_name,
                         value,
+                        ..
                     } => {
                         info!(target: "events", "QUALITY UPDATE: Object {} property {} = {}", object_id, property_name, value);
                     }
@@ -241,6 +303,8 @@ impl EventConsumer for LoggingConsumer {
                     _ => {}
                 }
             }
+            // Logged by the client as they arrive
+            EventType::Protocol(_) => {}
         }
     }
 }
@@ -301,6 +365,8 @@ impl EventConsumer for TuiConsumer {
                 tracing::info!(target: "tui_consumer", "TuiConsumer forwarding StateEvent: {:?}", std::mem::discriminant(&state_event));
                 let _ = self.tui_event_tx.send(state_event.into());
             }
+            // The TUI builds its views from game events
+            EventType::Protocol(_) => {}
         }
     }
 }
@@ -326,7 +392,10 @@ impl UptimeData {
     }
 }
 
-/// Event consumer that forwards chat messages to Discord
+/// Event consumer that forwards events to Discord
+///
+/// Which events are posted is controlled by [`DiscordConfig`]; see
+/// [`DiscordEventCategory`] for the events in each category.
 pub struct DiscordConsumer {
     _action_tx: UnboundedSender<SimpleClientAction>,
     http: Arc<Http>,
@@ -334,6 +403,7 @@ pub struct DiscordConsumer {
     bot_start_time: Instant,
     ingame_start_time: Option<Instant>,
     uptime_data: Option<Arc<tokio::sync::RwLock<UptimeData>>>,
+    config: DiscordConfig,
 }
 
 impl DiscordConsumer {
@@ -349,6 +419,7 @@ impl DiscordConsumer {
             bot_start_time: Instant::now(),
             ingame_start_time: None,
             uptime_data: None,
+            config: DiscordConfig::default(),
         }
     }
 
@@ -365,10 +436,32 @@ impl DiscordConsumer {
             bot_start_time: Instant::now(),
             ingame_start_time: None,
             uptime_data: Some(uptime_data),
+            config: DiscordConfig::default(),
+        }
+    }
+
+    /// Only relay the event categories allowed by `config`
+    pub fn with_config(mut self, config: DiscordConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Post a message to the channel if its category is relayed
+    fn relay(&self, category: DiscordEventCategory, message: String) {
+        if !self.config.relays(category) {
+            return;
         }
+
+        let http = self.http.clone();
+        let channel_id = self.channel_id;
+        tokio::spawn(async move {
+            if let Err(e) = channel_id.say(&http, &message).await {
+                error!("Failed to send Discord message: {}", e);
+            }
+        });
     }
 
-    fn handle_login_succeeded(&mut self, character_id: u32, character_name: &str) {
+    fn handle_login_succeeded(&mut self, character_id: CharacterId, character_name: &str) {
         let now = Instant::now();
         self.ingame_start_time = Some(now);
 
@@ -386,15 +479,17 @@ impl DiscordConsumer {
         info!(target: "events", "Bot uptime: {:02}:{:02}:{:02} | Now tracking in-game time", h, m, s);
     }
 
-    /// Create a factory for this consumer
+    /// Create a factory for this consumer, relaying the categories allowed by `config`
     pub fn from_factory(
         http: Arc<Http>,
         channel_id: ChannelId,
+        config: DiscordConfig,
     ) -> impl crate::client_runner_builder::ConsumerFactory {
         DiscordConsumerFactory {
             http,
             channel_id,
             uptime_data: None,
+            config,
         }
     }
 
@@ -403,11 +498,13 @@ impl DiscordConsumer {
         http: Arc<Http>,
         channel_id: ChannelId,
         uptime_data: Arc<tokio::sync::RwLock<UptimeData>>,
+        config: DiscordConfig,
     ) -> impl crate::client_runner_builder::ConsumerFactory {
         DiscordConsumerFactory {
             http,
             channel_id,
             uptime_data: Some(uptime_data),
+            config,
         }
     }
 }
@@ -416,6 +513,7 @@ struct DiscordConsumerFactory {
     http: Arc<Http>,
     channel_id: ChannelId,
     uptime_data: Option<Arc<tokio::sync::RwLock<UptimeData>>>,
+    config: DiscordConfig,
 }
 
 impl crate::client_runner_builder::ConsumerFactory for DiscordConsumerFactory {
@@ -423,20 +521,22 @@ impl crate::client_runner_builder::ConsumerFactory for DiscordConsumerFactory {
         &self,
         ctx: &crate::client_runner_builder::ConsumerContext,
     ) -> Box<dyn EventConsumer> {
-        if let Some(ref uptime_data) = self.uptime_data {
-            Box::new(DiscordConsumer::new_with_uptime(
+        let consumer = if let Some(ref uptime_data) = self.uptime_data {
+            DiscordConsumer::new_with_uptime(
                 ctx.action_tx.clone(),
                 self.http.clone(),
                 self.channel_id,
                 uptime_data.clone(),
-            ))
+            )
         } else {
-            Box::new(DiscordConsumer::new(
-                ctx.action_tx.clone(),
-                self.http.clone(),
-                self.channel_id,
-            ))
-        }
+            DiscordConsumer::new(ctx.action_tx.clone(), self.http.clone(), self.channel_id)
+        };
+        Box::new(consumer.with_config(self.config.clone()))
+    }
+
+    fn delivery_mode(&self) -> DeliveryMode {
+        // Posting to Discord is network I/O; keep it off the event task
+        DeliveryMode::async_default()
     }
 }
 
@@ -444,6 +544,24 @@ impl EventConsumer for DiscordConsumer {
     fn handle_event(&mut self, envelope: EventEnvelope) {
         match envelope.event {
             EventType::Game(game_event) => {
+                match &game_event {
+                    GameEvent::CharacterError { error_message, .. } => self.relay(
+                        DiscordEventCategory::Errors,
+                        format!("Character error: {}", error_message),
+                    ),
+                    GameEvent::LoginFailed { reason } => self.relay(
+                        DiscordEventCategory::Errors,
+                        format!("Login failed: {}", reason),
+                    ),
+                    GameEvent::ActionError { message, .. } => self.relay(
+                        DiscordEventCategory::Errors,
+                        format!("Action failed: {}", message),
+                    ),
+                    GameEvent::PlayerDeath { message, .. } => {
+                        self.relay(DiscordEventCategory::Deaths, message.clone())
+                    }
+                    _ => {}
+                }
                 if log_common_game_event(&game_event) {
                     return;
                 }
@@ -452,6 +570,10 @@ impl EventConsumer for DiscordConsumer {
                         character_id,
                         character_name,
                     } => {
+                        self.relay(
+                            DiscordEventCategory::Login,
+                            format!("Logged in as {}", character_name),
+                        );
                         self.handle_login_succeeded(character_id, &character_name);
                     }
                     GameEvent::ChatMessageReceived {
@@ -465,25 +587,36 @@ impl EventConsumer for DiscordConsumer {
                             info!(target: "events", "CHAT [{}]: {}", message_type, message);
                         }
 
-                        let discord_message = format!("[{}] {}", message_type, message);
-                        let http = self.http.clone();
-                        let channel_id = self.channel_id;
-
-                        tokio::spawn(async move {
-                            if let Err(e) = channel_id.say(&http, &discord_message).await {
-                                error!("Failed to send Discord message: {}", e);
-                            }
-                        });
+                        self.relay(
+                            DiscordEventCategory::Chat,
+                            format!("[{}] {}", message_type, message),
+                        );
+                    }
+                    GameEvent::ChannelMessage {
+                        channel,
+                        sender_name,
+                        message,
+                    } => {
+                        info!(target: "events", "CHANNEL [0x{:08X}] {}: {}", channel, sender_name, message);
+                        self.relay(
+                            DiscordEventCategory::Chat,
+                            format!("[channel 0x{:X}] {}: {}", channel, sender_name, message),
+                        );
                     }
                     GameEvent::CreatePlayer { character_id } => {
                         debug!(target: "events", "CREATE PLAYER: Character ID {}", character_id);
                     }
                     GameEvent::ItemCreateObject { .. }
                     | GameEvent::ItemOnViewContents { .. }
+                    | GameEvent::ContainerContents { .. }
                     | GameEvent::PlayerContainersReceived { .. }
                     | GameEvent::ItemDeleteObject { .. }
+                    | GameEvent::ItemUpdateStackSize { .. }
                     | GameEvent::ItemMovedObject { .. }
                     | GameEvent::QualitiesPrivateUpdateInt { .. }
+                    | GameEvent::QualitiesUpdateInt { .. }
+                    | GameEvent::QualitiesUpdateFloat { .. }
+                    | GameEvent::VitalUpdated { .. }
                     | GameEvent::ItemSetState { .. } => {
                         // Ignore inventory events in Discord consumer
                     }
@@ -494,6 +627,36 @@ impl EventConsumer for DiscordConsumer {
                 info!(target: "events", "STATE CHANGE: {:?}", state_event);
             }
             EventType::System(system_event) => {
+                match &system_event {
+                    SystemEvent::AuthenticationFailed { reason, .. } => self.relay(
+                        DiscordEventCategory::Errors,
+                        format!("Authentication failed: {}", reason),
+                    ),
+                    SystemEvent::ConnectionError { message, .. } => self.relay(
+                        DiscordEventCategory::Errors,
+                        format!("Connection error: {}", message),
+                    ),
+                    SystemEvent::Disconnected { will_reconnect, .. } => self.relay(
+                        DiscordEventCategory::System,
+                        if *will_reconnect {
+                            "Disconnected from the server, reconnecting".to_string()
+                        } else {
+                            "Disconnected from the server".to_string()
+                        },
+                    ),
+                    SystemEvent::Reconnecting { attempt, .. } => self.relay(
+                        DiscordEventCategory::System,
+                        format!("Reconnecting (attempt {})", attempt),
+                    ),
+                    SystemEvent::Resuming { character_name, .. } => self.relay(
+                        DiscordEventCategory::System,
+                        format!("Logging back in as {}", character_name),
+                    ),
+                    SystemEvent::Shutdown => {
+                        self.relay(DiscordEventCategory::System, "Shutting down".to_string())
+                    }
+                    _ => {}
+                }
                 if log_common_system_event(&system_event) {
                     return;
                 }
@@ -502,9 +665,14 @@ impl EventConsumer for DiscordConsumer {
                     character_name,
                 } = system_event
                 {
+                    self.relay(
+                        DiscordEventCategory::Login,
+                        format!("Logged in as {}", character_name),
+                    );
                     self.handle_login_succeeded(character_id, &character_name);
                 }
             }
+            EventType::Protocol(_) => {}
         }
     }
 }
@@ -588,7 +756,7 @@ impl EventConsumer for StatsConsumer {
                 }
                 _ => {}
             },
-            EventType::State(_) => {}
+            EventType::State(_) | EventType::Protocol(_) => {}
         }
     }
 }
@@ -718,7 +886,7 @@ impl EventConsumer for AutoLoginConsumer {
                         // Update state and proceed to login
                         self.state = AutoLoginState::CharacterFound;
                         if let Err(e) = self.action_tx.send(SimpleClientAction::LoginCharacter {
-                            character_id: char_info.character_id.0,
+                            character_id: char_info.character_id.0.into(),
                             character_name: char_info.name.clone(),
                             account: account.clone(),
                         }) {
@@ -761,6 +929,183 @@ impl EventConsumer for AutoLoginConsumer {
     }
 }
 
+/// Target used for structured events emitted by `TracingConsumer`
+const TRACING_TARGET: &str = "gromnie::events";
+
+/// Map an event to the name used for its span.
+///
+/// Names are `<kind>.<event>` in snake_case, e.g. `game.chat_message_received`.
+/// Returns `None` for high-frequency events that aren't worth a span.
+pub(crate) fn tracing_event_name(event: &EventType) -> Option<&'static str> {
+    let name = match event {
+        EventType::Game(game_event) => match game_event {
+            GameEvent::CharacterListReceived { .. } => "game.character_list_received",
+            GameEvent::LoginSucceeded { .. } => "game.login_succeeded",
+            GameEvent::LoginFailed { .. } => "game.login_failed",
+            GameEvent::CharacterError { .. } => "game.character_error",
+            GameEvent::ChatMessageReceived { .. } => "game.chat_message_received",
+            GameEvent::ChannelMessage { .. } => "game.channel_message",
+            GameEvent::CreatePlayer { .. } => "game.create_player",
+            GameEvent::ItemCreateObject { .. } => "game.item_create_object",
+            GameEvent::ItemOnViewContents { .. } => "game.item_on_view_contents",
+            GameEvent::PlayerContainersReceived { .. } => "game.player_containers_received",
+            GameEvent::ItemDeleteObject { .. } => "game.item_delete_object",
+            GameEvent::ItemUpdateStackSize { .. } => "game.item_update_stack_size",
+            GameEvent::ItemMovedObject { .. } => "game.item_moved_object",
+            GameEvent::QualitiesPrivateUpdateInt { .. } => "game.qualities_private_update_int",
+            GameEvent::QualitiesUpdateInt { .. } => "game.qualities_update_int",
+            GameEvent::QualitiesUpdateFloat { .. } => "game.qualities_update_float",
+            GameEvent::VitalUpdated { .. } => "game.vital_updated",
+            GameEvent::ItemSetState { .. } => "game.item_set_state",
+            GameEvent::ActionError { .. } => "game.action_error",
+            GameEvent::TargetHealth { .. } => "game.target_health",
+            GameEvent::PlayerDeath { .. } => "game.player_death",
+            GameEvent::AppraiseResult { .. } => "game.appraise_result",
+            GameEvent::VendorInfo { .. } => "game.vendor_info",
+            GameEvent::ContainerContents { .. } => "game.container_contents",
+            GameEvent::ConfirmationRequest { .. } => "game.confirmation_request",
+            GameEvent::FellowshipUpdated { .. } => "game.fellowship_updated",
+            GameEvent::FellowshipMemberUpdated { .. } => "game.fellowship_member_updated",
+            GameEvent::FellowshipMemberLeft { .. } => "game.fellowship_member_left",
+            GameEvent::FellowshipDisbanded => "game.fellowship_disbanded",
+            GameEvent::DamageDealt { .. } => "game.damage_dealt",
+            GameEvent::DamageTaken { .. } => "game.damage_taken",
+            GameEvent::AttackEvaded { .. } => "game.attack_evaded",
+            GameEvent::AttackAvoided { .. } => "game.attack_avoided",
+            GameEvent::CombatKill { .. } => "game.combat_kill",
+            GameEvent::AllegianceUpdated { .. } => "game.allegiance_updated",
+            GameEvent::AllegianceInfoReceived { .. } => "game.allegiance_info_received",
+            GameEvent::AllegianceMemberLogin { .. } => "game.allegiance_member_login",
+            GameEvent::TradeOpened { .. } => "game.trade_opened",
+            GameEvent::TradeItemAdded { .. } => "game.trade_item_added",
+            GameEvent::ConnectingSetProgress { .. }
+            | GameEvent::UpdatingSetProgress { .. }
+            | GameEvent::ObjectMoved { .. } => {
+                return None;
+            }
+        },
+        EventType::State(state_event) => match state_event {
+            ClientStateEvent::Connecting => "state.connecting",
+            ClientStateEvent::Connected => "state.connected",
+            ClientStateEvent::ConnectingFailed { .. } => "state.connecting_failed",
+            ClientStateEvent::Patching => "state.patching",
+            ClientStateEvent::Patched => "state.patched",
+            ClientStateEvent::PatchingFailed { .. } => "state.patching_failed",
+            ClientStateEvent::CharacterSelect => "state.character_select",
+            ClientStateEvent::EnteringWorld => "state.entering_world",
+            ClientStateEvent::InWorld => "state.in_world",
+            ClientStateEvent::ExitingWorld => "state.exiting_world",
+            ClientStateEvent::CharacterError => "state.character_error",
+            ClientStateEvent::StateTransition { .. } => "state.transition",
+        },
+        EventType::System(system_event) => match system_event {
+            SystemEvent::ReloadScripts { .. } => "system.reload_scripts",
+            SystemEvent::LogScriptMessage { .. } => "system.log_script_message",
+            SystemEvent::AuthenticationSucceeded { .. } => "system.authentication_succeeded",
+            SystemEvent::AuthenticationFailed { .. } => "system.authentication_failed",
+            SystemEvent::ConnectingStarted { .. } => "system.connecting_started",
+            SystemEvent::LoginRetry { .. } => "system.login_retry",
+            SystemEvent::ConnectingDone { .. } => "system.connecting_done",
+            SystemEvent::UpdatingStarted { .. } => "system.updating_started",
+            SystemEvent::UpdatingDone { .. } => "system.updating_done",
+            SystemEvent::LoginSucceeded { .. } => "system.login_succeeded",
+            SystemEvent::Disconnected { .. } => "system.disconnected",
+            SystemEvent::Reconnecting { .. } => "system.reconnecting",
+            SystemEvent::Resuming { .. } => "system.resuming",
+            SystemEvent::PingResult { .. } => "system.ping_result",
+            SystemEvent::ConnectionStats { .. } => "system.connection_stats",
+            SystemEvent::UnknownMessage { .. } => "system.unknown_message",
+            SystemEvent::ConnectionError { .. } => "system.connection_error",
+            SystemEvent::SquelchListChanged { .. } => "system.squelch_list_changed",
+            SystemEvent::Script { .. } => "system.script",
+            SystemEvent::Shutdown => "system.shutdown",
+        },
+        // Mostly duplicates of game events, and movement is high-frequency
+        EventType::Protocol(_) => return None,
+    };
+
+    Some(name)
+}
+
+/// Event consumer that emits events as structured `tracing` spans
+///
+/// Unlike `LoggingConsumer`, which formats events into human-readable strings,
+/// this records each event with typed fields (`event.name`, `client_id`,
+/// `character`, `sequence`) under the `gromnie::events` target. Registered by
+/// the runner when `trace_events` is set in config.toml.
+/// The span's `otel.name` is set to the event name so OTLP exporters such as
+/// `tracing-opentelemetry` group events consistently. Nothing is built when no
+/// subscriber is interested in the target.
+pub struct TracingConsumer {
+    character: Option<String>,
+}
+
+impl TracingConsumer {
+    pub fn new() -> Self {
+        Self { character: None }
+    }
+
+    /// Create a factory for this consumer
+    pub fn from_factory() -> impl crate::client_runner_builder::ConsumerFactory {
+        TracingConsumerFactory
+    }
+}
+
+impl Default for TracingConsumer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct TracingConsumerFactory;
+
+impl crate::client_runner_builder::ConsumerFactory for TracingConsumerFactory {
+    fn create(
+        &self,
+        _ctx: &crate::client_runner_builder::ConsumerContext,
+    ) -> Box<dyn EventConsumer> {
+        Box::new(TracingConsumer::new())
+    }
+}
+
+impl EventConsumer for TracingConsumer {
+    fn handle_event(&mut self, envelope: EventEnvelope) {
+        // Track the logged-in character so every span can be attributed to it
+        match &envelope.event {
+            EventType::Game(GameEvent::LoginSucceeded { character_name, .. })
+            | EventType::System(SystemEvent::LoginSucceeded { character_name, .. }) => {
+                self.character = Some(character_name.clone());
+            }
+            EventType::System(SystemEvent::Disconnected { .. }) => {
+                self.character = None;
+            }
+            _ => {}
+        }
+
+        if !tracing::enabled!(target: TRACING_TARGET, tracing::Level::INFO) {
+            return;
+        }
+
+        let Some(name) = tracing_event_name(&envelope.event) else {
+            return;
+        };
+
+        let span = tracing::info_span!(
+            target: TRACING_TARGET,
+            "gromnie.event",
+            otel.name = name,
+            event.name = name,
+            event.source = ?envelope.source,
+            client_id = envelope.context.client_id,
+            character = self.character.as_deref(),
+            sequence = envelope.context.client_sequence,
+        );
+        let _guard = span.enter();
+
+        tracing::info!(target: TRACING_TARGET, event.name = name, "{}", name);
+    }
+}
+
 /// Consumer that composes multiple consumers together
 ///
 /// This allows chaining multiple consumers to handle different aspects
@@ -785,7 +1130,221 @@ impl CompositeConsumer {
 impl EventConsumer for CompositeConsumer {
     fn handle_event(&mut self, envelope: EventEnvelope) {
         for consumer in &mut self.consumers {
-            consumer.handle_event(envelope.clone());
+            deliver(consumer.as_mut(), envelope.clone());
         }
     }
 }
+
+/// Hand `envelope` to `consumer`, containing any panic
+///
+/// A consumer that panics is logged and skipped for this event only, so a bug
+/// in one (say, the Discord consumer choking on a malformed message) doesn't
+/// stop delivery to the others or take down the runner. Returns false if the
+/// consumer panicked.
+pub fn deliver(consumer: &mut dyn EventConsumer, envelope: EventEnvelope) -> bool {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        consumer.handle_event(envelope)
+    }));
+    let Err(payload) = result else {
+        return true;
+    };
+
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload");
+    error!(
+        target: "events",
+        "Event consumer {} panicked, skipping the event for it: {}",
+        consumer.name(),
+        message
+    );
+    false
+}
+
+/// Runs a consumer on its own task, fed through a bounded channel
+///
+/// See [`DeliveryMode::Async`] for the ordering implications. Dropping this
+/// closes the channel; the task finishes the queued events and then exits.
+pub struct AsyncConsumer {
+    tx: tokio::sync::mpsc::Sender<EventEnvelope>,
+    dropped: u64,
+}
+
+impl AsyncConsumer {
+    /// Spawn a task that delivers events to `consumer`. Must be called within a Tokio runtime.
+    pub fn spawn(mut consumer: Box<dyn EventConsumer>, capacity: usize) -> Self {
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<EventEnvelope>(capacity.max(1));
+
+        // handle_event is synchronous and may block, so don't run it on a runtime worker
+        tokio::task::spawn_blocking(move || {
+            while let Some(envelope) = rx.blocking_recv() {
+                deliver(consumer.as_mut(), envelope);
+            }
+        });
+
+        Self { tx, dropped: 0 }
+    }
+}
+
+impl EventConsumer for AsyncConsumer {
+    fn handle_event(&mut self, envelope: EventEnvelope) {
+        match self.tx.try_send(envelope) {
+            Ok(()) => {}
+            Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {
+                self.dropped += 1;
+                warn!(
+                    target: "events",
+                    "Async consumer is falling behind, dropped event ({} total)",
+                    self.dropped
+                );
+            }
+            Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
+                debug!(target: "events", "Async consumer task has stopped, dropping event");
+            }
+        }
+    }
+}
+
+/// Wrap a consumer according to its factory's delivery mode
+pub fn with_delivery_mode(
+    consumer: Box<dyn EventConsumer>,
+    mode: DeliveryMode,
+) -> Box<dyn EventConsumer> {
+    match mode {
+        DeliveryMode::Sync => consumer,
+        DeliveryMode::Async { capacity } => Box::new(AsyncConsumer::spawn(consumer, capacity)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::EventSource;
+    use std::sync::mpsc;
+
+    /// Forwards every event it sees to a std channel
+    struct ForwardingConsumer(mpsc::Sender<EventEnvelope>);
+
+    impl EventConsumer for ForwardingConsumer {
+        fn handle_event(&mut self, envelope: EventEnvelope) {
+            let _ = self.0.send(envelope);
+        }
+    }
+
+    fn state_event(sequence: u64) -> EventEnvelope {
+        EventEnvelope::state_event(
+            ClientStateEvent::InWorld,
+            1,
+            sequence,
+            EventSource::ClientInternal,
+        )
+    }
+
+    /// Panics on every event
+    struct PanickingConsumer;
+
+    impl EventConsumer for PanickingConsumer {
+        fn handle_event(&mut self, _envelope: EventEnvelope) {
+            panic!("malformed message");
+        }
+    }
+
+    #[test]
+    fn test_panicking_consumer_is_isolated() {
+        let (tx, rx) = mpsc::channel();
+        let mut consumer = CompositeConsumer::new(vec![
+            Box::new(PanickingConsumer),
+            Box::new(ForwardingConsumer(tx)),
+        ]);
+
+        // Later consumers still get every event
+        consumer.handle_event(state_event(1));
+        consumer.handle_event(state_event(2));
+        let received: Vec<_> = rx
+            .try_iter()
+            .map(|envelope| envelope.context.client_sequence)
+            .collect();
+        assert_eq!(received, vec![1, 2]);
+
+        assert!(!deliver(&mut PanickingConsumer, state_event(3)));
+        assert!(PanickingConsumer.name().ends_with("PanickingConsumer"));
+    }
+
+    #[test]
+    fn test_tracing_consumer_handles_events_without_blocking() {
+        let (done_tx, done_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let subscriber = tracing_subscriber::fmt()
+                .with_writer(std::io::sink)
+                .finish();
+            tracing::subscriber::with_default(subscriber, || {
+                let mut consumer = TracingConsumer::new();
+                consumer.handle_event(EventEnvelope::system_event(
+                    SystemEvent::LoginSucceeded {
+                        character_id: 0x5000_0001.into(),
+                        character_name: "Tester".to_string(),
+                    },
+                    1,
+                    1,
+                    EventSource::ClientInternal,
+                ));
+                let logged_in = consumer.character.clone();
+
+                consumer.handle_event(state_event(2));
+                consumer.handle_event(EventEnvelope::system_event(
+                    SystemEvent::Disconnected {
+                        client_id: 1,
+                        will_reconnect: true,
+                        reconnect_attempt: 1,
+                        delay_secs: 0,
+                    },
+                    1,
+                    3,
+                    EventSource::ClientInternal,
+                ));
+                let _ = done_tx.send((logged_in, consumer.character));
+            });
+        });
+
+        let (logged_in, disconnected) = done_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("TracingConsumer blocked while handling events");
+        assert_eq!(logged_in.as_deref(), Some("Tester"));
+        assert_eq!(disconnected, None);
+    }
+
+    #[tokio::test]
+    async fn test_sync_delivery_is_inline() {
+        let (tx, rx) = mpsc::channel();
+        let mut consumer =
+            with_delivery_mode(Box::new(ForwardingConsumer(tx)), DeliveryMode::default());
+
+        consumer.handle_event(state_event(1));
+        assert_eq!(rx.try_recv().unwrap().context.client_sequence, 1);
+    }
+
+    #[tokio::test]
+    async fn test_async_delivery_preserves_order() {
+        let (tx, rx) = mpsc::channel();
+        let mut consumer = with_delivery_mode(
+            Box::new(ForwardingConsumer(tx)),
+            DeliveryMode::async_default(),
+        );
+
+        for sequence in 1..=3 {
+            consumer.handle_event(state_event(sequence));
+        }
+        drop(consumer);
+
+        let received = tokio::task::spawn_blocking(move || {
+            rx.iter()
+                .map(|envelope| envelope.context.client_sequence)
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap();
+        assert_eq!(received, vec![1, 2, 3]);
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use crate::event_bus::{EventContext, EventEnvelope, EventSource, EventType, SystemEvent};
-use gromnie_events::{ClientEvent, ClientSystemEvent};
+use gromnie_events::{ClientEvent, ClientSystemEvent, ScriptEventType};
 
 /// Wraps raw events from client and enriches them with context
 pub struct EventWrapper {
@@ -43,17 +43,7 @@ impl EventWrapper {
             ClientEvent::Game(game) => EventType::Game(game),
             ClientEvent::State(state) => EventType::State(state),
             ClientEvent::System(sys) => EventType::System(self.convert_system_event(sys)),
-            ClientEvent::Protocol(_) => {
-                // Protocol events are not yet fully supported in the event bus
-                // For now, convert to a placeholder system event
-                tracing::debug!(target: "event_wrapper", "Protocol event received, converting to System event");
-                EventType::System(SystemEvent::Disconnected {
-                    client_id: self.client_id,
-                    will_reconnect: false,
-                    reconnect_attempt: 0,
-                    delay_secs: 0,
-                })
-            }
+            ClientEvent::Protocol(protocol) => EventType::Protocol(protocol),
         };
 
         EventEnvelope::new(event, context, source)
@@ -63,6 +53,7 @@ impl EventWrapper {
         match raw {
             ClientEvent::Game(_) => EventSource::Network,
             ClientEvent::State(_) => EventSource::ClientInternal,
+            ClientEvent::System(ClientSystemEvent::ScriptError { .. }) => EventSource::Script,
             ClientEvent::System(_) => EventSource::System,
             ClientEvent::Protocol(_) => EventSource::Network,
         }
@@ -82,6 +73,14 @@ impl EventWrapper {
             ClientSystemEvent::ConnectingStarted => SystemEvent::ConnectingStarted {
                 client_id: self.client_id,
             },
+            ClientSystemEvent::LoginRetry {
+                attempt,
+                max_attempts,
+            } => SystemEvent::LoginRetry {
+                client_id: self.client_id,
+                attempt,
+                max_attempts,
+            },
             ClientSystemEvent::ConnectingDone => SystemEvent::ConnectingDone {
                 client_id: self.client_id,
             },
@@ -116,6 +115,53 @@ impl EventWrapper {
                 attempt,
                 delay_secs,
             },
+            ClientSystemEvent::Resuming {
+                character_id,
+                character_name,
+                attempt,
+            } => SystemEvent::Resuming {
+                client_id: self.client_id,
+                character_id,
+                character_name,
+                attempt,
+            },
+            ClientSystemEvent::PingResult { rtt_millis } => SystemEvent::PingResult {
+                client_id: self.client_id,
+                rtt_millis,
+            },
+            ClientSystemEvent::ConnectionStats(stats) => SystemEvent::ConnectionStats {
+                client_id: self.client_id,
+                stats,
+            },
+            ClientSystemEvent::UnknownMessage {
+                opcode,
+                game_event_opcode,
+                data,
+            } => SystemEvent::UnknownMessage {
+                client_id: self.client_id,
+                opcode,
+                game_event_opcode,
+                data,
+            },
+            ClientSystemEvent::ConnectionError {
+                kind,
+                message,
+                can_retry,
+            } => SystemEvent::ConnectionError {
+                client_id: self.client_id,
+                kind,
+                message,
+                can_retry,
+            },
+            ClientSystemEvent::ScriptError { script_id, message } => SystemEvent::Script {
+                client_id: self.client_id,
+                script_id,
+                event: ScriptEventType::Error { message },
+            },
+            ClientSystemEvent::SquelchListChanged { names } => SystemEvent::SquelchListChanged {
+                client_id: self.client_id,
+                names,
+            },
         }
     }
 }
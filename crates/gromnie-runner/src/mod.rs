@@ -1,4 +1,5 @@
 mod character_gen;
+mod client_manager;
 mod client_naming;
 mod client_runner;
 pub mod client_runner_builder;
@@ -6,8 +7,13 @@ pub mod event_bus;
 mod event_consumer;
 mod event_wrapper;
 pub mod logging;
+mod replay;
+mod signals;
+#[cfg(feature = "sqlite")]
+mod sqlite_consumer;
 
-pub use character_gen::CharacterBuilder;
+pub use character_gen::{CharacterBuilder, TemplateError};
+pub use client_manager::{ClientManager, ClientManagerError, ClientStatus};
 pub use client_naming::{ClientNaming, decode_client_id, encode_client_id};
 pub use client_runner::{
     ClientConfig, ConsumerBuilder, EventBusManager, FnConsumerBuilder, FnConsumerFactory,
@@ -17,14 +23,19 @@ pub use client_runner::{
 };
 pub use client_runner_builder::{
     BuildError, ClientMode, ClientRunner, ClientRunnerBuilder, ConsumerContext, ConsumerFactory,
-    RunResult as BuilderRunResult,
+    DeliveryMode, RunResult as BuilderRunResult,
 };
 pub use event_bus::{
     ClientStateEvent, EventBus, EventContext, EventEnvelope, EventSource, EventType,
     ScriptEventType, SystemEvent, TuiEvent,
 };
 pub use event_consumer::{
-    AutoLoginConsumer, AutoLoginState, CompositeConsumer, DiscordConsumer, EventConsumer,
-    LoggingConsumer, StatsConsumer, TuiConsumer, UptimeData,
+    AsyncConsumer, AutoLoginConsumer, AutoLoginState, CompositeConsumer, DiscordConsumer,
+    EventConsumer, LoggingConsumer, StatsConsumer, TracingConsumer, TuiConsumer, UptimeData,
+    deliver, with_delivery_mode,
 };
 pub use event_wrapper::EventWrapper;
+pub use replay::ReplayConfig;
+pub use signals::setup_shutdown_signal_handler;
+#[cfg(feature = "sqlite")]
+pub use sqlite_consumer::SqliteConsumer;
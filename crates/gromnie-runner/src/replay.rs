@@ -0,0 +1,231 @@
+//! Offline replay of message traces
+//!
+//! Feeds the received messages from a `--trace` file through a client with no
+//! socket, so consumers and scripts see the same events they did live, paced
+//! by the recorded timestamps.
+
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{RwLock, mpsc, watch};
+use tracing::{info, warn};
+
+use gromnie_client::client::{Client, TraceDirection, TraceEntry, read_trace};
+use gromnie_events::SimpleClientAction;
+
+/// A trace to replay and how to pace it
+#[derive(Debug, Clone)]
+pub struct ReplayConfig {
+    /// Received messages of one client, in recorded order
+    entries: Vec<TraceEntry>,
+    /// Playback rate; 2.0 replays twice as fast as recorded
+    speed: f64,
+    /// Start over from the first message after the last one
+    looped: bool,
+}
+
+impl ReplayConfig {
+    /// Load the trace at `path`
+    ///
+    /// Only messages the client received are kept. A trace shared by several
+    /// clients is narrowed to the first client that appears in it.
+    pub fn from_file(path: impl AsRef<Path>, speed: f64, looped: bool) -> io::Result<Self> {
+        let file = File::open(path)?;
+        Self::from_entries(read_trace(BufReader::new(file))?, speed, looped)
+    }
+
+    /// Replay already-parsed trace entries
+    pub fn from_entries(entries: Vec<TraceEntry>, speed: f64, looped: bool) -> io::Result<Self> {
+        if !speed.is_finite() || speed <= 0.0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("replay speed must be greater than 0, got {}", speed),
+            ));
+        }
+
+        let received: Vec<_> = entries
+            .into_iter()
+            .filter(|entry| entry.direction == TraceDirection::Received)
+            .collect();
+        let client_id = received.first().map(|entry| entry.client_id);
+        let total = received.len();
+        let entries: Vec<_> = received
+            .into_iter()
+            .filter(|entry| Some(entry.client_id) == client_id)
+            .collect();
+        if let Some(client_id) = client_id
+            && entries.len() < total
+        {
+            warn!(
+                "Trace holds several clients; replaying client {} only ({} of {} messages)",
+                client_id,
+                entries.len(),
+                total
+            );
+        }
+
+        Ok(Self {
+            entries,
+            speed,
+            looped,
+        })
+    }
+
+    /// Number of messages replayed per pass
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Wait before each message: the recorded gap to the previous one, scaled by the speed
+    fn delays(&self) -> impl Iterator<Item = Duration> + '_ {
+        let mut previous = None;
+        self.entries.iter().map(move |entry| {
+            let gap = previous
+                .and_then(|previous| (entry.timestamp - previous).to_std().ok())
+                .unwrap_or_default();
+            previous = Some(entry.timestamp);
+            gap.div_f64(self.speed)
+        })
+    }
+}
+
+/// Action channel for consumers during a replay
+///
+/// There is no connection to act on, so actions (including those sent by
+/// scripts) are logged and dropped.
+pub(crate) fn logged_action_channel(client_id: u32) -> mpsc::UnboundedSender<SimpleClientAction> {
+    let (action_tx, mut action_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(action) = action_rx.recv().await {
+            info!(
+                "[Client {}] Replay: ignoring action {:?} (no connection)",
+                client_id, action
+            );
+        }
+    });
+    action_tx
+}
+
+/// Feed the trace through `client` until it ends (or forever when looping)
+/// or a shutdown is requested
+pub(crate) async fn run_replay_loop(
+    client: Arc<RwLock<Client>>,
+    replay: ReplayConfig,
+    mut shutdown_rx: Option<watch::Receiver<bool>>,
+) {
+    if replay.is_empty() {
+        warn!("Trace holds no received messages; nothing to replay");
+        return;
+    }
+    info!(
+        "Replaying {} messages at {}x{}",
+        replay.len(),
+        replay.speed,
+        if replay.looped { ", looping" } else { "" }
+    );
+
+    let mut pass = 1;
+    loop {
+        for (sequence, (entry, delay)) in replay.entries.iter().zip(replay.delays()).enumerate() {
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = async {
+                    if let Some(ref mut rx) = shutdown_rx {
+                        let _ = rx.changed().await;
+                    } else {
+                        std::future::pending::<()>().await
+                    }
+                } => {
+                    info!("Replay received shutdown signal");
+                    return;
+                }
+                _ = tokio::signal::ctrl_c(), if shutdown_rx.is_none() => {
+                    info!("Received Ctrl+C, stopping replay...");
+                    return;
+                }
+            }
+
+            client
+                .write()
+                .await
+                .replay_message(entry.data.clone(), sequence as u32);
+            // Let the event wrapper drain between messages so fast replays
+            // don't overflow the client's event channel
+            tokio::task::yield_now().await;
+        }
+
+        if !replay.looped {
+            info!("Replay finished after {} messages", replay.len());
+            return;
+        }
+        pass += 1;
+        info!("Replay finished; starting pass {}", pass);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn entry(client_id: u32, direction: TraceDirection, secs: i64) -> TraceEntry {
+        TraceEntry {
+            client_id,
+            direction,
+            timestamp: DateTime::<Utc>::from_timestamp(secs, 0).unwrap(),
+            data: vec![0xB0, 0xF7, 0x00, 0x00],
+        }
+    }
+
+    #[test]
+    fn test_keeps_received_messages_of_first_client() {
+        let replay = ReplayConfig::from_entries(
+            vec![
+                entry(1, TraceDirection::Sent, 0),
+                entry(2, TraceDirection::Received, 1),
+                entry(1, TraceDirection::Received, 2),
+                entry(2, TraceDirection::Received, 3),
+            ],
+            1.0,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(replay.len(), 2);
+        assert!(replay.entries.iter().all(|e| e.client_id == 2));
+    }
+
+    #[test]
+    fn test_delays_scale_with_speed() {
+        let entries = vec![
+            entry(0, TraceDirection::Received, 10),
+            entry(0, TraceDirection::Received, 12),
+            entry(0, TraceDirection::Received, 16),
+        ];
+        let replay = ReplayConfig::from_entries(entries, 2.0, false).unwrap();
+
+        let delays: Vec<_> = replay.delays().collect();
+        assert_eq!(
+            delays,
+            [
+                Duration::ZERO,
+                Duration::from_secs(1),
+                Duration::from_secs(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_positive_speed() {
+        assert!(ReplayConfig::from_entries(Vec::new(), 0.0, false).is_err());
+        assert!(ReplayConfig::from_entries(Vec::new(), -1.0, false).is_err());
+        assert!(ReplayConfig::from_entries(Vec::new(), f64::NAN, false).is_err());
+    }
+}
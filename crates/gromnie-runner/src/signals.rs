@@ -0,0 +1,63 @@
+use tokio::sync::watch;
+use tracing::{debug, info};
+
+/// Setup SIGINT/SIGTERM handler that requests a graceful shutdown
+///
+/// This function spawns a task that waits for SIGINT (Ctrl+C) or SIGTERM and
+/// then sends `true` on `shutdown_tx`, so the client loop stops, flushes its
+/// outgoing queue and the consumers receive a shutdown event. A second signal
+/// exits the process immediately.
+#[cfg(unix)]
+pub fn setup_shutdown_signal_handler(shutdown_tx: watch::Sender<bool>) {
+    tokio::spawn(async move {
+        use tokio::signal::unix;
+
+        let mut sigint = match unix::signal(unix::SignalKind::interrupt()) {
+            Ok(sig) => sig,
+            Err(e) => {
+                tracing::error!("Failed to create SIGINT handler: {}", e);
+                return;
+            }
+        };
+        let mut sigterm = match unix::signal(unix::SignalKind::terminate()) {
+            Ok(sig) => sig,
+            Err(e) => {
+                tracing::error!("Failed to create SIGTERM handler: {}", e);
+                return;
+            }
+        };
+
+        debug!("SIGINT/SIGTERM handler installed");
+
+        tokio::select! {
+            _ = sigint.recv() => info!("Received SIGINT, shutting down gracefully..."),
+            _ = sigterm.recv() => info!("Received SIGTERM, shutting down gracefully..."),
+        }
+        let _ = shutdown_tx.send(true);
+
+        tokio::select! {
+            _ = sigint.recv() => {}
+            _ = sigterm.recv() => {}
+        }
+        info!("Second shutdown signal received, forcing immediate exit");
+        std::process::exit(130);
+    });
+}
+
+/// Setup shutdown signal handler (non-Unix platforms only have Ctrl+C)
+#[cfg(not(unix))]
+pub fn setup_shutdown_signal_handler(shutdown_tx: watch::Sender<bool>) {
+    tokio::spawn(async move {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl+C: {}", e);
+            return;
+        }
+        info!("Received Ctrl+C, shutting down gracefully...");
+        let _ = shutdown_tx.send(true);
+
+        if tokio::signal::ctrl_c().await.is_ok() {
+            info!("Second Ctrl+C received, forcing immediate exit");
+            std::process::exit(130);
+        }
+    });
+}
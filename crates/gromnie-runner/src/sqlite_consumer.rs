@@ -0,0 +1,407 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use chrono::{SecondsFormat, Utc};
+use rusqlite::{Connection, params};
+use tracing::{debug, error, warn};
+
+use crate::event_bus::{EventEnvelope, EventType};
+use crate::event_consumer::{EventConsumer, tracing_event_name};
+use gromnie_client::config::DatabaseConfig;
+
+/// Rows waiting for the writer thread before new events are dropped
+const QUEUE_CAPACITY: usize = 4096;
+/// Most rows written in one transaction
+const MAX_BATCH: usize = 256;
+/// How long the writer waits for more rows before committing a partial batch
+const BATCH_WINDOW: Duration = Duration::from_millis(250);
+/// Least time between warnings about dropped events
+const DROP_WARNING_INTERVAL: Duration = Duration::from_secs(10);
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS events (
+        id INTEGER PRIMARY KEY,
+        timestamp TEXT NOT NULL,
+        client_id INTEGER NOT NULL,
+        sequence INTEGER NOT NULL,
+        source TEXT NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS events_client_type ON events (client_id, event_type);
+";
+
+/// One event, ready to insert
+struct EventRow {
+    timestamp: String,
+    client_id: u32,
+    sequence: u64,
+    source: String,
+    event_type: String,
+    payload: String,
+}
+
+impl EventRow {
+    fn from_envelope(envelope: &EventEnvelope) -> Self {
+        // Envelopes carry a monotonic instant; turn it back into wall-clock time
+        let age = chrono::Duration::from_std(envelope.timestamp.elapsed()).unwrap_or_default();
+        let timestamp = (Utc::now() - age).to_rfc3339_opts(SecondsFormat::Millis, true);
+
+        let payload = match &envelope.event {
+            EventType::Game(event) => serde_json::to_string(event),
+            EventType::State(event) => serde_json::to_string(event),
+            EventType::System(event) => serde_json::to_string(event),
+            EventType::Protocol(event) => serde_json::to_string(event),
+        }
+        .unwrap_or_else(|e| {
+            warn!(target: "events", "Failed to serialize event for the database: {}", e);
+            "null".to_string()
+        });
+
+        Self {
+            timestamp,
+            client_id: envelope.context.client_id,
+            sequence: envelope.context.client_sequence,
+            source: format!("{:?}", envelope.source),
+            event_type: event_type_name(&envelope.event),
+            payload,
+        }
+    }
+}
+
+/// Name stored in the `event_type` column, e.g. `game.chat_message_received`
+///
+/// Events without a span name (progress updates, protocol events) are stored
+/// under their kind; the payload still says which one they are.
+fn event_type_name(event: &EventType) -> String {
+    match tracing_event_name(event) {
+        Some(name) => name.to_string(),
+        None => match event {
+            EventType::Game(_) => "game",
+            EventType::State(_) => "state",
+            EventType::System(_) => "system",
+            EventType::Protocol(_) => "protocol",
+        }
+        .to_string(),
+    }
+}
+
+/// Open the database at `path`, creating the file and schema if needed
+fn open(path: &Path) -> rusqlite::Result<Connection> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+        && let Err(e) = std::fs::create_dir_all(parent)
+    {
+        warn!(target: "events", "Failed to create {}: {}", parent.display(), e);
+    }
+
+    let conn = Connection::open(path)?;
+    // Several gromnie processes may write to the same file
+    conn.busy_timeout(Duration::from_secs(5))?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(conn)
+}
+
+fn insert_batch(conn: &mut Connection, rows: &[EventRow]) -> rusqlite::Result<()> {
+    let tx = conn.transaction()?;
+    {
+        let mut insert = tx.prepare_cached(
+            "INSERT INTO events (timestamp, client_id, sequence, source, event_type, payload)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for row in rows {
+            insert.execute(params![
+                row.timestamp,
+                row.client_id,
+                row.sequence as i64,
+                row.source,
+                row.event_type,
+                row.payload,
+            ])?;
+        }
+    }
+    tx.commit()
+}
+
+/// Writer thread: gather rows into batches and insert each in one transaction
+/// until every sender is gone
+fn write_rows(mut conn: Connection, rx: Receiver<EventRow>) {
+    let mut batch = Vec::with_capacity(MAX_BATCH);
+    while let Ok(row) = rx.recv() {
+        batch.push(row);
+        while batch.len() < MAX_BATCH {
+            match rx.recv_timeout(BATCH_WINDOW) {
+                Ok(row) => batch.push(row),
+                Err(_) => break,
+            }
+        }
+
+        if let Err(e) = insert_batch(&mut conn, &batch) {
+            error!(target: "events", "Failed to write {} events to the database: {}", batch.len(), e);
+        }
+        batch.clear();
+    }
+}
+
+/// Background thread that owns the database connection
+///
+/// Shared by every consumer a factory creates. Dropping the last handle
+/// closes the queue and waits for the rows already in it to be written; on a
+/// tokio runtime that wait happens on a blocking task, which the runtime
+/// finishes before it shuts down.
+struct SqliteWriter {
+    tx: Option<SyncSender<EventRow>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SqliteWriter {
+    fn spawn(path: &Path) -> rusqlite::Result<Self> {
+        let conn = open(path)?;
+        let (tx, rx) = mpsc::sync_channel(QUEUE_CAPACITY);
+        let thread = std::thread::Builder::new()
+            .name("gromnie-sqlite".to_string())
+            .spawn(move || write_rows(conn, rx))
+            .expect("failed to spawn database writer thread");
+
+        Ok(Self {
+            tx: Some(tx),
+            thread: Some(thread),
+        })
+    }
+
+    fn sender(&self) -> SyncSender<EventRow> {
+        self.tx.clone().expect("writer is open until dropped")
+    }
+}
+
+impl Drop for SqliteWriter {
+    fn drop(&mut self) {
+        self.tx.take();
+        let Some(thread) = self.thread.take() else {
+            return;
+        };
+        let join = move || {
+            if thread.join().is_err() {
+                error!(target: "events", "Database writer thread panicked");
+            }
+        };
+        // Don't block a runtime worker while the last batch is written
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => drop(handle.spawn_blocking(join)),
+            Err(_) => join(),
+        }
+    }
+}
+
+/// Event consumer that records every event to a SQLite database
+///
+/// Each event becomes a row in the `events` table with its wall-clock
+/// timestamp, client id, sequence number, source, type name (as used by
+/// `TracingConsumer`) and the event itself as JSON. Inserts are batched on a
+/// background thread so a slow disk never holds up the event loop; if the
+/// writer falls too far behind, new events are dropped, with a warning at
+/// most every [`DROP_WARNING_INTERVAL`] and another once the writer catches up.
+pub struct SqliteConsumer {
+    tx: SyncSender<EventRow>,
+    /// Events dropped since the last warning
+    dropped: u64,
+    /// When the last drop warning was logged
+    last_drop_warning: Option<Instant>,
+    // Keeps the writer thread running while this consumer is alive
+    _writer: Arc<SqliteWriter>,
+}
+
+impl SqliteConsumer {
+    /// Open (or create) the database at `path` and start writing to it
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Ok(Self::with_writer(Arc::new(SqliteWriter::spawn(
+            path.as_ref(),
+        )?)))
+    }
+
+    fn with_writer(writer: Arc<SqliteWriter>) -> Self {
+        Self {
+            tx: writer.sender(),
+            dropped: 0,
+            last_drop_warning: None,
+            _writer: writer,
+        }
+    }
+
+    /// Create a factory for this consumer from the `[database]` section
+    ///
+    /// All clients the factory creates consumers for share one connection and
+    /// writer thread.
+    pub fn factory(
+        config: &DatabaseConfig,
+    ) -> rusqlite::Result<impl crate::client_runner_builder::ConsumerFactory> {
+        Ok(SqliteConsumerFactory {
+            writer: Arc::new(SqliteWriter::spawn(&config.path)?),
+        })
+    }
+}
+
+struct SqliteConsumerFactory {
+    writer: Arc<SqliteWriter>,
+}
+
+impl crate::client_runner_builder::ConsumerFactory for SqliteConsumerFactory {
+    fn create(
+        &self,
+        _ctx: &crate::client_runner_builder::ConsumerContext,
+    ) -> Box<dyn EventConsumer> {
+        Box::new(SqliteConsumer::with_writer(self.writer.clone()))
+    }
+}
+
+impl EventConsumer for SqliteConsumer {
+    fn handle_event(&mut self, envelope: EventEnvelope) {
+        match self.tx.try_send(EventRow::from_envelope(&envelope)) {
+            Ok(()) => {
+                if self.dropped > 0 {
+                    warn!(
+                        target: "events",
+                        "Database writer caught up after dropping {} more events",
+                        self.dropped
+                    );
+                    self.dropped = 0;
+                    self.last_drop_warning = None;
+                }
+            }
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                let now = Instant::now();
+                if self
+                    .last_drop_warning
+                    .is_none_or(|last| now.duration_since(last) >= DROP_WARNING_INTERVAL)
+                {
+                    warn!(
+                        target: "events",
+                        "Database writer is falling behind, dropped {} events",
+                        self.dropped
+                    );
+                    self.dropped = 0;
+                    self.last_drop_warning = Some(now);
+                }
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                debug!(target: "events", "Database writer has stopped, dropping event");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::{ClientStateEvent, EventSource};
+    use gromnie_events::SimpleGameEvent;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_events_are_written_on_drop() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("events.db");
+        let mut consumer = SqliteConsumer::open(&path).unwrap();
+        consumer.handle_event(EventEnvelope::state_event(
+            ClientStateEvent::InWorld,
+            3,
+            1,
+            EventSource::ClientInternal,
+        ));
+        consumer.handle_event(EventEnvelope::game_event(
+            SimpleGameEvent::LoginFailed {
+                reason: "bad password".to_string(),
+            },
+            3,
+            2,
+            EventSource::Network,
+        ));
+        // Dropping the last consumer flushes the queue
+        drop(consumer);
+
+        let conn = Connection::open(&path).unwrap();
+        let mut query = conn
+            .prepare(
+                "SELECT client_id, sequence, source, event_type, payload FROM events ORDER BY id",
+            )
+            .unwrap();
+        let rows: Vec<(u32, i64, String, String, String)> = query
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            (
+                3,
+                1,
+                "ClientInternal".to_string(),
+                "state.in_world".to_string(),
+                "\"InWorld\"".to_string()
+            )
+        );
+        assert_eq!(rows[1].3, "game.login_failed");
+        assert!(rows[1].4.contains("bad password"));
+    }
+
+    #[test]
+    fn test_drop_on_runtime_flushes_before_shutdown() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("events.db");
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut consumer = SqliteConsumer::open(&path).unwrap();
+            consumer.handle_event(EventEnvelope::state_event(
+                ClientStateEvent::InWorld,
+                1,
+                1,
+                EventSource::ClientInternal,
+            ));
+            drop(consumer);
+        });
+        // The runtime waits for the blocking join before it goes away
+        drop(runtime);
+
+        let conn = Connection::open(&path).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM events", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_reopening_keeps_existing_events() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("events.db");
+        for sequence in 0..2 {
+            let mut consumer = SqliteConsumer::open(&path).unwrap();
+            consumer.handle_event(EventEnvelope::state_event(
+                ClientStateEvent::Connecting,
+                1,
+                sequence,
+                EventSource::ClientInternal,
+            ));
+        }
+
+        let conn = Connection::open(&path).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM events", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+}
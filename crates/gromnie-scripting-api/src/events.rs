@@ -8,6 +8,35 @@ pub const EVENT_ALL: u32 = 0;
 pub const EVENT_CHARACTER_LIST_RECEIVED: u32 = 1;
 pub const EVENT_CREATE_OBJECT: u32 = 2;
 pub const EVENT_CHAT_MESSAGE_RECEIVED: u32 = 3;
+pub const EVENT_ACTION_ERROR: u32 = 4;
+pub const EVENT_TARGET_HEALTH: u32 = 5;
+/// Delivered as the protocol `movement-position` / `movement-position-and-movement` events
+pub const EVENT_MOVEMENT_POSITION: u32 = 6;
+/// Delivered as the protocol `movement-set-object-movement` event
+pub const EVENT_MOVEMENT_SET_OBJECT_MOVEMENT: u32 = 7;
+pub const EVENT_PLAYER_DEATH: u32 = 8;
+pub const EVENT_APPRAISE_RESULT: u32 = 9;
+pub const EVENT_VENDOR_INFO: u32 = 10;
+pub const EVENT_CONTAINER_CONTENTS: u32 = 11;
+pub const EVENT_CONFIRMATION_REQUEST: u32 = 12;
+pub const EVENT_STACK_SIZE_CHANGED: u32 = 13;
+pub const EVENT_CHANNEL_MESSAGE: u32 = 14;
+
+// Combat events (20-29), a subrange of the game events
+pub const EVENT_DAMAGE_DEALT: u32 = 20;
+pub const EVENT_DAMAGE_TAKEN: u32 = 21;
+pub const EVENT_ATTACK_EVADED: u32 = 22;
+pub const EVENT_ATTACK_AVOIDED: u32 = 23;
+pub const EVENT_COMBAT_KILL: u32 = 24;
+
+// Allegiance events (30-39), a subrange of the game events
+pub const EVENT_ALLEGIANCE_UPDATED: u32 = 30;
+pub const EVENT_ALLEGIANCE_INFO: u32 = 31;
+pub const EVENT_ALLEGIANCE_MEMBER_LOGIN: u32 = 32;
+
+// Trade events (40-49), a subrange of the game events
+pub const EVENT_TRADE_OPENED: u32 = 40;
+pub const EVENT_TRADE_ITEM_ADDED: u32 = 41;
 
 // State events (100-199)
 pub const EVENT_STATE_CONNECTING: u32 = 100;
@@ -21,6 +50,7 @@ pub const EVENT_STATE_ENTERING_WORLD: u32 = 107;
 pub const EVENT_STATE_IN_WORLD: u32 = 108;
 pub const EVENT_STATE_EXITING_WORLD: u32 = 109;
 pub const EVENT_STATE_CHARACTER_ERROR: u32 = 110;
+pub const EVENT_STATE_TRANSITION: u32 = 111;
 
 // System events (200-299)
 pub const EVENT_SYSTEM_AUTHENTICATION_SUCCEEDED: u32 = 200;
@@ -32,3 +62,29 @@ pub const EVENT_SYSTEM_UPDATING_DONE: u32 = 205;
 pub const EVENT_SYSTEM_LOGIN_SUCCEEDED: u32 = 206;
 pub const EVENT_SYSTEM_RELOAD_SCRIPTS: u32 = 207;
 pub const EVENT_SYSTEM_SHUTDOWN: u32 = 208;
+pub const EVENT_SYSTEM_PING_RESULT: u32 = 209;
+pub const EVENT_SYSTEM_SCRIPT_ERROR: u32 = 210;
+pub const EVENT_SYSTEM_RESUMING: u32 = 211;
+pub const EVENT_SYSTEM_SQUELCH_LIST_CHANGED: u32 = 212;
+pub const EVENT_SYSTEM_LOGIN_RETRY: u32 = 213;
+
+// S2C protocol events (300-399): 300 + S2CEvent::discriminant, delivered as
+// the protocol `s2c` event
+pub const EVENT_S2C_BASE: u32 = 300;
+pub const EVENT_S2C_LOGIN_CREATE_PLAYER: u32 = 300;
+pub const EVENT_S2C_LOGIN_CHARACTER_SET: u32 = 301;
+pub const EVENT_S2C_ITEM_CREATE_OBJECT: u32 = 302;
+pub const EVENT_S2C_ITEM_DELETE_OBJECT: u32 = 303;
+pub const EVENT_S2C_ITEM_ON_VIEW_CONTENTS: u32 = 304;
+pub const EVENT_S2C_PLAYER_CONTAINERS_RECEIVED: u32 = 305;
+pub const EVENT_S2C_CHARACTER_ERROR: u32 = 306;
+pub const EVENT_S2C_HEAR_SPEECH: u32 = 307;
+pub const EVENT_S2C_HEAR_RANGED_SPEECH: u32 = 308;
+pub const EVENT_S2C_DDD_INTERROGATION: u32 = 309;
+pub const EVENT_S2C_CHARGEN_VERIFICATION_RESPONSE: u32 = 310;
+pub const EVENT_S2C_ITEM_SET_STATE: u32 = 311;
+pub const EVENT_S2C_QUALITIES_PRIVATE_UPDATE_INT: u32 = 312;
+pub const EVENT_S2C_MOVEMENT_POSITION: u32 = 313;
+pub const EVENT_S2C_MOVEMENT_POSITION_AND_MOVEMENT: u32 = 314;
+pub const EVENT_S2C_MOVEMENT_SET_OBJECT_MOVEMENT: u32 = 315;
+pub const EVENT_S2C_EFFECTS_PLAYER_TELEPORT: u32 = 316;
@@ -42,31 +42,116 @@ pub use gromnie::scripting::host::{GameEvent, ScriptEvent, StateEvent, SystemEve
 // Re-export Scene type
 pub use gromnie::scripting::host::Scene;
 
+// Re-export emote kinds for do_emote
+pub use gromnie::scripting::host::EmoteKind;
+
+// Re-export action types for schedule_action_on_event
+pub use gromnie::scripting::host::{ScheduledAction, TellAction};
+
 // Re-export host functions for WASM scripts
 pub use gromnie::scripting::host::{
+    // Fellowships
+    accept_fellowship,
     accept_trade,
     add_to_trade,
+    // Appraisal
+    appraise_object,
+    // Vendors
+    buy_item,
+    cancel_scheduled_action,
     cancel_timer,
+    cancel_when,
     // Spell casting
+    cast_spell,
     cast_targeted_spell,
     cast_untargeted_spell,
     check_timer,
     close_trade,
+    create_fellowship,
+    decline_fellowship,
     decline_trade,
+    dismiss_from_fellowship,
+    // Emotes
+    do_emote,
     // Movement
     do_movement_command,
+    do_text_emote,
+    // Character sheet
+    get_attributes,
+    // Character list
+    get_characters,
     get_client_state,
+    // Connection health
+    get_connection_stats,
     get_event_time_millis,
+    get_known_spells,
+    // Combat
+    get_object_health,
+    // World objects
+    get_objects,
+    get_quality_float,
+    get_quality_int,
+    get_server_time_millis,
+    get_skills,
+    get_squelched,
+    // Tick info
+    get_tick_count,
+    get_tick_rate_hz,
+    get_world_info,
+    invite_to_fellowship,
+    // Channels
+    join_channel,
+    leave_channel,
+    // Persistent state
+    load_state,
     log,
     login_character,
+    login_character_await,
+    move_to,
+    // Containers
+    open_container,
     // Trading
     open_trade,
+    query_health,
+    quit_fellowship,
+    // Event history
+    recent_events,
+    remove_from_trade,
+    // World objects
+    request_landblock_objects,
     reset_trade,
+    // Confirmations
+    respond_confirmation,
+    // Switching characters
+    return_to_character_select,
+    save_state,
+    // Scheduled actions
+    schedule_action_on_event,
+    schedule_at_server_time,
     schedule_recurring,
     schedule_timer,
+    // Vendors
+    sell_item,
     send_chat,
+    send_chat_lines,
+    send_emote,
+    // Raw game actions
+    send_game_action,
     send_tell,
+    // Squelch
+    squelch_add,
+    squelch_remove,
     stop_movement_command,
+    // Key/value storage
+    storage_get,
+    storage_set,
+    timer_remaining_millis,
+    turn_to,
+    // Quality watches
+    unwatch_quality,
+    watch_quality,
+    // One-shot event waits
+    when_event_once,
 };
 
 /// Trait for WASM script implementations
@@ -317,4 +402,31 @@ mod tests {
         // If this fails to compile, it means Scene is no longer exported.
         let _: Option<Scene> = None;
     }
+
+    /// Hash of the WIT as of its current package version. Scripts built
+    /// against an older WIT break in ways the host can only catch by the
+    /// version, so every WIT change has to come with a new version.
+    const VERSIONED_WIT_HASH: u64 = 0xe34b_d8f0_98f6_ce26;
+
+    /// FNV-1a over the non-comment lines, so doc edits don't need a bump
+    /// and Windows line endings hash the same
+    fn wit_hash(wit: &str) -> u64 {
+        wit.lines()
+            .map(str::trim_end)
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .flat_map(|line| line.bytes().chain([b'\n']))
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    #[test]
+    fn wit_changes_come_with_a_version_bump() {
+        let hash = wit_hash(WIT_CONTENT);
+        assert_eq!(
+            hash, VERSIONED_WIT_HASH,
+            "the WIT changed: bump the version in its `package` line, then set VERSIONED_WIT_HASH to {:#x}",
+            hash
+        );
+    }
 }
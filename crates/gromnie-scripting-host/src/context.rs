@@ -1,12 +1,12 @@
-use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::RwLock;
 use tokio::sync::mpsc::UnboundedSender;
 
 use super::timer::TimerId;
 use asheron_rs::message::GameActionMessage;
 use gromnie_client::client::Client;
-use gromnie_events::SimpleClientAction;
+use gromnie_events::{EmoteKind, SimpleClientAction};
 
 /// Client state snapshot for scripts (clones of session and scene state)
 #[derive(Debug, Clone)]
@@ -46,6 +46,19 @@ impl Default for ClientStateSnapshot {
     }
 }
 
+/// Host state shared by every script context
+#[derive(Clone)]
+pub(crate) struct SharedScriptState {
+    pub client: Arc<RwLock<Client>>,
+    pub action_tx: UnboundedSender<SimpleClientAction>,
+    pub timer_manager: Arc<super::timer::TimerManager>,
+    pub state_store: Arc<super::state_store::StateStore>,
+    pub storage: Arc<super::script_storage::ScriptStorage>,
+    pub log_sink: Arc<dyn super::script_log::ScriptLogSink>,
+    pub event_history: Arc<super::event_history::EventHistory>,
+    pub tick_interval: Duration,
+}
+
 /// Context provided to scripts for interacting with the client
 pub struct ScriptContext {
     /// Shared reference to the client
@@ -56,25 +69,58 @@ pub struct ScriptContext {
     game_action_tx: UnboundedSender<GameActionMessage>,
     /// Shared timer manager
     timer_manager: Arc<super::timer::TimerManager>,
+    /// Shared per-script persistent state
+    state_store: Arc<super::state_store::StateStore>,
+    /// Shared per-script key/value storage
+    storage: Arc<super::script_storage::ScriptStorage>,
+    /// Where log() messages are written besides tracing
+    log_sink: Arc<dyn super::script_log::ScriptLogSink>,
+    /// Recent events, shared by all scripts
+    event_history: Arc<super::event_history::EventHistory>,
+    /// Interval between script ticks
+    tick_interval: Duration,
     /// Timestamp when the current event occurred
     event_time: SystemTime,
+    /// Time scripts spent blocked in waiting host calls, which doesn't count
+    /// against the script timeout
+    host_wait: Mutex<HostWait>,
+}
+
+/// Bookkeeping for [`ScriptContext::take_host_wait`]
+#[derive(Default)]
+struct HostWait {
+    /// Start of the wait in progress, if any
+    since: Option<Instant>,
+    /// Finished waits not yet taken
+    total: Duration,
 }
 
 impl ScriptContext {
-    /// Create a new script context
-    pub(crate) async fn new(
-        client: Arc<RwLock<Client>>,
-        action_tx: UnboundedSender<SimpleClientAction>,
-        timer_manager: Arc<super::timer::TimerManager>,
-        event_time: SystemTime,
-    ) -> Self {
+    /// Create a new script context for an event that happened at `event_time`
+    pub(crate) async fn new(shared: SharedScriptState, event_time: SystemTime) -> Self {
+        let SharedScriptState {
+            client,
+            action_tx,
+            timer_manager,
+            state_store,
+            storage,
+            log_sink,
+            event_history,
+            tick_interval,
+        } = shared;
         let game_action_tx = client.read().await.game_action_tx.clone();
         Self {
             client,
             action_tx,
             game_action_tx,
             timer_manager,
+            state_store,
+            storage,
+            log_sink,
+            event_history,
+            tick_interval,
             event_time,
+            host_wait: Mutex::new(HostWait::default()),
         }
     }
 
@@ -107,12 +153,26 @@ impl ScriptContext {
     // ===== Action Methods =====
 
     /// Send a chat message (say to nearby players)
+    ///
+    /// The client reads the leading token, so `@Bob hi` sends a tell and
+    /// `/e waves` an emote.
     pub fn send_chat(&self, message: impl Into<String>) {
         let _ = self.action_tx.send(SimpleClientAction::SendChatSay {
             message: message.into(),
         });
     }
 
+    /// Send chat lines one after another, `interval_millis` apart
+    ///
+    /// The client raises the interval to its minimum and queues the lines
+    /// behind any it is still sending, so a long macro can't flood the server.
+    pub fn send_chat_lines(&self, lines: Vec<String>, interval_millis: u64) {
+        let _ = self.action_tx.send(SimpleClientAction::SendChatLines {
+            lines,
+            interval_millis,
+        });
+    }
+
     /// Send a direct message to a specific player
     pub fn send_tell(&self, recipient: impl Into<String>, message: impl Into<String>) {
         let _ = self.action_tx.send(SimpleClientAction::SendChatTell {
@@ -121,11 +181,51 @@ impl ScriptContext {
         });
     }
 
+    /// Play a predefined emote animation
+    pub fn do_emote(&self, emote: EmoteKind) {
+        let _ = self.action_tx.send(SimpleClientAction::DoEmote { emote });
+    }
+
+    /// Play an emote by motion command value. Returns false (and sends nothing) for unknown ids.
+    pub fn send_emote(&self, emote_id: u32) -> bool {
+        if EmoteKind::from_motion_command(emote_id).is_none() {
+            tracing::warn!(target: "scripting", "Unknown emote id 0x{:08X}, not sending", emote_id);
+            return false;
+        }
+        let _ = self
+            .action_tx
+            .send(SimpleClientAction::SendEmote { emote_id });
+        true
+    }
+
+    /// Send a custom "/e" style emote, e.g. "waves hello"
+    pub fn do_text_emote(&self, text: impl Into<String>) -> Result<(), String> {
+        let text = text.into();
+        if text.trim().is_empty() {
+            return Err("emote text cannot be empty".to_string());
+        }
+        let _ = self
+            .action_tx
+            .send(SimpleClientAction::SendTextEmote { text });
+        Ok(())
+    }
+
     // ===== Trading =====
 
-    pub fn open_trade(&self, partner_id: u32) {
+    /// Ask another player to trade. Fails if not in the world.
+    pub fn open_trade(&self, partner_id: u32) -> Result<(), String> {
         use asheron_rs::gameactions::TradeOpenTradeNegotiations;
         use asheron_rs::types::ObjectId;
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during open_trade");
+            if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+                return Err("cannot open a trade while not in the world".to_string());
+            }
+        }
+
         let _ = self
             .game_action_tx
             .send(GameActionMessage::TradeOpenTradeNegotiations(
@@ -133,65 +233,218 @@ impl ScriptContext {
                     object_id: ObjectId(partner_id),
                 },
             ));
+        Ok(())
     }
 
-    pub fn add_to_trade(&self, item_id: u32, slot: u32) {
-        use asheron_rs::gameactions::TradeAddToTrade;
-        use asheron_rs::types::ObjectId;
+    /// Put one of this character's items into the open trade. Fails if not
+    /// in the world, no trade is open, or the item isn't in the character's
+    /// inventory.
+    pub fn add_to_trade(&self, item_id: u32, slot: u32) -> Result<(), String> {
+        self.check_can_trade("add to a trade")?;
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during add_to_trade");
+            let owned = client
+                .scene
+                .as_in_world()
+                .is_some_and(|scene| client.objects().is_carried_by(item_id, scene.character_id));
+            if !owned {
+                return Err(format!(
+                    "item 0x{:08X} isn't in this character's inventory",
+                    item_id
+                ));
+            }
+        }
+
+        let _ = self.action_tx.send(SimpleClientAction::TradeAdd {
+            item_id: item_id.into(),
+            slot,
+        });
+        Ok(())
+    }
+
+    /// Take an item this character added back out of the open trade. Fails
+    /// if no trade is open or the item isn't in it.
+    pub fn remove_from_trade(&self, item_id: u32) -> Result<(), String> {
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during remove_from_trade");
+            let Some(trade) = client.pending_trade() else {
+                return Err("no trade is open".to_string());
+            };
+            if !trade.contains(item_id) {
+                return Err(format!("item 0x{:08X} isn't in the trade", item_id));
+            }
+        }
+
+        let _ = self.action_tx.send(SimpleClientAction::TradeRemove {
+            item_id: item_id.into(),
+        });
+        Ok(())
+    }
+
+    /// Accept the open trade. Fails if not in the world or no trade is open.
+    pub fn accept_trade(&self) -> Result<(), String> {
+        self.check_can_trade("accept a trade")?;
+        let _ = self.action_tx.send(SimpleClientAction::TradeAccept);
+        Ok(())
+    }
+
+    pub fn decline_trade(&self) -> Result<(), String> {
+        self.check_can_trade("decline a trade")?;
+        let _ = self.action_tx.send(SimpleClientAction::TradeDecline);
+        Ok(())
+    }
+
+    pub fn reset_trade(&self) {
+        let _ = self.action_tx.send(SimpleClientAction::TradeReset);
+    }
+
+    pub fn close_trade(&self) {
+        use asheron_rs::gameactions::TradeCloseTradeNegotiations;
         let _ = self
             .game_action_tx
-            .send(GameActionMessage::TradeAddToTrade(TradeAddToTrade {
-                object_id: ObjectId(item_id),
-                slot_index: slot,
-            }));
+            .send(GameActionMessage::TradeCloseTradeNegotiations(
+                TradeCloseTradeNegotiations {},
+            ));
     }
 
-    pub fn accept_trade(&self) {
-        use asheron_rs::gameactions::TradeAcceptTrade;
-        use asheron_rs::types::{ObjectId, Trade};
+    fn check_can_trade(&self, what: &str) -> Result<(), String> {
         let client = self
             .client
             .try_read()
-            .expect("client lock should not be contended during accept_trade");
-        let Some(trade) = client.pending_trade() else {
-            tracing::warn!(target: "scripting", "accept_trade called but no pending trade");
-            return;
+            .expect("client lock should not be contended during trade actions");
+        if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+            return Err(format!("cannot {} while not in the world", what));
+        }
+        if client.pending_trade().is_none() {
+            return Err("no trade is open".to_string());
+        }
+        Ok(())
+    }
+
+    // ===== Confirmations =====
+
+    /// Answer the unanswered confirmation prompt with `context_id`
+    pub fn respond_confirmation(&self, context_id: u32, accept: bool) -> Result<(), String> {
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during respond_confirmation");
+            if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+                return Err("cannot answer confirmations while not in the world".to_string());
+            }
+            if client.pending_confirmation(context_id).is_none() {
+                return Err(format!(
+                    "no pending confirmation with context {}",
+                    context_id
+                ));
+            }
+        }
+
+        let _ = self
+            .action_tx
+            .send(SimpleClientAction::RespondConfirmation { context_id, accept });
+        Ok(())
+    }
+
+    // ===== Fellowships =====
+
+    /// Answer the outstanding fellowship invite
+    pub fn respond_fellowship_invite(&self, accept: bool) -> Result<(), String> {
+        use asheron_rs::enums::ConfirmationType;
+        use asheron_rs::gameactions::CharacterConfirmationResponse;
+
+        let context_id = {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during respond_fellowship_invite");
+            if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+                return Err("cannot answer fellowship invites while not in the world".to_string());
+            }
+            let Some(invite) = client.pending_fellowship_invite() else {
+                return Err("no pending fellowship invite".to_string());
+            };
+            invite.context_id
         };
+
         let _ = self
             .game_action_tx
-            .send(GameActionMessage::TradeAcceptTrade(TradeAcceptTrade {
-                contents: Trade {
-                    partner_id: ObjectId(trade.partner_id),
-                    sequence: trade.stamp as u64,
-                    status: 0,
-                    initiator_id: ObjectId(trade.initiator_id),
-                    accepted: true,
-                    partner_accepted: false,
+            .send(GameActionMessage::CharacterConfirmationResponse(
+                CharacterConfirmationResponse {
+                    type_: ConfirmationType::Fellowship,
+                    context: context_id,
+                    accepted: accept,
                 },
-            }));
+            ));
+        Ok(())
     }
 
-    pub fn decline_trade(&self) {
-        use asheron_rs::gameactions::TradeDeclineTrade;
+    /// Create a new fellowship led by this character
+    pub fn create_fellowship(&self, name: impl Into<String>) -> Result<(), String> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err("fellowship name cannot be empty".to_string());
+        }
+        self.check_can_manage_fellowship("create a fellowship")?;
+
         let _ = self
-            .game_action_tx
-            .send(GameActionMessage::TradeDeclineTrade(TradeDeclineTrade {}));
+            .action_tx
+            .send(SimpleClientAction::FellowshipCreate { name });
+        Ok(())
     }
 
-    pub fn reset_trade(&self) {
-        use asheron_rs::gameactions::TradeResetTrade;
-        let _ = self
-            .game_action_tx
-            .send(GameActionMessage::TradeResetTrade(TradeResetTrade {}));
+    /// Invite a player into the fellowship. Fails if they aren't a known object.
+    pub fn invite_to_fellowship(&self, player_id: u32) -> Result<(), String> {
+        self.check_can_manage_fellowship("invite to a fellowship")?;
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during invite_to_fellowship");
+            if client.objects().get(player_id).is_none() {
+                return Err(format!("unknown player 0x{:08X}", player_id));
+            }
+        }
+
+        let _ = self.action_tx.send(SimpleClientAction::FellowshipInvite {
+            object_id: player_id.into(),
+        });
+        Ok(())
     }
 
-    pub fn close_trade(&self) {
-        use asheron_rs::gameactions::TradeCloseTradeNegotiations;
-        let _ = self
-            .game_action_tx
-            .send(GameActionMessage::TradeCloseTradeNegotiations(
-                TradeCloseTradeNegotiations {},
-            ));
+    /// Remove a member from the fellowship (leader only; the server ignores
+    /// anyone else)
+    pub fn dismiss_from_fellowship(&self, player_id: u32) -> Result<(), String> {
+        self.check_can_manage_fellowship("dismiss from a fellowship")?;
+        let _ = self.action_tx.send(SimpleClientAction::FellowshipDismiss {
+            object_id: player_id.into(),
+        });
+        Ok(())
+    }
+
+    /// Leave the fellowship
+    pub fn quit_fellowship(&self) -> Result<(), String> {
+        self.check_can_manage_fellowship("quit a fellowship")?;
+        let _ = self.action_tx.send(SimpleClientAction::FellowshipQuit);
+        Ok(())
+    }
+
+    fn check_can_manage_fellowship(&self, what: &str) -> Result<(), String> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during fellowship actions");
+        if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+            return Err(format!("cannot {} while not in the world", what));
+        }
+        Ok(())
     }
 
     // ===== Spell Casting =====
@@ -227,6 +480,404 @@ impl ScriptContext {
             ));
     }
 
+    /// Get the server/world identity learned so far
+    pub fn world_info(&self) -> gromnie_client::client::WorldInfo {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during world_info");
+        client.world_info().clone()
+    }
+
+    /// Get the client's packet counters and last measured round-trip time
+    pub fn connection_stats(&self) -> gromnie_client::client::ConnectionStats {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during connection_stats");
+        client.stats()
+    }
+
+    /// Get the estimated server time in milliseconds, if the server has synced
+    pub fn server_time_millis(&self) -> Option<u64> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during server_time_millis");
+        client.server_time_millis()
+    }
+
+    /// Get the spells known to the current character
+    pub fn known_spells(&self) -> Vec<u32> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during known_spells");
+        client.known_spells().iter().copied().collect()
+    }
+
+    /// Get a snapshot of the character's attributes and skills
+    pub fn character_sheet(&self) -> gromnie_client::client::character_sheet::CharacterSheet {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during character_sheet");
+        client.character_sheet().clone()
+    }
+
+    /// Last known value of one of the character's integer qualities or current vitals
+    pub fn quality_int(&self, property: &str) -> Option<i64> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during quality_int");
+        client.character_sheet().int(property)
+    }
+
+    /// Last known value of one of the character's float qualities
+    pub fn quality_float(&self, property: &str) -> Option<f64> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during quality_float");
+        client.character_sheet().float(property)
+    }
+
+    /// Ask the server for an object's health; the answer arrives as `TargetHealth`
+    pub fn query_health(&self, object_id: u32) {
+        let _ = self.action_tx.send(SimpleClientAction::QueryHealth {
+            object_id: object_id.into(),
+        });
+    }
+
+    /// Open a container; its items arrive as `ContainerContents`
+    pub fn open_container(&self, container_id: u32) {
+        let _ = self.action_tx.send(SimpleClientAction::OpenContainer {
+            container_id: container_id.into(),
+        });
+    }
+
+    /// Ask the server to appraise an object; the answer arrives as `AppraiseResult`
+    pub fn appraise_object(&self, object_id: u32) {
+        let _ = self.action_tx.send(SimpleClientAction::Appraise {
+            object_id: object_id.into(),
+        });
+    }
+
+    /// Buy `quantity` of a vendor's item. Fails outside the world or for a zero quantity.
+    pub fn buy_item(&self, vendor_id: u32, item_id: u32, quantity: u32) -> Result<(), String> {
+        self.check_vendor_transaction(quantity)?;
+        let _ = self.action_tx.send(SimpleClientAction::BuyItem {
+            vendor_id: vendor_id.into(),
+            item_id: item_id.into(),
+            quantity,
+        });
+        Ok(())
+    }
+
+    /// Sell `quantity` of an inventory item to a vendor. Fails outside the world or for a zero quantity.
+    pub fn sell_item(&self, vendor_id: u32, item_id: u32, quantity: u32) -> Result<(), String> {
+        self.check_vendor_transaction(quantity)?;
+        let _ = self.action_tx.send(SimpleClientAction::SellItem {
+            vendor_id: vendor_id.into(),
+            item_id: item_id.into(),
+            quantity,
+        });
+        Ok(())
+    }
+
+    /// Send a game action the client doesn't model. `payload` is everything
+    /// after the action type. Fails outside the world.
+    pub fn send_raw_game_action(&self, opcode: u32, payload: Vec<u8>) -> Result<(), String> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during send_raw_game_action");
+        if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+            return Err("cannot send game actions while not in the world".to_string());
+        }
+        let _ = self
+            .action_tx
+            .send(SimpleClientAction::SendRawGameAction { opcode, payload });
+        Ok(())
+    }
+
+    /// Log in as a character and wait until the server lets it into the world
+    /// or rejects it. Fails if the login can't start (e.g. not at character
+    /// select), the server sends a CharacterError, the connection drops, or
+    /// nothing happens within `timeout`.
+    pub async fn login_character_and_wait(
+        &self,
+        account: String,
+        character_id: u32,
+        character_name: String,
+        timeout: Duration,
+    ) -> Result<(), String> {
+        self.begin_host_wait();
+        let outcome = self
+            .wait_for_login(account, character_id, character_name, timeout)
+            .await;
+        self.end_host_wait();
+        outcome
+    }
+
+    async fn wait_for_login(
+        &self,
+        account: String,
+        character_id: u32,
+        character_name: String,
+        timeout: Duration,
+    ) -> Result<(), String> {
+        let result = self
+            .client
+            .write()
+            .await
+            .attempt_character_login_with_result(character_id, character_name, account)?;
+
+        match tokio::time::timeout(timeout, result).await {
+            Ok(Ok(outcome)) => outcome,
+            Ok(Err(_)) => Err("connection lost before the login finished".to_string()),
+            Err(_) => Err(format!(
+                "login did not finish within {}ms",
+                timeout.as_millis()
+            )),
+        }
+    }
+
+    /// Log the current character out, back to character select
+    ///
+    /// The client forgets the character's objects, qualities and spells as
+    /// part of the logout.
+    pub fn return_to_character_select(&self) -> Result<(), String> {
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during return_to_character_select");
+            if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+                return Err("not in the world".to_string());
+            }
+        }
+
+        let _ = self.action_tx.send(SimpleClientAction::Logout);
+        Ok(())
+    }
+
+    /// Get the characters on the account, waiting up to `timeout` for the
+    /// server to send the character list if it hasn't been seen yet
+    pub async fn character_list_and_wait(
+        &self,
+        timeout: Duration,
+    ) -> Result<Vec<asheron_rs::types::CharacterIdentity>, String> {
+        self.begin_host_wait();
+        let outcome = self.wait_for_character_list(timeout).await;
+        self.end_host_wait();
+        outcome
+    }
+
+    async fn wait_for_character_list(
+        &self,
+        timeout: Duration,
+    ) -> Result<Vec<asheron_rs::types::CharacterIdentity>, String> {
+        let result = self.client.write().await.character_list_with_result()?;
+
+        match tokio::time::timeout(timeout, result).await {
+            Ok(Ok(characters)) => Ok(characters),
+            Ok(Err(_)) => Err("connection lost before the character list arrived".to_string()),
+            Err(_) => Err(format!(
+                "no character list within {}ms",
+                timeout.as_millis()
+            )),
+        }
+    }
+
+    fn begin_host_wait(&self) {
+        let mut wait = self.host_wait.lock().expect("host wait poisoned");
+        wait.since = Some(Instant::now());
+    }
+
+    fn end_host_wait(&self) {
+        let mut wait = self.host_wait.lock().expect("host wait poisoned");
+        if let Some(since) = wait.since.take() {
+            wait.total += since.elapsed();
+        }
+    }
+
+    /// Time spent in waiting host calls since the last call, including a wait
+    /// still in progress at `now`
+    pub(crate) fn take_host_wait(&self, now: Instant) -> Duration {
+        let mut wait = self.host_wait.lock().expect("host wait poisoned");
+        let mut waited = std::mem::take(&mut wait.total);
+        if let Some(since) = wait.since.as_mut() {
+            waited += now.saturating_duration_since(*since);
+            *since = now;
+        }
+        waited
+    }
+
+    /// Walk toward local coordinates in the current landblock. Fails outside
+    /// the world, for non-finite coordinates or x/y outside the landblock, or
+    /// before the character's position is known.
+    pub fn move_to(&self, x: f32, y: f32, z: f32) -> Result<(), String> {
+        if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+            return Err("coordinates must be finite numbers".to_string());
+        }
+        gromnie_client::client::check_in_landblock(x, y)?;
+        self.check_can_move()?;
+        let _ = self.action_tx.send(SimpleClientAction::MoveTo { x, y, z });
+        Ok(())
+    }
+
+    /// Face `heading` degrees clockwise from north. Fails outside the world
+    /// or before the character's position is known.
+    pub fn turn_to(&self, heading: f32) -> Result<(), String> {
+        if !heading.is_finite() {
+            return Err("heading must be a finite number".to_string());
+        }
+        self.check_can_move()?;
+        let _ = self.action_tx.send(SimpleClientAction::TurnTo { heading });
+        Ok(())
+    }
+
+    /// Squelch a player by name. Applied by the client after the callback.
+    pub fn squelch_add(&self, name: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("name must not be empty".to_string());
+        }
+        let _ = self.action_tx.send(SimpleClientAction::Squelch {
+            name: name.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Stop squelching a player. Fails if they aren't squelched.
+    pub fn squelch_remove(&self, name: &str) -> Result<(), String> {
+        let name = name.trim();
+        let squelched = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during squelch_remove")
+            .is_name_squelched(name);
+        if !squelched {
+            return Err(format!("{} isn't squelched", name));
+        }
+        let _ = self.action_tx.send(SimpleClientAction::Unsquelch {
+            name: name.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Names currently squelched, sorted
+    pub fn squelched(&self) -> Vec<String> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during squelched");
+        client.squelched()
+    }
+
+    // ===== Channels =====
+
+    /// Join a chat channel by its Channel flag value
+    pub fn join_channel(&self, channel: u32) -> Result<(), String> {
+        self.check_can_use_channels("join a channel")?;
+        let _ = self
+            .action_tx
+            .send(SimpleClientAction::JoinChannel { channel });
+        Ok(())
+    }
+
+    /// Leave a chat channel by its Channel flag value
+    pub fn leave_channel(&self, channel: u32) -> Result<(), String> {
+        self.check_can_use_channels("leave a channel")?;
+        let _ = self
+            .action_tx
+            .send(SimpleClientAction::LeaveChannel { channel });
+        Ok(())
+    }
+
+    fn check_can_use_channels(&self, what: &str) -> Result<(), String> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during channel actions");
+        if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+            return Err(format!("cannot {} while not in the world", what));
+        }
+        Ok(())
+    }
+
+    fn check_can_move(&self) -> Result<(), String> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during movement");
+        if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+            return Err("cannot move while not in the world".to_string());
+        }
+        if client.player_position().is_none() {
+            return Err("the character's position isn't known yet".to_string());
+        }
+        Ok(())
+    }
+
+    fn check_vendor_transaction(&self, quantity: u32) -> Result<(), String> {
+        if quantity == 0 {
+            return Err("quantity must be at least 1".to_string());
+        }
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during vendor transactions");
+        if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+            return Err("cannot trade with vendors while not in the world".to_string());
+        }
+        Ok(())
+    }
+
+    /// Last reported health of an object, in percent
+    pub fn object_health(&self, object_id: u32) -> Option<f32> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during object_health");
+        client.object_health(object_id)
+    }
+
+    /// Objects the client currently knows about, in object ID order
+    pub fn objects(&self) -> Vec<gromnie_client::client::objects::KnownObject> {
+        let client = self
+            .client
+            .try_read()
+            .expect("client lock should not be contended during objects");
+        client.objects().iter().cloned().collect()
+    }
+
+    /// Cast a known spell, targeted if `target_id` is given
+    ///
+    /// Only validates and sends the cast; the outcome arrives later as game events.
+    pub fn cast_spell(&self, spell_id: u32, target_id: Option<u32>) -> Result<(), String> {
+        {
+            let client = self
+                .client
+                .try_read()
+                .expect("client lock should not be contended during cast_spell");
+            if !matches!(client.scene, gromnie_client::client::Scene::InWorld(_)) {
+                return Err("cannot cast spells while not in the world".to_string());
+            }
+            if !client.known_spells().contains(&spell_id) {
+                return Err(format!("spell {} is not known", spell_id));
+            }
+        }
+
+        match target_id {
+            Some(target_id) => self.cast_targeted_spell(target_id, spell_id),
+            None => self.cast_untargeted_spell(spell_id),
+        }
+        Ok(())
+    }
+
     /// Send a client action
     pub fn send_action(&self, action: SimpleClientAction) {
         let _ = self.action_tx.send(action);
@@ -246,6 +897,21 @@ impl ScriptContext {
             .schedule_timer(Duration::from_secs(delay_secs), name.into())
     }
 
+    /// Schedule a one-shot timer for a time on the server clock (see
+    /// [`ScriptContext::server_time_millis`]). Fails until the server time is known.
+    pub fn schedule_at_server_time(
+        &self,
+        server_time_millis: u64,
+        name: impl Into<String>,
+    ) -> Result<TimerId, String> {
+        let now = self
+            .server_time_millis()
+            .ok_or_else(|| "server time is not known yet (no TIME_SYNC received)".to_string())?;
+        Ok(self
+            .timer_manager
+            .schedule_at_server_time(server_time_millis, now, name.into()))
+    }
+
     /// Schedule a recurring timer that fires repeatedly at an interval
     pub fn schedule_recurring(&self, interval_secs: u64, name: impl Into<String>) -> TimerId {
         self.timer_manager
@@ -262,6 +928,74 @@ impl ScriptContext {
         self.timer_manager.check_timer(timer_id)
     }
 
+    /// Time until a timer next fires, or None if it is no longer active
+    pub fn timer_remaining(&self, timer_id: TimerId) -> Option<Duration> {
+        self.timer_manager.remaining(timer_id)
+    }
+
+    /// Ticks per second the host drives on_tick at, rounded to the nearest whole number
+    pub fn tick_rate_hz(&self) -> u32 {
+        if self.tick_interval.is_zero() {
+            return 0;
+        }
+        (1.0 / self.tick_interval.as_secs_f64()).round() as u32
+    }
+
+    // ===== Persistent State =====
+
+    /// Save a script's persistent state (empty data clears it)
+    pub fn save_state(&self, script_id: &str, data: Vec<u8>) -> Result<(), String> {
+        self.state_store.save(script_id, data)
+    }
+
+    /// Load a script's persistent state, if any was saved
+    pub fn load_state(&self, script_id: &str) -> Option<Vec<u8>> {
+        self.state_store.load(script_id)
+    }
+
+    // ===== Key/Value Storage =====
+
+    /// Get a value from a script's key/value storage
+    pub fn storage_get(&self, script_id: &str, key: &str) -> Option<String> {
+        self.storage.get(script_id, key)
+    }
+
+    /// Set a value in a script's key/value storage (an empty value removes the key)
+    pub fn storage_set(&self, script_id: &str, key: &str, value: &str) -> Result<(), String> {
+        self.storage.set(script_id, key, value)
+    }
+
+    /// Write a script's state and key/value storage to disk and flush its
+    /// log file. Called after on_unload.
+    pub fn flush_storage(&self, script_id: &str) {
+        if let Err(e) = self.state_store.flush_script(script_id) {
+            tracing::error!(target: "scripting", "Failed to save state for {}: {}", script_id, e);
+        }
+        if let Err(e) = self.storage.flush_script(script_id) {
+            tracing::error!(target: "scripting", "Failed to save storage for {}: {}", script_id, e);
+        }
+        self.log_sink.flush_script(script_id);
+    }
+
+    // ===== Logging =====
+
+    /// Log a message from a script: it goes to tracing through the client
+    /// and to the log sink, if one is attached
+    pub fn log(&self, script_id: &str, message: String) {
+        self.log_sink.write(script_id, &message);
+        let _ = self.action_tx.send(SimpleClientAction::LogScriptMessage {
+            script_id: script_id.to_string(),
+            message,
+        });
+    }
+
+    // ===== Event History =====
+
+    /// Up to `max` of the most recent client events, oldest first
+    pub fn recent_events(&self, max: usize) -> Vec<gromnie_events::ClientEvent> {
+        self.event_history.recent(max)
+    }
+
     // ===== State Access =====
 
     /// Get a read-only snapshot of the client state
@@ -276,3 +1010,167 @@ impl ScriptContext {
         self.event_time
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_history::EventHistory;
+    use crate::script_log::NullLogSink;
+    use crate::script_storage::ScriptStorage;
+    use crate::state_store::StateStore;
+    use crate::timer::TimerManager;
+    use gromnie_client::client::{InWorldScene, Scene};
+    use tokio::sync::mpsc;
+
+    /// A context and what it sends, for a client that is or isn't in the world
+    struct TestContext {
+        context: ScriptContext,
+        client: Arc<RwLock<Client>>,
+        /// The client's own action channel, to apply what the script sent
+        client_action_tx: mpsc::UnboundedSender<SimpleClientAction>,
+        action_rx: mpsc::UnboundedReceiver<SimpleClientAction>,
+        game_action_rx: mpsc::UnboundedReceiver<GameActionMessage>,
+    }
+
+    async fn test_context(in_world: bool) -> TestContext {
+        let (mut client, client_action_tx) = Client::new(
+            1,
+            "127.0.0.1:9000".to_string(),
+            "test_user".to_string(),
+            "test_pass".to_string(),
+            None,
+            mpsc::channel(100).0,
+            false,
+        )
+        .await;
+        if in_world {
+            client.scene = Scene::InWorld(InWorldScene::new(1, "Test".to_string()));
+        }
+        let (game_action_tx, game_action_rx) = mpsc::unbounded_channel();
+        client.game_action_tx = game_action_tx;
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+
+        let client = Arc::new(RwLock::new(client));
+        let shared = SharedScriptState {
+            client: client.clone(),
+            action_tx,
+            timer_manager: Arc::new(TimerManager::new()),
+            state_store: Arc::new(StateStore::new()),
+            storage: Arc::new(ScriptStorage::new()),
+            log_sink: Arc::new(NullLogSink),
+            event_history: Arc::new(EventHistory::default()),
+            tick_interval: Duration::from_millis(50),
+        };
+        TestContext {
+            context: ScriptContext::new(shared, SystemTime::now()).await,
+            client,
+            client_action_tx,
+            action_rx,
+            game_action_rx,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cast_spell_from_login_spell_book() {
+        let TestContext {
+            context,
+            client,
+            mut game_action_rx,
+            ..
+        } = test_context(true).await;
+        assert!(context.cast_spell(27, None).is_err());
+
+        // What LoginPlayerDescription hands over right after login
+        client.write().await.load_spell_book([27, 1782]);
+
+        context.cast_spell(27, None).unwrap();
+        let Ok(GameActionMessage::MagicCastUntargetedSpell(cast)) = game_action_rx.try_recv()
+        else {
+            panic!("expected an untargeted cast");
+        };
+        assert_eq!(cast.spell_id.id.0, 27);
+    }
+
+    #[tokio::test]
+    async fn test_move_to_outside_landblock_is_rejected() {
+        let TestContext { context, .. } = test_context(true).await;
+        let err = context.move_to(200.0, 10.0, 0.0).unwrap_err();
+        assert!(err.contains("outside the landblock"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_respond_confirmation_needs_world_and_prompt() {
+        let mut outside = test_context(false).await;
+        let err = outside.context.respond_confirmation(17, true).unwrap_err();
+        assert!(err.contains("not in the world"), "{}", err);
+        assert!(outside.action_rx.try_recv().is_err());
+
+        let mut in_world = test_context(true).await;
+        let err = in_world.context.respond_confirmation(17, true).unwrap_err();
+        assert!(err.contains("no pending confirmation"), "{}", err);
+        assert!(in_world.action_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_squelch_add_and_remove() {
+        let mut t = test_context(true).await;
+        assert!(t.context.squelch_add("  ").is_err());
+        assert!(t.context.squelch_remove("Spammer").is_err());
+        assert!(t.action_rx.try_recv().is_err());
+
+        t.context.squelch_add(" Spammer ").unwrap();
+        let action = t.action_rx.try_recv().unwrap();
+        assert!(
+            matches!(&action, SimpleClientAction::Squelch { name } if name == "Spammer"),
+            "{:?}",
+            action
+        );
+
+        // Once the client has applied it, any capitalisation can be removed
+        t.client_action_tx.send(action).unwrap();
+        t.client.write().await.process_actions();
+        t.context.squelch_remove("SPAMMER").unwrap();
+        assert!(matches!(
+            t.action_rx.try_recv(),
+            Ok(SimpleClientAction::Unsquelch { name }) if name == "SPAMMER"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_trade_calls_without_a_trade() {
+        let mut t = test_context(true).await;
+        let err = t.context.remove_from_trade(0x8000_0010).unwrap_err();
+        assert_eq!(err, "no trade is open");
+        let err = t.context.add_to_trade(0x8000_0010, 0).unwrap_err();
+        assert_eq!(err, "no trade is open");
+        let err = t.context.accept_trade().unwrap_err();
+        assert_eq!(err, "no trade is open");
+        let err = t.context.decline_trade().unwrap_err();
+        assert_eq!(err, "no trade is open");
+        assert!(t.action_rx.try_recv().is_err());
+        // Opening a trade only needs the world
+        t.context.open_trade(0x5000_0002).unwrap();
+        assert!(matches!(
+            t.game_action_rx.try_recv(),
+            Ok(GameActionMessage::TradeOpenTradeNegotiations(_))
+        ));
+
+        let mut t = test_context(false).await;
+        let err = t.context.accept_trade().unwrap_err();
+        assert_eq!(err, "cannot accept a trade while not in the world");
+        let err = t.context.decline_trade().unwrap_err();
+        assert_eq!(err, "cannot decline a trade while not in the world");
+        let err = t.context.open_trade(0x5000_0002).unwrap_err();
+        assert_eq!(err, "cannot open a trade while not in the world");
+        assert!(t.action_rx.try_recv().is_err());
+        assert!(t.game_action_rx.try_recv().is_err());
+
+        // Resets go through the client so its trade bookkeeping sees them
+        t.context.reset_trade();
+        assert!(matches!(
+            t.action_rx.try_recv(),
+            Ok(SimpleClientAction::TradeReset)
+        ));
+        assert!(t.game_action_rx.try_recv().is_err());
+    }
+}
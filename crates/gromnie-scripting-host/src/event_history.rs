@@ -0,0 +1,138 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use gromnie_events::ClientEvent;
+
+use crate::EventFilter;
+
+/// Default number of events kept for `recent-events`
+pub const DEFAULT_EVENT_HISTORY_SIZE: usize = 200;
+
+/// Recent client events, so a script loaded mid-session can catch up
+///
+/// Only events scripts can subscribe to by name are kept. Protocol events are
+/// skipped: they arrive far more often than anything else, would push the
+/// useful context (character list, login, chat) out of the buffer, and carry
+/// the largest payloads. Once full, the oldest event is dropped for each new
+/// one, so nothing is retained past the last `capacity` recorded events.
+#[derive(Debug)]
+pub struct EventHistory {
+    capacity: usize,
+    events: Mutex<VecDeque<ClientEvent>>,
+}
+
+impl EventHistory {
+    /// Create a history holding up to `capacity` events (0 disables it)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Maximum number of events kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Record an event if scripts could have subscribed to it
+    pub fn record(&self, event: &ClientEvent) {
+        if self.capacity == 0 || !Self::is_recorded(event) {
+            return;
+        }
+        let mut events = self.events.lock().unwrap();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event.clone());
+    }
+
+    /// Up to `max` of the most recent events, oldest first
+    pub fn recent(&self, max: usize) -> Vec<ClientEvent> {
+        let events = self.events.lock().unwrap();
+        let skip = events.len().saturating_sub(max);
+        events.iter().skip(skip).cloned().collect()
+    }
+
+    fn is_recorded(event: &ClientEvent) -> bool {
+        match event {
+            ClientEvent::Protocol(_) => false,
+            // Game events without a filter have no WIT representation
+            ClientEvent::Game(_) => (1..100)
+                .filter_map(EventFilter::from_discriminant)
+                .any(|filter| filter.matches(event)),
+            ClientEvent::State(_) | ClientEvent::System(_) => true,
+        }
+    }
+}
+
+impl Default for EventHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_HISTORY_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gromnie_events::{ClientStateEvent, ProtocolEvent, S2CEvent, SimpleGameEvent as GameEvent};
+
+    fn chat(message: &str) -> ClientEvent {
+        ClientEvent::Game(GameEvent::ChatMessageReceived {
+            message: message.to_string(),
+            message_type: 0x02,
+        })
+    }
+
+    fn chat_text(event: &ClientEvent) -> &str {
+        match event {
+            ClientEvent::Game(GameEvent::ChatMessageReceived { message, .. }) => message,
+            other => panic!("Expected chat, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_keeps_most_recent_events() {
+        let history = EventHistory::new(3);
+        for i in 0..5 {
+            history.record(&chat(&i.to_string()));
+        }
+
+        let recent = history.recent(10);
+        let texts: Vec<_> = recent.iter().map(chat_text).collect();
+        assert_eq!(texts, ["2", "3", "4"]);
+
+        let recent = history.recent(2);
+        let texts: Vec<_> = recent.iter().map(chat_text).collect();
+        assert_eq!(texts, ["3", "4"]);
+        assert!(history.recent(0).is_empty());
+    }
+
+    #[test]
+    fn test_skips_protocol_and_unsubscribable_events() {
+        let history = EventHistory::new(10);
+        history.record(&ClientEvent::Protocol(ProtocolEvent::S2C(
+            S2CEvent::ItemDeleteObject { object_id: 1 },
+        )));
+        history.record(&ClientEvent::Game(GameEvent::ItemDeleteObject {
+            object_id: 1.into(),
+        }));
+        history.record(&ClientEvent::State(ClientStateEvent::InWorld));
+        history.record(&chat("hi"));
+
+        let recent = history.recent(10);
+        assert_eq!(recent.len(), 2);
+        assert!(matches!(
+            recent[0],
+            ClientEvent::State(ClientStateEvent::InWorld)
+        ));
+        assert_eq!(chat_text(&recent[1]), "hi");
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_history() {
+        let history = EventHistory::new(0);
+        history.record(&chat("hi"));
+        assert!(history.recent(10).is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+use gromnie_events::ClientEvent;
+
+use crate::EventFilter;
+
+/// A one-shot wait: fire once for the next event matching `filter`, then deregister
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventWait {
+    pub filter: EventFilter,
+    /// Script-chosen value echoed back in the `when-fired` event
+    pub token: u64,
+}
+
+/// One-shot event waits registered by a single script
+#[derive(Debug, Default, Clone)]
+pub struct EventWaitSet {
+    waits: Vec<EventWait>,
+}
+
+impl EventWaitSet {
+    /// Create an empty wait set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a wait. Several waits may share a token; each fires separately.
+    pub fn register(&mut self, filter: EventFilter, token: u64) {
+        self.waits.push(EventWait { filter, token });
+    }
+
+    /// Remove every pending wait with this token. Returns true if any were removed.
+    pub fn cancel(&mut self, token: u64) -> bool {
+        let before = self.waits.len();
+        self.waits.retain(|w| w.token != token);
+        self.waits.len() != before
+    }
+
+    /// Remove and return every wait that matches the event, in registration order
+    pub fn take_matching(&mut self, event: &ClientEvent) -> Vec<EventWait> {
+        let (fired, pending) = std::mem::take(&mut self.waits)
+            .into_iter()
+            .partition(|w| w.filter.matches(event));
+        self.waits = pending;
+        fired
+    }
+
+    /// Whether no waits are pending
+    pub fn is_empty(&self) -> bool {
+        self.waits.is_empty()
+    }
+
+    /// Number of pending waits
+    pub fn len(&self) -> usize {
+        self.waits.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gromnie_events::ClientStateEvent;
+
+    #[test]
+    fn test_wait_fires_once() {
+        let mut set = EventWaitSet::new();
+        set.register(EventFilter::StateInWorld, 7);
+
+        let event = ClientEvent::State(ClientStateEvent::InWorld);
+        let fired = set.take_matching(&event);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].token, 7);
+
+        assert!(set.take_matching(&event).is_empty());
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_non_matching_waits_stay_pending() {
+        let mut set = EventWaitSet::new();
+        set.register(EventFilter::StateInWorld, 1);
+        set.register(EventFilter::StateCharacterSelect, 2);
+
+        let fired = set.take_matching(&ClientEvent::State(ClientStateEvent::CharacterSelect));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].token, 2);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut set = EventWaitSet::new();
+        set.register(EventFilter::StateInWorld, 1);
+
+        assert!(!set.cancel(2));
+        assert!(set.cancel(1));
+        assert!(
+            set.take_matching(&ClientEvent::State(ClientStateEvent::InWorld))
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tracing::{debug, warn};
+
+/// How one kind of per-script value is stored on disk
+pub(crate) trait FileFormat {
+    /// The per-script value; its default is what a script without a file gets
+    type Value: Default;
+
+    /// Extension of each script's file
+    const EXTENSION: &'static str;
+    /// What the files hold, for log messages
+    const KIND: &'static str;
+
+    /// Whether a value holds nothing, in which case its file is removed
+    fn is_empty(value: &Self::Value) -> bool;
+
+    fn encode(script_id: &str, value: &Self::Value) -> std::io::Result<Vec<u8>>;
+
+    fn decode(script_id: &str, bytes: &[u8]) -> Result<Self::Value, String>;
+}
+
+struct Entry<V> {
+    value: V,
+    /// Changed since the last flush
+    dirty: bool,
+}
+
+/// Per-script values keyed by script ID, optionally backed by one file per
+/// script in a persist directory
+///
+/// A script's file is read the first time the script touches its value, and
+/// written back by [`KeyedFileStore::flush_script`] or
+/// [`KeyedFileStore::flush`] only if the value changed since.
+pub(crate) struct KeyedFileStore<F: FileFormat> {
+    scripts: Mutex<HashMap<String, Entry<F::Value>>>,
+    persist_dir: Option<PathBuf>,
+}
+
+impl<F: FileFormat> Default for KeyedFileStore<F> {
+    fn default() -> Self {
+        Self {
+            scripts: Mutex::new(HashMap::new()),
+            persist_dir: None,
+        }
+    }
+}
+
+impl<F: FileFormat> KeyedFileStore<F> {
+    /// Create a store backed by files in `dir`. Unreadable or corrupt files
+    /// are skipped with a warning, and that script starts fresh.
+    pub fn with_persist_dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            scripts: Mutex::new(HashMap::new()),
+            persist_dir: Some(dir.into()),
+        }
+    }
+
+    /// Read a script's value
+    pub fn read<R>(&self, script_id: &str, f: impl FnOnce(&F::Value) -> R) -> R {
+        let mut scripts = self.scripts.lock().expect("keyed file store poisoned");
+        f(&self.entry(&mut scripts, script_id).value)
+    }
+
+    /// Change a script's value. `f` returns whether it changed anything, so
+    /// that unchanged values aren't rewritten on the next flush.
+    pub fn modify(
+        &self,
+        script_id: &str,
+        f: impl FnOnce(&mut F::Value) -> Result<bool, String>,
+    ) -> Result<(), String> {
+        let mut scripts = self.scripts.lock().expect("keyed file store poisoned");
+        let entry = self.entry(&mut scripts, script_id);
+        if f(&mut entry.value)? {
+            entry.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Write one script's value to the persist directory if it changed.
+    /// Returns whether a file was written or removed.
+    pub fn flush_script(&self, script_id: &str) -> std::io::Result<bool> {
+        let Some(dir) = &self.persist_dir else {
+            return Ok(false);
+        };
+
+        let mut scripts = self.scripts.lock().expect("keyed file store poisoned");
+        let Some(entry) = scripts.get_mut(script_id) else {
+            return Ok(false);
+        };
+        write_entry::<F>(dir, script_id, entry)
+    }
+
+    /// Write every script's changed value. Returns the number of files
+    /// written or removed; always 0 for an in-memory store.
+    pub fn flush(&self) -> std::io::Result<usize> {
+        let Some(dir) = &self.persist_dir else {
+            return Ok(0);
+        };
+
+        let mut scripts = self.scripts.lock().expect("keyed file store poisoned");
+        let mut written = 0;
+        for (script_id, entry) in scripts.iter_mut() {
+            if write_entry::<F>(dir, script_id, entry)? {
+                written += 1;
+            }
+        }
+        Ok(written)
+    }
+
+    /// A script's entry, loading it from disk on first use
+    fn entry<'a>(
+        &self,
+        scripts: &'a mut HashMap<String, Entry<F::Value>>,
+        script_id: &str,
+    ) -> &'a mut Entry<F::Value> {
+        scripts
+            .entry(script_id.to_string())
+            .or_insert_with(|| Entry {
+                value: self
+                    .persist_dir
+                    .as_deref()
+                    .map(|dir| load_file::<F>(dir, script_id))
+                    .unwrap_or_default(),
+                dirty: false,
+            })
+    }
+}
+
+fn write_entry<F: FileFormat>(
+    dir: &Path,
+    script_id: &str,
+    entry: &mut Entry<F::Value>,
+) -> std::io::Result<bool> {
+    if !entry.dirty {
+        return Ok(false);
+    }
+
+    let path = script_file_path(dir, script_id, F::EXTENSION);
+    if F::is_empty(&entry.value) {
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    } else {
+        std::fs::create_dir_all(dir)?;
+        let bytes = F::encode(script_id, &entry.value)?;
+        // Write then rename so a crash mid-write can't leave a truncated file
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, bytes)?;
+        std::fs::rename(&tmp, &path)?;
+    }
+
+    entry.dirty = false;
+    debug!(target: "scripting", "Saved {} for {} to {}", F::KIND, script_id, path.display());
+    Ok(true)
+}
+
+fn load_file<F: FileFormat>(dir: &Path, script_id: &str) -> F::Value {
+    let path = script_file_path(dir, script_id, F::EXTENSION);
+    let bytes = match std::fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return F::Value::default(),
+        Err(e) => {
+            warn!(
+                target: "scripting",
+                "Failed to read script {} {}: {}",
+                F::KIND,
+                path.display(),
+                e
+            );
+            return F::Value::default();
+        }
+    };
+
+    match F::decode(script_id, &bytes) {
+        Ok(value) => value,
+        Err(e) => {
+            warn!(
+                target: "scripting",
+                "Ignoring script {} file {}: {}",
+                F::KIND,
+                path.display(),
+                e
+            );
+            F::Value::default()
+        }
+    }
+}
+
+/// Path of a per-script file in `dir`, with anything but `[A-Za-z0-9_-]` in
+/// the script ID replaced
+fn script_file_path(dir: &Path, script_id: &str, extension: &str) -> PathBuf {
+    let name: String = script_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    dir.join(name).with_extension(extension)
+}
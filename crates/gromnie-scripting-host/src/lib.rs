@@ -8,18 +8,33 @@ use std::sync::Arc;
 use std::time::Duration;
 
 pub mod context;
+pub mod event_history;
+pub mod event_wait;
+mod keyed_file_store;
+pub mod quality_watch;
 pub mod registry;
 pub mod reload;
+pub mod scheduled_action;
+pub mod script_log;
 pub mod script_runner;
 pub mod script_scanner;
+pub mod script_storage;
+pub mod state_store;
 pub mod timer;
 pub mod wasm;
 
 // Re-export commonly used types for host-side scripting
 pub use api::Script as ApiScript;
 pub use context::{ClientState, ClientStateSnapshot, ScriptContext};
-pub use reload::setup_reload_signal_handler;
+pub use event_history::EventHistory;
+pub use event_wait::EventWaitSet;
+pub use quality_watch::{QualityChange, QualityWatchSet};
+pub use reload::{ReloadSignalHandle, setup_reload_signal_handler};
+pub use scheduled_action::ScheduledActionSet;
+pub use script_log::{FileLogSink, NullLogSink, ScriptLogSink};
 pub use script_runner::{ScriptConsumer, ScriptRunner, create_script_consumer};
+pub use script_storage::ScriptStorage;
+pub use state_store::StateStore;
 pub use timer::{TimerId, TimerManager};
 
 // Registry is now just a utility function
@@ -59,7 +74,9 @@ pub trait Script: Send + 'static {
         ctx: Arc<ScriptContext>,
     ) -> ::core::pin::Pin<Box<dyn ::core::future::Future<Output = ()> + ::core::marker::Send + 'a>>;
 
-    /// Called periodically at a fixed rate (configurable, default ~20Hz)
+    /// Called periodically at `tick_hz` from the scripting config (default
+    /// 20Hz). `delta` is the time since the previous tick, which can be longer
+    /// than the nominal interval when the runner is busy
     fn on_tick<'a>(
         &'a mut self,
         ctx: Arc<ScriptContext>,
@@ -83,6 +100,59 @@ pub enum EventFilter {
     CharacterError,
     /// Chat message received
     ChatMessageReceived,
+    /// Server rejected an action
+    ActionError,
+    /// Server reported an object's health
+    TargetHealth,
+    /// An object's position changed (Movement_PositionEvent or PositionAndMovementEvent)
+    MovementPosition,
+    /// An object's movement/animation state changed (Movement_SetObjectMovement)
+    MovementSetObjectMovement,
+    /// A player died (Combat_HandlePlayerDeathEvent)
+    PlayerDeath,
+    /// An appraisal was answered (Item_SetAppraiseInfo)
+    AppraiseResult,
+    /// A vendor's stock arrived (Vendor_VendorInfo)
+    VendorInfo,
+    /// A container opened by a script reported its items (Item_OnViewContents)
+    ContainerContents,
+    /// The server asked a yes/no question (Character_ConfirmationRequest)
+    ConfirmationRequest,
+    /// A stack of items changed size (Item_UpdateStackSize)
+    StackSizeChanged,
+    /// A message on a joined chat channel (Communication_ChannelBroadcast)
+    ChannelMessage,
+
+    // Combat events
+    /// The local player's attack hit (Combat_HandleAttackerNotificationEvent)
+    DamageDealt,
+    /// The local player was hit (Combat_HandleDefenderNotificationEvent)
+    DamageTaken,
+    /// The defender evaded the local player's attack (Combat_HandleEvasionAttackerNotificationEvent)
+    AttackEvaded,
+    /// The local player evaded an attack (Combat_HandleEvasionDefenderNotificationEvent)
+    AttackAvoided,
+    /// The local player killed something or was killed (Combat_HandleVictimNotificationEvent*)
+    CombatKill,
+
+    // Allegiance events
+    /// The character's allegiance changed (Allegiance_AllegianceUpdate)
+    AllegianceUpdated,
+    /// Another player's allegiance arrived (Allegiance_AllegianceInfoResponseEvent)
+    AllegianceInfo,
+    /// An allegiance member logged in or out (Allegiance_AllegianceLoginNotificationEvent)
+    AllegianceMemberLogin,
+
+    // Trade events
+    /// Another player opened a trade (Trade_OpenTrade)
+    TradeOpened,
+    /// An item was added to the open trade (Trade_AddToTrade)
+    TradeItemAdded,
+
+    // Protocol events
+    /// Any S2C protocol event whose [`gromnie_events::S2CEvent::discriminant`]
+    /// is this value. Scripts subscribe with `300 + discriminant`.
+    S2C(u32),
 
     // State events
     /// Client state: Connecting
@@ -107,6 +177,8 @@ pub enum EventFilter {
     StateExitingWorld,
     /// Client state: Character error
     StateCharacterError,
+    /// Any scene change, with the scenes it was between
+    StateTransition,
 
     // System events
     /// System: Authentication succeeded
@@ -127,6 +199,16 @@ pub enum EventFilter {
     SystemReloadScripts,
     /// System: Shutdown
     SystemShutdown,
+    /// System: Ping result
+    SystemPingResult,
+    /// System: Another script failed and was unloaded
+    SystemScriptError,
+    /// System: Logging back into the world after a reconnect
+    SystemResuming,
+    /// System: The squelch list was loaded or changed
+    SystemSquelchListChanged,
+    /// System: The LoginRequest was resent
+    SystemLoginRetry,
 }
 
 impl EventFilter {
@@ -153,6 +235,113 @@ impl EventFilter {
                     ClientEvent::Game(GameEvent::ChatMessageReceived { .. })
                 )
             }
+            EventFilter::ActionError => {
+                matches!(event, ClientEvent::Game(GameEvent::ActionError { .. }))
+            }
+            EventFilter::TargetHealth => {
+                matches!(event, ClientEvent::Game(GameEvent::TargetHealth { .. }))
+            }
+            EventFilter::MovementPosition => {
+                matches!(
+                    event,
+                    ClientEvent::Protocol(gromnie_events::ProtocolEvent::S2C(
+                        gromnie_events::S2CEvent::MovementPositionEvent { .. }
+                            | gromnie_events::S2CEvent::MovementPositionAndMovementEvent { .. }
+                    ))
+                )
+            }
+            EventFilter::MovementSetObjectMovement => {
+                matches!(
+                    event,
+                    ClientEvent::Protocol(gromnie_events::ProtocolEvent::S2C(
+                        gromnie_events::S2CEvent::MovementSetObjectMovement { .. }
+                    ))
+                )
+            }
+            EventFilter::PlayerDeath => {
+                matches!(event, ClientEvent::Game(GameEvent::PlayerDeath { .. }))
+            }
+            EventFilter::AppraiseResult => {
+                matches!(event, ClientEvent::Game(GameEvent::AppraiseResult { .. }))
+            }
+            EventFilter::VendorInfo => {
+                matches!(event, ClientEvent::Game(GameEvent::VendorInfo { .. }))
+            }
+            EventFilter::ContainerContents => {
+                matches!(
+                    event,
+                    ClientEvent::Game(GameEvent::ContainerContents { .. })
+                )
+            }
+            EventFilter::ConfirmationRequest => {
+                matches!(
+                    event,
+                    ClientEvent::Game(GameEvent::ConfirmationRequest { .. })
+                )
+            }
+            EventFilter::StackSizeChanged => {
+                matches!(
+                    event,
+                    ClientEvent::Game(GameEvent::ItemUpdateStackSize { .. })
+                )
+            }
+            EventFilter::ChannelMessage => {
+                matches!(event, ClientEvent::Game(GameEvent::ChannelMessage { .. }))
+            }
+
+            // Combat event filters
+            EventFilter::DamageDealt => {
+                matches!(event, ClientEvent::Game(GameEvent::DamageDealt { .. }))
+            }
+            EventFilter::DamageTaken => {
+                matches!(event, ClientEvent::Game(GameEvent::DamageTaken { .. }))
+            }
+            EventFilter::AttackEvaded => {
+                matches!(event, ClientEvent::Game(GameEvent::AttackEvaded { .. }))
+            }
+            EventFilter::AttackAvoided => {
+                matches!(event, ClientEvent::Game(GameEvent::AttackAvoided { .. }))
+            }
+            EventFilter::CombatKill => {
+                matches!(event, ClientEvent::Game(GameEvent::CombatKill { .. }))
+            }
+
+            // Allegiance event filters
+            EventFilter::AllegianceUpdated => {
+                matches!(
+                    event,
+                    ClientEvent::Game(GameEvent::AllegianceUpdated { .. })
+                )
+            }
+            EventFilter::AllegianceInfo => {
+                matches!(
+                    event,
+                    ClientEvent::Game(GameEvent::AllegianceInfoReceived { .. })
+                )
+            }
+            EventFilter::AllegianceMemberLogin => {
+                matches!(
+                    event,
+                    ClientEvent::Game(GameEvent::AllegianceMemberLogin { .. })
+                )
+            }
+
+            // Trade event filters
+            EventFilter::TradeOpened => {
+                matches!(event, ClientEvent::Game(GameEvent::TradeOpened { .. }))
+            }
+            EventFilter::TradeItemAdded => {
+                matches!(event, ClientEvent::Game(GameEvent::TradeItemAdded { .. }))
+            }
+
+            // Protocol event filters
+            EventFilter::S2C(discriminant) => {
+                matches!(
+                    event,
+                    ClientEvent::Protocol(gromnie_events::ProtocolEvent::S2C(s2c))
+                        if s2c.discriminant() == *discriminant
+                )
+            }
 
             // State event filters
             EventFilter::StateConnecting => {
@@ -194,6 +383,12 @@ impl EventFilter {
             EventFilter::StateCharacterError => {
                 matches!(event, ClientEvent::State(ClientStateEvent::CharacterError))
             }
+            EventFilter::StateTransition => {
+                matches!(
+                    event,
+                    ClientEvent::State(ClientStateEvent::StateTransition { .. })
+                )
+            }
 
             // System event filters
             EventFilter::SystemAuthenticationSucceeded => {
@@ -250,6 +445,38 @@ impl EventFilter {
                 // Scripts won't receive this as it's handled at the runner level
                 false
             }
+            EventFilter::SystemPingResult => {
+                matches!(
+                    event,
+                    ClientEvent::System(gromnie_events::ClientSystemEvent::PingResult { .. })
+                )
+            }
+            EventFilter::SystemScriptError => {
+                matches!(
+                    event,
+                    ClientEvent::System(gromnie_events::ClientSystemEvent::ScriptError { .. })
+                )
+            }
+            EventFilter::SystemResuming => {
+                matches!(
+                    event,
+                    ClientEvent::System(gromnie_events::ClientSystemEvent::Resuming { .. })
+                )
+            }
+            EventFilter::SystemSquelchListChanged => {
+                matches!(
+                    event,
+                    ClientEvent::System(
+                        gromnie_events::ClientSystemEvent::SquelchListChanged { .. }
+                    )
+                )
+            }
+            EventFilter::SystemLoginRetry => {
+                matches!(
+                    event,
+                    ClientEvent::System(gromnie_events::ClientSystemEvent::LoginRetry { .. })
+                )
+            }
         }
     }
 
@@ -262,6 +489,32 @@ impl EventFilter {
             1 => Some(EventFilter::CharacterListReceived),
             2 => Some(EventFilter::CharacterError),
             3 => Some(EventFilter::ChatMessageReceived),
+            4 => Some(EventFilter::ActionError),
+            5 => Some(EventFilter::TargetHealth),
+            6 => Some(EventFilter::MovementPosition),
+            7 => Some(EventFilter::MovementSetObjectMovement),
+            8 => Some(EventFilter::PlayerDeath),
+            9 => Some(EventFilter::AppraiseResult),
+            10 => Some(EventFilter::VendorInfo),
+            11 => Some(EventFilter::ContainerContents),
+            12 => Some(EventFilter::ConfirmationRequest),
+            13 => Some(EventFilter::StackSizeChanged),
+            14 => Some(EventFilter::ChannelMessage),
+            // Combat events (20-29)
+            20 => Some(EventFilter::DamageDealt),
+            21 => Some(EventFilter::DamageTaken),
+            22 => Some(EventFilter::AttackEvaded),
+            23 => Some(EventFilter::AttackAvoided),
+            24 => Some(EventFilter::CombatKill),
+            // Allegiance events (30-39)
+            30 => Some(EventFilter::AllegianceUpdated),
+            31 => Some(EventFilter::AllegianceInfo),
+            32 => Some(EventFilter::AllegianceMemberLogin),
+            // Trade events (40-49)
+            40 => Some(EventFilter::TradeOpened),
+            41 => Some(EventFilter::TradeItemAdded),
+            // S2C protocol events (300-399)
+            300..=399 => Some(EventFilter::S2C(id - 300)),
             // State events (100-199)
             100 => Some(EventFilter::StateConnecting),
             101 => Some(EventFilter::StateConnected),
@@ -274,6 +527,7 @@ impl EventFilter {
             108 => Some(EventFilter::StateInWorld),
             109 => Some(EventFilter::StateExitingWorld),
             110 => Some(EventFilter::StateCharacterError),
+            111 => Some(EventFilter::StateTransition),
             // System events (200-299)
             200 => Some(EventFilter::SystemAuthenticationSucceeded),
             201 => Some(EventFilter::SystemAuthenticationFailed),
@@ -284,6 +538,11 @@ impl EventFilter {
             206 => Some(EventFilter::SystemLoginSucceeded),
             207 => Some(EventFilter::SystemReloadScripts),
             208 => Some(EventFilter::SystemShutdown),
+            209 => Some(EventFilter::SystemPingResult),
+            210 => Some(EventFilter::SystemScriptError),
+            211 => Some(EventFilter::SystemResuming),
+            212 => Some(EventFilter::SystemSquelchListChanged),
+            213 => Some(EventFilter::SystemLoginRetry),
             _ => None,
         }
     }
@@ -296,6 +555,32 @@ impl EventFilter {
             EventFilter::CharacterListReceived => 1,
             EventFilter::CharacterError => 2,
             EventFilter::ChatMessageReceived => 3,
+            EventFilter::ActionError => 4,
+            EventFilter::TargetHealth => 5,
+            EventFilter::MovementPosition => 6,
+            EventFilter::MovementSetObjectMovement => 7,
+            EventFilter::PlayerDeath => 8,
+            EventFilter::AppraiseResult => 9,
+            EventFilter::VendorInfo => 10,
+            EventFilter::ContainerContents => 11,
+            EventFilter::ConfirmationRequest => 12,
+            EventFilter::StackSizeChanged => 13,
+            EventFilter::ChannelMessage => 14,
+            // Combat events (20-29)
+            EventFilter::DamageDealt => 20,
+            EventFilter::DamageTaken => 21,
+            EventFilter::AttackEvaded => 22,
+            EventFilter::AttackAvoided => 23,
+            EventFilter::CombatKill => 24,
+            // Allegiance events (30-39)
+            EventFilter::AllegianceUpdated => 30,
+            EventFilter::AllegianceInfo => 31,
+            EventFilter::AllegianceMemberLogin => 32,
+            // Trade events (40-49)
+            EventFilter::TradeOpened => 40,
+            EventFilter::TradeItemAdded => 41,
+            // S2C protocol events (300-399)
+            EventFilter::S2C(discriminant) => 300 + discriminant,
             // State events (100-199)
             EventFilter::StateConnecting => 100,
             EventFilter::StateConnected => 101,
@@ -308,6 +593,7 @@ impl EventFilter {
             EventFilter::StateInWorld => 108,
             EventFilter::StateExitingWorld => 109,
             EventFilter::StateCharacterError => 110,
+            EventFilter::StateTransition => 111,
             // System events (200-299)
             EventFilter::SystemAuthenticationSucceeded => 200,
             EventFilter::SystemAuthenticationFailed => 201,
@@ -318,6 +604,11 @@ impl EventFilter {
             EventFilter::SystemLoginSucceeded => 206,
             EventFilter::SystemReloadScripts => 207,
             EventFilter::SystemShutdown => 208,
+            EventFilter::SystemPingResult => 209,
+            EventFilter::SystemScriptError => 210,
+            EventFilter::SystemResuming => 211,
+            EventFilter::SystemSquelchListChanged => 212,
+            EventFilter::SystemLoginRetry => 213,
         }
     }
 }
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet};
+
+/// A single watch registration. `None` acts as a wildcard for that half of the pair.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QualityWatch {
+    /// Object to watch, or `None` to watch every object
+    pub object_id: Option<u32>,
+    /// Property to watch, or `None` to watch every property
+    pub property: Option<String>,
+}
+
+impl QualityWatch {
+    /// Check if this watch covers the given (object, property) pair
+    pub fn matches(&self, object_id: u32, property: &str) -> bool {
+        self.object_id.is_none_or(|id| id == object_id)
+            && self.property.as_deref().is_none_or(|p| p == property)
+    }
+}
+
+/// Set of (object, property) pairs a script is interested in
+#[derive(Debug, Default, Clone)]
+pub struct QualityWatchSet {
+    watches: HashSet<QualityWatch>,
+}
+
+impl QualityWatchSet {
+    /// Create an empty watch set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register interest in a property on an object (either half may be a wildcard)
+    pub fn watch(&mut self, object_id: Option<u32>, property: Option<String>) -> bool {
+        self.watches.insert(QualityWatch {
+            object_id,
+            property,
+        })
+    }
+
+    /// Remove a previously registered watch. The arguments must match the original registration.
+    pub fn unwatch(&mut self, object_id: Option<u32>, property: Option<String>) -> bool {
+        self.watches.remove(&QualityWatch {
+            object_id,
+            property,
+        })
+    }
+
+    /// Check if any watch covers the given (object, property) pair
+    pub fn matches(&self, object_id: u32, property: &str) -> bool {
+        self.watches.iter().any(|w| w.matches(object_id, property))
+    }
+
+    /// Whether no watches are registered
+    pub fn is_empty(&self) -> bool {
+        self.watches.is_empty()
+    }
+
+    /// Number of registered watches
+    pub fn len(&self) -> usize {
+        self.watches.len()
+    }
+}
+
+/// A change to a watched quality, delivered to scripts as `GameEvent::QualityChanged`
+///
+/// Integer qualities and vitals are widened to `f64` so one type covers every
+/// kind of quality update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityChange {
+    pub object_id: u32,
+    pub property: String,
+    /// Previous value, if the host has seen this quality before
+    pub old: Option<f64>,
+    pub new: f64,
+}
+
+/// Last known value of every quality seen by the runner, used to fill in `old`
+#[derive(Debug, Default)]
+pub struct QualityCache {
+    values: HashMap<(u32, String), f64>,
+}
+
+impl QualityCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a new value and return the resulting change
+    pub fn update(&mut self, object_id: u32, property: &str, value: f64) -> QualityChange {
+        let old = self.values.insert((object_id, property.to_string()), value);
+        QualityChange {
+            object_id,
+            property: property.to_string(),
+            old,
+            new: value,
+        }
+    }
+
+    /// Forget every quality recorded for an object (e.g. when it leaves the world)
+    pub fn remove_object(&mut self, object_id: u32) {
+        self.values.retain(|(id, _), _| *id != object_id);
+    }
+
+    /// Forget every recorded quality (e.g. when the character leaves the world)
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_watch() {
+        let mut set = QualityWatchSet::new();
+        set.watch(Some(1), Some("Health".to_string()));
+
+        assert!(set.matches(1, "Health"));
+        assert!(!set.matches(1, "Stamina"));
+        assert!(!set.matches(2, "Health"));
+    }
+
+    #[test]
+    fn test_wildcard_watches() {
+        let mut set = QualityWatchSet::new();
+        set.watch(Some(1), None);
+        set.watch(None, Some("Mana".to_string()));
+
+        assert!(set.matches(1, "Anything"));
+        assert!(set.matches(42, "Mana"));
+        assert!(!set.matches(42, "Health"));
+    }
+
+    #[test]
+    fn test_unwatch() {
+        let mut set = QualityWatchSet::new();
+        set.watch(Some(1), Some("Health".to_string()));
+
+        assert!(!set.unwatch(Some(1), None));
+        assert!(set.unwatch(Some(1), Some("Health".to_string())));
+        assert!(set.is_empty());
+        assert!(!set.matches(1, "Health"));
+    }
+
+    #[test]
+    fn test_cache_tracks_old_value() {
+        let mut cache = QualityCache::new();
+
+        let first = cache.update(1, "Health", 100.0);
+        assert_eq!(first.old, None);
+        assert_eq!(first.new, 100.0);
+
+        let second = cache.update(1, "Health", 80.0);
+        assert_eq!(second.old, Some(100.0));
+        assert_eq!(second.new, 80.0);
+
+        cache.remove_object(1);
+        assert_eq!(cache.update(1, "Health", 50.0).old, None);
+
+        cache.update(2, "Mana", 10.0);
+        cache.clear();
+        assert_eq!(cache.update(2, "Mana", 20.0).old, None);
+    }
+}
@@ -3,7 +3,10 @@ use tokio::sync::RwLock;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::debug;
 
+use super::script_log::FileLogSink;
 use super::script_runner::ScriptRunner;
+use super::script_storage::ScriptStorage;
+use super::state_store::StateStore;
 use gromnie_client::client::Client;
 use gromnie_client::config::scripting_config::ScriptingConfig;
 use gromnie_events::SimpleClientAction;
@@ -20,5 +23,47 @@ pub fn create_runner_from_config(
     // Create runner with script support and configured timeout
     debug!(target: "scripting", "Creating script runner with {}ms timeout", config.script_timeout_ms);
     let timeout = std::time::Duration::from_millis(config.script_timeout_ms);
-    ScriptRunner::new_with_wasm_and_config(client, action_tx, timeout)
+    // The client was just created, so nothing else holds its lock yet
+    let client_id = client
+        .try_read()
+        .map(|client| client.id)
+        .unwrap_or_default();
+    let mut runner = ScriptRunner::new_with_wasm_and_config(client, action_tx, timeout);
+    debug!(target: "scripting", "Ticking scripts at {}Hz", config.tick_hz);
+    runner.set_tick_interval(config.tick_interval());
+    runner.set_fuel_limit(config.script_fuel_limit);
+    runner.set_event_history_size(config.event_history_size);
+    if config.allow_raw_actions {
+        debug!(target: "scripting", "Scripts may send raw game actions");
+    }
+    runner.set_allow_raw_actions(config.allow_raw_actions);
+
+    let storage_dir = config.storage_dir(client_id);
+    debug!(target: "scripting", "Script storage in {}", storage_dir.display());
+    runner.set_storage(ScriptStorage::with_persist_dir(storage_dir));
+
+    if config.persist_state_to_disk {
+        let dir = config.state_dir(client_id);
+        debug!(target: "scripting", "Persisting script state to {}", dir.display());
+        runner.set_state_store(StateStore::with_persist_dir(dir));
+    }
+
+    if config.log_to_file {
+        let dir = config.log_dir(client_id);
+        debug!(target: "scripting", "Writing script logs to {}", dir.display());
+        runner.set_log_sink(Arc::new(FileLogSink::new(
+            dir,
+            config.log_file_max_kb * 1024,
+        )));
+    }
+
+    let selection = config.script_selection();
+    if let Some(allow) = &selection.allow {
+        debug!(target: "scripting", "Only loading scripts: {}", allow.join(", "));
+    } else if !selection.deny.is_empty() {
+        debug!(target: "scripting", "Not loading scripts: {}", selection.deny.join(", "));
+    }
+    runner.set_script_selection(selection);
+
+    runner
 }
@@ -1,15 +1,42 @@
+use tokio::task::JoinHandle;
 use tracing::{debug, info};
 
+/// Handle to an installed reload signal handler
+///
+/// Dropping the handle stops listening for the signal, so a runner that is
+/// started again in the same process doesn't deliver each reload twice.
+#[must_use = "the reload handler is removed when the handle is dropped"]
+pub struct ReloadSignalHandle {
+    task: Option<JoinHandle<()>>,
+}
+
+impl ReloadSignalHandle {
+    /// Whether the listening task has stopped
+    pub fn is_finished(&self) -> bool {
+        self.task.as_ref().is_none_or(|task| task.is_finished())
+    }
+}
+
+impl Drop for ReloadSignalHandle {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+            debug!(target: "scripting", "SIGUSR2 handler removed");
+        }
+    }
+}
+
 /// Setup SIGUSR2 handler that calls a callback when reload is requested
 ///
 /// This function spawns a task that listens for SIGUSR2 signals and calls
-/// the provided callback when the signal is received.
+/// the provided callback when the signal is received. The task runs until
+/// the returned handle is dropped.
 #[cfg(unix)]
-pub fn setup_reload_signal_handler<F>(on_reload: F)
+pub fn setup_reload_signal_handler<F>(on_reload: F) -> ReloadSignalHandle
 where
     F: Fn() + Send + Sync + 'static,
 {
-    tokio::spawn(async move {
+    let task = tokio::spawn(async move {
         use tokio::signal::unix;
 
         // Create signal stream for SIGUSR2
@@ -37,13 +64,46 @@ where
             }
         }
     });
+
+    ReloadSignalHandle { task: Some(task) }
 }
 
 /// Setup reload signal handler (non-Unix platforms don't support SIGUSR2)
 #[cfg(not(unix))]
-pub fn setup_reload_signal_handler<F>(_on_reload: F)
+pub fn setup_reload_signal_handler<F>(_on_reload: F) -> ReloadSignalHandle
 where
     F: Fn() + Send + Sync + 'static,
 {
     tracing::warn!(target: "scripting", "SIGUSR2 reload not supported on this platform");
+    ReloadSignalHandle { task: None }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_dropping_handle_removes_handler() {
+        let marker = Arc::new(());
+        let captured = marker.clone();
+        let handle = setup_reload_signal_handler(move || {
+            let _ = &captured;
+        });
+        tokio::task::yield_now().await;
+        assert!(!handle.is_finished());
+        assert_eq!(Arc::strong_count(&marker), 2);
+
+        drop(handle);
+
+        // The aborted task drops its callback once the runtime polls it
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while Arc::strong_count(&marker) > 1 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("reload handler task was not torn down");
+    }
 }
@@ -0,0 +1,140 @@
+use std::time::{Duration, Instant};
+
+use gromnie_events::{ClientEvent, SimpleClientAction};
+
+use crate::EventFilter;
+
+/// An action the host performs the next time `filter` matches, or at `deadline`
+#[derive(Debug, Clone)]
+pub struct ScheduledAction {
+    pub token: u64,
+    pub filter: EventFilter,
+    pub action: SimpleClientAction,
+    /// When to perform the action even if no matching event arrived
+    pub deadline: Option<Instant>,
+}
+
+/// Event-triggered actions registered by a single script
+///
+/// Each registration fires at most once: on the first matching event, or on
+/// the first check after its deadline, whichever comes first.
+#[derive(Debug, Default)]
+pub struct ScheduledActionSet {
+    pending: Vec<ScheduledAction>,
+    next_token: u64,
+}
+
+impl ScheduledActionSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an action. A zero `timeout` waits for the event indefinitely.
+    /// Returns the token used to cancel it.
+    pub fn register(
+        &mut self,
+        filter: EventFilter,
+        action: SimpleClientAction,
+        timeout: Duration,
+        now: Instant,
+    ) -> u64 {
+        self.next_token += 1;
+        let token = self.next_token;
+        self.pending.push(ScheduledAction {
+            token,
+            filter,
+            action,
+            deadline: (!timeout.is_zero()).then(|| now + timeout),
+        });
+        token
+    }
+
+    /// Remove a pending action. Returns true if it had not fired yet.
+    pub fn cancel(&mut self, token: u64) -> bool {
+        let before = self.pending.len();
+        self.pending.retain(|a| a.token != token);
+        self.pending.len() != before
+    }
+
+    /// Remove and return every action whose filter matches the event
+    pub fn take_matching(&mut self, event: &ClientEvent) -> Vec<ScheduledAction> {
+        self.take_where(|a| a.filter.matches(event))
+    }
+
+    /// Remove and return every action whose deadline has passed
+    pub fn take_expired(&mut self, now: Instant) -> Vec<ScheduledAction> {
+        self.take_where(|a| a.deadline.is_some_and(|deadline| now >= deadline))
+    }
+
+    fn take_where(&mut self, pred: impl Fn(&ScheduledAction) -> bool) -> Vec<ScheduledAction> {
+        let (fired, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|a| pred(a));
+        self.pending = pending;
+        fired
+    }
+
+    /// Number of pending actions
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether no actions are pending
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gromnie_events::ClientStateEvent;
+
+    fn say(message: &str) -> SimpleClientAction {
+        SimpleClientAction::SendChatSay {
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fires_once_on_event() {
+        let now = Instant::now();
+        let mut set = ScheduledActionSet::new();
+        set.register(EventFilter::StateInWorld, say("hello"), Duration::ZERO, now);
+
+        let event = ClientEvent::State(ClientStateEvent::InWorld);
+        assert_eq!(set.take_matching(&event).len(), 1);
+        assert!(set.take_matching(&event).is_empty());
+        // No timeout: never expires
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_timeout_fallback_and_cancel() {
+        let now = Instant::now();
+        let mut set = ScheduledActionSet::new();
+        let a = set.register(
+            EventFilter::StateInWorld,
+            say("late"),
+            Duration::from_secs(5),
+            now,
+        );
+        let b = set.register(
+            EventFilter::StateInWorld,
+            say("cancelled"),
+            Duration::from_secs(5),
+            now,
+        );
+        assert_ne!(a, b);
+
+        assert!(set.cancel(b));
+        assert!(!set.cancel(b));
+        assert!(set.take_expired(now + Duration::from_secs(4)).is_empty());
+
+        let expired = set.take_expired(now + Duration::from_secs(5));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].token, a);
+        assert!(set.is_empty());
+    }
+}
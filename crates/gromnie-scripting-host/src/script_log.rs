@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tracing::{debug, warn};
+
+use crate::script_storage::script_file_path;
+
+const LOG_FILE_EXTENSION: &str = "log";
+
+/// Somewhere scripts' `log()` messages are written, in addition to tracing
+pub trait ScriptLogSink: Send + Sync {
+    /// Record one message from `script_id`
+    fn write(&self, script_id: &str, message: &str);
+
+    /// Flush anything buffered for `script_id`, e.g. when it unloads
+    fn flush_script(&self, script_id: &str);
+
+    /// Flush every script's buffered output, e.g. on shutdown
+    fn flush(&self);
+}
+
+/// Discards messages; used when `log_to_file` is off
+#[derive(Debug, Default)]
+pub struct NullLogSink;
+
+impl ScriptLogSink for NullLogSink {
+    fn write(&self, _script_id: &str, _message: &str) {}
+
+    fn flush_script(&self, _script_id: &str) {}
+
+    fn flush(&self) {}
+}
+
+struct LogFile {
+    writer: BufWriter<File>,
+    /// Bytes in the file, including what's still buffered
+    size: u64,
+}
+
+/// Writes each script's messages to `<dir>/<script_id>.log`
+///
+/// Files are opened for appending the first time a script logs. Once a file
+/// would grow past the size cap it is renamed to `<script_id>.log.1`,
+/// replacing the previous one, and a fresh file is started, so each script
+/// keeps at most twice the cap on disk.
+pub struct FileLogSink {
+    dir: PathBuf,
+    max_bytes: u64,
+    files: Mutex<HashMap<String, LogFile>>,
+}
+
+impl FileLogSink {
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes,
+            files: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Path of a script's current log file
+    pub fn log_path(&self, script_id: &str) -> PathBuf {
+        script_file_path(&self.dir, script_id, LOG_FILE_EXTENSION)
+    }
+
+    fn append(&self, script_id: &str, line: &str) -> std::io::Result<()> {
+        let mut files = self.files.lock().expect("script log files poisoned");
+        let path = self.log_path(script_id);
+
+        if let Some(file) = files.get(script_id)
+            && file.size > 0
+            && file.size + line.len() as u64 > self.max_bytes
+        {
+            files.remove(script_id);
+            rotate(&path)?;
+        }
+
+        let file = match files.entry(script_id.to_string()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let file = open(&path)?;
+                let size = file.metadata()?.len();
+                if size > 0 && size + line.len() as u64 > self.max_bytes {
+                    drop(file);
+                    rotate(&path)?;
+                    entry.insert(LogFile {
+                        writer: BufWriter::new(open(&path)?),
+                        size: 0,
+                    })
+                } else {
+                    entry.insert(LogFile {
+                        writer: BufWriter::new(file),
+                        size,
+                    })
+                }
+            }
+        };
+
+        file.writer.write_all(line.as_bytes())?;
+        file.size += line.len() as u64;
+        Ok(())
+    }
+}
+
+impl ScriptLogSink for FileLogSink {
+    fn write(&self, script_id: &str, message: &str) {
+        let line = format!(
+            "{} {}\n",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+            message
+        );
+        if let Err(e) = self.append(script_id, &line) {
+            warn!(target: "scripting", "Failed to write log file for {}: {}", script_id, e);
+        }
+    }
+
+    fn flush_script(&self, script_id: &str) {
+        let mut files = self.files.lock().expect("script log files poisoned");
+        if let Some(file) = files.get_mut(script_id)
+            && let Err(e) = file.writer.flush()
+        {
+            warn!(target: "scripting", "Failed to flush log file for {}: {}", script_id, e);
+        }
+    }
+
+    fn flush(&self) {
+        let mut files = self.files.lock().expect("script log files poisoned");
+        for (script_id, file) in files.iter_mut() {
+            if let Err(e) = file.writer.flush() {
+                warn!(target: "scripting", "Failed to flush log file for {}: {}", script_id, e);
+            }
+        }
+    }
+}
+
+impl Drop for FileLogSink {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn open(path: &Path) -> std::io::Result<File> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Move a full log file aside to `<name>.log.1`
+fn rotate(path: &Path) -> std::io::Result<()> {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(".1");
+    std::fs::rename(path, &rotated)?;
+    debug!(target: "scripting", "Rotated script log {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn read(path: impl AsRef<Path>) -> String {
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn test_writes_one_file_per_script() {
+        let dir = TempDir::new().unwrap();
+        let sink = FileLogSink::new(dir.path(), 1024 * 1024);
+
+        sink.write("greeter", "hello");
+        sink.write("buffer", "casting");
+        sink.write("greeter", "goodbye");
+        sink.flush_script("greeter");
+
+        let greeter = read(dir.path().join("greeter.log"));
+        let lines: Vec<&str> = greeter.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" hello"));
+        assert!(lines[1].ends_with(" goodbye"));
+
+        // Not flushed yet
+        assert_eq!(read(dir.path().join("buffer.log")), "");
+        sink.flush();
+        assert!(read(dir.path().join("buffer.log")).ends_with(" casting\n"));
+    }
+
+    #[test]
+    fn test_rotates_at_size_cap() {
+        let dir = TempDir::new().unwrap();
+        let lines = |name: &str| read(dir.path().join(name)).lines().count();
+        // Each line is a 24 byte timestamp, the message and a newline: two fit
+        let message = "x".repeat(40);
+        let sink = FileLogSink::new(dir.path(), 150);
+
+        for _ in 0..3 {
+            sink.write("chatty", &message);
+        }
+        sink.flush();
+        assert_eq!(lines("chatty.log"), 1);
+        assert_eq!(lines("chatty.log.1"), 2);
+        drop(sink);
+
+        // A new sink picks up the existing file's size
+        let reopened = FileLogSink::new(dir.path(), 150);
+        reopened.write("chatty", &message);
+        reopened.flush();
+        assert_eq!(lines("chatty.log"), 2);
+        reopened.write("chatty", &message);
+        reopened.flush();
+        assert_eq!(lines("chatty.log"), 1);
+        assert_eq!(lines("chatty.log.1"), 2);
+    }
+}
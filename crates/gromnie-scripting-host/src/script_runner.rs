@@ -1,5 +1,5 @@
 use gromnie_client::client::Client;
-use gromnie_client::config::scripting_config::ScriptingConfig;
+use gromnie_client::config::scripting_config::{ScriptSelection, ScriptingConfig};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -10,17 +10,31 @@ use tracing::{debug, error, info};
 
 use super::EventFilter;
 use super::Script;
-use super::context::ScriptContext;
+use super::context::{ScriptContext, SharedScriptState};
+use super::event_history::EventHistory;
+use super::event_wait::EventWait;
+use super::quality_watch::{QualityCache, QualityChange};
+use super::scheduled_action::ScheduledAction;
+use super::script_log::{NullLogSink, ScriptLogSink};
 use super::script_scanner::ScriptScanner;
+use super::script_storage::ScriptStorage;
+use super::state_store::StateStore;
 use super::timer::TimerManager;
-use super::wasm::WasmScript;
+use super::wasm::{WasmScript, WorldMismatch};
 use crate::create_runner_from_config;
-use gromnie_events::{ClientEvent, ClientSystemEvent, SimpleClientAction};
+use gromnie_events::{
+    ClientEvent, ClientStateEvent, ClientSystemEvent, ProtocolEvent, S2CEvent, SimpleClientAction,
+    SimpleGameEvent as GameEvent,
+};
 use gromnie_events::{EventConsumer, EventEnvelope};
 
 /// Default tick rate for scripts (50ms = 20Hz)
 const DEFAULT_TICK_INTERVAL: Duration = Duration::from_millis(50);
 
+/// How often changed script state and storage are written to disk, so a
+/// crash loses at most this much
+const PERSIST_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Message sent to the runner task
 enum RunnerMessage {
     Event(ClientEvent),
@@ -32,7 +46,11 @@ enum RunnerMessage {
 
 enum ReloadCandidate {
     Loaded(Box<WasmScript>),
-    Disabled { script_id: String },
+    Disabled {
+        script_id: String,
+    },
+    /// Built against a different version of the script world
+    Rejected(WorldMismatch),
     Failed,
 }
 
@@ -50,14 +68,32 @@ pub struct ScriptRunner {
     timer_manager: Arc<TimerManager>,
     /// Last time scripts were ticked
     last_tick: Instant,
+    /// Last time changed state and storage were written to disk
+    last_persist: Instant,
     /// Interval between ticks (default 50ms for 20Hz)
     tick_interval: Duration,
     /// Script execution timeout
     script_timeout: Duration,
+    /// Fuel each script callback may burn (0 = unlimited)
+    fuel_limit: u64,
+    /// Whether scripts may call send-game-action
+    allow_raw_actions: bool,
     /// Script configuration for reload operations
     script_config: Option<HashMap<String, toml::Value>>,
     /// Script directory path
     script_dir: Option<std::path::PathBuf>,
+    /// Last known quality values, used to report old/new pairs to watching scripts
+    quality_cache: QualityCache,
+    /// Recent events for scripts that load mid-session
+    event_history: Arc<EventHistory>,
+    /// Per-script persistent state, kept across reloads
+    state_store: Arc<StateStore>,
+    /// Per-script key/value storage, shared with every script context
+    storage: Arc<ScriptStorage>,
+    /// Where scripts' log() messages are written besides tracing
+    log_sink: Arc<dyn ScriptLogSink>,
+    /// Allowlist/denylist of script IDs from the scripting config
+    script_selection: ScriptSelection,
 }
 
 impl ScriptRunner {
@@ -88,10 +124,19 @@ impl ScriptRunner {
             action_tx,
             timer_manager: Arc::new(TimerManager::new()),
             last_tick: Instant::now(),
+            last_persist: Instant::now(),
             tick_interval,
             script_timeout,
+            fuel_limit: super::wasm::DEFAULT_FUEL_LIMIT,
+            allow_raw_actions: false,
             script_config: None,
             script_dir: None,
+            quality_cache: QualityCache::new(),
+            event_history: Arc::new(EventHistory::default()),
+            state_store: Arc::new(StateStore::new()),
+            storage: Arc::new(ScriptStorage::new()),
+            log_sink: Arc::new(NullLogSink),
+            script_selection: ScriptSelection::default(),
         }
     }
 
@@ -136,10 +181,19 @@ impl ScriptRunner {
             action_tx,
             timer_manager: Arc::new(TimerManager::new()),
             last_tick: Instant::now(),
+            last_persist: Instant::now(),
             tick_interval: DEFAULT_TICK_INTERVAL,
             script_timeout,
+            fuel_limit: super::wasm::DEFAULT_FUEL_LIMIT,
+            allow_raw_actions: false,
             script_config: None,
             script_dir: None,
+            quality_cache: QualityCache::new(),
+            event_history: Arc::new(EventHistory::default()),
+            state_store: Arc::new(StateStore::new()),
+            storage: Arc::new(ScriptStorage::new()),
+            log_sink: Arc::new(NullLogSink),
+            script_selection: ScriptSelection::default(),
         }
     }
 
@@ -148,19 +202,16 @@ impl ScriptRunner {
         debug!(target: "scripting", "Registering script: {} ({})", script.name(), script.id());
 
         // Create context for on_load
-        let ctx = Self::create_script_context(
-            self.client.clone(),
-            self.action_tx.clone(),
-            Arc::clone(&self.timer_manager),
-            SystemTime::now(),
-        )
-        .await;
+        let ctx = self.create_script_context().await;
 
         // Call on_load
         let mut script = script;
+        script.set_fuel_limit(self.fuel_limit);
+        script.set_allow_raw_actions(self.allow_raw_actions);
         script.on_load(Arc::clone(&ctx)).await;
 
         self.scripts.push(script);
+        self.unload_out_of_fuel_scripts().await;
     }
 
     /// Register a trait object script (for testing) - only available for WASM scripts
@@ -185,27 +236,99 @@ impl ScriptRunner {
         self.wasm_engine.is_some()
     }
 
+    /// Replace the persistent state store (e.g. with a disk-backed one)
+    pub fn set_state_store(&mut self, state_store: StateStore) {
+        self.state_store = Arc::new(state_store);
+    }
+
+    /// Replace the key/value storage (e.g. with a disk-backed one)
+    pub fn set_storage(&mut self, storage: ScriptStorage) {
+        self.storage = Arc::new(storage);
+    }
+
+    /// Write scripts' log() messages somewhere besides tracing, e.g. to
+    /// per-script files
+    pub fn set_log_sink(&mut self, log_sink: Arc<dyn ScriptLogSink>) {
+        self.log_sink = log_sink;
+    }
+
+    /// Set the time between script ticks. Takes effect when the runner task
+    /// starts.
+    pub fn set_tick_interval(&mut self, tick_interval: Duration) {
+        self.tick_interval = tick_interval;
+    }
+
+    /// Set the fuel each script callback may burn (0 = unlimited). Applies to
+    /// scripts registered after the call.
+    pub fn set_fuel_limit(&mut self, fuel_limit: u64) {
+        self.fuel_limit = fuel_limit;
+    }
+
+    /// Allow or forbid send-game-action. Applies to scripts registered after
+    /// the call.
+    pub fn set_allow_raw_actions(&mut self, allow: bool) {
+        self.allow_raw_actions = allow;
+    }
+
+    /// Set how many recent events scripts can fetch with `recent-events`
+    /// (0 disables the history). Clears any events already recorded.
+    pub fn set_event_history_size(&mut self, size: usize) {
+        self.event_history = Arc::new(EventHistory::new(size));
+    }
+
+    /// Restrict which scripts load by ID. Applies to the next load or reload.
+    pub fn set_script_selection(&mut self, selection: ScriptSelection) {
+        self.script_selection = selection;
+    }
+
+    /// Write persistent script state to disk, if the store is disk-backed
+    pub fn persist_state(&self) {
+        match self.state_store.flush() {
+            Ok(0) => {}
+            Ok(count) => {
+                info!(target: "scripting", "Saved persistent state for {} script(s)", count)
+            }
+            Err(e) => {
+                error!(target: "scripting", "Failed to save persistent script state: {}", e)
+            }
+        }
+        if let Err(e) = self.storage.flush() {
+            error!(target: "scripting", "Failed to save script storage: {}", e);
+        }
+        self.log_sink.flush();
+    }
+
     /// Get the IDs of all registered scripts
     pub fn script_ids(&self) -> Vec<&str> {
         self.scripts.iter().map(|s| s.id()).collect()
     }
 
     /// Create a script context for the current state
-    async fn create_script_context(
-        client: Arc<RwLock<Client>>,
-        action_tx: UnboundedSender<SimpleClientAction>,
-        timer_manager: Arc<TimerManager>,
-        now: SystemTime,
-    ) -> Arc<ScriptContext> {
-        Arc::new(ScriptContext::new(client, action_tx, timer_manager, now).await)
+    async fn create_script_context(&self) -> Arc<ScriptContext> {
+        let shared = SharedScriptState {
+            client: self.client.clone(),
+            action_tx: self.action_tx.clone(),
+            timer_manager: Arc::clone(&self.timer_manager),
+            state_store: Arc::clone(&self.state_store),
+            storage: Arc::clone(&self.storage),
+            log_sink: Arc::clone(&self.log_sink),
+            event_history: Arc::clone(&self.event_history),
+            tick_interval: self.tick_interval,
+        };
+        Arc::new(ScriptContext::new(shared, SystemTime::now()).await)
     }
 
-    fn is_script_enabled(script_id: &str, script_config: &HashMap<String, toml::Value>) -> bool {
+    fn is_script_enabled(
+        script_id: &str,
+        script_config: &HashMap<String, toml::Value>,
+        selection: &ScriptSelection,
+    ) -> bool {
         script_config
             .get(script_id)
             .and_then(|config| config.get("enabled"))
             .and_then(|value| value.as_bool())
             .unwrap_or(true)
+            && selection.skip_reason(script_id).is_none()
     }
 
     fn current_script_files(dir: &Path) -> HashMap<PathBuf, SystemTime> {
@@ -267,6 +390,7 @@ impl ScriptRunner {
         engine: &wasmtime::Engine,
         path: &Path,
         script_config: &HashMap<String, toml::Value>,
+        selection: &ScriptSelection,
     ) -> ReloadCandidate {
         let script = match WasmScript::from_file(engine, path).await {
             Ok(script) => script,
@@ -277,12 +401,15 @@ impl ScriptRunner {
                     path.display(),
                     err
                 );
+                if let Some(mismatch) = err.downcast_ref::<WorldMismatch>() {
+                    return ReloadCandidate::Rejected(mismatch.clone());
+                }
                 return ReloadCandidate::Failed;
             }
         };
 
         let script_id = script.id().to_string();
-        if !Self::is_script_enabled(&script_id, script_config) {
+        if !Self::is_script_enabled(&script_id, script_config, selection) {
             debug!(
                 target: "scripting",
                 "Skipping disabled script during reload: {} ({})",
@@ -295,6 +422,19 @@ impl ScriptRunner {
         ReloadCandidate::Loaded(Box::new(script))
     }
 
+    /// Report a script file turned away by the world check as a script error
+    async fn report_rejected_script(&self, path: &Path, mismatch: &WorldMismatch) {
+        let script_id = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let client = self.client.read().await;
+        client.emit_system_event(ClientSystemEvent::ScriptError {
+            script_id,
+            message: mismatch.to_string(),
+        });
+    }
+
     async fn unload_scripts_by_paths(&mut self, paths: &[PathBuf]) -> usize {
         let mut removed_indices: Vec<usize> = paths
             .iter()
@@ -311,13 +451,7 @@ impl ScriptRunner {
         let mut unloaded = 0;
         for index in removed_indices.into_iter().rev() {
             let mut script = self.scripts.remove(index);
-            let ctx = Self::create_script_context(
-                self.client.clone(),
-                self.action_tx.clone(),
-                Arc::clone(&self.timer_manager),
-                SystemTime::now(),
-            )
-            .await;
+            let ctx = self.create_script_context().await;
 
             debug!(
                 target: "scripting",
@@ -326,6 +460,7 @@ impl ScriptRunner {
                 script.id()
             );
             script.on_unload(Arc::clone(&ctx)).await;
+            ctx.flush_storage(script.id());
             unloaded += 1;
         }
 
@@ -345,10 +480,16 @@ impl ScriptRunner {
             return;
         };
 
-        let scripts = super::wasm::load_wasm_scripts(engine, dir, script_config).await;
-        let loaded_count = scripts.len();
+        let loaded =
+            super::wasm::load_wasm_scripts(engine, dir, script_config, &self.script_selection)
+                .await;
+        let loaded_count = loaded.scripts.len();
+
+        for (path, mismatch) in &loaded.rejected {
+            self.report_rejected_script(path, mismatch).await;
+        }
 
-        for script in scripts {
+        for script in loaded.scripts {
             debug!(target: "scripting", "Registering script: {} ({})", script.name(), script.id());
             self.register_script(script).await;
         }
@@ -384,7 +525,13 @@ impl ScriptRunner {
         for script in &self.scripts {
             let path = script.file_path().clone();
             match current_files.get(&path) {
-                Some(modified_time) if Self::is_script_enabled(script.id(), script_config) => {
+                Some(modified_time)
+                    if Self::is_script_enabled(
+                        script.id(),
+                        script_config,
+                        &self.script_selection,
+                    ) =>
+                {
                     if &script.modified_time() != modified_time {
                         changed_paths.push(path);
                     }
@@ -416,7 +563,9 @@ impl ScriptRunner {
         let mut pending_scripts = Vec::new();
 
         for path in changed_paths {
-            match Self::load_reload_candidate(engine, &path, script_config).await {
+            match Self::load_reload_candidate(engine, &path, script_config, &self.script_selection)
+                .await
+            {
                 ReloadCandidate::Loaded(script) => pending_scripts.push((path, *script)),
                 ReloadCandidate::Disabled { script_id } => {
                     info!(
@@ -427,6 +576,14 @@ impl ScriptRunner {
                     );
                     paths_to_remove.push(path);
                 }
+                ReloadCandidate::Rejected(mismatch) => {
+                    self.report_rejected_script(&path, &mismatch).await;
+                    tracing::warn!(
+                        target: "scripting",
+                        "Keeping existing script at {} because the updated version failed to load",
+                        path.display()
+                    );
+                }
                 ReloadCandidate::Failed => {
                     tracing::warn!(
                         target: "scripting",
@@ -438,10 +595,14 @@ impl ScriptRunner {
         }
 
         for path in added_paths {
-            if let ReloadCandidate::Loaded(script) =
-                Self::load_reload_candidate(engine, &path, script_config).await
+            match Self::load_reload_candidate(engine, &path, script_config, &self.script_selection)
+                .await
             {
-                pending_scripts.push((path, *script));
+                ReloadCandidate::Loaded(script) => pending_scripts.push((path, *script)),
+                ReloadCandidate::Rejected(mismatch) => {
+                    self.report_rejected_script(&path, &mismatch).await;
+                }
+                ReloadCandidate::Disabled { .. } | ReloadCandidate::Failed => {}
             }
         }
 
@@ -493,24 +654,14 @@ impl ScriptRunner {
                 script.id()
             );
 
-            let ctx = Self::create_script_context(
-                self.client.clone(),
-                self.action_tx.clone(),
-                Arc::clone(&self.timer_manager),
-                SystemTime::now(),
-            )
-            .await;
+            let ctx = self.create_script_context().await;
+            script.set_fuel_limit(self.fuel_limit);
+            script.set_allow_raw_actions(self.allow_raw_actions);
             script.on_load(Arc::clone(&ctx)).await;
 
             if let Some(index) = self.script_index_by_path(&path) {
                 let mut old_script = std::mem::replace(&mut self.scripts[index], script);
-                let unload_ctx = Self::create_script_context(
-                    self.client.clone(),
-                    self.action_tx.clone(),
-                    Arc::clone(&self.timer_manager),
-                    SystemTime::now(),
-                )
-                .await;
+                let unload_ctx = self.create_script_context().await;
                 debug!(
                     target: "scripting",
                     "Calling on_unload for replaced script: {} ({})",
@@ -518,6 +669,7 @@ impl ScriptRunner {
                     old_script.id()
                 );
                 old_script.on_unload(Arc::clone(&unload_ctx)).await;
+                unload_ctx.flush_storage(old_script.id());
             } else {
                 self.scripts.push(script);
             }
@@ -529,6 +681,7 @@ impl ScriptRunner {
             }
         }
 
+        self.unload_out_of_fuel_scripts().await;
         let new_script_count = self.scripts.len();
 
         if old_script_count > 0 && new_script_count == 0 {
@@ -563,18 +716,13 @@ impl ScriptRunner {
         debug!(target: "scripting", "Unloading {} script(s)", count);
 
         // Create context once before the loop
-        let ctx = Self::create_script_context(
-            self.client.clone(),
-            self.action_tx.clone(),
-            Arc::clone(&self.timer_manager),
-            SystemTime::now(),
-        )
-        .await;
+        let ctx = self.create_script_context().await;
 
         // Unload all scripts
         for script in self.scripts.iter_mut() {
             debug!(target: "scripting", "Calling on_unload for: {} ({})", script.name(), script.id());
             script.on_unload(Arc::clone(&ctx)).await;
+            ctx.flush_storage(script.id());
         }
 
         self.scripts.clear();
@@ -585,6 +733,55 @@ impl ScriptRunner {
         self.timer_manager.tick(now)
     }
 
+    /// Perform scheduled actions whose timeout passed before their event arrived
+    fn perform_expired_actions(&mut self, now: Instant) {
+        let expired: Vec<_> = self
+            .scripts
+            .iter_mut()
+            .flat_map(|script| script.take_expired_actions(now))
+            .collect();
+        self.perform_scheduled_actions(expired, "timeout");
+    }
+
+    fn perform_scheduled_actions(&self, actions: Vec<ScheduledAction>, trigger: &str) {
+        for scheduled in actions {
+            debug!(
+                target: "scripting",
+                "Performing scheduled action {} on {}: {:?}",
+                scheduled.token,
+                trigger,
+                scheduled.action
+            );
+            if let Err(e) = self.action_tx.send(scheduled.action) {
+                error!(target: "scripting", "Failed to send scheduled action: {}", e);
+            }
+        }
+    }
+
+    /// Run one tick: fire due timers, perform expired scheduled actions and
+    /// call every script's on_tick if the tick interval has elapsed
+    pub async fn tick(&mut self) {
+        let now = Instant::now();
+        self.tick_timers(now);
+        self.perform_expired_actions(now);
+        self.tick_scripts(now).await;
+
+        if now.duration_since(self.last_persist) >= PERSIST_INTERVAL {
+            self.last_persist = now;
+            self.persist_changes();
+        }
+    }
+
+    /// Write state and storage that changed since the last write
+    fn persist_changes(&self) {
+        if let Err(e) = self.state_store.flush() {
+            error!(target: "scripting", "Failed to save persistent script state: {}", e);
+        }
+        if let Err(e) = self.storage.flush() {
+            error!(target: "scripting", "Failed to save script storage: {}", e);
+        }
+    }
+
     /// Tick all scripts if enough time has elapsed
     async fn tick_scripts(&mut self, now: Instant) {
         let elapsed = now.duration_since(self.last_tick);
@@ -596,13 +793,7 @@ impl ScriptRunner {
         self.last_tick = now;
 
         // Create context once for all scripts
-        let ctx = Self::create_script_context(
-            self.client.clone(),
-            self.action_tx.clone(),
-            Arc::clone(&self.timer_manager),
-            SystemTime::now(),
-        )
-        .await;
+        let ctx = self.create_script_context().await;
 
         // Execute each script's tick with timeout protection
         for script in &mut self.scripts {
@@ -611,10 +802,10 @@ impl ScriptRunner {
             let ctx_clone = Arc::clone(&ctx);
             let timeout = self.script_timeout;
 
-            let result = tokio::time::timeout(timeout, script.on_tick(ctx_clone, elapsed)).await;
+            let result = run_with_timeout(timeout, &ctx, script.on_tick(ctx_clone, elapsed)).await;
 
             match result {
-                Ok(()) => {
+                Some(()) => {
                     debug!(
                         target: "scripting",
                         "Script {} ({}) completed tick",
@@ -622,7 +813,7 @@ impl ScriptRunner {
                         script_id
                     );
                 }
-                Err(_) => {
+                None => {
                     tracing::warn!(
                         target: "scripting",
                         "Script {} ({}) timed out after {}ms during tick",
@@ -633,6 +824,49 @@ impl ScriptRunner {
                 }
             }
         }
+
+        self.replay_landblock_objects(ctx).await;
+        self.unload_out_of_fuel_scripts().await;
+    }
+
+    /// Drop scripts whose callback ran out of fuel and report each one as a
+    /// script error. Their `on_unload` isn't called, since a trapped instance
+    /// can't be entered again.
+    async fn unload_out_of_fuel_scripts(&mut self) {
+        if !self.scripts.iter().any(|script| script.out_of_fuel()) {
+            return;
+        }
+
+        let (exhausted, running): (Vec<_>, Vec<_>) = std::mem::take(&mut self.scripts)
+            .into_iter()
+            .partition(|script| script.out_of_fuel());
+        self.scripts = running;
+
+        let client = self.client.read().await;
+        for script in exhausted {
+            let message = format!(
+                "ran out of fuel (limit {}) and was unloaded",
+                self.fuel_limit
+            );
+            error!(
+                target: "scripting",
+                "Script {} ({}) {}",
+                script.name(),
+                script.id(),
+                message
+            );
+            if let Err(e) = self.state_store.flush_script(script.id()) {
+                error!(target: "scripting", "Failed to save state for {}: {}", script.id(), e);
+            }
+            if let Err(e) = self.storage.flush_script(script.id()) {
+                error!(target: "scripting", "Failed to save storage for {}: {}", script.id(), e);
+            }
+            self.log_sink.flush_script(script.id());
+            client.emit_system_event(ClientSystemEvent::ScriptError {
+                script_id: script.id().to_string(),
+                message,
+            });
+        }
     }
 
     /// Handle a raw event
@@ -650,18 +884,31 @@ impl ScriptRunner {
         if !fired_timers.is_empty() {
             debug!(target: "scripting", "Timers fired: {:?}", fired_timers);
         }
+        self.perform_expired_actions(now);
 
         // THEN tick scripts
         self.tick_scripts(now).await;
 
         // Create context once before the loop
-        let ctx = Self::create_script_context(
-            self.client.clone(),
-            self.action_tx.clone(),
-            Arc::clone(&self.timer_manager),
-            SystemTime::now(),
-        )
-        .await;
+        let ctx = self.create_script_context().await;
+
+        self.event_history.record(&raw_event);
+
+        // Take one-shot waits before dispatch so a wait registered while handling
+        // this event only fires on a later one
+        let fired_waits: Vec<_> = self
+            .scripts
+            .iter_mut()
+            .map(|script| script.take_fired_waits(&raw_event))
+            .collect();
+
+        // Same for scheduled actions; the host performs these itself
+        let triggered: Vec<_> = self
+            .scripts
+            .iter_mut()
+            .flat_map(|script| script.take_triggered_actions(&raw_event))
+            .collect();
+        self.perform_scheduled_actions(triggered, "event");
 
         // Collect scripts that are subscribed to this event and execute them in parallel
         let subscribed_scripts: Vec<_> = self
@@ -691,16 +938,20 @@ impl ScriptRunner {
         for (_index, script) in subscribed_scripts {
             let script_name = script.name().to_string();
             let script_id = script.id().to_string();
-            let ctx = Arc::clone(&ctx);
             let event_copy = raw_event.clone();
             let timeout = self.script_timeout;
 
             // We need to handle this carefully since we can't move mutable references across tasks
             // For now, we'll execute them sequentially but with timeout protection
-            let result = tokio::time::timeout(timeout, script.on_event(&event_copy, ctx)).await;
+            let result = run_with_timeout(
+                timeout,
+                &ctx,
+                script.on_event(&event_copy, Arc::clone(&ctx)),
+            )
+            .await;
 
             match result {
-                Ok(()) => {
+                Some(()) => {
                     debug!(
                         target: "scripting",
                         "Script {} ({}) completed event handling",
@@ -708,7 +959,7 @@ impl ScriptRunner {
                         script_id
                     );
                 }
-                Err(_) => {
+                None => {
                     tracing::warn!(
                         target: "scripting",
                         "Script {} ({}) timed out after {}ms during event handling",
@@ -719,11 +970,215 @@ impl ScriptRunner {
                 }
             }
         }
+
+        self.dispatch_fired_waits(fired_waits, Arc::clone(&ctx))
+            .await;
+
+        // Filter quality updates against each script's watch set
+        if let Some((object_id, property, value)) = quality_update(&raw_event) {
+            let change = self.quality_cache.update(object_id, property, value);
+            if change.old != Some(change.new) {
+                self.dispatch_quality_change(&change, Arc::clone(&ctx))
+                    .await;
+            }
+        }
+
+        match &raw_event {
+            ClientEvent::Game(GameEvent::ItemDeleteObject { object_id }) => {
+                self.quality_cache.remove_object(object_id.get());
+            }
+            // Object IDs mean nothing once the character is gone
+            ClientEvent::State(
+                ClientStateEvent::ExitingWorld | ClientStateEvent::CharacterSelect,
+            ) => {
+                self.quality_cache.clear();
+            }
+            _ => {}
+        }
+
+        self.replay_landblock_objects(ctx).await;
+        self.unload_out_of_fuel_scripts().await;
+    }
+
+    /// Send the objects in the player's landblock to every script that asked for them
+    async fn replay_landblock_objects(&mut self, ctx: Arc<ScriptContext>) {
+        let timeout = self.script_timeout;
+        let mut replay = None;
+
+        for script in &mut self.scripts {
+            if !script.take_landblock_objects_request() {
+                continue;
+            }
+
+            if replay.is_none() {
+                replay = Some(landblock_object_events(&*self.client.read().await));
+            }
+            let events = replay.as_ref().unwrap();
+            debug!(
+                target: "scripting",
+                "Replaying {} world objects to script {} ({})",
+                events.len(),
+                script.name(),
+                script.id()
+            );
+
+            for event in events.iter() {
+                let result =
+                    run_with_timeout(timeout, &ctx, script.on_event(event, Arc::clone(&ctx))).await;
+
+                if result.is_none() {
+                    tracing::warn!(
+                        target: "scripting",
+                        "Script {} ({}) timed out after {}ms during landblock object replay",
+                        script.name(),
+                        script.id(),
+                        timeout.as_millis()
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Deliver fired one-shot waits; `fired` is indexed like `self.scripts`
+    async fn dispatch_fired_waits(&mut self, fired: Vec<Vec<EventWait>>, ctx: Arc<ScriptContext>) {
+        let timeout = self.script_timeout;
+
+        for (script, waits) in self.scripts.iter_mut().zip(fired) {
+            for wait in waits {
+                let result =
+                    run_with_timeout(timeout, &ctx, script.on_when_fired(&wait, Arc::clone(&ctx)))
+                        .await;
+
+                if result.is_none() {
+                    tracing::warn!(
+                        target: "scripting",
+                        "Script {} ({}) timed out after {}ms during when-fired handling",
+                        script.name(),
+                        script.id(),
+                        timeout.as_millis()
+                    );
+                }
+            }
+        }
     }
+
+    /// Deliver a quality change to every script watching that (object, property) pair
+    async fn dispatch_quality_change(&mut self, change: &QualityChange, ctx: Arc<ScriptContext>) {
+        let timeout = self.script_timeout;
+
+        for script in self.scripts.iter_mut().filter(|script| {
+            script
+                .quality_watches()
+                .matches(change.object_id, &change.property)
+        }) {
+            let result = run_with_timeout(
+                timeout,
+                &ctx,
+                script.on_quality_changed(change, Arc::clone(&ctx)),
+            )
+            .await;
+
+            if result.is_none() {
+                tracing::warn!(
+                    target: "scripting",
+                    "Script {} ({}) timed out after {}ms during quality change handling",
+                    script.name(),
+                    script.id(),
+                    timeout.as_millis()
+                );
+            }
+        }
+    }
+}
+
+/// Run a script callback, giving up once it has run for `timeout`. Time the
+/// script spends blocked in a waiting host call (see
+/// [`ScriptContext::login_character_and_wait`]) doesn't count.
+async fn run_with_timeout<F: std::future::Future>(
+    timeout: Duration,
+    ctx: &ScriptContext,
+    callback: F,
+) -> Option<F::Output> {
+    // Waits left over from an earlier callback sharing this context don't count
+    ctx.take_host_wait(Instant::now());
+
+    tokio::pin!(callback);
+    let mut deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        tokio::select! {
+            output = &mut callback => return Some(output),
+            _ = tokio::time::sleep_until(deadline) => {
+                let waited = ctx.take_host_wait(Instant::now());
+                if waited.is_zero() {
+                    return None;
+                }
+                deadline += waited;
+            }
+        }
+    }
+}
+
+/// The (object, property, value) a quality update reports, for matching against watches
+fn quality_update(event: &ClientEvent) -> Option<(u32, &str, f64)> {
+    match event {
+        ClientEvent::Game(
+            GameEvent::QualitiesPrivateUpdateInt {
+                object_id,
+                property_name,
+                value,
+                ..
+            }
+            | GameEvent::QualitiesUpdateInt {
+                object_id,
+                property_name,
+                value,
+                ..
+            },
+        ) => Some((object_id.get(), property_name.as_ref(), f64::from(*value))),
+        ClientEvent::Game(GameEvent::QualitiesUpdateFloat {
+            object_id,
+            property_name,
+            value,
+        }) => Some((object_id.get(), property_name.as_ref(), *value)),
+        ClientEvent::Game(GameEvent::VitalUpdated {
+            object_id,
+            vital_name,
+            value,
+        }) => Some((object_id.get(), vital_name.as_ref(), f64::from(*value))),
+        _ => None,
+    }
+}
+
+/// Synthetic create events for the objects lying in the player's current
+/// landblock, in object ID order; none if the player's position is unknown
+fn landblock_object_events(client: &Client) -> Vec<ClientEvent> {
+    let Some(landblock) = client.player_position().map(|pos| pos.landblock()) else {
+        return Vec::new();
+    };
+
+    client
+        .objects()
+        .in_landblock(landblock)
+        .map(|object| {
+            ClientEvent::Protocol(ProtocolEvent::S2C(S2CEvent::ItemCreateObject {
+                object_id: object.id,
+                name: object.name.clone(),
+                item_type: object.item_type.clone(),
+                container_id: None,
+                burden: object.burden,
+                value: object.value,
+                items_capacity: object.items_capacity,
+                container_capacity: object.container_capacity,
+            }))
+        })
+        .collect()
 }
 
 impl Drop for ScriptRunner {
     fn drop(&mut self) {
+        self.persist_state();
+
         if self.scripts.is_empty() {
             return;
         }
@@ -877,6 +1332,9 @@ impl ScriptConsumer {
                 runner.load_scripts(dir, &script_config).await;
             }
 
+            // Measure the first tick's delta from here, not from construction
+            runner.last_tick = Instant::now();
+
             // Start tick timer
             let mut tick_interval = tokio::time::interval(runner.tick_interval);
             tick_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -894,9 +1352,7 @@ impl ScriptConsumer {
                         }
                     }
                     _ = tick_interval.tick() => {
-                        let now = Instant::now();
-                        runner.tick_timers(now);
-                        runner.tick_scripts(now).await;
+                        runner.tick().await;
                     }
                     else => break,
                 }
@@ -963,6 +1419,14 @@ impl EventConsumer for ScriptConsumer {
                 gromnie_events::SystemEvent::ConnectingStarted { .. } => {
                     ClientEvent::System(ClientSystemEvent::ConnectingStarted)
                 }
+                gromnie_events::SystemEvent::LoginRetry {
+                    attempt,
+                    max_attempts,
+                    ..
+                } => ClientEvent::System(ClientSystemEvent::LoginRetry {
+                    attempt,
+                    max_attempts,
+                }),
                 gromnie_events::SystemEvent::ConnectingDone { .. } => {
                     ClientEvent::System(ClientSystemEvent::ConnectingDone)
                 }
@@ -997,12 +1461,60 @@ impl EventConsumer for ScriptConsumer {
                     attempt,
                     delay_secs,
                 }),
+                gromnie_events::SystemEvent::Resuming {
+                    character_id,
+                    character_name,
+                    attempt,
+                    ..
+                } => ClientEvent::System(ClientSystemEvent::Resuming {
+                    character_id,
+                    character_name,
+                    attempt,
+                }),
+                gromnie_events::SystemEvent::PingResult { rtt_millis, .. } => {
+                    ClientEvent::System(ClientSystemEvent::PingResult { rtt_millis })
+                }
+                gromnie_events::SystemEvent::ConnectionStats { stats, .. } => {
+                    ClientEvent::System(ClientSystemEvent::ConnectionStats(stats))
+                }
+                gromnie_events::SystemEvent::UnknownMessage {
+                    opcode,
+                    game_event_opcode,
+                    data,
+                    ..
+                } => ClientEvent::System(ClientSystemEvent::UnknownMessage {
+                    opcode,
+                    game_event_opcode,
+                    data,
+                }),
+                gromnie_events::SystemEvent::ConnectionError {
+                    kind,
+                    message,
+                    can_retry,
+                    ..
+                } => ClientEvent::System(ClientSystemEvent::ConnectionError {
+                    kind,
+                    message,
+                    can_retry,
+                }),
+                gromnie_events::SystemEvent::Script {
+                    script_id,
+                    event: gromnie_events::ScriptEventType::Error { message },
+                    ..
+                } => ClientEvent::System(ClientSystemEvent::ScriptError { script_id, message }),
+                gromnie_events::SystemEvent::SquelchListChanged { names, .. } => {
+                    ClientEvent::System(ClientSystemEvent::SquelchListChanged { names })
+                }
                 gromnie_events::SystemEvent::ReloadScripts { .. }
                 | gromnie_events::SystemEvent::LogScriptMessage { .. }
+                | gromnie_events::SystemEvent::Script { .. }
                 | gromnie_events::SystemEvent::Shutdown => {
                     return;
                 }
             },
+            gromnie_events::EventType::Protocol(protocol_event) => {
+                ClientEvent::Protocol(protocol_event)
+            }
         };
 
         let _ = tx.send(RunnerMessage::Event(client_event));
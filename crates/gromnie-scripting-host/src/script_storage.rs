@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::keyed_file_store::{FileFormat, KeyedFileStore};
+
+/// Maximum total size of a single script's keys and values
+pub const MAX_STORAGE_BYTES: usize = 64 * 1024;
+
+/// Storage files are a JSON object of keys to values
+struct StorageFormat;
+
+impl FileFormat for StorageFormat {
+    type Value = BTreeMap<String, String>;
+
+    const EXTENSION: &'static str = "json";
+    const KIND: &'static str = "storage";
+
+    fn is_empty(values: &Self::Value) -> bool {
+        values.is_empty()
+    }
+
+    fn encode(_script_id: &str, values: &Self::Value) -> std::io::Result<Vec<u8>> {
+        Ok(serde_json::to_vec_pretty(values)?)
+    }
+
+    fn decode(_script_id: &str, bytes: &[u8]) -> Result<Self::Value, String> {
+        serde_json::from_slice(bytes).map_err(|e| e.to_string())
+    }
+}
+
+/// Per-script key/value storage, keyed by script ID
+///
+/// Like [`StateStore`](crate::StateStore), entries live in the runner and so
+/// survive script reloads. With a persist directory each script's entries are
+/// read from `<dir>/<script_id>.json` the first time the script touches
+/// storage, and written back by [`ScriptStorage::flush_script`] when the
+/// script unloads. The directory should be namespaced per client so that
+/// several clients running the same script don't overwrite each other.
+#[derive(Default)]
+pub struct ScriptStorage {
+    store: KeyedFileStore<StorageFormat>,
+}
+
+impl ScriptStorage {
+    /// Create an in-memory store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a store backed by JSON files in `dir`
+    pub fn with_persist_dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            store: KeyedFileStore::with_persist_dir(dir),
+        }
+    }
+
+    /// Get the value stored under `key`, if any
+    pub fn get(&self, script_id: &str, key: &str) -> Option<String> {
+        self.store
+            .read(script_id, |values| values.get(key).cloned())
+    }
+
+    /// Store `value` under `key`. An empty value removes the key. Fails if the
+    /// script's total stored bytes would exceed [`MAX_STORAGE_BYTES`].
+    pub fn set(&self, script_id: &str, key: &str, value: &str) -> Result<(), String> {
+        self.store.modify(script_id, |values| {
+            if value.is_empty() {
+                return Ok(values.remove(key).is_some());
+            }
+
+            let current: usize = values.iter().map(|(k, v)| k.len() + v.len()).sum();
+            let replaced = values.get(key).map_or(0, |old| key.len() + old.len());
+            let new_size = current - replaced + key.len() + value.len();
+            if new_size > MAX_STORAGE_BYTES {
+                return Err(format!(
+                    "storage would be {} bytes; the limit is {} bytes",
+                    new_size, MAX_STORAGE_BYTES
+                ));
+            }
+
+            values.insert(key.to_string(), value.to_string());
+            Ok(true)
+        })
+    }
+
+    /// Write one script's entries to the persist directory if they changed.
+    /// Returns whether a file was written or removed.
+    pub fn flush_script(&self, script_id: &str) -> std::io::Result<bool> {
+        self.store.flush_script(script_id)
+    }
+
+    /// Write every script's changed entries. Returns the number of files
+    /// written or removed; always 0 for an in-memory store.
+    pub fn flush(&self) -> std::io::Result<usize> {
+        self.store.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_storage_round_trips_through_disk() {
+        let dir = TempDir::new().unwrap();
+
+        let storage = ScriptStorage::with_persist_dir(dir.path());
+        storage.set("greeter", "greeted:Alice", "3").unwrap();
+        assert_eq!(
+            storage.get("greeter", "greeted:Alice"),
+            Some("3".to_string())
+        );
+        assert_eq!(storage.get("greeter", "greeted:Bob"), None);
+        assert!(storage.flush_script("greeter").unwrap());
+        // Nothing changed since the last flush
+        assert!(!storage.flush_script("greeter").unwrap());
+
+        let restored = ScriptStorage::with_persist_dir(dir.path());
+        assert_eq!(
+            restored.get("greeter", "greeted:Alice"),
+            Some("3".to_string())
+        );
+        assert_eq!(restored.get("other", "greeted:Alice"), None);
+
+        restored.set("greeter", "greeted:Alice", "").unwrap();
+        assert_eq!(restored.flush().unwrap(), 1);
+        assert!(!dir.path().join("greeter.json").exists());
+    }
+
+    #[test]
+    fn test_size_cap() {
+        let storage = ScriptStorage::new();
+        let half = "x".repeat(MAX_STORAGE_BYTES / 2);
+
+        storage.set("s", "a", &half).unwrap();
+        assert!(storage.set("s", "b", &half).is_err());
+        assert_eq!(storage.get("s", "b"), None);
+
+        // Replacing a value only counts the difference
+        storage.set("s", "a", &half[1..]).unwrap();
+        // Other scripts have their own budget
+        storage.set("t", "b", &half).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_file_is_ignored() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("broken.json"), b"{not json").unwrap();
+
+        let storage = ScriptStorage::with_persist_dir(dir.path());
+        assert_eq!(storage.get("broken", "k"), None);
+
+        storage.set("broken", "k", "v").unwrap();
+        storage.flush().unwrap();
+        assert_eq!(
+            ScriptStorage::with_persist_dir(dir.path()).get("broken", "k"),
+            Some("v".to_string())
+        );
+    }
+}
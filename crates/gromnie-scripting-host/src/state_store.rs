@@ -0,0 +1,191 @@
+use std::path::PathBuf;
+
+use crate::keyed_file_store::{FileFormat, KeyedFileStore};
+
+/// Maximum size of a single script's saved state
+pub const MAX_STATE_BYTES: usize = 64 * 1024;
+
+/// Leading bytes of every state file, used to reject files that aren't ours
+const STATE_FILE_MAGIC: &[u8; 4] = b"GRST";
+
+struct StateFormat;
+
+impl FileFormat for StateFormat {
+    /// Empty when the script has no state
+    type Value = Vec<u8>;
+
+    const EXTENSION: &'static str = "state";
+    const KIND: &'static str = "state";
+
+    fn is_empty(data: &Self::Value) -> bool {
+        data.is_empty()
+    }
+
+    fn encode(script_id: &str, data: &Self::Value) -> std::io::Result<Vec<u8>> {
+        Ok(encode(script_id, data))
+    }
+
+    fn decode(script_id: &str, bytes: &[u8]) -> Result<Self::Value, String> {
+        match decode(bytes)? {
+            (id, data) if id == script_id => Ok(data),
+            // IDs differing only in replaced characters share a file name
+            (id, _) => Err(format!("it belongs to {}", id)),
+        }
+    }
+}
+
+/// Per-script persistent state, keyed by script ID
+///
+/// State always survives script reloads because it lives in the runner rather
+/// than in the script instance. Like [`ScriptStorage`](crate::ScriptStorage),
+/// a store with a persist directory reads a script's state from
+/// `<dir>/<script_id>.state` the first time the script touches it, and writes
+/// it back when the script unloads or the runner flushes. The file format is:
+///
+/// `"GRST"`, u16 id length, id bytes, u32 data length, data bytes (little-endian)
+#[derive(Default)]
+pub struct StateStore {
+    store: KeyedFileStore<StateFormat>,
+}
+
+impl StateStore {
+    /// Create an in-memory store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a store backed by state files in `dir`. Unreadable or corrupt
+    /// files are skipped with a warning, and that script starts fresh.
+    pub fn with_persist_dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            store: KeyedFileStore::with_persist_dir(dir),
+        }
+    }
+
+    /// Replace a script's state. Empty data clears it.
+    pub fn save(&self, script_id: &str, data: Vec<u8>) -> Result<(), String> {
+        if data.len() > MAX_STATE_BYTES {
+            return Err(format!(
+                "state is {} bytes; the limit is {} bytes",
+                data.len(),
+                MAX_STATE_BYTES
+            ));
+        }
+
+        self.store.modify(script_id, |state| {
+            if *state == data {
+                return Ok(false);
+            }
+            *state = data;
+            Ok(true)
+        })
+    }
+
+    /// Get a copy of a script's state, if it has saved any
+    pub fn load(&self, script_id: &str) -> Option<Vec<u8>> {
+        self.store.read(script_id, |state| {
+            (!state.is_empty()).then(|| state.clone())
+        })
+    }
+
+    /// Write one script's state to the persist directory if it changed.
+    /// Returns whether a file was written or removed.
+    pub fn flush_script(&self, script_id: &str) -> std::io::Result<bool> {
+        self.store.flush_script(script_id)
+    }
+
+    /// Write every script's changed state. Returns the number of files
+    /// written or removed; always 0 for an in-memory store.
+    pub fn flush(&self) -> std::io::Result<usize> {
+        self.store.flush()
+    }
+}
+
+fn encode(script_id: &str, data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(STATE_FILE_MAGIC.len() + 6 + script_id.len() + data.len());
+    buf.extend_from_slice(STATE_FILE_MAGIC);
+    buf.extend_from_slice(&(script_id.len() as u16).to_le_bytes());
+    buf.extend_from_slice(script_id.as_bytes());
+    buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    buf.extend_from_slice(data);
+    buf
+}
+
+fn decode(bytes: &[u8]) -> Result<(String, Vec<u8>), &'static str> {
+    let rest = bytes
+        .strip_prefix(STATE_FILE_MAGIC.as_slice())
+        .ok_or("missing header")?;
+
+    let (id_len, rest) = rest.split_at_checked(2).ok_or("truncated id length")?;
+    let id_len = u16::from_le_bytes([id_len[0], id_len[1]]) as usize;
+    let (id, rest) = rest.split_at_checked(id_len).ok_or("truncated id")?;
+    let id = String::from_utf8(id.to_vec()).map_err(|_| "id is not UTF-8")?;
+
+    let (data_len, data) = rest.split_at_checked(4).ok_or("truncated data length")?;
+    let data_len = u32::from_le_bytes([data_len[0], data_len[1], data_len[2], data_len[3]]);
+    if data_len as usize != data.len() {
+        return Err("data length mismatch");
+    }
+    if data.len() > MAX_STATE_BYTES {
+        return Err("state exceeds size limit");
+    }
+
+    Ok((id, data.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_state_round_trips_through_disk() {
+        let dir = TempDir::new().unwrap();
+
+        let store = StateStore::with_persist_dir(dir.path());
+        store.save("greeter", b"seen: Alice".to_vec()).unwrap();
+        store.save("other/script", vec![1, 2, 3]).unwrap();
+        assert_eq!(store.flush().unwrap(), 2);
+        // Nothing changed since the last flush
+        assert!(!store.flush_script("greeter").unwrap());
+        store.save("greeter", b"seen: Alice".to_vec()).unwrap();
+        assert_eq!(store.flush().unwrap(), 0);
+
+        let restored = StateStore::with_persist_dir(dir.path());
+        assert_eq!(restored.load("greeter"), Some(b"seen: Alice".to_vec()));
+        assert_eq!(restored.load("other/script"), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_corrupt_file_is_ignored() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("broken.state"), b"GRST\x05\x00ab").unwrap();
+
+        let store = StateStore::with_persist_dir(dir.path());
+        assert_eq!(store.load("broken"), None);
+
+        // The store is still usable and overwrites the bad file
+        store.save("broken", vec![9]).unwrap();
+        store.flush().unwrap();
+        assert_eq!(
+            StateStore::with_persist_dir(dir.path()).load("broken"),
+            Some(vec![9])
+        );
+    }
+
+    #[test]
+    fn test_size_cap_and_clear() {
+        let dir = TempDir::new().unwrap();
+        let store = StateStore::with_persist_dir(dir.path());
+
+        assert!(store.save("big", vec![0; MAX_STATE_BYTES + 1]).is_err());
+        assert_eq!(store.load("big"), None);
+
+        store.save("s", vec![1]).unwrap();
+        store.flush().unwrap();
+        store.save("s", Vec::new()).unwrap();
+        assert_eq!(store.load("s"), None);
+        store.flush().unwrap();
+        assert_eq!(StateStore::with_persist_dir(dir.path()).load("s"), None);
+    }
+}
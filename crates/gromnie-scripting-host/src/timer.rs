@@ -23,6 +23,8 @@ impl From<u64> for TimerId {
 #[derive(Debug)]
 struct Timer {
     cancel: Arc<AtomicBool>,
+    /// When the timer fires next (tokio's clock, so paused-time tests work)
+    next_fire: tokio::time::Instant,
 }
 
 #[derive(Default)]
@@ -48,15 +50,17 @@ impl TimerManager {
 
     /// Schedule a one-shot timer that fires after a delay
     pub fn schedule_timer(&self, delay: Duration, name: String) -> TimerId {
-        let (id, cancel) = self.insert_timer();
+        let deadline = tokio::time::Instant::now() + delay;
+        let (id, cancel) = self.insert_timer(deadline);
         let state = Arc::clone(&self.state);
         tokio::spawn(async move {
-            tokio::time::sleep(delay).await;
+            tokio::time::sleep_until(deadline).await;
+
+            // Checked under the lock so a concurrent cancel_timer can't race the fire
+            let mut state = state.lock().expect("timer state poisoned");
             if cancel.load(Ordering::SeqCst) {
                 return;
             }
-
-            let mut state = state.lock().expect("timer state poisoned");
             state.fired_timers.insert(id);
             state.fired_events.push((id, name));
             state.timers.remove(&id);
@@ -64,29 +68,46 @@ impl TimerManager {
         id
     }
 
+    /// Schedule a one-shot timer for `target_millis` on the server clock,
+    /// given the current server time. Times already past fire on the next tick.
+    pub fn schedule_at_server_time(
+        &self,
+        target_millis: u64,
+        server_now_millis: u64,
+        name: String,
+    ) -> TimerId {
+        let delay = Duration::from_millis(target_millis.saturating_sub(server_now_millis));
+        self.schedule_timer(delay, name)
+    }
+
     /// Schedule a recurring timer that fires repeatedly at an interval
     pub fn schedule_recurring(&self, interval: Duration, name: String) -> TimerId {
-        let (id, cancel) = self.insert_timer();
+        let mut deadline = tokio::time::Instant::now() + interval;
+        let (id, cancel) = self.insert_timer(deadline);
         let state = Arc::clone(&self.state);
         tokio::spawn(async move {
             loop {
-                tokio::time::sleep(interval).await;
+                tokio::time::sleep_until(deadline).await;
+
+                let mut state = state.lock().expect("timer state poisoned");
                 if cancel.load(Ordering::SeqCst) {
                     break;
                 }
-
-                let mut state = state.lock().expect("timer state poisoned");
+                deadline += interval;
+                let Some(timer) = state.timers.get_mut(&id) else {
+                    break;
+                };
+                timer.next_fire = deadline;
                 state.fired_timers.insert(id);
                 state.fired_events.push((id, name.clone()));
-                if !state.timers.contains_key(&id) {
-                    break;
-                }
             }
         });
         id
     }
 
-    /// Cancel a timer
+    /// Cancel a timer. Works for one-shot and recurring timers: a cancelled
+    /// recurring timer never fires again, and any fire not yet drained by
+    /// `tick` is discarded.
     pub fn cancel_timer(&self, id: TimerId) -> bool {
         let mut state = self.state.lock().expect("timer state poisoned");
         let Some(timer) = state.timers.remove(&id) else {
@@ -95,9 +116,21 @@ impl TimerManager {
 
         timer.cancel.store(true, Ordering::SeqCst);
         state.fired_timers.remove(&id);
+        state.fired_events.retain(|(fired_id, _)| *fired_id != id);
         true
     }
 
+    /// Time until a timer next fires, or None if it has fired (one-shot),
+    /// been cancelled, or never existed
+    pub fn remaining(&self, id: TimerId) -> Option<Duration> {
+        let state = self.state.lock().expect("timer state poisoned");
+        state.timers.get(&id).map(|timer| {
+            timer
+                .next_fire
+                .saturating_duration_since(tokio::time::Instant::now())
+        })
+    }
+
     /// Check if a timer has fired (and consume the fired state)
     pub fn check_timer(&self, id: TimerId) -> bool {
         self.state
@@ -122,7 +155,7 @@ impl TimerManager {
             .len()
     }
 
-    fn insert_timer(&self) -> (TimerId, Arc<AtomicBool>) {
+    fn insert_timer(&self, next_fire: tokio::time::Instant) -> (TimerId, Arc<AtomicBool>) {
         let cancel = Arc::new(AtomicBool::new(false));
         let mut state = self.state.lock().expect("timer state poisoned");
         let id = TimerId(state.next_id);
@@ -131,6 +164,7 @@ impl TimerManager {
             id,
             Timer {
                 cancel: Arc::clone(&cancel),
+                next_fire,
             },
         );
         (id, cancel)
@@ -187,6 +221,22 @@ mod tests {
         assert_eq!(manager.active_count(), 0);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_timer_at_server_time() {
+        let manager = TimerManager::new();
+        let id = manager.schedule_at_server_time(1_000_250, 1_000_000, "storm".to_string());
+        assert_eq!(manager.remaining(id), Some(Duration::from_millis(250)));
+
+        // A time that has already passed fires right away
+        let late = manager.schedule_at_server_time(999_000, 1_000_000, "late".to_string());
+        tokio::task::yield_now().await;
+        assert!(manager.check_timer(late));
+
+        tokio::time::advance(Duration::from_millis(250)).await;
+        tokio::task::yield_now().await;
+        assert!(manager.check_timer(id));
+    }
+
     #[tokio::test(start_paused = true)]
     async fn test_recurring_timer() {
         let manager = TimerManager::new();
@@ -228,6 +278,49 @@ mod tests {
         assert!(!manager.cancel_timer(id)); // Already removed
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_cancel_recurring_timer_mid_cycle() {
+        let manager = TimerManager::new();
+        let id = manager.schedule_recurring(Duration::from_millis(50), "recurring".to_string());
+
+        // First fire at T=50ms
+        tokio::task::yield_now().await;
+        tokio::time::advance(Duration::from_millis(60)).await;
+        tokio::task::yield_now().await;
+        assert_eq!(manager.tick(Instant::now()).len(), 1);
+        assert_eq!(manager.remaining(id), Some(Duration::from_millis(40)));
+
+        // Second fire at T=100ms is collected but not drained before the cancel
+        tokio::time::advance(Duration::from_millis(45)).await;
+        tokio::task::yield_now().await;
+        assert!(manager.cancel_timer(id));
+        assert_eq!(manager.remaining(id), None);
+        assert!(manager.tick(Instant::now()).is_empty());
+
+        // And it never fires again
+        tokio::time::advance(Duration::from_millis(200)).await;
+        tokio::task::yield_now().await;
+        assert!(manager.tick(Instant::now()).is_empty());
+        assert!(!manager.check_timer(id));
+        assert_eq!(manager.active_count(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_one_shot_remaining_time() {
+        let manager = TimerManager::new();
+        let id = manager.schedule_timer(Duration::from_millis(50), "test".to_string());
+        assert_eq!(manager.remaining(id), Some(Duration::from_millis(50)));
+
+        tokio::task::yield_now().await;
+        tokio::time::advance(Duration::from_millis(20)).await;
+        assert_eq!(manager.remaining(id), Some(Duration::from_millis(30)));
+
+        tokio::time::advance(Duration::from_millis(40)).await;
+        tokio::task::yield_now().await;
+        assert_eq!(manager.tick(Instant::now()).len(), 1);
+        assert_eq!(manager.remaining(id), None);
+    }
+
     #[tokio::test(start_paused = true)]
     async fn test_check_timer() {
         let manager = TimerManager::new();
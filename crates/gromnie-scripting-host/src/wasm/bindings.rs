@@ -1,10 +1,29 @@
 use anyhow::Result;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use wasmtime::component::Linker;
 
-use super::wasm_script::{WasmScriptState, gromnie};
+use super::wasm_script::{
+    WasmScriptState, client_event_to_wasm, connection_stats_to_wasm, gromnie,
+};
 use crate::ScriptContext;
-use gromnie_events::SimpleClientAction;
+use gromnie_events::{EmoteKind, SimpleClientAction};
+
+/// Minimum time between spell casts from a single script
+const SPELL_CAST_MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Minimum time between appraisal requests from a single script
+const APPRAISE_MIN_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Minimum time between vendor buy/sell requests from a single script
+const VENDOR_MIN_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Longest a script may wait in login-character-await; the script receives no
+/// events while it waits
+const LOGIN_AWAIT_MAX_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long get-character-list waits for the server's character list
+const CHARACTER_LIST_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Add all host imports to the linker
 pub fn add_host_imports(linker: &mut Linker<WasmScriptState>) -> Result<()> {
@@ -27,35 +46,97 @@ fn get_context(state: &WasmScriptState) -> Arc<ScriptContext> {
     )
 }
 
+fn emote_kind_from_wit(emote: gromnie::scripting::host::EmoteKind) -> EmoteKind {
+    use gromnie::scripting::host::EmoteKind as Wit;
+    match emote {
+        Wit::BowDeep => EmoteKind::BowDeep,
+        Wit::ClapHands => EmoteKind::ClapHands,
+        Wit::Cry => EmoteKind::Cry,
+        Wit::Laugh => EmoteKind::Laugh,
+        Wit::Nod => EmoteKind::Nod,
+        Wit::Point => EmoteKind::Point,
+        Wit::ShakeHead => EmoteKind::ShakeHead,
+        Wit::Shrug => EmoteKind::Shrug,
+        Wit::Wave => EmoteKind::Wave,
+        Wit::Salute => EmoteKind::Salute,
+        Wit::Kneel => EmoteKind::Kneel,
+    }
+}
+
+fn scheduled_action_from_wit(
+    action: gromnie::scripting::host::ScheduledAction,
+) -> SimpleClientAction {
+    use gromnie::scripting::host::ScheduledAction as Wit;
+    match action {
+        Wit::Say(message) => SimpleClientAction::SendChatSay { message },
+        Wit::Tell(tell) => SimpleClientAction::SendChatTell {
+            recipient_name: tell.recipient,
+            message: tell.message,
+        },
+        Wit::Emote(emote) => SimpleClientAction::DoEmote {
+            emote: emote_kind_from_wit(emote),
+        },
+        Wit::TextEmote(text) => SimpleClientAction::SendTextEmote { text },
+        Wit::UseItem(object_id) => SimpleClientAction::UseItem {
+            object_id: object_id.into(),
+        },
+    }
+}
+
 impl gromnie::scripting::host::Host for WasmScriptState {
     async fn send_chat(&mut self, message: String) {
         let ctx = get_context(self);
         ctx.send_chat(message);
     }
 
+    async fn send_chat_lines(&mut self, lines: Vec<String>, interval_millis: u64) {
+        let ctx = get_context(self);
+        ctx.send_chat_lines(lines, interval_millis);
+    }
+
     async fn send_tell(&mut self, recipient: String, message: String) {
         let ctx = get_context(self);
         ctx.send_tell(recipient, message);
     }
 
-    async fn open_trade(&mut self, partner_id: u32) {
+    async fn do_emote(&mut self, emote: gromnie::scripting::host::EmoteKind) {
+        let ctx = get_context(self);
+        ctx.do_emote(emote_kind_from_wit(emote));
+    }
+
+    async fn send_emote(&mut self, emote_id: u32) -> bool {
+        let ctx = get_context(self);
+        ctx.send_emote(emote_id)
+    }
+
+    async fn do_text_emote(&mut self, text: String) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.do_text_emote(text)
+    }
+
+    async fn open_trade(&mut self, partner_id: u32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.open_trade(partner_id)
+    }
+
+    async fn add_to_trade(&mut self, item_id: u32, slot: u32) -> Result<(), String> {
         let ctx = get_context(self);
-        ctx.open_trade(partner_id);
+        ctx.add_to_trade(item_id, slot)
     }
 
-    async fn add_to_trade(&mut self, item_id: u32, slot: u32) {
+    async fn remove_from_trade(&mut self, item_id: u32) -> Result<(), String> {
         let ctx = get_context(self);
-        ctx.add_to_trade(item_id, slot);
+        ctx.remove_from_trade(item_id)
     }
 
-    async fn accept_trade(&mut self) {
+    async fn accept_trade(&mut self) -> Result<(), String> {
         let ctx = get_context(self);
-        ctx.accept_trade();
+        ctx.accept_trade()
     }
 
-    async fn decline_trade(&mut self) {
+    async fn decline_trade(&mut self) -> Result<(), String> {
         let ctx = get_context(self);
-        ctx.decline_trade();
+        ctx.decline_trade()
     }
 
     async fn reset_trade(&mut self) {
@@ -68,6 +149,41 @@ impl gromnie::scripting::host::Host for WasmScriptState {
         ctx.close_trade();
     }
 
+    async fn respond_confirmation(&mut self, context_id: u32, accept: bool) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.respond_confirmation(context_id, accept)
+    }
+
+    async fn accept_fellowship(&mut self) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.respond_fellowship_invite(true)
+    }
+
+    async fn decline_fellowship(&mut self) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.respond_fellowship_invite(false)
+    }
+
+    async fn create_fellowship(&mut self, name: String) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.create_fellowship(name)
+    }
+
+    async fn invite_to_fellowship(&mut self, player_id: u32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.invite_to_fellowship(player_id)
+    }
+
+    async fn dismiss_from_fellowship(&mut self, player_id: u32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.dismiss_from_fellowship(player_id)
+    }
+
+    async fn quit_fellowship(&mut self) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.quit_fellowship()
+    }
+
     async fn cast_targeted_spell(&mut self, target_id: u32, spell_id: u32) {
         let ctx = get_context(self);
         ctx.cast_targeted_spell(target_id, spell_id);
@@ -78,6 +194,175 @@ impl gromnie::scripting::host::Host for WasmScriptState {
         ctx.cast_untargeted_spell(spell_id);
     }
 
+    async fn query_health(&mut self, object_id: u32) {
+        let ctx = get_context(self);
+        ctx.query_health(object_id);
+    }
+
+    async fn open_container(&mut self, object_id: u32) {
+        let ctx = get_context(self);
+        ctx.open_container(object_id);
+    }
+
+    async fn get_object_health(&mut self, object_id: u32) -> Option<f32> {
+        let ctx = get_context(self);
+        ctx.object_health(object_id)
+    }
+
+    async fn get_objects(&mut self) -> Vec<gromnie::scripting::host::ObjectInfo> {
+        let ctx = get_context(self);
+        ctx.objects()
+            .into_iter()
+            .map(|object| gromnie::scripting::host::ObjectInfo {
+                id: object.id,
+                name: object.name,
+                object_type: object.item_type,
+                container_id: object.container_id,
+                stack_size: object.stack_size,
+            })
+            .collect()
+    }
+
+    async fn get_characters(
+        &mut self,
+    ) -> Result<Vec<gromnie::scripting::host::CharacterIdentity>, String> {
+        let ctx = get_context(self);
+        let characters = ctx.character_list_and_wait(CHARACTER_LIST_TIMEOUT).await?;
+        Ok(characters
+            .into_iter()
+            .map(|c| gromnie::scripting::host::CharacterIdentity {
+                character_id: c.character_id.0,
+                name: c.name,
+                seconds_greyed_out: c.seconds_greyed_out,
+            })
+            .collect())
+    }
+
+    async fn get_known_spells(&mut self) -> Vec<u32> {
+        let ctx = get_context(self);
+        ctx.known_spells()
+    }
+
+    async fn get_attributes(&mut self) -> gromnie::scripting::host::Attributes {
+        let ctx = get_context(self);
+        let sheet = ctx.character_sheet();
+        let attributes = &sheet.attributes;
+
+        gromnie::scripting::host::Attributes {
+            received: sheet.received,
+            strength: convert_attribute_value(&attributes.strength),
+            endurance: convert_attribute_value(&attributes.endurance),
+            quickness: convert_attribute_value(&attributes.quickness),
+            coordination: convert_attribute_value(&attributes.coordination),
+            focus: convert_attribute_value(&attributes.focus),
+            self_attribute: convert_attribute_value(&attributes.self_),
+        }
+    }
+
+    async fn get_quality_int(&mut self, property: String) -> Option<i64> {
+        let ctx = get_context(self);
+        ctx.quality_int(&property)
+    }
+
+    async fn get_quality_float(&mut self, property: String) -> Option<f64> {
+        let ctx = get_context(self);
+        ctx.quality_float(&property)
+    }
+
+    async fn get_skills(&mut self) -> Vec<gromnie::scripting::host::Skill> {
+        use gromnie::scripting::host::SkillTraining as WitSkillTraining;
+        use gromnie_client::client::character_sheet::SkillTraining;
+
+        let ctx = get_context(self);
+        ctx.character_sheet()
+            .trained_skills()
+            .map(|skill| gromnie::scripting::host::Skill {
+                id: skill.skill_id,
+                training: match skill.training {
+                    SkillTraining::Inactive => WitSkillTraining::Inactive,
+                    SkillTraining::Untrained => WitSkillTraining::Untrained,
+                    SkillTraining::Trained => WitSkillTraining::Trained,
+                    SkillTraining::Specialized => WitSkillTraining::Specialized,
+                },
+                ranks: skill.ranks,
+                init_level: skill.init_level,
+                level: skill.level(),
+                experience_spent: skill.experience_spent,
+            })
+            .collect()
+    }
+
+    async fn cast_spell(&mut self, spell_id: u32, target_id: Option<u32>) -> Result<(), String> {
+        let now = Instant::now();
+        if let Some(last) = self.last_spell_cast
+            && now.duration_since(last) < SPELL_CAST_MIN_INTERVAL
+        {
+            return Err(format!(
+                "spell casts are limited to one per {}ms",
+                SPELL_CAST_MIN_INTERVAL.as_millis()
+            ));
+        }
+
+        let ctx = get_context(self);
+        ctx.cast_spell(spell_id, target_id)?;
+        self.last_spell_cast = Some(now);
+        Ok(())
+    }
+
+    async fn appraise_object(&mut self, object_id: u32) -> Result<(), String> {
+        let now = Instant::now();
+        if let Some(last) = self.last_appraisal
+            && now.duration_since(last) < APPRAISE_MIN_INTERVAL
+        {
+            return Err(format!(
+                "appraisals are limited to one per {}ms",
+                APPRAISE_MIN_INTERVAL.as_millis()
+            ));
+        }
+
+        let ctx = get_context(self);
+        ctx.appraise_object(object_id);
+        self.last_appraisal = Some(now);
+        Ok(())
+    }
+
+    async fn buy_item(
+        &mut self,
+        vendor_id: u32,
+        item_id: u32,
+        quantity: u32,
+    ) -> Result<(), String> {
+        self.check_vendor_rate()?;
+        let ctx = get_context(self);
+        ctx.buy_item(vendor_id, item_id, quantity)?;
+        self.last_vendor_transaction = Some(Instant::now());
+        Ok(())
+    }
+
+    async fn sell_item(
+        &mut self,
+        vendor_id: u32,
+        item_id: u32,
+        quantity: u32,
+    ) -> Result<(), String> {
+        self.check_vendor_rate()?;
+        let ctx = get_context(self);
+        ctx.sell_item(vendor_id, item_id, quantity)?;
+        self.last_vendor_transaction = Some(Instant::now());
+        Ok(())
+    }
+
+    async fn send_game_action(&mut self, opcode: u32, payload: Vec<u8>) -> Result<(), String> {
+        if !self.allow_raw_actions {
+            return Err(
+                "raw game actions are disabled; set allow_raw_actions in the [scripting] config"
+                    .to_string(),
+            );
+        }
+        let ctx = get_context(self);
+        ctx.send_raw_game_action(opcode, payload)
+    }
+
     async fn login_character(
         &mut self,
         account_name: String,
@@ -86,16 +371,62 @@ impl gromnie::scripting::host::Host for WasmScriptState {
     ) {
         let ctx = get_context(self);
         ctx.send_action(SimpleClientAction::LoginCharacter {
-            character_id,
+            character_id: character_id.into(),
             character_name,
             account: account_name,
         });
     }
 
+    async fn login_character_await(
+        &mut self,
+        account_name: String,
+        character_id: u32,
+        character_name: String,
+        timeout_ms: u32,
+    ) -> Result<(), String> {
+        let timeout = Duration::from_millis(timeout_ms.into()).min(LOGIN_AWAIT_MAX_TIMEOUT);
+        let ctx = get_context(self);
+        ctx.login_character_and_wait(account_name, character_id, character_name, timeout)
+            .await
+    }
+
+    async fn return_to_character_select(&mut self) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.return_to_character_select()
+    }
+
+    async fn get_tick_count(&mut self) -> u64 {
+        self.tick_count
+    }
+
+    async fn get_tick_rate_hz(&mut self) -> u32 {
+        let ctx = get_context(self);
+        ctx.tick_rate_hz()
+    }
+
+    async fn save_state(&mut self, data: Vec<u8>) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.save_state(&self.script_id, data)
+    }
+
+    async fn load_state(&mut self) -> Option<Vec<u8>> {
+        let ctx = get_context(self);
+        ctx.load_state(&self.script_id)
+    }
+
+    async fn storage_get(&mut self, key: String) -> Option<String> {
+        let ctx = get_context(self);
+        ctx.storage_get(&self.script_id, &key)
+    }
+
+    async fn storage_set(&mut self, key: String, value: String) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.storage_set(&self.script_id, &key, &value)
+    }
+
     async fn log(&mut self, message: String) {
-        let script_id = self.script_id.clone();
         let ctx = get_context(self);
-        ctx.send_action(SimpleClientAction::LogScriptMessage { script_id, message });
+        ctx.log(&self.script_id, message);
     }
 
     async fn do_movement_command(&mut self, motion: u32, speed: f32, hold_key: u32) {
@@ -112,12 +443,97 @@ impl gromnie::scripting::host::Host for WasmScriptState {
         ctx.send_action(SimpleClientAction::StopMovementCommand { motion, hold_key });
     }
 
+    async fn move_to(&mut self, x: f32, y: f32, z: f32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.move_to(x, y, z)
+    }
+
+    async fn turn_to(&mut self, heading: f32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.turn_to(heading)
+    }
+
+    async fn squelch_add(&mut self, name: String) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.squelch_add(&name)
+    }
+
+    async fn squelch_remove(&mut self, name: String) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.squelch_remove(&name)
+    }
+
+    async fn get_squelched(&mut self) -> Vec<String> {
+        let ctx = get_context(self);
+        ctx.squelched()
+    }
+
+    async fn join_channel(&mut self, channel: u32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.join_channel(channel)
+    }
+
+    async fn leave_channel(&mut self, channel: u32) -> Result<(), String> {
+        let ctx = get_context(self);
+        ctx.leave_channel(channel)
+    }
+
+    async fn watch_quality(&mut self, object_id: Option<u32>, property: Option<String>) {
+        self.quality_watches.watch(object_id, property);
+    }
+
+    async fn unwatch_quality(&mut self, object_id: Option<u32>, property: Option<String>) {
+        self.quality_watches.unwatch(object_id, property);
+    }
+
+    async fn when_event_once(&mut self, event_id: u32, token: u64) -> bool {
+        let Some(filter) = crate::EventFilter::from_discriminant(event_id) else {
+            return false;
+        };
+        self.event_waits.register(filter, token);
+        true
+    }
+
+    async fn cancel_when(&mut self, token: u64) -> bool {
+        self.event_waits.cancel(token)
+    }
+
+    async fn schedule_action_on_event(
+        &mut self,
+        event_id: u32,
+        action: gromnie::scripting::host::ScheduledAction,
+        timeout_ms: u64,
+    ) -> Option<u64> {
+        let filter = crate::EventFilter::from_discriminant(event_id)?;
+        let token = self.scheduled_actions.register(
+            filter,
+            scheduled_action_from_wit(action),
+            Duration::from_millis(timeout_ms),
+            Instant::now(),
+        );
+        Some(token)
+    }
+
+    async fn cancel_scheduled_action(&mut self, token: u64) -> bool {
+        self.scheduled_actions.cancel(token)
+    }
+
     async fn schedule_timer(&mut self, delay_secs: u64, name: String) -> u64 {
         let ctx = get_context(self);
         let timer_id = ctx.schedule_timer(delay_secs, name);
         timer_id_to_u64(timer_id)
     }
 
+    async fn schedule_at_server_time(
+        &mut self,
+        server_time_millis: u64,
+        name: String,
+    ) -> Result<u64, String> {
+        let ctx = get_context(self);
+        let timer_id = ctx.schedule_at_server_time(server_time_millis, name)?;
+        Ok(timer_id_to_u64(timer_id))
+    }
+
     async fn schedule_recurring(&mut self, interval_secs: u64, name: String) -> u64 {
         let ctx = get_context(self);
         let timer_id = ctx.schedule_recurring(interval_secs, name);
@@ -136,6 +552,13 @@ impl gromnie::scripting::host::Host for WasmScriptState {
         ctx.check_timer(timer_id)
     }
 
+    async fn timer_remaining_millis(&mut self, timer_id: u64) -> Option<u64> {
+        let ctx = get_context(self);
+        let timer_id = timer_id_from_u64(timer_id);
+        ctx.timer_remaining(timer_id)
+            .map(|remaining| remaining.as_millis() as u64)
+    }
+
     async fn get_client_state(&mut self) -> gromnie::scripting::host::ClientState {
         use gromnie_client::client::SessionState;
 
@@ -165,6 +588,37 @@ impl gromnie::scripting::host::Host for WasmScriptState {
         gromnie::scripting::host::ClientState { session, scene }
     }
 
+    async fn request_landblock_objects(&mut self) {
+        self.landblock_objects_requested = true;
+    }
+
+    async fn recent_events(&mut self, max: u32) -> Vec<gromnie::scripting::host::ScriptEvent> {
+        let ctx = get_context(self);
+        ctx.recent_events(max as usize)
+            .iter()
+            .map(client_event_to_wasm)
+            .collect()
+    }
+
+    async fn get_world_info(&mut self) -> gromnie::scripting::host::WorldInfo {
+        let ctx = get_context(self);
+        let info = ctx.world_info();
+
+        gromnie::scripting::host::WorldInfo {
+            world_name: info.world_name,
+            language: info.language,
+            region: info.region,
+            product: info.product,
+            server_host: info.server_host,
+            server_port: info.server_port,
+        }
+    }
+
+    async fn get_connection_stats(&mut self) -> gromnie::scripting::host::ConnectionStats {
+        let ctx = get_context(self);
+        connection_stats_to_wasm(&ctx.connection_stats())
+    }
+
     async fn get_event_time_millis(&mut self) -> u64 {
         use std::time::SystemTime;
         let now = SystemTime::now();
@@ -172,6 +626,25 @@ impl gromnie::scripting::host::Host for WasmScriptState {
             .unwrap_or_default()
             .as_millis() as u64
     }
+
+    async fn get_server_time_millis(&mut self) -> Option<u64> {
+        let ctx = get_context(self);
+        ctx.server_time_millis()
+    }
+}
+
+impl WasmScriptState {
+    fn check_vendor_rate(&self) -> Result<(), String> {
+        if let Some(last) = self.last_vendor_transaction
+            && last.elapsed() < VENDOR_MIN_INTERVAL
+        {
+            return Err(format!(
+                "vendor transactions are limited to one per {}ms",
+                VENDOR_MIN_INTERVAL.as_millis()
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Convert TimerId to u64 for WASM ABI
@@ -289,3 +762,14 @@ fn convert_scene_to_wit(scene: &gromnie_client::client::Scene) -> gromnie::scrip
         }
     }
 }
+
+/// Convert a character sheet attribute to WIT
+fn convert_attribute_value(
+    value: &gromnie_client::client::character_sheet::AttributeValue,
+) -> gromnie::scripting::host::AttributeValue {
+    gromnie::scripting::host::AttributeValue {
+        innate: value.innate,
+        raised: value.raised,
+        base: value.base(),
+    }
+}
@@ -15,6 +15,9 @@ pub fn create_engine() -> Result<Engine> {
     // Enable async support — scripts can perform long-running I/O without blocking
     config.async_support(true);
 
+    // Meter execution so a runaway callback traps instead of hanging the runner
+    config.consume_fuel(true);
+
     // Enable optimizations for release builds
     #[cfg(not(debug_assertions))]
     {
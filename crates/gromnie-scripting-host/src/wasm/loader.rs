@@ -1,10 +1,19 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use tracing::warn;
+use tracing::{info, warn};
 use wasmtime::Engine;
 
-use super::WasmScript;
+use super::{WasmScript, WorldMismatch};
 use crate::Script;
+use gromnie_client::config::scripting_config::ScriptSelection;
+
+/// Scripts loaded from a directory
+#[derive(Default)]
+pub struct LoadedScripts {
+    pub scripts: Vec<WasmScript>,
+    /// Files built against a different version of the script world
+    pub rejected: Vec<(PathBuf, WorldMismatch)>,
+}
 
 /// Load all scripts from a directory, filtering by config
 ///
@@ -14,18 +23,20 @@ pub async fn load_wasm_scripts(
     engine: &Engine,
     dir: &Path,
     script_config: &HashMap<String, toml::Value>,
-) -> Vec<WasmScript> {
-    load_wasm_scripts_inner(engine, dir, script_config).await
+    selection: &ScriptSelection,
+) -> LoadedScripts {
+    load_wasm_scripts_inner(engine, dir, script_config, selection).await
 }
 
 async fn load_wasm_scripts_inner(
     engine: &Engine,
     dir: &Path,
     script_config: &HashMap<String, toml::Value>,
-) -> Vec<WasmScript> {
+    selection: &ScriptSelection,
+) -> LoadedScripts {
     use tracing::debug;
 
-    let mut scripts = Vec::new();
+    let mut loaded = LoadedScripts::default();
     let mut seen_ids = std::collections::HashSet::new();
 
     // Check if directory exists
@@ -35,7 +46,7 @@ async fn load_wasm_scripts_inner(
             "Script directory does not exist: {}",
             dir.display()
         );
-        return scripts;
+        return loaded;
     }
 
     let entries = match std::fs::read_dir(dir) {
@@ -47,7 +58,7 @@ async fn load_wasm_scripts_inner(
                 dir.display(),
                 e
             );
-            return scripts;
+            return loaded;
         }
     };
 
@@ -69,6 +80,9 @@ async fn load_wasm_scripts_inner(
                     path.display(),
                     e
                 );
+                if let Some(mismatch) = e.downcast_ref::<WorldMismatch>() {
+                    loaded.rejected.push((path, mismatch.clone()));
+                }
                 continue;
             }
         };
@@ -95,25 +109,46 @@ async fn load_wasm_scripts_inner(
             .unwrap_or(true); // Default to true
 
         if !is_enabled {
-            debug!(
+            info!(
                 target: "scripting",
-                "Skipping disabled script: {} ({})",
+                "Skipping script {} ({}): disabled in its config",
                 script.name(),
                 script_id
             );
             continue;
         }
 
-        debug!(
+        if let Some(reason) = selection.skip_reason(script_id) {
+            info!(
+                target: "scripting",
+                "Skipping script {} ({}): {}",
+                script.name(),
+                script_id,
+                reason
+            );
+            continue;
+        }
+
+        info!(
             target: "scripting",
             "Loaded: {} ({})",
             script.name(),
             script_id
         );
-        scripts.push(script);
+        loaded.scripts.push(script);
+    }
+
+    let found: Vec<&str> = seen_ids.iter().map(String::as_str).collect();
+    for script_id in selection.missing_enabled(&found) {
+        tracing::error!(
+            target: "scripting",
+            "Script '{}' is listed in enabled_scripts but no script in {} reports that ID",
+            script_id,
+            dir.display()
+        );
     }
 
-    scripts
+    loaded
 }
 
 /// Get the default scripts directory
@@ -2,7 +2,9 @@ pub(crate) mod bindings;
 mod engine;
 mod loader;
 mod wasm_script;
+mod world_check;
 
 pub use engine::{create_engine, create_wasi_context};
-pub use loader::{get_wasm_dir, load_wasm_scripts};
-pub use wasm_script::WasmScript;
+pub use loader::{LoadedScripts, get_wasm_dir, load_wasm_scripts};
+pub use wasm_script::{DEFAULT_FUEL_LIMIT, WasmScript};
+pub use world_check::{WorldMismatch, expected_package};
@@ -3,18 +3,24 @@ use std::any::Any;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
-use tracing::warn;
+use tracing::{error, warn};
 use wasmtime::component::{Component, Linker, ResourceTable};
-use wasmtime::{Engine, Store};
+use wasmtime::{Engine, Store, Trap};
 use wasmtime_wasi::{WasiCtx, WasiView};
 
 use crate::Script as HostScript;
+use crate::event_wait::{EventWait, EventWaitSet};
+use crate::quality_watch::{QualityChange, QualityWatchSet};
+use crate::scheduled_action::{ScheduledAction, ScheduledActionSet};
 use crate::{EventFilter, context::ScriptContext};
 use gromnie_events::{
     ClientEvent, ClientStateEvent, ClientSystemEvent, GameEventMsg, ProtocolEvent, S2CEvent,
     SimpleGameEvent as GameEvent,
 };
 
+/// Fuel each callback may burn unless the runner sets another limit
+pub const DEFAULT_FUEL_LIMIT: u64 = 100_000_000;
+
 // Generate bindings from WIT (use the canonical definition from gromnie-scripting-api)
 wasmtime::component::bindgen!({
     path: "../gromnie-scripting-api/src/wit",
@@ -37,6 +43,24 @@ pub struct WasmScriptState {
     pub(crate) host_context: Option<Arc<ScriptContext>>,
     /// Script ID (for logging)
     pub(crate) script_id: String,
+    /// Qualities this script has asked to be notified about
+    pub(crate) quality_watches: QualityWatchSet,
+    /// One-shot waits registered via when-event-once
+    pub(crate) event_waits: EventWaitSet,
+    /// Actions registered via schedule-action-on-event
+    pub(crate) scheduled_actions: ScheduledActionSet,
+    /// Set by request-landblock-objects until the runner replays them
+    pub(crate) landblock_objects_requested: bool,
+    /// When this script last sent a spell cast (for rate limiting)
+    pub(crate) last_spell_cast: Option<std::time::Instant>,
+    /// When this script last requested an appraisal, for rate limiting
+    pub(crate) last_appraisal: Option<std::time::Instant>,
+    /// When this script last bought or sold from a vendor, for rate limiting
+    pub(crate) last_vendor_transaction: Option<std::time::Instant>,
+    /// Number of on_tick calls since this instance was loaded
+    pub(crate) tick_count: u64,
+    /// Whether send-game-action is allowed (the `allow_raw_actions` config)
+    pub(crate) allow_raw_actions: bool,
 }
 
 impl WasiView for WasmScriptState {
@@ -65,6 +89,11 @@ pub struct WasmScript {
     file_path: PathBuf,
     /// File modification time for change detection
     modified_time: SystemTime,
+
+    /// Fuel each callback may burn (0 = unlimited)
+    fuel_limit: u64,
+    /// Set once a callback runs out of fuel; the script must not be called again
+    out_of_fuel: bool,
 }
 
 impl WasmScript {
@@ -83,6 +112,10 @@ impl WasmScript {
         let component = Component::from_file(engine, path)
             .with_context(|| format!("Failed to load WASM component: {}", path.display()))?;
 
+        // Catch scripts built against another WIT version before linking
+        // fails on some mismatched import
+        super::world_check::check_component(engine, &component)?;
+
         // Create WASI context
         let wasi = super::engine::create_wasi_context()?;
         let table = ResourceTable::new();
@@ -92,9 +125,20 @@ impl WasmScript {
             table,
             host_context: None,
             script_id: String::new(), // Will be updated after instantiation
+            quality_watches: QualityWatchSet::new(),
+            event_waits: EventWaitSet::new(),
+            scheduled_actions: ScheduledActionSet::new(),
+            landblock_objects_requested: false,
+            last_spell_cast: None,
+            last_appraisal: None,
+            last_vendor_transaction: None,
+            tick_count: 0,
+            allow_raw_actions: false,
         };
 
         let mut store = Store::new(engine, state);
+        // Budget for instantiation and the metadata calls below
+        let _ = store.set_fuel(DEFAULT_FUEL_LIMIT);
 
         // Create linker and add WASI + host imports
         let mut linker = Linker::new(engine);
@@ -158,6 +202,8 @@ impl WasmScript {
             subscribed_events,
             file_path: path.to_path_buf(),
             modified_time,
+            fuel_limit: DEFAULT_FUEL_LIMIT,
+            out_of_fuel: false,
         })
     }
 
@@ -170,6 +216,45 @@ impl WasmScript {
     fn clear_context(&mut self) {
         self.store.data_mut().host_context = None;
     }
+
+    /// Give the script a fresh fuel budget for one callback. Returns false if
+    /// the script already ran out of fuel and must not be called again.
+    fn refuel(&mut self) -> bool {
+        if self.out_of_fuel {
+            return false;
+        }
+        let fuel = if self.fuel_limit == 0 {
+            u64::MAX
+        } else {
+            self.fuel_limit
+        };
+        // Only fails if the engine doesn't meter fuel, which create_engine always enables
+        let _ = self.store.set_fuel(fuel);
+        true
+    }
+
+    /// Log a failed callback, remembering if it failed by running out of fuel
+    fn report_callback_error(&mut self, callback: &str, err: wasmtime::Error) {
+        if matches!(err.downcast_ref::<Trap>(), Some(Trap::OutOfFuel)) {
+            self.out_of_fuel = true;
+            error!(
+                target: "scripting",
+                "Script {} ({}) ran out of fuel in {}",
+                self.name,
+                self.id,
+                callback
+            );
+        } else {
+            warn!(
+                target: "scripting",
+                "Script {} ({}) {} failed: {:#}",
+                self.name,
+                self.id,
+                callback,
+                err
+            );
+        }
+    }
 }
 
 impl HostScript for WasmScript {
@@ -193,16 +278,14 @@ impl HostScript for WasmScript {
         self.set_context(ctx);
         Box::pin(async move {
             let guest = self.script.gromnie_scripting_guest();
+            if !self.refuel() {
+                self.clear_context();
+                return;
+            }
             let result = guest.call_on_load(&mut self.store).await;
             self.clear_context();
             if let Err(err) = result {
-                warn!(
-                    target: "scripting",
-                    "Script {} ({}) on_load failed: {:#}",
-                    self.name,
-                    self.id,
-                    err
-                );
+                self.report_callback_error("on_load", err);
             }
         })
     }
@@ -215,16 +298,14 @@ impl HostScript for WasmScript {
         self.set_context(ctx);
         Box::pin(async move {
             let guest = self.script.gromnie_scripting_guest();
+            if !self.refuel() {
+                self.clear_context();
+                return;
+            }
             let result = guest.call_on_unload(&mut self.store).await;
             self.clear_context();
             if let Err(err) = result {
-                warn!(
-                    target: "scripting",
-                    "Script {} ({}) on_unload failed: {:#}",
-                    self.name,
-                    self.id,
-                    err
-                );
+                self.report_callback_error("on_unload", err);
             }
         })
     }
@@ -243,16 +324,14 @@ impl HostScript for WasmScript {
         self.set_context(ctx);
         Box::pin(async move {
             let guest = self.script.gromnie_scripting_guest();
+            if !self.refuel() {
+                self.clear_context();
+                return;
+            }
             let result = guest.call_on_event(&mut self.store, &wasm_event).await;
             self.clear_context();
             if let Err(err) = result {
-                warn!(
-                    target: "scripting",
-                    "Script {} ({}) on_event failed: {:#}",
-                    self.name,
-                    self.id,
-                    err
-                );
+                self.report_callback_error("on_event", err);
             }
         })
     }
@@ -264,19 +343,18 @@ impl HostScript for WasmScript {
     ) -> ::core::pin::Pin<Box<dyn ::core::future::Future<Output = ()> + ::core::marker::Send + 'a>>
     {
         let delta_millis = delta.as_millis() as u64;
+        self.store.data_mut().tick_count += 1;
         self.set_context(ctx);
         Box::pin(async move {
             let guest = self.script.gromnie_scripting_guest();
+            if !self.refuel() {
+                self.clear_context();
+                return;
+            }
             let result = guest.call_on_tick(&mut self.store, delta_millis).await;
             self.clear_context();
             if let Err(err) = result {
-                warn!(
-                    target: "scripting",
-                    "Script {} ({}) on_tick failed: {:#}",
-                    self.name,
-                    self.id,
-                    err
-                );
+                self.report_callback_error("on_tick", err);
             }
         })
     }
@@ -287,6 +365,22 @@ impl HostScript for WasmScript {
 }
 
 impl WasmScript {
+    /// Set the fuel each callback may burn (0 = unlimited)
+    pub fn set_fuel_limit(&mut self, fuel_limit: u64) {
+        self.fuel_limit = fuel_limit;
+    }
+
+    /// Allow or forbid this script's send-game-action calls
+    pub fn set_allow_raw_actions(&mut self, allow: bool) {
+        self.store.data_mut().allow_raw_actions = allow;
+    }
+
+    /// Whether a callback ran out of fuel. Such a script can't be called again
+    /// and should be unloaded.
+    pub fn out_of_fuel(&self) -> bool {
+        self.out_of_fuel
+    }
+
     /// Get the file path for this script
     pub fn file_path(&self) -> &PathBuf {
         &self.file_path
@@ -296,10 +390,84 @@ impl WasmScript {
     pub fn modified_time(&self) -> SystemTime {
         self.modified_time
     }
+
+    /// Get the qualities this script is watching
+    pub fn quality_watches(&self) -> &QualityWatchSet {
+        &self.store.data().quality_watches
+    }
+
+    /// Remove and return this script's one-shot waits matching the event
+    pub fn take_fired_waits(&mut self, event: &ClientEvent) -> Vec<EventWait> {
+        self.store.data_mut().event_waits.take_matching(event)
+    }
+
+    /// Remove and return this script's scheduled actions triggered by the event
+    pub fn take_triggered_actions(&mut self, event: &ClientEvent) -> Vec<ScheduledAction> {
+        self.store.data_mut().scheduled_actions.take_matching(event)
+    }
+
+    /// Remove and return this script's scheduled actions whose timeout has passed
+    pub fn take_expired_actions(&mut self, now: std::time::Instant) -> Vec<ScheduledAction> {
+        self.store.data_mut().scheduled_actions.take_expired(now)
+    }
+
+    /// Check and clear a pending request-landblock-objects call
+    pub fn take_landblock_objects_request(&mut self) -> bool {
+        std::mem::take(&mut self.store.data_mut().landblock_objects_requested)
+    }
+
+    /// Deliver a fired one-shot wait as a `ScriptEvent::WhenFired` event
+    pub async fn on_when_fired(&mut self, wait: &EventWait, ctx: Arc<ScriptContext>) {
+        use gromnie::scripting::host::{ScriptEvent as WitScriptEvent, WhenFired};
+
+        let wasm_event = WitScriptEvent::WhenFired(WhenFired {
+            token: wait.token,
+            event_id: wait.filter.to_discriminant(),
+        });
+
+        self.set_context(ctx);
+        let guest = self.script.gromnie_scripting_guest();
+        if !self.refuel() {
+            self.clear_context();
+            return;
+        }
+        let result = guest.call_on_event(&mut self.store, &wasm_event).await;
+        self.clear_context();
+        if let Err(err) = result {
+            self.report_callback_error("on_event (when-fired)", err);
+        }
+    }
+
+    /// Deliver a watched quality change as a `GameEvent::QualityChanged` event
+    pub async fn on_quality_changed(&mut self, change: &QualityChange, ctx: Arc<ScriptContext>) {
+        use gromnie::scripting::host::{
+            GameEvent as WitGameEvent, QualityChange as WitQualityChange,
+            ScriptEvent as WitScriptEvent,
+        };
+
+        let wasm_event = WitScriptEvent::Game(WitGameEvent::QualityChanged(WitQualityChange {
+            object_id: change.object_id,
+            property: change.property.clone(),
+            old: change.old,
+            new: change.new,
+        }));
+
+        self.set_context(ctx);
+        let guest = self.script.gromnie_scripting_guest();
+        if !self.refuel() {
+            self.clear_context();
+            return;
+        }
+        let result = guest.call_on_event(&mut self.store, &wasm_event).await;
+        self.clear_context();
+        if let Err(err) = result {
+            self.report_callback_error("on_event (quality-changed)", err);
+        }
+    }
 }
 
 /// Convert Rust ClientEvent to WIT ScriptEvent
-fn client_event_to_wasm(event: &ClientEvent) -> gromnie::scripting::host::ScriptEvent {
+pub(super) fn client_event_to_wasm(event: &ClientEvent) -> gromnie::scripting::host::ScriptEvent {
     use gromnie::scripting::host::ScriptEvent as WitScriptEvent;
 
     match event {
@@ -317,8 +485,8 @@ fn client_event_to_wasm(event: &ClientEvent) -> gromnie::scripting::host::Script
 /// Convert Rust GameEvent to WIT GameEvent
 fn game_event_to_wasm(event: &GameEvent) -> gromnie::scripting::host::GameEvent {
     use gromnie::scripting::host::{
-        AccountData, CharacterError as WitCharacterError, CharacterIdentity, ChatMessage,
-        GameEvent as WitGameEvent,
+        AccountData, ActionError as WitActionError, CharacterError as WitCharacterError,
+        CharacterIdentity, ChatMessage, CombatDamage, GameEvent as WitGameEvent,
     };
 
     match event {
@@ -355,6 +523,189 @@ fn game_event_to_wasm(event: &GameEvent) -> gromnie::scripting::host::GameEvent
             message: message.clone(),
         }),
 
+        GameEvent::ActionError {
+            error_code,
+            message,
+        } => WitGameEvent::ActionError(WitActionError {
+            error_code: *error_code,
+            message: message.clone(),
+        }),
+
+        GameEvent::TargetHealth {
+            object_id,
+            health_percent,
+        } => WitGameEvent::TargetHealth(gromnie::scripting::host::TargetHealth {
+            object_id: object_id.get(),
+            health_percent: *health_percent,
+        }),
+
+        GameEvent::PlayerDeath {
+            victim_id,
+            killer_id,
+            message,
+        } => WitGameEvent::PlayerDeath(gromnie::scripting::host::PlayerDeath {
+            victim_id: victim_id.get(),
+            killer_id: killer_id.get(),
+            message: message.clone(),
+        }),
+
+        GameEvent::AppraiseResult {
+            object_id,
+            success,
+            int_properties,
+        } => WitGameEvent::AppraiseResult(gromnie::scripting::host::AppraiseResult {
+            object_id: object_id.get(),
+            success: *success,
+            int_properties: int_properties.clone(),
+        }),
+
+        GameEvent::VendorInfo { vendor_id, items } => {
+            WitGameEvent::VendorInfo(gromnie::scripting::host::VendorInfo {
+                vendor_id: vendor_id.get(),
+                items: items
+                    .iter()
+                    .map(|item| gromnie::scripting::host::VendorItem {
+                        object_id: item.object_id.get(),
+                        name: item.name.clone(),
+                        stock: item.stock,
+                    })
+                    .collect(),
+            })
+        }
+
+        GameEvent::ContainerContents {
+            container_id,
+            items,
+        } => WitGameEvent::ContainerContents(gromnie::scripting::host::ContainerContents {
+            container_id: container_id.get(),
+            items: items.iter().map(|id| id.get()).collect(),
+        }),
+
+        GameEvent::ConfirmationRequest {
+            confirmation_type,
+            context_id,
+            message,
+        } => WitGameEvent::ConfirmationRequest(gromnie::scripting::host::ConfirmationRequestMsg {
+            confirmation_type: *confirmation_type,
+            context_id: *context_id,
+            message: message.clone(),
+        }),
+
+        GameEvent::DamageDealt {
+            defender_name,
+            damage_type,
+            damage_amount,
+            damage_percent,
+            critical,
+        } => WitGameEvent::DamageDealt(CombatDamage {
+            opponent_name: defender_name.clone(),
+            damage_type: *damage_type,
+            damage_amount: *damage_amount,
+            damage_percent: *damage_percent,
+            critical: *critical,
+        }),
+
+        GameEvent::DamageTaken {
+            attacker_name,
+            damage_type,
+            damage_amount,
+            damage_percent,
+            critical,
+        } => WitGameEvent::DamageTaken(CombatDamage {
+            opponent_name: attacker_name.clone(),
+            damage_type: *damage_type,
+            damage_amount: *damage_amount,
+            damage_percent: *damage_percent,
+            critical: *critical,
+        }),
+
+        GameEvent::AttackEvaded { defender_name } => {
+            WitGameEvent::AttackEvaded(defender_name.clone())
+        }
+
+        GameEvent::AttackAvoided { attacker_name } => {
+            WitGameEvent::AttackAvoided(attacker_name.clone())
+        }
+
+        GameEvent::CombatKill { message, own_death } => {
+            WitGameEvent::CombatKill(gromnie::scripting::host::CombatKill {
+                message: message.clone(),
+                own_death: *own_death,
+            })
+        }
+
+        GameEvent::ChannelMessage {
+            channel,
+            sender_name,
+            message,
+        } => WitGameEvent::ChannelMessage(gromnie::scripting::host::ChannelMessage {
+            channel: *channel,
+            sender_name: sender_name.clone(),
+            message: message.clone(),
+        }),
+
+        GameEvent::ItemUpdateStackSize {
+            object_id,
+            name,
+            previous_stack_size,
+            stack_size,
+            value,
+        } => WitGameEvent::StackSizeChanged(gromnie::scripting::host::StackSizeChanged {
+            object_id: object_id.get(),
+            name: name.clone(),
+            previous_stack_size: *previous_stack_size,
+            stack_size: *stack_size,
+            value: *value,
+        }),
+
+        GameEvent::AllegianceUpdated {
+            name,
+            rank,
+            members,
+        } => WitGameEvent::AllegianceUpdated(gromnie::scripting::host::Allegiance {
+            name: name.clone(),
+            rank: *rank,
+            members: members.iter().map(allegiance_member_to_wasm).collect(),
+        }),
+
+        GameEvent::AllegianceInfoReceived {
+            target_id,
+            name,
+            members,
+        } => WitGameEvent::AllegianceInfo(gromnie::scripting::host::AllegianceInfo {
+            target_id: target_id.get(),
+            name: name.clone(),
+            members: members.iter().map(allegiance_member_to_wasm).collect(),
+        }),
+
+        GameEvent::AllegianceMemberLogin {
+            object_id,
+            name,
+            logged_in,
+        } => WitGameEvent::AllegianceMemberLogin(gromnie::scripting::host::AllegianceMemberLogin {
+            object_id: object_id.get(),
+            name: name.clone(),
+            logged_in: *logged_in,
+        }),
+
+        GameEvent::TradeOpened {
+            partner_id,
+            partner_name,
+        } => WitGameEvent::TradeOpened(gromnie::scripting::host::TradePartner {
+            partner_id: partner_id.get(),
+            name: partner_name.clone(),
+        }),
+
+        GameEvent::TradeItemAdded {
+            item_id,
+            name,
+            own_item,
+        } => WitGameEvent::TradeItemAdded(gromnie::scripting::host::TradeItem {
+            item_id: item_id.get(),
+            name: name.clone(),
+            own_item: *own_item,
+        }),
+
         // Handle events that still exist in GameEvent but aren't in WIT
         // These will be filtered out by the event filter in the future
         _ => {
@@ -393,12 +744,21 @@ fn state_event_to_wasm(event: &ClientStateEvent) -> gromnie::scripting::host::St
         ClientStateEvent::InWorld => WitStateEvent::InWorld,
         ClientStateEvent::ExitingWorld => WitStateEvent::ExitingWorld,
         ClientStateEvent::CharacterError => WitStateEvent::CharacterError,
+        ClientStateEvent::StateTransition { from, to } => {
+            WitStateEvent::StateTransition(gromnie::scripting::host::StateTransition {
+                from: from.clone(),
+                to: to.clone(),
+            })
+        }
     }
 }
 
 /// Convert Rust SystemEvent to WIT SystemEvent
 fn system_event_to_wasm(event: &ClientSystemEvent) -> gromnie::scripting::host::SystemEvent {
-    use gromnie::scripting::host::{LoginInfo, SystemEvent as WitSystemEvent};
+    use gromnie::scripting::host::{
+        ConnectionError, ConnectionErrorKind, LoginInfo, LoginRetry, ScriptError,
+        SystemEvent as WitSystemEvent, UnknownMessage,
+    };
 
     match event {
         ClientSystemEvent::AuthenticationSucceeded => WitSystemEvent::AuthenticationSucceeded,
@@ -406,6 +766,13 @@ fn system_event_to_wasm(event: &ClientSystemEvent) -> gromnie::scripting::host::
             WitSystemEvent::AuthenticationFailed(reason.clone())
         }
         ClientSystemEvent::ConnectingStarted => WitSystemEvent::ConnectingStarted,
+        ClientSystemEvent::LoginRetry {
+            attempt,
+            max_attempts,
+        } => WitSystemEvent::LoginRetry(LoginRetry {
+            attempt: *attempt,
+            max_attempts: *max_attempts,
+        }),
         ClientSystemEvent::ConnectingDone => WitSystemEvent::ConnectingDone,
         ClientSystemEvent::UpdatingStarted => WitSystemEvent::UpdatingStarted,
         ClientSystemEvent::UpdatingDone => WitSystemEvent::UpdatingDone,
@@ -413,11 +780,80 @@ fn system_event_to_wasm(event: &ClientSystemEvent) -> gromnie::scripting::host::
             character_id,
             character_name,
         } => WitSystemEvent::LoginSucceeded(LoginInfo {
-            character_id: *character_id,
+            character_id: character_id.get(),
             character_name: character_name.clone(),
         }),
         ClientSystemEvent::Disconnected { .. } => WitSystemEvent::Disconnected,
         ClientSystemEvent::Reconnecting { .. } => WitSystemEvent::Reconnecting,
+        ClientSystemEvent::Resuming {
+            character_id,
+            character_name,
+            ..
+        } => WitSystemEvent::Resuming(LoginInfo {
+            character_id: character_id.get(),
+            character_name: character_name.clone(),
+        }),
+        ClientSystemEvent::PingResult { rtt_millis } => WitSystemEvent::PingResult(*rtt_millis),
+        ClientSystemEvent::ConnectionStats(stats) => {
+            WitSystemEvent::ConnectionStats(connection_stats_to_wasm(stats))
+        }
+        ClientSystemEvent::UnknownMessage {
+            opcode,
+            game_event_opcode,
+            data,
+        } => WitSystemEvent::UnknownMessage(UnknownMessage {
+            opcode: *opcode,
+            game_event_opcode: *game_event_opcode,
+            data: data.clone(),
+        }),
+        ClientSystemEvent::ConnectionError {
+            kind,
+            message,
+            can_retry,
+        } => WitSystemEvent::ConnectionError(ConnectionError {
+            kind: match kind {
+                gromnie_events::ConnectionErrorKind::Timeout => ConnectionErrorKind::Timeout,
+                gromnie_events::ConnectionErrorKind::Rejected => ConnectionErrorKind::Rejected,
+            },
+            message: message.clone(),
+            can_retry: *can_retry,
+        }),
+        ClientSystemEvent::ScriptError { script_id, message } => {
+            WitSystemEvent::ScriptError(ScriptError {
+                script_id: script_id.clone(),
+                message: message.clone(),
+            })
+        }
+        ClientSystemEvent::SquelchListChanged { names } => {
+            WitSystemEvent::SquelchListChanged(names.clone())
+        }
+    }
+}
+
+fn allegiance_member_to_wasm(
+    member: &gromnie_events::AllegianceMember,
+) -> gromnie::scripting::host::AllegianceMember {
+    gromnie::scripting::host::AllegianceMember {
+        object_id: member.object_id.get(),
+        name: member.name.clone(),
+        rank: member.rank,
+        level: member.level,
+        patron_id: member.patron_id.map(|id| id.get()),
+        officer_level: member.officer_level,
+    }
+}
+
+/// Convert the client's connection counters to the WIT record
+pub(super) fn connection_stats_to_wasm(
+    stats: &gromnie_events::ConnectionStats,
+) -> gromnie::scripting::host::ConnectionStats {
+    gromnie::scripting::host::ConnectionStats {
+        packets_sent: stats.packets_sent,
+        packets_received: stats.packets_received,
+        unacked_sends: stats.unacked_sends,
+        reconnect_attempts: stats.reconnect_attempts,
+        rtt_millis: stats.rtt_millis,
+        unknown_opcodes: stats.unknown_opcodes,
     }
 }
 
@@ -580,6 +1016,44 @@ fn s2c_event_to_wit(event: &S2CEvent) -> gromnie::scripting::host::S2cEvent {
                 object_teleport_sequence: *object_teleport_sequence,
             })
         }
+        S2CEvent::ItemDeleteObject { object_id } => {
+            WitS2cEvent::ItemDeleteObject(gromnie::scripting::host::ItemDeleteObjectMsg {
+                object_id: *object_id,
+            })
+        }
+        S2CEvent::ItemOnViewContents {
+            container_id,
+            items,
+        } => WitS2cEvent::ItemOnViewContents(gromnie::scripting::host::ItemOnViewContentsMsg {
+            container_id: *container_id,
+            items: items.clone(),
+        }),
+        S2CEvent::PlayerContainersReceived {
+            player_id,
+            containers,
+        } => WitS2cEvent::PlayerContainersReceived(
+            gromnie::scripting::host::PlayerContainersReceivedMsg {
+                player_id: *player_id,
+                containers: containers.clone(),
+            },
+        ),
+        S2CEvent::ItemSetState { object_id, state } => {
+            WitS2cEvent::ItemSetState(gromnie::scripting::host::ItemSetStateMsg {
+                object_id: *object_id,
+                state: state.clone(),
+            })
+        }
+        S2CEvent::QualitiesPrivateUpdateInt {
+            key,
+            property,
+            value,
+        } => WitS2cEvent::QualitiesPrivateUpdateInt(
+            gromnie::scripting::host::QualitiesPrivateUpdateIntMsg {
+                key: *key,
+                property: property.to_string(),
+                value: *value,
+            },
+        ),
         // Ignore unknown S2C events (future variants added via #[non_exhaustive])
         _ => {
             warn!(target: "scripting", "Unknown S2C event variant, returning placeholder");
@@ -591,9 +1065,10 @@ fn s2c_event_to_wit(event: &S2CEvent) -> gromnie::scripting::host::S2cEvent {
 /// Convert Rust GameEventMsg to WIT GameEventMsg
 fn game_event_msg_to_wit(event: &GameEventMsg) -> gromnie::scripting::host::GameEventMsg {
     use gromnie::scripting::host::{
-        EnchantmentRemovedMsg, EnchantmentUpdatedMsg, GameEventMsg as WitGameEventMsg,
-        HearDirectSpeechMsg, TradeFailureMsg, TradeItemAddedMsg, TradeItemRemovedMsg,
-        TradeOpenedMsg, TradeRegisteredMsg, TransientStringMsg,
+        CombatDamage, ConfirmationRequestMsg, EnchantmentRemovedMsg, EnchantmentUpdatedMsg,
+        GameEventMsg as WitGameEventMsg, HearDirectSpeechMsg, SpellUpdatedMsg, TradeFailureMsg,
+        TradeItemAddedMsg, TradeItemRemovedMsg, TradeOpenedMsg, TradeRegisteredMsg,
+        TransientStringMsg, WeenieErrorMsg, WeenieErrorWithStringMsg,
     };
 
     match event {
@@ -615,6 +1090,25 @@ fn game_event_msg_to_wit(event: &GameEventMsg) -> gromnie::scripting::host::Game
                 message: message.clone(),
             })
         }
+        GameEventMsg::ChannelBroadcast {
+            channel,
+            sender_name,
+            message,
+        } => WitGameEventMsg::ChannelBroadcast(gromnie::scripting::host::ChannelBroadcastMsg {
+            channel: *channel,
+            sender_name: sender_name.clone(),
+            message: message.clone(),
+        }),
+        GameEventMsg::SquelchDb => WitGameEventMsg::SquelchDb,
+        GameEventMsg::ConfirmationRequest {
+            confirmation_type,
+            context_id,
+            message,
+        } => WitGameEventMsg::ConfirmationRequest(ConfirmationRequestMsg {
+            confirmation_type: *confirmation_type,
+            context_id: *context_id,
+            message: message.clone(),
+        }),
         GameEventMsg::TradeRegistered {
             initiator_id,
             partner_id,
@@ -656,6 +1150,136 @@ fn game_event_msg_to_wit(event: &GameEventMsg) -> gromnie::scripting::host::Game
                 spell_id: *spell_id,
             })
         }
+        GameEventMsg::SpellUpdated { spell_id } => WitGameEventMsg::SpellUpdated(SpellUpdatedMsg {
+            spell_id: *spell_id,
+        }),
+        GameEventMsg::WeenieError { error_code } => WitGameEventMsg::WeenieError(WeenieErrorMsg {
+            error_code: *error_code,
+        }),
+        GameEventMsg::WeenieErrorWithString { error_code, text } => {
+            WitGameEventMsg::WeenieErrorWithString(WeenieErrorWithStringMsg {
+                error_code: *error_code,
+                text: text.clone(),
+            })
+        }
+        GameEventMsg::PlayerDescription => WitGameEventMsg::PlayerDescription,
+        GameEventMsg::AppraiseInfo { object_id, success } => {
+            WitGameEventMsg::AppraiseInfo(gromnie::scripting::host::AppraiseInfoMsg {
+                object_id: *object_id,
+                success: *success,
+            })
+        }
+        GameEventMsg::ItemOnViewContents {
+            container_id,
+            items,
+        } => WitGameEventMsg::ItemOnViewContents(gromnie::scripting::host::ItemOnViewContentsMsg {
+            container_id: *container_id,
+            items: items.clone(),
+        }),
+        GameEventMsg::FellowshipFullUpdate {
+            name,
+            leader_id,
+            member_count,
+        } => WitGameEventMsg::FellowshipFullUpdate(
+            gromnie::scripting::host::FellowshipFullUpdateMsg {
+                name: name.clone(),
+                leader_id: *leader_id,
+                member_count: *member_count,
+            },
+        ),
+        GameEventMsg::FellowshipUpdateFellow { fellow_id } => {
+            WitGameEventMsg::FellowshipUpdateFellow(
+                gromnie::scripting::host::FellowshipUpdateFellowMsg {
+                    fellow_id: *fellow_id,
+                },
+            )
+        }
+        GameEventMsg::FellowshipQuit { fellow_id } => {
+            WitGameEventMsg::FellowshipQuit(gromnie::scripting::host::FellowshipQuitMsg {
+                fellow_id: *fellow_id,
+            })
+        }
+        GameEventMsg::FellowshipDisband => WitGameEventMsg::FellowshipDisband,
+        GameEventMsg::VendorInfo {
+            vendor_id,
+            item_count,
+        } => WitGameEventMsg::VendorInfo(gromnie::scripting::host::VendorInfoMsg {
+            vendor_id: *vendor_id,
+            item_count: *item_count,
+        }),
+        GameEventMsg::QueryHealthResponse { object_id, health } => {
+            WitGameEventMsg::QueryHealthResponse(gromnie::scripting::host::QueryHealthResponseMsg {
+                object_id: *object_id,
+                health: *health,
+            })
+        }
+        GameEventMsg::AllegianceUpdate {
+            rank,
+            allegiance_name,
+            member_count,
+        } => WitGameEventMsg::AllegianceUpdate(gromnie::scripting::host::AllegianceUpdateMsg {
+            rank: *rank,
+            allegiance_name: allegiance_name.clone(),
+            member_count: *member_count,
+        }),
+        GameEventMsg::AllegianceInfoResponse {
+            target_id,
+            allegiance_name,
+            member_count,
+        } => WitGameEventMsg::AllegianceInfoResponse(
+            gromnie::scripting::host::AllegianceInfoResponseMsg {
+                target_id: *target_id,
+                allegiance_name: allegiance_name.clone(),
+                member_count: *member_count,
+            },
+        ),
+        GameEventMsg::AllegianceLoginNotification {
+            character_id,
+            logged_in,
+        } => WitGameEventMsg::AllegianceLoginNotification(
+            gromnie::scripting::host::AllegianceLoginNotificationMsg {
+                character_id: *character_id,
+                logged_in: *logged_in,
+            },
+        ),
+        GameEventMsg::AttackerNotification {
+            defender_name,
+            damage_type,
+            damage_amount,
+            damage_percent,
+            critical,
+        } => WitGameEventMsg::AttackerNotification(CombatDamage {
+            opponent_name: defender_name.clone(),
+            damage_type: *damage_type,
+            damage_amount: *damage_amount,
+            damage_percent: *damage_percent,
+            critical: *critical,
+        }),
+        GameEventMsg::DefenderNotification {
+            attacker_name,
+            damage_type,
+            damage_amount,
+            damage_percent,
+            critical,
+        } => WitGameEventMsg::DefenderNotification(CombatDamage {
+            opponent_name: attacker_name.clone(),
+            damage_type: *damage_type,
+            damage_amount: *damage_amount,
+            damage_percent: *damage_percent,
+            critical: *critical,
+        }),
+        GameEventMsg::EvasionAttackerNotification { defender_name } => {
+            WitGameEventMsg::EvasionAttackerNotification(defender_name.clone())
+        }
+        GameEventMsg::EvasionDefenderNotification { attacker_name } => {
+            WitGameEventMsg::EvasionDefenderNotification(attacker_name.clone())
+        }
+        GameEventMsg::VictimNotificationSelf { message } => {
+            WitGameEventMsg::VictimNotificationSelf(message.clone())
+        }
+        GameEventMsg::VictimNotificationOther { message } => {
+            WitGameEventMsg::VictimNotificationOther(message.clone())
+        }
         // Ignore unknown game event messages (future variants added via #[non_exhaustive])
         _ => {
             warn!(target: "scripting", "Unknown game event message variant, returning placeholder");
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use wasmtime::Engine;
+use wasmtime::component::Component;
+
+/// Interface every script exports, without its version
+const GUEST_INTERFACE: &str = "guest";
+
+/// WIT package this host implements, e.g. `gromnie:scripting@0.2.0`, read from
+/// the `package` line of the embedded WIT
+pub fn expected_package() -> &'static str {
+    gromnie_scripting_api::WIT_CONTENT
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("package "))
+        .map(|package| package.trim_end_matches(';').trim())
+        .expect("embedded WIT declares its package")
+}
+
+/// A script built against a different version of the script world
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorldMismatch {
+    pub expected: String,
+    pub found: String,
+}
+
+impl fmt::Display for WorldMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "script was built for {} but this host expects {}; rebuild it against the current gromnie-scripting-api",
+            self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for WorldMismatch {}
+
+/// Check a component's exports against the package this host implements
+pub fn check_component(engine: &Engine, component: &Component) -> Result<(), WorldMismatch> {
+    let component_type = component.component_type();
+    check_exports(component_type.exports(engine).map(|(name, _)| name))
+}
+
+/// Check export names such as `gromnie:scripting/guest@0.2.0` against the
+/// package this host implements
+pub fn check_exports<'a>(exports: impl IntoIterator<Item = &'a str>) -> Result<(), WorldMismatch> {
+    let expected = expected_package();
+    let (namespace, _) = expected.split_once('@').unwrap_or((expected, ""));
+    let guest_prefix = format!("{}/{}", namespace, GUEST_INTERFACE);
+
+    let exports: Vec<&str> = exports.into_iter().collect();
+    let found = match exports.iter().find(|name| name.starts_with(&guest_prefix)) {
+        // `gromnie:scripting/guest@0.2.0` -> `gromnie:scripting@0.2.0`
+        Some(guest) => match guest.split_once('@') {
+            Some((_, version)) => format!("{}@{}", namespace, version),
+            None => namespace.to_string(),
+        },
+        None if exports.is_empty() => "a component with no exports".to_string(),
+        None => format!("a component exporting {}", exports.join(", ")),
+    };
+
+    if found == expected {
+        Ok(())
+    } else {
+        Err(WorldMismatch {
+            expected: expected.to_string(),
+            found,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_package_from_wit() {
+        assert!(expected_package().starts_with("gromnie:scripting@"));
+    }
+
+    #[test]
+    fn test_matching_guest_export_passes() {
+        let guest = expected_package().replace('@', "/guest@");
+        assert_eq!(check_exports([guest.as_str()]), Ok(()));
+    }
+
+    #[test]
+    fn test_stale_guest_export_is_rejected() {
+        let mismatch = check_exports(["gromnie:scripting/guest@0.0.1"]).unwrap_err();
+        assert_eq!(mismatch.found, "gromnie:scripting@0.0.1");
+        assert_eq!(mismatch.expected, expected_package());
+        assert!(mismatch.to_string().contains("gromnie:scripting@0.0.1"));
+        assert!(mismatch.to_string().contains(expected_package()));
+    }
+
+    #[test]
+    fn test_stale_component_is_rejected() {
+        // The test script as built before the WIT grew past 0.1.0
+        let engine = crate::wasm::create_engine().unwrap();
+        let component = Component::from_file(
+            &engine,
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/stale_script_0.1.0.wasm"
+            ),
+        )
+        .unwrap();
+
+        let mismatch = check_component(&engine, &component).unwrap_err();
+        assert_eq!(mismatch.found, "gromnie:scripting@0.1.0");
+        assert_eq!(mismatch.expected, expected_package());
+    }
+
+    #[test]
+    fn test_unrelated_component_is_rejected() {
+        let mismatch = check_exports(["wasi:cli/run@0.2.0"]).unwrap_err();
+        assert_eq!(mismatch.found, "a component exporting wasi:cli/run@0.2.0");
+    }
+}
@@ -3,10 +3,10 @@
 use asheron_rs::types::{CharacterIdentity, ObjectId};
 use gromnie_client::client::Client;
 use gromnie_events::{
-    ClientEvent, GameEventMsg, OrderedGameEvent, ProtocolEvent, S2CEvent,
+    ClientEvent, ClientStateEvent, GameEventMsg, OrderedGameEvent, ProtocolEvent, S2CEvent,
     SimpleGameEvent as GameEvent,
 };
-use gromnie_scripting_host::ScriptRunner;
+use gromnie_scripting_host::{EventFilter, ScriptRunner};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
@@ -369,3 +369,310 @@ async fn test_protocol_event_data_integrity() {
 
     println!("Protocol event data integrity test completed successfully");
 }
+
+#[test]
+fn test_movement_event_filters() {
+    // Existing IDs must keep their meaning for already-installed scripts
+    assert_eq!(
+        EventFilter::from_discriminant(4),
+        Some(EventFilter::ActionError)
+    );
+    assert_eq!(EventFilter::MovementPosition.to_discriminant(), 6);
+    assert_eq!(
+        EventFilter::from_discriminant(7),
+        Some(EventFilter::MovementSetObjectMovement)
+    );
+
+    let position = |object_id| {
+        ClientEvent::Protocol(ProtocolEvent::S2C(S2CEvent::MovementPositionEvent {
+            object_id,
+            landcell: 0xA9B4_0001,
+            x: 10.0,
+            y: 20.0,
+            z: 0.5,
+            quat_w: None,
+            quat_x: None,
+            quat_y: None,
+            quat_z: None,
+        }))
+    };
+    let set_movement =
+        ClientEvent::Protocol(ProtocolEvent::S2C(S2CEvent::MovementSetObjectMovement {
+            object_id: 0x8000_0001,
+            object_instance_sequence: 3,
+        }));
+
+    assert!(EventFilter::MovementPosition.matches(&position(0x8000_0001)));
+    assert!(!EventFilter::MovementPosition.matches(&set_movement));
+    assert!(EventFilter::MovementSetObjectMovement.matches(&set_movement));
+    assert!(!EventFilter::MovementSetObjectMovement.matches(&position(0x8000_0001)));
+}
+
+/// Each filter with a stable discriminant, paired with an event only it matches
+fn filter_cases() -> Vec<(u32, EventFilter, ClientEvent)> {
+    use gromnie_events::ClientSystemEvent;
+
+    vec![
+        (
+            8,
+            EventFilter::PlayerDeath,
+            ClientEvent::Game(GameEvent::PlayerDeath {
+                victim_id: 0x5000_0001.into(),
+                killer_id: 0x8000_0042.into(),
+                message: "You were killed by Drudge Slinker!".to_string(),
+            }),
+        ),
+        (
+            9,
+            EventFilter::AppraiseResult,
+            ClientEvent::Game(GameEvent::AppraiseResult {
+                object_id: 0x8000_1234.into(),
+                success: true,
+                int_properties: vec![("Value".to_string(), 250)],
+            }),
+        ),
+        (
+            10,
+            EventFilter::VendorInfo,
+            ClientEvent::Game(GameEvent::VendorInfo {
+                vendor_id: 0x8000_0100.into(),
+                items: vec![gromnie_events::VendorItem {
+                    object_id: 0x8000_0101.into(),
+                    name: "Prismatic Taper".to_string(),
+                    stock: None,
+                }],
+            }),
+        ),
+        (
+            11,
+            EventFilter::ContainerContents,
+            ClientEvent::Game(GameEvent::ContainerContents {
+                container_id: 0x8000_0200.into(),
+                items: vec![0x8000_0201.into(), 0x8000_0202.into()],
+            }),
+        ),
+        (
+            12,
+            EventFilter::ConfirmationRequest,
+            ClientEvent::Game(GameEvent::ConfirmationRequest {
+                confirmation_type: 4,
+                context_id: 17,
+                message: "Bob invites you to join his fellowship".to_string(),
+            }),
+        ),
+        (
+            13,
+            EventFilter::StackSizeChanged,
+            ClientEvent::Game(GameEvent::ItemUpdateStackSize {
+                object_id: 0x8000_0300.into(),
+                name: "Arrow".to_string(),
+                previous_stack_size: Some(20),
+                stack_size: 19,
+                value: 19,
+            }),
+        ),
+        (
+            14,
+            EventFilter::ChannelMessage,
+            ClientEvent::Game(GameEvent::ChannelMessage {
+                channel: 0x0800,
+                sender_name: "Sentinel Sam".to_string(),
+                message: "Server restart in 5 minutes".to_string(),
+            }),
+        ),
+        (
+            20,
+            EventFilter::DamageDealt,
+            ClientEvent::Game(GameEvent::DamageDealt {
+                defender_name: "Drudge Slinker".to_string(),
+                damage_type: 0x1,
+                damage_amount: 42,
+                damage_percent: 0.35,
+                critical: true,
+            }),
+        ),
+        (
+            21,
+            EventFilter::DamageTaken,
+            ClientEvent::Game(GameEvent::DamageTaken {
+                attacker_name: "Drudge Slinker".to_string(),
+                damage_type: 0x4,
+                damage_amount: 7,
+                damage_percent: 0.05,
+                critical: false,
+            }),
+        ),
+        (
+            22,
+            EventFilter::AttackEvaded,
+            ClientEvent::Game(GameEvent::AttackEvaded {
+                defender_name: "Drudge Slinker".to_string(),
+            }),
+        ),
+        (
+            23,
+            EventFilter::AttackAvoided,
+            ClientEvent::Game(GameEvent::AttackAvoided {
+                attacker_name: "Drudge Slinker".to_string(),
+            }),
+        ),
+        (
+            24,
+            EventFilter::CombatKill,
+            ClientEvent::Game(GameEvent::CombatKill {
+                message: "You killed the Drudge Slinker!".to_string(),
+                own_death: false,
+            }),
+        ),
+        (
+            30,
+            EventFilter::AllegianceUpdated,
+            ClientEvent::Game(GameEvent::AllegianceUpdated {
+                name: "Order".to_string(),
+                rank: 1,
+                members: Vec::new(),
+            }),
+        ),
+        (
+            31,
+            EventFilter::AllegianceInfo,
+            ClientEvent::Game(GameEvent::AllegianceInfoReceived {
+                target_id: 0x5000_0002.into(),
+                name: "Order".to_string(),
+                members: Vec::new(),
+            }),
+        ),
+        (
+            32,
+            EventFilter::AllegianceMemberLogin,
+            ClientEvent::Game(GameEvent::AllegianceMemberLogin {
+                object_id: 0x5000_0002.into(),
+                name: "Officer Bob".to_string(),
+                logged_in: true,
+            }),
+        ),
+        (
+            40,
+            EventFilter::TradeOpened,
+            ClientEvent::Game(GameEvent::TradeOpened {
+                partner_id: 0x5000_0003.into(),
+                partner_name: "Trader Joe".to_string(),
+            }),
+        ),
+        (
+            41,
+            EventFilter::TradeItemAdded,
+            ClientEvent::Game(GameEvent::TradeItemAdded {
+                item_id: 0x8000_0010.into(),
+                name: "Pyreal".to_string(),
+                own_item: false,
+            }),
+        ),
+        (
+            108,
+            EventFilter::StateInWorld,
+            ClientEvent::State(ClientStateEvent::InWorld),
+        ),
+        (
+            111,
+            EventFilter::StateTransition,
+            ClientEvent::State(ClientStateEvent::StateTransition {
+                from: "CharacterSelect".to_string(),
+                to: "InWorld".to_string(),
+            }),
+        ),
+        (
+            210,
+            EventFilter::SystemScriptError,
+            ClientEvent::System(ClientSystemEvent::ScriptError {
+                script_id: "busy_loop".to_string(),
+                message: "ran out of fuel (limit 100000000) and was unloaded".to_string(),
+            }),
+        ),
+        (
+            211,
+            EventFilter::SystemResuming,
+            ClientEvent::System(ClientSystemEvent::Resuming {
+                character_id: 0x5000_0001.into(),
+                character_name: "Bob".to_string(),
+                attempt: 1,
+            }),
+        ),
+        (
+            212,
+            EventFilter::SystemSquelchListChanged,
+            ClientEvent::System(ClientSystemEvent::SquelchListChanged {
+                names: vec!["Spammer".to_string()],
+            }),
+        ),
+        (
+            213,
+            EventFilter::SystemLoginRetry,
+            ClientEvent::System(ClientSystemEvent::LoginRetry {
+                attempt: 2,
+                max_attempts: 5,
+            }),
+        ),
+    ]
+}
+
+#[test]
+fn test_event_filters() {
+    let cases = filter_cases();
+    for (discriminant, filter, event) in &cases {
+        // Existing IDs must keep their meaning for already-installed scripts
+        assert_eq!(
+            EventFilter::from_discriminant(*discriminant).as_ref(),
+            Some(filter)
+        );
+        assert_eq!(filter.to_discriminant(), *discriminant);
+
+        assert!(EventFilter::All.matches(event));
+        for (_, other, _) in &cases {
+            assert_eq!(
+                other.matches(event),
+                other == filter,
+                "{:?} against {:?}",
+                other,
+                event
+            );
+        }
+    }
+
+    // Gaps in a range stay unassigned
+    assert_eq!(EventFilter::from_discriminant(25), None);
+
+    // Contents of containers nobody asked to open don't match
+    assert!(!EventFilter::ContainerContents.matches(&ClientEvent::Game(
+        GameEvent::ItemOnViewContents {
+            container_id: 0x8000_0200.into(),
+            items: vec![],
+        }
+    )));
+}
+
+#[test]
+fn test_s2c_discriminant_filter() {
+    let quality = ClientEvent::Protocol(ProtocolEvent::S2C(S2CEvent::QualitiesPrivateUpdateInt {
+        key: 25,
+        property: "Level".into(),
+        value: 12,
+    }));
+
+    let filter = EventFilter::from_discriminant(312).unwrap();
+    assert_eq!(filter, EventFilter::S2C(12));
+    assert_eq!(filter.to_discriminant(), 312);
+    assert!(filter.matches(&quality));
+
+    // Other S2C filters and the specific game event filters don't match
+    assert!(!EventFilter::S2C(3).matches(&quality));
+    assert!(!EventFilter::ChatMessageReceived.matches(&quality));
+
+    // Events without a specific filter are reachable by discriminant
+    let teleport = ClientEvent::Protocol(ProtocolEvent::S2C(S2CEvent::EffectsPlayerTeleport {
+        object_teleport_sequence: 1,
+    }));
+    assert!(EventFilter::S2C(16).matches(&teleport));
+    assert!(!EventFilter::MovementPosition.matches(&teleport));
+    assert!(EventFilter::All.matches(&teleport));
+}
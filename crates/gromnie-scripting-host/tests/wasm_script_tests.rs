@@ -0,0 +1,264 @@
+//! End-to-end tests that run the compiled test script against a real host
+//!
+//! The script in tests/scripting is loaded as a WASM component and driven
+//! through a ScriptRunner; everything it asks the host to do is captured
+//! from the action channel. These catch regressions in the WIT bindings that
+//! the conversion unit tests can't.
+//!
+//! The script is built from source on the first test, so it always matches
+//! the current WIT. That needs the wasm32-wasip2 target; without it the tests
+//! are skipped:
+//!
+//! ```sh
+//! rustup target add wasm32-wasip2
+//! ```
+
+use gromnie_client::client::Client;
+use gromnie_events::{ClientEvent, SimpleClientAction, SimpleGameEvent as GameEvent};
+use gromnie_scripting_host::ScriptRunner;
+use gromnie_scripting_host::wasm::{WasmScript, create_engine};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+/// Whether the standard library for wasm32-wasip2 is installed
+fn wasm_target_installed() -> bool {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    Command::new(rustc)
+        .args(["--print", "target-libdir", "--target", "wasm32-wasip2"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()))
+        .is_some_and(|libdir| libdir.is_dir())
+}
+
+/// Build tests/scripting against the current WIT and return the component,
+/// or `None` when the wasm32-wasip2 target isn't installed
+fn test_script() -> Option<PathBuf> {
+    static BUILT: OnceLock<Option<PathBuf>> = OnceLock::new();
+    BUILT
+        .get_or_init(|| {
+            if !wasm_target_installed() {
+                eprintln!("wasm32-wasip2 target not installed, skipping test script tests");
+                return None;
+            }
+
+            // A target dir of its own so the outer cargo's lock isn't contended
+            let target_dir = Path::new(WORKSPACE_DIR).join("target/test-script");
+            let status = Command::new(env!("CARGO"))
+                .current_dir(WORKSPACE_DIR)
+                .args([
+                    "build",
+                    "-p",
+                    "gromnie-test-script",
+                    "--release",
+                    "--target",
+                    "wasm32-wasip2",
+                    "--target-dir",
+                ])
+                .arg(&target_dir)
+                .status()
+                .expect("Failed to run cargo");
+            assert!(status.success(), "Failed to build the test script");
+
+            Some(target_dir.join("wasm32-wasip2/release/gromnie_test_script.wasm"))
+        })
+        .clone()
+}
+
+/// Runs one script and records the host calls it makes
+struct ScriptHarness {
+    runner: ScriptRunner,
+    action_rx: UnboundedReceiver<SimpleClientAction>,
+    recorded: Vec<SimpleClientAction>,
+}
+
+impl ScriptHarness {
+    /// Load the component at `path` and call its on_load
+    async fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let (client, _client_action_tx) = Client::new(
+            1,
+            "127.0.0.1:9000".to_string(),
+            "test_user".to_string(),
+            "test_pass".to_string(),
+            None,
+            mpsc::channel(100).0,
+            false,
+        )
+        .await;
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let mut runner = ScriptRunner::new_with_wasm(Arc::new(RwLock::new(client)), action_tx);
+        // Every tick() reaches on_tick
+        runner.set_tick_interval(Duration::ZERO);
+
+        let engine = create_engine().expect("Failed to create WASM engine");
+        let script = WasmScript::from_file(&engine, path)
+            .await
+            .unwrap_or_else(|e| panic!("Failed to load {}: {:#}", path.display(), e));
+        runner.register_script(script).await;
+        assert_eq!(
+            runner.script_count(),
+            1,
+            "script was unloaded during on_load"
+        );
+
+        Self {
+            runner,
+            action_rx,
+            recorded: Vec::new(),
+        }
+    }
+
+    async fn send_event(&mut self, event: ClientEvent) {
+        self.runner.handle_event(event).await;
+    }
+
+    async fn tick(&mut self) {
+        self.runner.tick().await;
+    }
+
+    /// Everything the script has asked the host to do so far
+    fn actions(&mut self) -> &[SimpleClientAction] {
+        while let Ok(action) = self.action_rx.try_recv() {
+            self.recorded.push(action);
+        }
+        &self.recorded
+    }
+
+    /// Messages passed to send_chat
+    fn chats(&mut self) -> Vec<String> {
+        self.actions()
+            .iter()
+            .filter_map(|action| match action {
+                SimpleClientAction::SendChatSay { message } => Some(message.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Messages passed to log
+    fn logs(&mut self) -> Vec<String> {
+        self.actions()
+            .iter()
+            .filter_map(|action| match action {
+                SimpleClientAction::LogScriptMessage { message, .. } => Some(message.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn clear(&mut self) {
+        self.actions();
+        self.recorded.clear();
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_greeting_sent_after_timer_fires() {
+    let Some(script) = test_script() else {
+        return;
+    };
+    let mut harness = ScriptHarness::load(script).await;
+    assert!(
+        harness
+            .logs()
+            .contains(&"Test script loaded successfully".to_string())
+    );
+
+    // The greeting waits for its timer
+    harness.tick().await;
+    assert!(harness.chats().is_empty());
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    harness.tick().await;
+    assert_eq!(harness.chats(), vec!["Hello from test script!".to_string()]);
+
+    // One-shot: later ticks don't greet again
+    harness.tick().await;
+    assert_eq!(harness.chats().len(), 1);
+}
+
+#[tokio::test]
+async fn test_events_and_ticks_reach_script() {
+    let Some(script) = test_script() else {
+        return;
+    };
+    let mut harness = ScriptHarness::load(script).await;
+    harness.clear();
+
+    harness
+        .send_event(ClientEvent::Game(GameEvent::ChatMessageReceived {
+            message: "Hello World".to_string(),
+            message_type: 1,
+        }))
+        .await;
+    assert!(
+        harness
+            .logs()
+            .contains(&"Chat message: Hello World".to_string()),
+        "{:?}",
+        harness.logs()
+    );
+
+    harness.clear();
+    harness.tick().await;
+    assert!(
+        harness.logs().iter().any(|log| log.starts_with("Tick: ")),
+        "{:?}",
+        harness.logs()
+    );
+}
+
+#[tokio::test]
+async fn test_watched_quality_fires_for_its_object() {
+    let Some(script) = test_script() else {
+        return;
+    };
+    let mut harness = ScriptHarness::load(script).await;
+    harness.clear();
+
+    let health = |object_id: u32, value: u32| {
+        ClientEvent::Game(GameEvent::VitalUpdated {
+            object_id: object_id.into(),
+            vital_name: "Health".into(),
+            value,
+        })
+    };
+    let quality_logs = |harness: &mut ScriptHarness| {
+        harness
+            .logs()
+            .into_iter()
+            .filter(|log| log.starts_with("Quality changed: "))
+            .collect::<Vec<_>>()
+    };
+
+    // The script watches Health on 0x50000001 only
+    harness.send_event(health(0x5000_0002, 90)).await;
+    harness
+        .send_event(ClientEvent::Game(GameEvent::QualitiesUpdateInt {
+            object_id: 0x5000_0001.into(),
+            property_key: 25,
+            property_name: "Level".into(),
+            value: 12,
+        }))
+        .await;
+    assert!(quality_logs(&mut harness).is_empty());
+
+    harness.send_event(health(0x5000_0001, 80)).await;
+    harness.send_event(health(0x5000_0001, 80)).await;
+    harness.send_event(health(0x5000_0001, 70)).await;
+    assert_eq!(
+        quality_logs(&mut harness),
+        vec![
+            "Quality changed: Health on 1342177281 (None -> 80)".to_string(),
+            "Quality changed: Health on 1342177281 (Some(80.0) -> 70)".to_string(),
+        ]
+    );
+}
@@ -1,10 +1,15 @@
 use asheron_rs::types::CharacterIdentity;
-use gromnie_events::{ClientStateEvent, SimpleClientAction, SimpleGameEvent};
+use gromnie_events::{
+    AllegianceMember, ClientStateEvent, ConnectionErrorKind, ConnectionStats, FellowMember,
+    SimpleClientAction, SimpleGameEvent,
+};
 
 // Type alias for backward compatibility
 pub type GameEvent = SimpleGameEvent;
 use crate::object_tracker::ObjectTracker;
-use std::collections::{HashMap, VecDeque};
+use crate::widgets::{ChatScroll, CommandPalette, PaletteCommand, ScrollAction};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::path::PathBuf;
 use tokio::sync::{broadcast, mpsc};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -95,7 +100,11 @@ pub enum GameWorldTab {
     World,
     Chat,
     Map,
+    Inventory,
     Objects,
+    Nearby,
+    Fellowship,
+    Allegiance,
 }
 
 #[derive(Debug, Clone)]
@@ -107,6 +116,10 @@ pub struct ClientStatus {
     pub session_state: SessionState,
     /// Scene state from the client (UI-level state)
     pub scene_state: SceneState,
+    /// Latest connection counters reported by the client
+    pub connection_stats: Option<ConnectionStats>,
+    /// Reconnection in progress, until the client connects again
+    pub reconnect: Option<ReconnectBanner>,
 }
 
 impl ClientStatus {
@@ -136,6 +149,8 @@ impl Default for ClientStatus {
             characters: Vec::new(),
             session_state: SessionState::Unknown,
             scene_state: SceneState::Unknown,
+            connection_stats: None,
+            reconnect: None,
         }
     }
 }
@@ -162,6 +177,37 @@ pub struct ChatMessage {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+/// Broad grouping of `ChatMessageType` values used for coloring and filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatCategory {
+    Say,
+    Tell,
+    Emote,
+    System,
+    Combat,
+    Other,
+}
+
+impl ChatCategory {
+    /// Whether the chat filter hides this category
+    pub fn is_spam(self) -> bool {
+        matches!(self, ChatCategory::System | ChatCategory::Combat)
+    }
+}
+
+impl ChatMessage {
+    pub fn category(&self) -> ChatCategory {
+        match self.message_type {
+            0x02 => ChatCategory::Say,                  // Speech
+            0x03 | 0x04 | 0x1F => ChatCategory::Tell,   // Tell, OutgoingTell, AdminTell
+            0x0A | 0x0C => ChatCategory::Emote,         // Social, Emote
+            0x05 | 0x07 | 0x11 => ChatCategory::System, // System, Magic, Spellcasting
+            0x06 | 0x15 | 0x16 => ChatCategory::Combat, // Combat, CombatEnemy, CombatSelf
+            _ => ChatCategory::Other,
+        }
+    }
+}
+
 /// Inventory state - stores references to protocol objects
 #[derive(Debug, Clone)]
 pub struct InventoryState {
@@ -176,6 +222,8 @@ pub struct InventoryState {
     pub selected_container: Option<u32>,
     /// Index of the selected container in the container list
     pub selected_container_index: usize,
+    /// Index of the selected item within the selected container
+    pub selected_item_index: usize,
 }
 
 /// Simplified inventory item representation
@@ -189,6 +237,11 @@ pub struct InventoryItem {
     pub value: u32,
     pub items_capacity: Option<u32>,
     pub container_capacity: Option<u32>,
+    pub stack_size: Option<u32>,
+    /// Object wielding this item, if it is equipped
+    pub wielder_id: Option<u32>,
+    /// EquipMask bits for the slot(s) this item is equipped in
+    pub equipped_location: Option<u32>,
 }
 
 impl InventoryState {
@@ -199,6 +252,7 @@ impl InventoryState {
             container_items: HashMap::new(),
             selected_container: None,
             selected_container_index: 0,
+            selected_item_index: 0,
         }
     }
 
@@ -235,6 +289,53 @@ impl InventoryState {
         }
     }
 
+    /// Get items equipped by the player, ordered by slot
+    pub fn get_equipped_items(&self) -> Vec<&InventoryItem> {
+        let Some(player_id) = self.player_id else {
+            return vec![];
+        };
+        let mut items: Vec<_> = self
+            .items
+            .values()
+            .filter(|item| item.wielder_id == Some(player_id))
+            .collect();
+        items.sort_by_key(|item| (item.equipped_location.unwrap_or(0), item.object_id));
+        items
+    }
+
+    /// Get the items in the selected container, sorted by name
+    pub fn get_selected_container_items(&self) -> Vec<&InventoryItem> {
+        let mut items = match self.selected_container {
+            Some(id) if Some(id) != self.player_id => self.get_container_contents(id),
+            _ => self.get_player_items(),
+        };
+        items.sort_by(|a, b| a.name.cmp(&b.name).then(a.object_id.cmp(&b.object_id)));
+        items
+    }
+
+    /// Get the currently selected item, if any
+    pub fn selected_item(&self) -> Option<&InventoryItem> {
+        self.get_selected_container_items()
+            .get(self.selected_item_index)
+            .copied()
+    }
+
+    /// Select the next item in the selected container
+    pub fn select_next_item(&mut self) {
+        let count = self.get_selected_container_items().len();
+        if count > 0 {
+            self.selected_item_index = (self.selected_item_index + 1) % count;
+        }
+    }
+
+    /// Select the previous item in the selected container
+    pub fn select_previous_item(&mut self) {
+        let count = self.get_selected_container_items().len();
+        if count > 0 {
+            self.selected_item_index = self.selected_item_index.checked_sub(1).unwrap_or(count - 1);
+        }
+    }
+
     /// Get the list of containers including the player as "Main Inventory"
     pub fn get_container_list(&self) -> Vec<ContainerListItem> {
         let mut list = vec![ContainerListItem {
@@ -260,6 +361,7 @@ impl InventoryState {
         if !list.is_empty() {
             self.selected_container_index = (self.selected_container_index + 1) % list.len();
             self.selected_container = list[self.selected_container_index].object_id;
+            self.selected_item_index = 0;
         }
     }
 
@@ -273,6 +375,7 @@ impl InventoryState {
                 self.selected_container_index -= 1;
             }
             self.selected_container = list[self.selected_container_index].object_id;
+            self.selected_item_index = 0;
         }
     }
 }
@@ -283,6 +386,99 @@ impl Default for InventoryState {
     }
 }
 
+/// Why the client stopped, as shown by the error view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionError {
+    pub kind: ConnectionErrorKind,
+    pub message: String,
+    /// Whether the client is waiting to be told to retry
+    pub can_retry: bool,
+}
+
+/// A reconnection waiting out its backoff, shown in the status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectBanner {
+    pub attempt: u32,
+    /// When the next attempt starts
+    pub retry_at: std::time::Instant,
+}
+
+impl ReconnectBanner {
+    pub fn new(attempt: u32, delay_secs: u64, now: std::time::Instant) -> Self {
+        Self {
+            attempt,
+            retry_at: now + std::time::Duration::from_secs(delay_secs),
+        }
+    }
+
+    /// Banner text, counting down to the next attempt
+    pub fn text(&self, now: std::time::Instant) -> String {
+        let remaining = self.retry_at.saturating_duration_since(now);
+        if remaining.is_zero() {
+            return format!("Reconnecting (attempt {})...", self.attempt);
+        }
+        // Round up so the countdown reaches 0s only when the attempt starts
+        let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        format!(
+            "Reconnecting (attempt {}, retry in {}s)",
+            self.attempt, secs
+        )
+    }
+}
+
+/// The character's fellowship, kept up to date from the server's fellowship events
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fellowship {
+    pub name: String,
+    pub leader_id: u32,
+    /// Members keyed by object ID
+    pub members: BTreeMap<u32, FellowMember>,
+}
+
+impl Fellowship {
+    /// Members sorted with the leader first, then by name
+    pub fn sorted_members(&self) -> Vec<&FellowMember> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            (a.object_id.get() != self.leader_id, &a.name)
+                .cmp(&(b.object_id.get() != self.leader_id, &b.name))
+        });
+        members
+    }
+}
+
+/// The character's allegiance, kept up to date from the server's allegiance events
+#[derive(Debug, Clone, PartialEq)]
+pub struct Allegiance {
+    pub name: String,
+    /// The character's rank
+    pub rank: u32,
+    /// Members keyed by object ID
+    pub members: BTreeMap<u32, AllegianceMember>,
+    /// Members the server has said are logged in, and not since logged out
+    pub online: BTreeSet<u32>,
+}
+
+impl Allegiance {
+    /// Members sorted with officers first, then by rank (highest first), then by name
+    pub fn sorted_members(&self) -> Vec<&AllegianceMember> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            (
+                std::cmp::Reverse(a.officer_level),
+                std::cmp::Reverse(a.rank),
+                &a.name,
+            )
+                .cmp(&(
+                    std::cmp::Reverse(b.officer_level),
+                    std::cmp::Reverse(b.rank),
+                    &b.name,
+                ))
+        });
+        members
+    }
+}
+
 /// Helper struct for displaying containers in a list
 #[derive(Debug, Clone)]
 pub struct ContainerListItem {
@@ -298,6 +494,8 @@ pub struct App {
     pub client_status: ClientStatus,
     pub network_messages: VecDeque<NetworkMessage>,
     pub max_network_messages: usize,
+    /// Scrollback position in the debug view's message list
+    pub network_scroll: ChatScroll,
     pub event_rx: Option<broadcast::Receiver<GameEvent>>,
     pub action_tx: Option<mpsc::UnboundedSender<SimpleClientAction>>,
     /// Currently selected character index in the character list
@@ -305,25 +503,50 @@ pub struct App {
     /// Chat messages received from the server
     pub chat_messages: VecDeque<ChatMessage>,
     pub max_chat_messages: usize,
+    /// Scrollback position in the chat tab
+    pub chat_scroll: ChatScroll,
     /// Current chat input text being typed by the user
     pub chat_input: String,
     /// Whether the chat input is active (visible and ready for input)
     pub chat_input_active: bool,
+    /// Hide system and combat messages in the chat tab
+    pub hide_chat_spam: bool,
+    /// Players whose chat is being dropped
+    pub squelched: Vec<String>,
     /// Currently active tab in the GameWorld scene
     pub game_world_tab: GameWorldTab,
 
     /// Progress for connecting/authenticating (0.0 to 1.0)
     pub connecting_progress: f64,
+    /// Latest LoginRequest resend as (attempt, max attempts), until the
+    /// server answers
+    pub login_retry: Option<(u32, u32)>,
     /// Progress for updating/DDD (0.0 to 1.0)
     pub updating_progress: f64,
     /// Timestamp of last progress update for connecting
     pub last_connecting_update: Option<std::time::Instant>,
     /// Timestamp of last progress update for updating
     pub last_updating_update: Option<std::time::Instant>,
+    /// When the character list was received; deletion countdowns run from here
+    pub character_list_received_at: Option<std::time::Instant>,
     /// Inventory state
     pub inventory_state: InventoryState,
     /// Object tracker - syncs with server state
     pub object_tracker: ObjectTracker,
+    /// Highlighted row in the Nearby tab
+    pub nearby_selected_index: usize,
+    /// Current fellowship, if the character is in one
+    pub fellowship: Option<Fellowship>,
+    /// Current allegiance, if the character is in one
+    pub allegiance: Option<Allegiance>,
+    /// The `:` command palette, while it's open
+    pub command_palette: Option<CommandPalette>,
+    /// Details of the client's error scene, while it's in one
+    pub connection_error: Option<ConnectionError>,
+    /// Reason given by the last AuthenticationFailed event
+    pub auth_failure: Option<String>,
+    /// Where scripts are loaded from; `None` if scripting is disabled
+    pub script_dir: Option<PathBuf>,
 }
 
 impl App {
@@ -338,20 +561,33 @@ impl App {
             client_status: ClientStatus::default(),
             network_messages: VecDeque::new(),
             max_network_messages: 1000,
+            network_scroll: ChatScroll::new(),
             event_rx: None,
             action_tx: None,
             selected_character_index: 0,
             chat_messages: VecDeque::new(),
             max_chat_messages: 100,
+            chat_scroll: ChatScroll::new(),
             chat_input: String::new(),
             chat_input_active: false,
+            hide_chat_spam: false,
+            squelched: Vec::new(),
             game_world_tab: GameWorldTab::World,
             connecting_progress: 0.0,
+            login_retry: None,
             updating_progress: 0.0,
             last_connecting_update: None,
             last_updating_update: None,
+            character_list_received_at: None,
             inventory_state: InventoryState::new(),
             object_tracker: ObjectTracker::new(),
+            nearby_selected_index: 0,
+            fellowship: None,
+            allegiance: None,
+            command_palette: None,
+            connection_error: None,
+            auth_failure: None,
+            script_dir: None,
         }
     }
 
@@ -373,6 +609,28 @@ impl App {
         if self.network_messages.len() > self.max_network_messages {
             self.network_messages.pop_front();
         }
+        self.network_scroll
+            .message_added(self.network_messages.len());
+    }
+
+    /// Scroll the message list in the focused view: the debug view, or the
+    /// chat tab in game. Returns false if neither is focused.
+    pub fn scroll_messages(&mut self, action: ScrollAction) -> bool {
+        match self.current_view {
+            AppView::Debug => {
+                self.network_scroll
+                    .apply(action, self.network_messages.len());
+                true
+            }
+            AppView::Game
+                if matches!(self.game_scene, GameScene::GameWorld { .. })
+                    && self.game_world_tab == GameWorldTab::Chat =>
+            {
+                self.chat_scroll.apply(action, self.visible_chat_count());
+                true
+            }
+            AppView::Game => false,
+        }
     }
 
     pub fn update_from_system_event(&mut self, event: gromnie_runner::SystemEvent) {
@@ -394,6 +652,7 @@ impl App {
             }
             gromnie_runner::SystemEvent::AuthenticationFailed { reason, .. } => {
                 self.client_status.scene_state = SceneState::Error(reason.clone());
+                self.auth_failure = Some(reason.clone());
 
                 self.add_network_message(NetworkMessage::Received {
                     opcode: "ERROR".to_string(),
@@ -416,6 +675,91 @@ impl App {
                     timestamp: chrono::Utc::now(),
                 });
             }
+            gromnie_runner::SystemEvent::ConnectionStats { stats, .. } => {
+                self.client_status.connection_stats = Some(stats);
+            }
+            gromnie_runner::SystemEvent::ConnectionError {
+                kind,
+                message,
+                can_retry,
+                ..
+            } => {
+                self.game_scene = GameScene::Error(message.clone());
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "ERROR".to_string(),
+                    description: format!("Connection error: {}", message),
+                    timestamp: chrono::Utc::now(),
+                });
+                self.connection_error = Some(ConnectionError {
+                    kind,
+                    message,
+                    can_retry,
+                });
+            }
+            gromnie_runner::SystemEvent::Disconnected {
+                will_reconnect,
+                reconnect_attempt,
+                delay_secs,
+                ..
+            } => {
+                self.client_status.reconnect = will_reconnect.then(|| {
+                    ReconnectBanner::new(reconnect_attempt, delay_secs, std::time::Instant::now())
+                });
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "DISCONNECT".to_string(),
+                    description: if will_reconnect {
+                        format!("Connection lost, reconnecting in {}s", delay_secs)
+                    } else {
+                        "Connection lost".to_string()
+                    },
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            gromnie_runner::SystemEvent::Reconnecting {
+                attempt,
+                delay_secs,
+                ..
+            } => {
+                self.client_status.reconnect = Some(ReconnectBanner::new(
+                    attempt,
+                    delay_secs,
+                    std::time::Instant::now(),
+                ));
+            }
+            gromnie_runner::SystemEvent::UnknownMessage {
+                opcode,
+                game_event_opcode,
+                data,
+                ..
+            } => {
+                let opcode = match game_event_opcode {
+                    Some(event_opcode) => format!("0x{:04X}/0x{:04X}", opcode, event_opcode),
+                    None => format!("0x{:04X}", opcode),
+                };
+                // The first bytes are usually enough to recognize the message
+                let preview: Vec<String> =
+                    data.iter().take(16).map(|b| format!("{:02X}", b)).collect();
+                self.add_network_message(NetworkMessage::Received {
+                    opcode,
+                    description: format!(
+                        "Unknown message ({} bytes): {}{}",
+                        data.len(),
+                        preview.join(" "),
+                        if data.len() > 16 { " ..." } else { "" }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            gromnie_runner::SystemEvent::SquelchListChanged { names, .. } => {
+                self.squelched = names;
+            }
+            gromnie_runner::SystemEvent::LoginRetry {
+                attempt,
+                max_attempts,
+                ..
+            } => {
+                self.login_retry = Some((attempt, max_attempts));
+            }
             _ => {
                 // Other system events don't need special handling in the TUI
             }
@@ -431,6 +775,7 @@ impl App {
             } => {
                 self.client_status.account_name = account;
                 self.client_status.characters = characters;
+                self.character_list_received_at = Some(std::time::Instant::now());
                 self.selected_character_index = 0; // Reset to first character when list updates
 
                 // Transition to CharacterSelect scene when we receive the character list
@@ -492,22 +837,46 @@ impl App {
 
                 self.add_network_message(NetworkMessage::Received {
                     opcode: "0xF7E0".to_string(),
-                    description: format!("Chat (type {}): {}", message_type, message),
+                    description: format!("Chat (type 0x{:02X}): {}", message_type, message),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::ChannelMessage {
+                channel,
+                sender_name,
+                message,
+            } => {
+                self.add_chat_message(ChatMessage {
+                    text: if sender_name.is_empty() {
+                        format!("[0x{:X}] {}", channel, message)
+                    } else {
+                        format!("[0x{:X}] {}: {}", channel, sender_name, message)
+                    },
+                    message_type: 0x08, // Channels
                     timestamp: chrono::Utc::now(),
                 });
             }
             GameEvent::ConnectingSetProgress { progress } => {
                 self.connecting_progress = progress.clamp(0.0, 1.0);
+                // Past 33% the server has answered the LoginRequest
+                if self.connecting_progress > 0.5 {
+                    self.login_retry = None;
+                }
             }
             GameEvent::UpdatingSetProgress { progress } => {
                 self.updating_progress = progress.clamp(0.0, 1.0);
             }
 
             GameEvent::CreatePlayer { character_id } => {
+                let character_id = character_id.get();
+                // A new character starts outside any fellowship or allegiance until the server says otherwise
+                self.fellowship = None;
+                self.allegiance = None;
                 // Set the player ID for inventory tracking
                 self.inventory_state.player_id = Some(character_id);
                 self.inventory_state.selected_container = self.inventory_state.player_id;
                 self.inventory_state.selected_container_index = 0;
+                self.inventory_state.selected_item_index = 0;
 
                 // Also set in object tracker
                 self.object_tracker.set_player_id(character_id);
@@ -541,9 +910,19 @@ impl App {
                 value,
                 items_capacity,
                 container_capacity,
+                stack_size,
+                wielder_id,
+                equipped_location,
+                position,
             } => {
-                // Store the item in inventory state (we receive all items, not just player's)
-                self.inventory_state.items.insert(
+                // Inventory state is keyed by raw ids
+                let object_id = object_id.get();
+                let container_id = container_id.map(u32::from);
+                let wielder_id = wielder_id.map(u32::from);
+
+                // Store the item in inventory state (we receive all items, not just player's).
+                // The server re-sends objects, so drop any row left under a previous container.
+                let previous = self.inventory_state.items.insert(
                     object_id,
                     InventoryItem {
                         object_id,
@@ -554,16 +933,24 @@ impl App {
                         value,
                         items_capacity,
                         container_capacity,
+                        stack_size,
+                        wielder_id,
+                        equipped_location,
                     },
                 );
 
+                if let Some(old_cid) = previous.and_then(|item| item.container_id)
+                    && let Some(contents) = self.inventory_state.container_items.get_mut(&old_cid)
+                {
+                    contents.retain(|&id| id != object_id);
+                }
+
                 // If this item has a container_id, track it in container_items
                 if let Some(cid) = container_id {
-                    self.inventory_state
-                        .container_items
-                        .entry(cid)
-                        .or_default()
-                        .push(object_id);
+                    let contents = self.inventory_state.container_items.entry(cid).or_default();
+                    if !contents.contains(&object_id) {
+                        contents.push(object_id);
+                    }
                 }
 
                 // Also track in object tracker
@@ -577,6 +964,8 @@ impl App {
                 obj.value = value;
                 obj.items_capacity = items_capacity;
                 obj.container_capacity = container_capacity;
+                obj.stack_size = stack_size;
+                obj.position = position;
                 self.object_tracker.handle_item_create(obj);
 
                 // Add object to the list if we're in the game world scene
@@ -604,9 +993,10 @@ impl App {
                 items,
             } => {
                 // Store the container->items mapping
-                self.inventory_state
-                    .container_items
-                    .insert(container_id, items.clone());
+                self.inventory_state.container_items.insert(
+                    container_id.get(),
+                    items.iter().copied().map(u32::from).collect(),
+                );
 
                 self.add_network_message(NetworkMessage::Received {
                     opcode: "0xF7E0".to_string(),
@@ -623,11 +1013,12 @@ impl App {
                 containers: _,
             } => {
                 // Store the player ID
-                self.inventory_state.player_id = Some(player_id);
+                self.inventory_state.player_id = Some(player_id.get());
 
                 // Initialize selected container to player's main inventory
                 self.inventory_state.selected_container = self.inventory_state.player_id;
                 self.inventory_state.selected_container_index = 0;
+                self.inventory_state.selected_item_index = 0;
 
                 self.add_network_message(NetworkMessage::Received {
                     opcode: "0xF7E0".to_string(),
@@ -636,6 +1027,7 @@ impl App {
                 });
             }
             GameEvent::ItemDeleteObject { object_id } => {
+                let object_id = object_id.get();
                 // Remove from inventory state
                 self.inventory_state.items.remove(&object_id);
 
@@ -655,14 +1047,28 @@ impl App {
                     timestamp: chrono::Utc::now(),
                 });
             }
+            GameEvent::ObjectMoved {
+                object_id,
+                position,
+            } => {
+                // Too frequent for the network log
+                self.object_tracker
+                    .handle_object_moved(object_id.get(), position);
+            }
             GameEvent::ItemMovedObject {
                 object_id,
                 new_container_id,
             } => {
+                let object_id = object_id.get();
+                let new_container_id = new_container_id.get();
+
                 // Update inventory state
                 if let Some(item) = self.inventory_state.items.get_mut(&object_id) {
                     let old_container = item.container_id;
                     item.container_id = Some(new_container_id);
+                    // Moving into a container unequips the item
+                    item.wielder_id = None;
+                    item.equipped_location = None;
 
                     // Remove from old container's contents
                     if let Some(old_cid) = old_container
@@ -700,14 +1106,50 @@ impl App {
                     timestamp: chrono::Utc::now(),
                 });
             }
+            GameEvent::ItemUpdateStackSize {
+                object_id,
+                stack_size,
+                value,
+                ..
+            } => {
+                let object_id = object_id.get();
+                self.object_tracker
+                    .handle_stack_size(object_id, stack_size, value);
+
+                if let Some(item) = self.inventory_state.items.get_mut(&object_id) {
+                    item.stack_size = Some(stack_size);
+                    item.value = value;
+                }
+
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x0197".to_string(),
+                    description: format!(
+                        "ItemUpdateStackSize: Object {} stack = {} (value {})",
+                        object_id, stack_size, value
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
             GameEvent::QualitiesPrivateUpdateInt {
                 object_id,
                 property_name,
                 value,
+                ..
             } => {
+                let object_id = object_id.get();
+
                 // Update object tracker
-                self.object_tracker
-                    .handle_quality_update(object_id, property_name.clone(), value);
+                self.object_tracker.handle_quality_update(
+                    object_id,
+                    property_name.to_string(),
+                    value,
+                );
+
+                if property_name == "StackSize"
+                    && let Some(item) = self.inventory_state.items.get_mut(&object_id)
+                {
+                    item.stack_size = Some(value as u32);
+                }
 
                 // For now, just log the update
                 self.add_network_message(NetworkMessage::Received {
@@ -719,14 +1161,40 @@ impl App {
                     timestamp: chrono::Utc::now(),
                 });
             }
+            GameEvent::QualitiesUpdateInt {
+                object_id,
+                property_name,
+                value,
+                ..
+            } => {
+                // Too frequent for the network log
+                let object_id = object_id.get();
+                self.object_tracker.handle_quality_update(
+                    object_id,
+                    property_name.to_string(),
+                    value,
+                );
+
+                if property_name == "StackSize"
+                    && let Some(item) = self.inventory_state.items.get_mut(&object_id)
+                {
+                    item.stack_size = Some(value as u32);
+                }
+            }
+            GameEvent::QualitiesUpdateFloat { .. } | GameEvent::VitalUpdated { .. } => {
+                // Too frequent for the network log, and not shown anywhere else
+            }
             GameEvent::ItemSetState {
                 object_id,
                 property_name,
                 value,
             } => {
                 // Update object tracker
-                self.object_tracker
-                    .handle_item_set_state(object_id, property_name.clone(), value);
+                self.object_tracker.handle_item_set_state(
+                    object_id.get(),
+                    property_name.clone(),
+                    value,
+                );
 
                 // For now, just log the update
                 self.add_network_message(NetworkMessage::Received {
@@ -738,19 +1206,388 @@ impl App {
                     timestamp: chrono::Utc::now(),
                 });
             }
+            GameEvent::ActionError {
+                error_code,
+                message,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0xF7B0".to_string(),
+                    description: format!("Action failed (0x{:04X}): {}", error_code, message),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::TargetHealth {
+                object_id,
+                health_percent,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0xF7B0".to_string(),
+                    description: format!("Health of 0x{:08X}: {:.0}%", object_id, health_percent),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::PlayerDeath { message, .. } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x019E".to_string(),
+                    description: format!("Death: {}", message),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::AppraiseResult {
+                object_id, success, ..
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x00C9".to_string(),
+                    description: format!(
+                        "Appraisal of 0x{:08X}: {}",
+                        object_id,
+                        if success { "succeeded" } else { "failed" }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::FellowshipUpdated {
+                name,
+                leader_id,
+                members,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x02BE".to_string(),
+                    description: format!("Fellowship {}: {} members", name, members.len()),
+                    timestamp: chrono::Utc::now(),
+                });
+                self.fellowship = Some(Fellowship {
+                    name,
+                    leader_id: leader_id.get(),
+                    members: members
+                        .into_iter()
+                        .map(|member| (member.object_id.get(), member))
+                        .collect(),
+                });
+            }
+            GameEvent::FellowshipMemberUpdated { member } => {
+                if let Some(fellowship) = &mut self.fellowship {
+                    fellowship.members.insert(member.object_id.get(), member);
+                }
+            }
+            GameEvent::FellowshipMemberLeft { object_id } => {
+                let object_id = object_id.get();
+                if Some(object_id) == self.inventory_state.player_id {
+                    // We left, so the whole fellowship is gone from our point of view
+                    self.fellowship = None;
+                } else if let Some(fellowship) = &mut self.fellowship {
+                    fellowship.members.remove(&object_id);
+                }
+            }
+            GameEvent::FellowshipDisbanded => {
+                self.fellowship = None;
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x02BF".to_string(),
+                    description: "Fellowship disbanded".to_string(),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::ContainerContents { .. } => {
+                // Already shown from the ItemOnViewContents event sent alongside it
+            }
+            GameEvent::ConfirmationRequest {
+                confirmation_type,
+                context_id,
+                message,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x0274".to_string(),
+                    description: format!(
+                        "Confirmation request (type {}, context {}): {}",
+                        confirmation_type, context_id, message
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::VendorInfo { vendor_id, items } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x0062".to_string(),
+                    description: format!("Vendor 0x{:08X}: {} items", vendor_id, items.len()),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::DamageDealt {
+                defender_name,
+                damage_amount,
+                critical,
+                ..
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x01B1".to_string(),
+                    description: format!(
+                        "Hit {} for {}{}",
+                        defender_name,
+                        damage_amount,
+                        if critical { " (critical)" } else { "" }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::DamageTaken {
+                attacker_name,
+                damage_amount,
+                critical,
+                ..
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x01B2".to_string(),
+                    description: format!(
+                        "{} hit you for {}{}",
+                        attacker_name,
+                        damage_amount,
+                        if critical { " (critical)" } else { "" }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::AttackEvaded { defender_name } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x01B3".to_string(),
+                    description: format!("{} evaded your attack", defender_name),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::AttackAvoided { attacker_name } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x01B4".to_string(),
+                    description: format!("You evaded {}", attacker_name),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::AllegianceUpdated {
+                name,
+                rank,
+                members,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x0020".to_string(),
+                    description: format!("Allegiance {}: {} members", name, members.len()),
+                    timestamp: chrono::Utc::now(),
+                });
+                let members: BTreeMap<_, _> = members
+                    .into_iter()
+                    .map(|member| (member.object_id.get(), member))
+                    .collect();
+                // Keep who is online across updates, for members still in it
+                let online = self
+                    .allegiance
+                    .take()
+                    .map(|allegiance| allegiance.online)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|id| members.contains_key(id))
+                    .collect();
+                self.allegiance = if members.is_empty() {
+                    None
+                } else {
+                    Some(Allegiance {
+                        name,
+                        rank,
+                        members,
+                        online,
+                    })
+                };
+            }
+            GameEvent::AllegianceInfoReceived {
+                target_id,
+                name,
+                members,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x027C".to_string(),
+                    description: format!(
+                        "Allegiance of 0x{:08X}: {} ({} members)",
+                        target_id,
+                        name,
+                        members.len()
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::AllegianceMemberLogin {
+                object_id,
+                name,
+                logged_in,
+            } => {
+                if let Some(allegiance) = &mut self.allegiance {
+                    if logged_in {
+                        allegiance.online.insert(object_id.get());
+                    } else {
+                        allegiance.online.remove(&object_id.get());
+                    }
+                }
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x027A".to_string(),
+                    description: format!(
+                        "Allegiance member {} logged {}",
+                        if name.is_empty() {
+                            format!("0x{:08X}", object_id)
+                        } else {
+                            name
+                        },
+                        if logged_in { "in" } else { "out" }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::CombatKill { message, own_death } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: if own_death { "0x01AC" } else { "0x01AD" }.to_string(),
+                    description: format!("Kill: {}", message),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::TradeOpened {
+                partner_id,
+                partner_name,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x001F".to_string(),
+                    description: format!(
+                        "Trade opened with {}",
+                        if partner_name.is_empty() {
+                            format!("0x{:08X}", partner_id)
+                        } else {
+                            partner_name
+                        }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+            GameEvent::TradeItemAdded {
+                item_id,
+                name,
+                own_item,
+            } => {
+                self.add_network_message(NetworkMessage::Received {
+                    opcode: "0x0021".to_string(),
+                    description: format!(
+                        "Added to trade by {}: {}",
+                        if own_item { "us" } else { "partner" },
+                        if name.is_empty() {
+                            format!("0x{:08X}", item_id)
+                        } else {
+                            name
+                        }
+                    ),
+                    timestamp: chrono::Utc::now(),
+                });
+            }
         }
     }
 
     pub fn add_chat_message(&mut self, message: ChatMessage) {
+        let visible = self.is_chat_message_visible(&message);
         self.chat_messages.push_back(message);
         if self.chat_messages.len() > self.max_chat_messages {
             self.chat_messages.pop_front();
         }
+        if visible {
+            self.chat_scroll.message_added(self.visible_chat_count());
+        }
+    }
+
+    /// Whether a chat message passes the current chat filter
+    pub fn is_chat_message_visible(&self, message: &ChatMessage) -> bool {
+        !(self.hide_chat_spam && message.category().is_spam())
+    }
+
+    /// Number of chat messages shown with the current filter
+    pub fn visible_chat_count(&self) -> usize {
+        self.chat_messages
+            .iter()
+            .filter(|m| self.is_chat_message_visible(m))
+            .count()
+    }
+
+    /// Toggle hiding of system and combat messages in the chat tab
+    pub fn toggle_chat_filter(&mut self) {
+        self.hide_chat_spam = !self.hide_chat_spam;
+        // The offset counts shown lines, so it means something else now
+        self.chat_scroll
+            .apply(ScrollAction::Bottom, self.visible_chat_count());
+    }
+
+    /// Open the command palette with an empty query
+    pub fn open_command_palette(&mut self) {
+        self.command_palette = Some(CommandPalette::new());
+    }
+
+    /// Close the command palette and run its selected command, if any
+    pub fn run_selected_palette_command(&mut self) -> Result<(), String> {
+        match self
+            .command_palette
+            .take()
+            .and_then(|palette| palette.selected_command())
+        {
+            Some(command) => self.run_palette_command(command),
+            None => Ok(()),
+        }
+    }
+
+    /// Run a command palette entry
+    pub fn run_palette_command(&mut self, command: PaletteCommand) -> Result<(), String> {
+        match command {
+            PaletteCommand::LoginCharacter => {
+                if self.game_scene != GameScene::CharacterSelect {
+                    return Err("Not at character select".to_string());
+                }
+                self.login_selected_character()
+            }
+            PaletteCommand::Logout => self.logout(),
+            PaletteCommand::ReloadScripts => {
+                let script_dir = self
+                    .script_dir
+                    .clone()
+                    .ok_or_else(|| "Scripting is disabled".to_string())?;
+                let tx = self
+                    .action_tx
+                    .as_ref()
+                    .ok_or_else(|| "No action channel available".to_string())?;
+                tx.send(SimpleClientAction::ReloadScripts { script_dir })
+                    .map_err(|e| format!("Failed to send reload action: {}", e))
+            }
+            PaletteCommand::SendTell => {
+                if !matches!(
+                    self.game_scene,
+                    GameScene::GameWorld {
+                        state: GameWorldState::InWorld,
+                        ..
+                    }
+                ) {
+                    return Err("Not in world".to_string());
+                }
+                self.current_view = AppView::Game;
+                self.game_world_tab = GameWorldTab::Chat;
+                self.chat_input = "/tell ".to_string();
+                self.chat_input_active = true;
+                Ok(())
+            }
+            PaletteCommand::Quit => {
+                self.should_quit = true;
+                Ok(())
+            }
+        }
     }
 
     /// Update from state events from the client
     pub fn update_from_state_event(&mut self, state_event: ClientStateEvent) {
+        // Back online, so any reconnect countdown is over
+        if matches!(
+            state_event,
+            ClientStateEvent::Connected | ClientStateEvent::CharacterSelect
+        ) {
+            self.client_status.reconnect = None;
+        }
+
         let (session, scene, game_scene_update) = match state_event {
+            // The event for the new scene follows and carries the update
+            ClientStateEvent::StateTransition { .. } => return,
             ClientStateEvent::Connecting => {
                 (SessionState::AuthLoginRequest, SceneState::Connecting, None)
             }
@@ -789,7 +1626,7 @@ impl App {
             ClientStateEvent::ExitingWorld => (
                 SessionState::AuthConnected,
                 SceneState::CharacterSelect,
-                None,
+                Some(GameScene::CharacterSelect),
             ),
             ClientStateEvent::CharacterError => (
                 SessionState::AuthConnected,
@@ -811,3 +1648,478 @@ impl Default for App {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(object_id: u32, name: &str, container_id: Option<u32>) -> InventoryItem {
+        InventoryItem {
+            object_id,
+            name: name.to_string(),
+            item_type: "MISC".to_string(),
+            container_id,
+            burden: 0,
+            value: 0,
+            items_capacity: None,
+            container_capacity: None,
+            stack_size: None,
+            wielder_id: None,
+            equipped_location: None,
+        }
+    }
+
+    #[test]
+    fn test_inventory_selection_and_equipped_items() {
+        let mut inventory = InventoryState::new();
+        inventory.player_id = Some(1);
+        inventory.selected_container = Some(1);
+        for (id, name) in [(10, "Potion"), (11, "Apple")] {
+            inventory.items.insert(id, item(id, name, Some(1)));
+        }
+        inventory.container_items.insert(1, vec![10, 11]);
+
+        let mut sword = item(20, "Sword", None);
+        sword.wielder_id = Some(1);
+        sword.equipped_location = Some(0x0010_0000);
+        inventory.items.insert(20, sword);
+
+        // Contents are sorted by name, and selection wraps
+        assert_eq!(inventory.selected_item().unwrap().name, "Apple");
+        inventory.select_next_item();
+        assert_eq!(inventory.selected_item().unwrap().name, "Potion");
+        inventory.select_next_item();
+        assert_eq!(inventory.selected_item().unwrap().name, "Apple");
+        inventory.select_previous_item();
+        assert_eq!(inventory.selected_item().unwrap().name, "Potion");
+
+        let equipped = inventory.get_equipped_items();
+        assert_eq!(equipped.len(), 1);
+        assert_eq!(equipped[0].name, "Sword");
+    }
+
+    #[test]
+    fn test_palette_commands() {
+        let mut app = App::new();
+        let (action_tx, mut action_rx) = mpsc::unbounded_channel();
+        app.action_tx = Some(action_tx);
+
+        // Nothing to reload from while scripting is off
+        assert!(
+            app.run_palette_command(PaletteCommand::ReloadScripts)
+                .is_err()
+        );
+        app.script_dir = Some(PathBuf::from("scripts"));
+        app.run_palette_command(PaletteCommand::ReloadScripts)
+            .unwrap();
+        assert!(matches!(
+            action_rx.try_recv(),
+            Ok(SimpleClientAction::ReloadScripts { script_dir }) if script_dir == PathBuf::from("scripts")
+        ));
+
+        assert!(app.run_palette_command(PaletteCommand::SendTell).is_err());
+        app.game_scene = GameScene::GameWorld {
+            state: GameWorldState::InWorld,
+            created_objects: Vec::new(),
+        };
+        app.run_palette_command(PaletteCommand::SendTell).unwrap();
+        assert_eq!(app.game_world_tab, GameWorldTab::Chat);
+        assert!(app.chat_input_active);
+        assert_eq!(app.chat_input, "/tell ");
+
+        // Enter runs the highlighted match and closes the palette
+        app.open_command_palette();
+        for c in "quit".chars() {
+            app.command_palette.as_mut().unwrap().push(c);
+        }
+        app.run_selected_palette_command().unwrap();
+        assert!(app.command_palette.is_none());
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_chat_filter_hides_spam() {
+        let mut app = App::new();
+        for (text, message_type) in [("hit", 0x06), ("psst", 0x03), ("note", 0x05), ("hi", 0x02)] {
+            app.update_from_event(GameEvent::ChatMessageReceived {
+                message: text.to_string(),
+                message_type,
+            });
+        }
+        assert_eq!(app.visible_chat_count(), 4);
+
+        app.toggle_chat_filter();
+        let shown: Vec<_> = app
+            .chat_messages
+            .iter()
+            .filter(|m| app.is_chat_message_visible(m))
+            .map(|m| m.text.as_str())
+            .collect();
+        assert_eq!(shown, ["psst", "hi"]);
+
+        // Filtered messages are still kept, so toggling back shows them
+        app.toggle_chat_filter();
+        assert_eq!(app.visible_chat_count(), 4);
+        assert_eq!(app.chat_messages[0].category(), ChatCategory::Combat);
+    }
+
+    #[test]
+    fn test_nearby_objects_sorted_with_distance() {
+        let mut app = App::new();
+        app.object_tracker.set_player_id(1);
+        let create = |object_id: u32, name: &str, container_id: Option<u32>, x: f32| {
+            GameEvent::ItemCreateObject {
+                object_id: object_id.into(),
+                name: name.to_string(),
+                item_type: "CREATURE".to_string(),
+                container_id: container_id.map(Into::into),
+                burden: 0,
+                value: 25,
+                items_capacity: None,
+                container_capacity: None,
+                stack_size: None,
+                wielder_id: None,
+                equipped_location: None,
+                position: container_id
+                    .is_none()
+                    .then_some(gromnie_events::PlayerPosition {
+                        landcell: 0xA9B4_0001,
+                        x,
+                        y: 0.0,
+                        z: 0.0,
+                        heading: 0.0,
+                    }),
+            }
+        };
+        app.update_from_event(create(1, "Me", None, 0.0));
+        app.update_from_event(create(2, "Olthoi", None, 30.0));
+        app.update_from_event(create(3, "Drudge", None, 12.0));
+        app.update_from_event(create(4, "Pack Item", Some(1), 0.0));
+        app.update_from_event(create(5, "Banderling", None, 5.0));
+        app.update_from_event(GameEvent::ItemDeleteObject {
+            object_id: 5.into(),
+        });
+
+        // The player, carried items and deleted objects aren't listed
+        let names: Vec<&str> = app
+            .nearby_objects()
+            .iter()
+            .map(|obj| obj.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Drudge", "Olthoi"]);
+
+        let selected = app.selected_nearby_object().unwrap();
+        assert_eq!(app.distance_to_object(selected), Some(12.0));
+
+        app.select_next_nearby_object();
+        app.select_next_nearby_object();
+        let selected = app.selected_nearby_object().unwrap();
+        assert_eq!(selected.name, "Olthoi");
+        assert_eq!(selected.value, 25);
+
+        // The selection stays in range when the list shrinks
+        app.update_from_event(GameEvent::ItemDeleteObject {
+            object_id: 2.into(),
+        });
+        assert_eq!(app.selected_nearby_object().unwrap().name, "Drudge");
+
+        // Distances follow movement of either side
+        let moved = |object_id: u32, x: f32| GameEvent::ObjectMoved {
+            object_id: object_id.into(),
+            position: gromnie_events::PlayerPosition {
+                landcell: 0xA9B4_0001,
+                x,
+                y: 0.0,
+                z: 0.0,
+                heading: 0.0,
+            },
+        };
+        app.update_from_event(moved(3, 20.0));
+        app.update_from_event(moved(1, 4.0));
+        let selected = app.selected_nearby_object().unwrap();
+        assert_eq!(app.distance_to_object(selected), Some(16.0));
+    }
+
+    #[test]
+    fn test_stack_size_updates_in_place() {
+        let mut app = App::new();
+        app.inventory_state.player_id = Some(1);
+        app.inventory_state.selected_container = Some(1);
+
+        let arrows = || GameEvent::ItemCreateObject {
+            object_id: 10.into(),
+            name: "Arrow".to_string(),
+            item_type: "MISSILE_WEAPON".to_string(),
+            container_id: Some(1.into()),
+            burden: 50,
+            value: 100,
+            items_capacity: None,
+            container_capacity: None,
+            stack_size: Some(100),
+            wielder_id: None,
+            equipped_location: None,
+            position: None,
+        };
+        // A re-sent object doesn't add a second row
+        app.update_from_event(arrows());
+        app.update_from_event(arrows());
+        assert_eq!(app.inventory_state.get_selected_container_items().len(), 1);
+
+        app.update_from_event(GameEvent::ItemUpdateStackSize {
+            object_id: 10.into(),
+            name: "Arrow".to_string(),
+            previous_stack_size: Some(100),
+            stack_size: 42,
+            value: 42,
+        });
+        let items = app.inventory_state.get_selected_container_items();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].stack_size, Some(42));
+        assert_eq!(items[0].value, 42);
+
+        app.update_from_event(GameEvent::ItemDeleteObject {
+            object_id: 10.into(),
+        });
+        assert!(
+            app.inventory_state
+                .get_selected_container_items()
+                .is_empty()
+        );
+    }
+
+    fn allegiance_member(
+        object_id: u32,
+        name: &str,
+        rank: u32,
+        officer_level: u32,
+    ) -> AllegianceMember {
+        AllegianceMember {
+            object_id: object_id.into(),
+            name: name.to_string(),
+            rank,
+            level: 50,
+            patron_id: None,
+            officer_level,
+        }
+    }
+
+    #[test]
+    fn test_allegiance_updates() {
+        let mut app = App::new();
+        app.update_from_event(GameEvent::AllegianceUpdated {
+            name: "Order".to_string(),
+            rank: 2,
+            members: vec![
+                allegiance_member(1, "Monarch", 5, 0),
+                allegiance_member(2, "Bob", 2, 0),
+                allegiance_member(3, "Officer", 3, 1),
+                allegiance_member(4, "Amy", 2, 0),
+            ],
+        });
+        let names: Vec<_> = app
+            .allegiance
+            .as_ref()
+            .unwrap()
+            .sorted_members()
+            .iter()
+            .map(|member| member.name.clone())
+            .collect();
+        assert_eq!(names, ["Officer", "Monarch", "Amy", "Bob"]);
+
+        app.update_from_event(GameEvent::AllegianceMemberLogin {
+            object_id: 3.into(),
+            name: "Officer".to_string(),
+            logged_in: true,
+        });
+        app.update_from_event(GameEvent::AllegianceMemberLogin {
+            object_id: 4.into(),
+            name: "Amy".to_string(),
+            logged_in: true,
+        });
+        app.update_from_event(GameEvent::AllegianceMemberLogin {
+            object_id: 4.into(),
+            name: "Amy".to_string(),
+            logged_in: false,
+        });
+        assert_eq!(app.allegiance.as_ref().unwrap().online, BTreeSet::from([3]));
+
+        // Online status survives an update for members still in the allegiance
+        app.update_from_event(GameEvent::AllegianceUpdated {
+            name: "Order".to_string(),
+            rank: 2,
+            members: vec![allegiance_member(3, "Officer", 3, 1)],
+        });
+        assert_eq!(app.allegiance.as_ref().unwrap().online, BTreeSet::from([3]));
+
+        // An empty hierarchy means the character left
+        app.update_from_event(GameEvent::AllegianceUpdated {
+            name: String::new(),
+            rank: 0,
+            members: Vec::new(),
+        });
+        assert!(app.allegiance.is_none());
+    }
+
+    fn fellow(object_id: u32, name: &str, health: u32) -> FellowMember {
+        FellowMember {
+            object_id: object_id.into(),
+            name: name.to_string(),
+            level: 50,
+            health,
+            max_health: 100,
+            stamina: 100,
+            max_stamina: 100,
+            mana: 100,
+            max_mana: 100,
+        }
+    }
+
+    #[test]
+    fn test_fellowship_updates() {
+        let mut app = App::new();
+        app.inventory_state.player_id = Some(1);
+
+        app.update_from_event(GameEvent::FellowshipUpdated {
+            name: "Crafters".to_string(),
+            leader_id: 2.into(),
+            members: vec![
+                fellow(1, "Me", 100),
+                fellow(2, "Zed", 100),
+                fellow(3, "Amy", 100),
+            ],
+        });
+        let names: Vec<_> = app
+            .fellowship
+            .as_ref()
+            .unwrap()
+            .sorted_members()
+            .iter()
+            .map(|member| member.name.clone())
+            .collect();
+        assert_eq!(names, ["Zed", "Amy", "Me"]);
+
+        app.update_from_event(GameEvent::FellowshipMemberUpdated {
+            member: fellow(3, "Amy", 40),
+        });
+        // Another member leaving only removes their row
+        app.update_from_event(GameEvent::FellowshipMemberLeft {
+            object_id: 2.into(),
+        });
+        let fellowship = app.fellowship.as_ref().unwrap();
+        assert_eq!(fellowship.members.len(), 2);
+        assert_eq!(fellowship.members[&3].health, 40);
+
+        app.update_from_event(GameEvent::FellowshipDisbanded);
+        assert!(app.fellowship.is_none());
+
+        // Leaving ourselves clears the panel too
+        app.update_from_event(GameEvent::FellowshipUpdated {
+            name: "Crafters".to_string(),
+            leader_id: 1.into(),
+            members: vec![fellow(1, "Me", 100), fellow(3, "Amy", 100)],
+        });
+        app.update_from_event(GameEvent::FellowshipMemberLeft {
+            object_id: 1.into(),
+        });
+        assert!(app.fellowship.is_none());
+    }
+
+    #[test]
+    fn test_reconnect_banner() {
+        let now = std::time::Instant::now();
+        let banner = ReconnectBanner::new(2, 5, now);
+        assert_eq!(banner.text(now), "Reconnecting (attempt 2, retry in 5s)");
+        // Partial seconds round up
+        assert_eq!(
+            banner.text(now + std::time::Duration::from_millis(1500)),
+            "Reconnecting (attempt 2, retry in 4s)"
+        );
+        assert_eq!(
+            banner.text(now + std::time::Duration::from_secs(6)),
+            "Reconnecting (attempt 2)..."
+        );
+
+        let mut app = App::new();
+        app.update_from_system_event(gromnie_runner::SystemEvent::Disconnected {
+            client_id: 0,
+            will_reconnect: true,
+            reconnect_attempt: 1,
+            delay_secs: 2,
+        });
+        assert_eq!(app.client_status.reconnect.unwrap().attempt, 1);
+
+        app.update_from_system_event(gromnie_runner::SystemEvent::Reconnecting {
+            client_id: 0,
+            attempt: 2,
+            delay_secs: 4,
+        });
+        assert_eq!(app.client_status.reconnect.unwrap().attempt, 2);
+
+        // Still reconnecting until the login server answers
+        app.update_from_state_event(ClientStateEvent::Connecting);
+        assert!(app.client_status.reconnect.is_some());
+        app.update_from_state_event(ClientStateEvent::Connected);
+        assert!(app.client_status.reconnect.is_none());
+
+        // No banner when the client isn't going to retry
+        app.update_from_system_event(gromnie_runner::SystemEvent::Disconnected {
+            client_id: 0,
+            will_reconnect: false,
+            reconnect_attempt: 0,
+            delay_secs: 0,
+        });
+        assert!(app.client_status.reconnect.is_none());
+    }
+
+    #[test]
+    fn test_login_retry_cleared_when_server_answers() {
+        let mut app = App::new();
+        app.update_from_system_event(gromnie_runner::SystemEvent::LoginRetry {
+            client_id: 0,
+            attempt: 2,
+            max_attempts: 5,
+        });
+        assert_eq!(app.login_retry, Some((2, 5)));
+
+        app.update_from_event(GameEvent::ConnectingSetProgress { progress: 0.33 });
+        assert_eq!(app.login_retry, Some((2, 5)));
+        app.update_from_event(GameEvent::ConnectingSetProgress { progress: 0.66 });
+        assert_eq!(app.login_retry, None);
+    }
+
+    #[test]
+    fn test_connection_error_and_retry() {
+        let mut app = App::new();
+        app.update_from_system_event(gromnie_runner::SystemEvent::ConnectionError {
+            client_id: 0,
+            kind: ConnectionErrorKind::Rejected,
+            message: "Character error: Logon".to_string(),
+            can_retry: true,
+        });
+        assert_eq!(
+            app.game_scene,
+            GameScene::Error("Character error: Logon".to_string())
+        );
+        assert!(app.connection_error.as_ref().unwrap().can_retry);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        app.action_tx = Some(tx);
+        app.retry_connection().unwrap();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(SimpleClientAction::RetryConnection)
+        ));
+        assert!(matches!(app.game_scene, GameScene::Logging { .. }));
+        assert!(app.connection_error.is_none());
+
+        // A client that gave up can't be retried
+        app.update_from_system_event(gromnie_runner::SystemEvent::ConnectionError {
+            client_id: 0,
+            kind: ConnectionErrorKind::Timeout,
+            message: "Timed out waiting for the login server".to_string(),
+            can_retry: false,
+        });
+        assert!(app.retry_connection().is_err());
+        assert!(rx.try_recv().is_err());
+    }
+}
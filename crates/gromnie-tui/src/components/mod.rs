@@ -85,14 +85,61 @@ pub fn render_status_bar(frame: &mut Frame, area: Rect, app: &crate::app::App) {
     };
 
     // Create spans for each part
-    let spans = vec![
+    let mut spans = Vec::new();
+
+    // Reconnect countdown leads the bar so it's hard to miss
+    if let Some(banner) = &app.client_status.reconnect {
+        spans.push(Span::styled(
+            format!(" {} ", banner.text(std::time::Instant::now())),
+            Style::default().bg(Color::Yellow).fg(Color::Black).bold(),
+        ));
+        spans.push(Span::styled(
+            " ",
+            Style::default().bg(Color::White).fg(Color::Black),
+        ));
+    }
+
+    spans.extend([
         Span::styled(
             format!("Session: {}", session_text),
             Style::default().bg(Color::White).fg(Color::Black),
         ),
         Span::styled(" | ", Style::default().bg(Color::White).fg(Color::Black)),
         Span::styled(format!("Scene: {}", scene_text), scene_style),
-    ];
+    ]);
+
+    if let Some(stats) = &app.client_status.connection_stats {
+        let rtt = stats
+            .rtt_millis
+            .map_or_else(|| "--".to_string(), |ms| format!("{}ms", ms));
+        // Unanswered packets are the first sign of a flaky link
+        let link_style = if stats.unacked_sends > 0 {
+            Style::default().bg(Color::White).fg(Color::Red)
+        } else {
+            Style::default().bg(Color::White).fg(Color::Black)
+        };
+        spans.push(Span::styled(
+            " | ",
+            Style::default().bg(Color::White).fg(Color::Black),
+        ));
+        spans.push(Span::styled(
+            format!(
+                "RTT: {} | Sent/Recv: {}/{} | Unacked: {} | Reconnects: {}",
+                rtt,
+                stats.packets_sent,
+                stats.packets_received,
+                stats.unacked_sends,
+                stats.reconnect_attempts
+            ),
+            link_style,
+        ));
+        if stats.unknown_opcodes > 0 {
+            spans.push(Span::styled(
+                format!(" | Unknown opcodes: {}", stats.unknown_opcodes),
+                Style::default().bg(Color::White).fg(Color::Black),
+            ));
+        }
+    }
 
     let status_line = Line::from(spans);
 
@@ -1,9 +1,10 @@
 use clap::Parser;
 use tracing::{error, info};
 
-use gromnie_client::config::GromnieConfig;
-use gromnie_events::SimpleClientAction;
-use gromnie_runner::{ClientConfig, ClientRunner, TuiConsumer, TuiEvent, logging};
+use gromnie_client::config::{GromnieConfig, PortRouting, ReconnectOverride};
+use gromnie_runner::{
+    BuilderRunResult, ClientConfig, ClientRunner, TuiConsumer, TuiEvent, logging,
+};
 use gromnie_tui::{App, event_handler::EventHandler, ui::try_init_tui};
 
 #[derive(Parser)]
@@ -42,6 +43,20 @@ pub struct Cli {
     reconnect: bool,
 }
 
+/// Where and as whom to connect, from the command line or the config file
+struct Connection {
+    host: String,
+    port: u16,
+    account_name: String,
+    password: String,
+    character_name: Option<String>,
+    port_routing: PortRouting,
+    bind_addr: Option<std::net::SocketAddr>,
+    /// Whether to reconnect, before the --reconnect flag is applied
+    reconnect: bool,
+    reconnect_override: Option<ReconnectOverride>,
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging (opt-in file logging via GROMNIE_LOG_FILE env var)
@@ -83,82 +98,98 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // Determine connection parameters: CLI args take precedence, then fall back to config
-    let (host, port, account_name, password, character_name) =
-        if cli.host.is_some() || cli.port.is_some() || cli.password.is_some() {
-            // Using CLI arguments for connection
-            let host = cli
-                .host
-                .ok_or("--host is required when using direct connection")?;
-            let port = cli
-                .port
-                .ok_or("--port is required when using direct connection")?;
-            let account_name = cli
-                .account
-                .ok_or("--account is required when using direct connection")?;
-            let password = cli
-                .password
-                .ok_or("--password is required when using direct connection")?;
-            let character_name = cli.character;
-
-            info!(
-                "Connecting to server {}:{} with account '{}' (from CLI args)",
-                host, port, account_name
-            );
-            (host, port, account_name, password, character_name)
-        } else {
-            // Using config file
-            let server_name = cli
-                .server
-                .ok_or("Either --server (config name) or --host/--port must be specified")?;
-            let account_name = cli.account.ok_or(
-                "Either --account (config name) or --account/--password must be specified",
-            )?;
-
-            let server = config.servers.get(&server_name).ok_or_else(|| {
-                let available = config
-                    .servers
-                    .keys()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!(
-                    "Server '{}' not found. Available: {}",
-                    server_name, available
-                )
-            })?;
-
-            let account = config.accounts.get(&account_name).ok_or_else(|| {
-                let available = config
-                    .accounts
-                    .keys()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!(
-                    "Account '{}' not found. Available: {}",
-                    account_name, available
-                )
-            })?;
-
-            info!(
-                "Connecting to server '{}' with account '{}' (from config)",
-                server_name, account_name
-            );
-
-            (
-                server.host.clone(),
-                server.port,
-                account.username.clone(),
-                account.password.clone(),
-                None, // Don't auto-login character
+    let connection = if cli.host.is_some() || cli.port.is_some() || cli.password.is_some() {
+        // Using CLI arguments for connection
+        let host = cli
+            .host
+            .ok_or("--host is required when using direct connection")?;
+        let port = cli
+            .port
+            .ok_or("--port is required when using direct connection")?;
+        let account_name = cli
+            .account
+            .ok_or("--account is required when using direct connection")?;
+        let password = cli
+            .password
+            .ok_or("--password is required when using direct connection")?;
+        let character_name = cli.character;
+
+        info!(
+            "Connecting to server {}:{} with account '{}' (from CLI args)",
+            host, port, account_name
+        );
+        Connection {
+            host,
+            port,
+            account_name,
+            password,
+            character_name,
+            port_routing: PortRouting::default(),
+            bind_addr: None,
+            reconnect: config.reconnect,
+            reconnect_override: None,
+        }
+    } else {
+        // Using config file
+        let server_name = cli
+            .server
+            .ok_or("Either --server (config name) or --host/--port must be specified")?;
+        let account_name = cli
+            .account
+            .ok_or("Either --account (config name) or --account/--password must be specified")?;
+
+        let server = config.servers.get(&server_name).ok_or_else(|| {
+            let available = config
+                .servers
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Server '{}' not found. Available: {}",
+                server_name, available
             )
-        };
+        })?;
+
+        let account = config.accounts.get(&account_name).ok_or_else(|| {
+            let available = config
+                .accounts
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Account '{}' not found. Available: {}",
+                account_name, available
+            )
+        })?;
+
+        info!(
+            "Connecting to server '{}' with account '{}' (from config)",
+            server_name, account_name
+        );
+
+        Connection {
+            host: server.host.clone(),
+            port: server.port,
+            account_name: account.username.clone(),
+            password: account.password.clone(),
+            character_name: None, // Don't auto-login character
+            port_routing: server.routing.clone(),
+            bind_addr: server.bind_addr(),
+            reconnect: server.reconnect_enabled(config.reconnect),
+            reconnect_override: server.reconnect.clone(),
+        }
+    };
 
-    let address = format!("{}:{}", host, port);
+    let address = format!("{}:{}", connection.host, connection.port);
 
     // Initialize TUI
     let mut tui = try_init_tui()?;
     let mut app = App::new();
+    if config.scripting.enabled {
+        app.script_dir = Some(config.scripting.script_dir());
+    }
 
     // Set up event handler
     let (event_handler, mut tui_event_rx) = EventHandler::new();
@@ -175,11 +206,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client_config = ClientConfig {
         id: 0,
         address,
-        account_name,
-        password,
+        account_name: connection.account_name,
+        password: connection.password,
         // CLI flag overrides config file
-        reconnect: cli.reconnect || config.reconnect,
-        character_name,
+        reconnect: cli.reconnect || connection.reconnect,
+        initial_reconnect_delay_secs: config.initial_reconnect_delay_secs,
+        resume_in_world: config.resume_in_world,
+        strict_checksums: config.strict_checksums,
+        fragment_timeout_secs: config.fragment_timeout_secs,
+        keepalive_interval_secs: config.keepalive_interval_secs,
+        idle_timeout_secs: config.idle_timeout_secs,
+        max_login_attempts: config.max_login_attempts,
+        character_name: connection.character_name,
+        port_routing: connection.port_routing,
+        bind_addr: connection.bind_addr,
+        reconnect_override: connection.reconnect_override,
     };
 
     // Spawn client task using the runner module
@@ -192,9 +233,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build()
         .expect("Failed to build client runner");
 
-    let mut client_handle = tokio::spawn(async move {
-        runner.run().await;
-    });
+    let mut client_handle = tokio::spawn(async move { runner.run().await });
+    // Set if the client couldn't start, so the TUI exits with an error
+    let mut start_error = None;
 
     // Wait for the action_tx channel from the client task (with timeout)
     match tokio::time::timeout(tokio::time::Duration::from_secs(5), action_tx_rx.recv()).await {
@@ -250,8 +291,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
             // Check if client task exited
-            _ = &mut client_handle => {
+            result = &mut client_handle => {
                 info!("Client task finished");
+                if let Ok(BuilderRunResult::Failed(e)) = result {
+                    start_error = Some(e);
+                }
                 break;
             }
         }
@@ -265,15 +309,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("TUI shutting down - waiting for client task to finish...");
 
-    // Give client task a moment to clean up gracefully
+    // Give client task a moment to clean up gracefully, unless it already
+    // finished and its handle was awaited above
     let timeout = tokio::time::Duration::from_millis(250);
-    match tokio::time::timeout(timeout, client_handle).await {
-        Ok(result) => match result {
-            Ok(_) => info!("Client task shut down gracefully"),
-            Err(e) => error!("Client task panicked: {}", e),
-        },
-        Err(_) => {
-            info!("Client task did not shut down within timeout, proceeding anyway");
+    if !client_handle.is_finished() {
+        match tokio::time::timeout(timeout, client_handle).await {
+            Ok(result) => match result {
+                Ok(_) => info!("Client task shut down gracefully"),
+                Err(e) => error!("Client task panicked: {}", e),
+            },
+            Err(_) => {
+                info!("Client task did not shut down within timeout, proceeding anyway");
+            }
         }
     }
 
@@ -285,6 +332,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Shutdown event handler task
     event_handler.shutdown();
 
+    if let Some(e) = start_error {
+        return Err(e.into());
+    }
     Ok(())
 }
 
@@ -296,10 +346,49 @@ fn handle_tui_event(
 ) -> Result<(), Box<dyn std::error::Error>> {
     use crossterm::event::{KeyCode, KeyModifiers};
     use gromnie_tui::event_handler::TuiEvent;
+    use gromnie_tui::widgets::ScrollAction;
     use tracing::{error, info};
 
     match tui_event {
         TuiEvent::Key(key) => {
+            // Scroll the chat tab or debug message list, even while typing
+            let scroll = match key.code {
+                KeyCode::PageUp => Some(ScrollAction::PageUp),
+                KeyCode::PageDown => Some(ScrollAction::PageDown),
+                KeyCode::Home => Some(ScrollAction::Top),
+                KeyCode::End => Some(ScrollAction::Bottom),
+                _ => None,
+            };
+            if let Some(action) = scroll
+                && app.scroll_messages(action)
+            {
+                return Ok(());
+            }
+
+            // The command palette takes all other keys while it's open
+            if let Some(ref mut palette) = app.command_palette {
+                match key.code {
+                    KeyCode::Esc => app.command_palette = None,
+                    KeyCode::Enter => {
+                        if let Err(e) = app.run_selected_palette_command() {
+                            error!("Failed to run command: {}", e);
+                        }
+                    }
+                    KeyCode::Up => palette.select_previous(),
+                    KeyCode::Down => palette.select_next(),
+                    KeyCode::Backspace => palette.pop(),
+                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        palette.push(c);
+                    }
+                    _ => {}
+                }
+                return Ok(());
+            }
+            if key.code == KeyCode::Char(':') && !app.chat_input_active {
+                app.open_command_palette();
+                return Ok(());
+            }
+
             // Handle Tab/BackTab for GameWorld tab switching (only when not in portal space)
             if matches!(
                 app.game_scene,
@@ -323,17 +412,9 @@ fn handle_tui_event(
             } else if app.chat_input_active {
                 match key.code {
                     KeyCode::Enter => {
-                        // Send chat message if there's text
-                        if !app.chat_input.is_empty() {
-                            if let Some(ref tx) = app.action_tx {
-                                let message = app.chat_input.clone();
-                                if let Err(e) = tx.send(SimpleClientAction::SendChatSay { message })
-                                {
-                                    error!("Failed to send chat message action: {}", e);
-                                }
-                            }
-                            // Clear input
-                            app.chat_input.clear();
+                        // Send chat message (or /command) if there's text
+                        if let Err(e) = app.submit_chat_input() {
+                            error!("Failed to send chat message action: {}", e);
                         }
                         // Deactivate chat input after sending
                         app.chat_input_active = false;
@@ -353,8 +434,46 @@ fn handle_tui_event(
                     _ => {}
                 }
             } else {
+                let on_inventory_tab = matches!(
+                    app.game_scene,
+                    gromnie_tui::app::GameScene::GameWorld { .. }
+                ) && app.game_world_tab
+                    == gromnie_tui::app::GameWorldTab::Inventory;
+
+                // Handle GameView inventory controls
+                if app.current_view == gromnie_tui::app::AppView::Game && on_inventory_tab {
+                    match key.code {
+                        KeyCode::Up => app.inventory_state.select_previous_item(),
+                        KeyCode::Down => app.inventory_state.select_next_item(),
+                        KeyCode::Left => app.inventory_state.select_previous_container(),
+                        KeyCode::Right => app.inventory_state.select_next_container(),
+                        KeyCode::Char('u') => {
+                            if let Err(e) = app.use_selected_item() {
+                                error!("Failed to use item: {}", e);
+                            }
+                        }
+                        KeyCode::Char('d') => {
+                            if let Err(e) = app.drop_selected_item() {
+                                error!("Failed to drop item: {}", e);
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if app.current_view == gromnie_tui::app::AppView::Game
+                    && matches!(
+                        app.game_scene,
+                        gromnie_tui::app::GameScene::GameWorld { .. }
+                    )
+                    && app.game_world_tab == gromnie_tui::app::GameWorldTab::Nearby
+                {
+                    match key.code {
+                        KeyCode::Up => app.select_previous_nearby_object(),
+                        KeyCode::Down => app.select_next_nearby_object(),
+                        _ => {}
+                    }
+                }
                 // Handle GameView character selection controls
-                if app.current_view == gromnie_tui::app::AppView::Game {
+                else if app.current_view == gromnie_tui::app::AppView::Game {
                     match key.code {
                         KeyCode::Up => {
                             app.select_previous_character();
@@ -406,6 +525,34 @@ fn handle_tui_event(
                                 }
                             }
                         }
+                        KeyCode::Char('f')
+                            if matches!(
+                                app.game_scene,
+                                gromnie_tui::app::GameScene::GameWorld { .. }
+                            ) && app.game_world_tab == gromnie_tui::app::GameWorldTab::Chat =>
+                        {
+                            app.toggle_chat_filter();
+                        }
+                        KeyCode::Char('r')
+                            if matches!(app.game_scene, gromnie_tui::app::GameScene::Error(_)) =>
+                        {
+                            match app.retry_connection() {
+                                Ok(_) => {
+                                    info!("Retrying connection");
+                                }
+                                Err(e) => {
+                                    error!("Failed to retry: {}", e);
+                                }
+                            }
+                        }
+                        KeyCode::Char('l') => match app.logout() {
+                            Ok(_) => {
+                                info!("Logging out to character select");
+                            }
+                            Err(e) => {
+                                error!("Failed to log out: {}", e);
+                            }
+                        },
                         _ => {}
                     }
                 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::time::Instant;
 
+use gromnie_events::PlayerPosition;
+
 /// State of an object for display purposes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ObjectState {
@@ -30,6 +32,10 @@ pub struct WorldObject {
     pub stack_size: Option<u32>,
     pub max_stack_size: Option<u32>,
 
+    /// Where the object is, if it's out in the world; kept current from
+    /// movement updates
+    pub position: Option<PlayerPosition>,
+
     // Quality/condition tracking
     pub properties: HashMap<String, i32>,
 
@@ -53,6 +59,7 @@ impl WorldObject {
             value: 0,
             stack_size: None,
             max_stack_size: None,
+            position: None,
             properties: HashMap::new(),
             last_updated: Instant::now(),
             state: ObjectState::Created,
@@ -120,6 +127,15 @@ impl ObjectTracker {
         self.container_contents.remove(&object_id);
     }
 
+    /// Process a movement update for an object out in the world
+    pub fn handle_object_moved(&mut self, object_id: u32, position: PlayerPosition) {
+        if let Some(obj) = self.objects.get_mut(&object_id)
+            && obj.container_id.is_none()
+        {
+            obj.position = Some(position);
+        }
+    }
+
     /// Process ItemMovedObject message - move item between containers
     pub fn handle_item_moved(&mut self, object_id: u32, new_container_id: u32) {
         if let Some(obj) = self.objects.get_mut(&object_id) {
@@ -141,6 +157,16 @@ impl ObjectTracker {
         }
     }
 
+    /// Process ItemUpdateStackSize message - the stack is updated in place
+    pub fn handle_stack_size(&mut self, object_id: u32, stack_size: u32, value: u32) {
+        if let Some(obj) = self.objects.get_mut(&object_id) {
+            obj.stack_size = Some(stack_size);
+            obj.value = value;
+            obj.state = ObjectState::Updated;
+            obj.last_updated = Instant::now();
+        }
+    }
+
     /// Process QualitiesPrivateUpdateInt message
     pub fn handle_quality_update(&mut self, object_id: u32, property_name: String, value: i32) {
         if let Some(obj) = self.objects.get_mut(&object_id) {
@@ -4,6 +4,7 @@ use std::io;
 
 use crate::app::App;
 use crate::views::{render_debug_view, render_game_view};
+use crate::widgets::CommandPaletteWidget;
 
 pub struct Tui {
     terminal: Terminal<CrosstermBackend<io::Stdout>>,
@@ -12,6 +13,9 @@ pub struct Tui {
 impl Tui {
     pub fn new() -> io::Result<Self> {
         enable_raw_mode()?;
+        // The default hook prints to stderr, which would scribble over the
+        // screen even for panics the runner contains, so log them instead
+        std::panic::set_hook(Box::new(|info| tracing::error!("{}", info)));
         let backend = CrosstermBackend::new(io::stdout());
         let options = ratatui::TerminalOptions {
             viewport: ratatui::Viewport::Fullscreen,
@@ -22,9 +26,15 @@ impl Tui {
     }
 
     pub fn draw(&mut self, app: &App) -> io::Result<()> {
-        self.terminal.draw(|frame| match app.current_view {
-            crate::app::AppView::Game => render_game_view(frame, app),
-            crate::app::AppView::Debug => render_debug_view(frame, app),
+        self.terminal.draw(|frame| {
+            match app.current_view {
+                crate::app::AppView::Game => render_game_view(frame, app),
+                crate::app::AppView::Debug => render_debug_view(frame, app),
+            }
+            // The palette overlays whichever view is showing
+            if let Some(ref palette) = app.command_palette {
+                frame.render_widget(CommandPaletteWidget::new(palette), frame.area());
+            }
         })?;
 
         Ok(())
@@ -34,6 +44,8 @@ impl Tui {
 impl Drop for Tui {
     fn drop(&mut self) {
         disable_raw_mode().ok();
+        // Back to the default hook now stderr is usable again
+        let _ = std::panic::take_hook();
     }
 }
 
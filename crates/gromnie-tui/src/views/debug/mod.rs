@@ -57,7 +57,8 @@ fn render_debug_content(frame: &mut Frame, area: Rect, app: &App) {
             Span::raw(description),
         ])
     })
-    .block(block);
+    .block(block)
+    .scroll(&app.network_scroll);
 
     frame.render_widget(chat_widget, area);
 }
@@ -0,0 +1,70 @@
+use crate::app::App;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Render the Allegiance tab - one row per member, officers first, with who is online
+pub fn render_allegiance_tab(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(allegiance) = &app.allegiance else {
+        let paragraph = Paragraph::new("Not in an allegiance")
+            .block(Block::default().title("Allegiance").borders(Borders::ALL))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Gray));
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let rows: Vec<Row> = allegiance
+        .sorted_members()
+        .into_iter()
+        .map(|member| {
+            let online = allegiance.online.contains(&member.object_id.get());
+            let style = if online {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            };
+            let role = match (member.patron_id, member.officer_level) {
+                (None, _) => "Monarch".to_string(),
+                (_, 0) => String::new(),
+                (_, level) => format!("Officer {}", level),
+            };
+            Row::new(vec![
+                Cell::from(member.name.clone()),
+                Cell::from(member.rank.to_string()),
+                Cell::from(member.level.to_string()),
+                Cell::from(role),
+                Cell::from(if online { "online" } else { "" }),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let header = Row::new(vec!["Name", "Rank", "Level", "Role", "Status"])
+        .style(Style::default().fg(Color::Yellow).bold());
+
+    let title = if allegiance.name.is_empty() {
+        format!("Allegiance [{} members]", allegiance.members.len())
+    } else {
+        format!(
+            "Allegiance: {} [{} members]",
+            allegiance.name,
+            allegiance.members.len()
+        )
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Max(6),
+            Constraint::Max(6),
+            Constraint::Max(12),
+            Constraint::Max(8),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title(title).borders(Borders::ALL))
+    .style(Style::default().fg(Color::White));
+
+    frame.render_widget(table, area);
+}
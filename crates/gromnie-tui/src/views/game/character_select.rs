@@ -1,6 +1,7 @@
 use crate::app::App;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
+use std::time::Instant;
 
 pub fn render_character_select_view(frame: &mut Frame, area: Rect, app: &App) {
     render_character_list(frame, area, app);
@@ -9,13 +10,14 @@ pub fn render_character_select_view(frame: &mut Frame, area: Rect, app: &App) {
 /// Render interactive character list with selection
 fn render_character_list(frame: &mut Frame, area: Rect, app: &App) {
     let mut lines = vec![];
+    let now = Instant::now();
 
     for (index, character) in app.client_status.characters.iter().enumerate() {
         let is_selected = index == app.selected_character_index;
-        let delete_indicator = if character.seconds_greyed_out > 0 {
-            " [PENDING DELETION]"
-        } else {
-            ""
+        let deletes_in = app.deletion_seconds_remaining(character, now);
+        let delete_indicator = match deletes_in {
+            Some(seconds) => format!(" [deletes in {}]", format_countdown(seconds)),
+            None => String::new(),
         };
 
         let character_text = format!(
@@ -23,19 +25,29 @@ fn render_character_list(frame: &mut Frame, area: Rect, app: &App) {
             character.name, character.character_id.0, delete_indicator
         );
 
+        // Characters pending deletion are dimmed and can't be logged into
+        let pending_deletion = deletes_in.is_some();
+
         if is_selected {
             // Highlight selected character
+            let style = if pending_deletion {
+                Style::default().bg(Color::DarkGray).fg(Color::Gray)
+            } else {
+                Style::default().bg(Color::DarkGray).fg(Color::White).bold()
+            };
             lines.push(Line::from(vec![
                 Span::styled("▶ ", Style::default().fg(Color::Green).bold()),
-                Span::styled(
-                    character_text,
-                    Style::default().bg(Color::DarkGray).fg(Color::White).bold(),
-                ),
+                Span::styled(character_text, style),
             ]));
         } else {
+            let color = if pending_deletion {
+                Color::DarkGray
+            } else {
+                Color::White
+            };
             lines.push(Line::from(vec![
                 Span::raw("  "),
-                Span::styled(character_text, Style::default().fg(Color::White)),
+                Span::styled(character_text, Style::default().fg(color)),
             ]));
         }
     }
@@ -59,3 +71,72 @@ fn render_character_list(frame: &mut Frame, area: Rect, app: &App) {
 
     frame.render_widget(paragraph, area);
 }
+
+/// Format a deletion countdown as days and hours, or hours and minutes once
+/// under a day
+fn format_countdown(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = seconds % 86_400 / 3_600;
+    let minutes = seconds % 3_600 / 60;
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asheron_rs::types::{CharacterIdentity, ObjectId};
+    use std::time::Duration;
+
+    fn character(name: &str, seconds_greyed_out: u32) -> CharacterIdentity {
+        CharacterIdentity {
+            name: name.to_string(),
+            character_id: ObjectId(1),
+            seconds_greyed_out,
+        }
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(86_400 * 2 + 3_600 * 5 + 59), "2d 5h");
+        assert_eq!(format_countdown(3_600 * 3 + 60 * 7), "3h 7m");
+        assert_eq!(format_countdown(125), "2m");
+        assert_eq!(format_countdown(0), "1m");
+    }
+
+    #[test]
+    fn test_deletion_countdown_and_login() {
+        let mut app = App::new();
+        let received = Instant::now();
+        app.character_list_received_at = Some(received);
+        app.client_status.characters = vec![character("Deleting", 3_600)];
+
+        let deleting = &app.client_status.characters[0];
+        assert_eq!(
+            app.deletion_seconds_remaining(deleting, received),
+            Some(3_600)
+        );
+        assert_eq!(
+            app.deletion_seconds_remaining(deleting, received + Duration::from_secs(600)),
+            Some(3_000)
+        );
+        assert_eq!(
+            app.deletion_seconds_remaining(deleting, received + Duration::from_secs(7_200)),
+            Some(0)
+        );
+        assert_eq!(
+            app.deletion_seconds_remaining(&character("Active", 0), received),
+            None
+        );
+
+        assert_eq!(
+            app.login_selected_character().unwrap_err(),
+            "Deleting is pending deletion"
+        );
+    }
+}
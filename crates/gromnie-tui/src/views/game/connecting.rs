@@ -1,6 +1,7 @@
 use crate::app::{App, GameScene};
 use asheron_rs::types::CharacterIdentity;
 use gromnie_events::SimpleClientAction;
+use std::time::Instant;
 
 // Character selection functions that belong with the character selection view
 impl App {
@@ -30,12 +31,33 @@ impl App {
             .get(self.selected_character_index)
     }
 
+    /// Seconds until a greyed-out character is deleted, counted down from
+    /// when the character list arrived. `None` if it isn't pending deletion.
+    pub fn deletion_seconds_remaining(
+        &self,
+        character: &CharacterIdentity,
+        now: Instant,
+    ) -> Option<u64> {
+        if character.seconds_greyed_out == 0 {
+            return None;
+        }
+        let elapsed = self.character_list_received_at.map_or(0, |received| {
+            now.saturating_duration_since(received).as_secs()
+        });
+        Some((character.seconds_greyed_out as u64).saturating_sub(elapsed))
+    }
+
     /// Login with the selected character
+    ///
+    /// Characters pending deletion can't be logged into.
     pub fn login_selected_character(&mut self) -> Result<(), String> {
         // Get character info first to avoid borrow conflicts
         let (character_id, character_name) = if let Some(character) = self.get_selected_character()
         {
-            (character.character_id.0, character.name.clone())
+            if character.seconds_greyed_out > 0 {
+                return Err(format!("{} is pending deletion", character.name));
+            }
+            (character.character_id.0.into(), character.name.clone())
         } else {
             return Err("No character selected".to_string());
         };
@@ -107,7 +129,7 @@ pub fn render_connecting_view(frame: &mut ratatui::Frame, area: ratatui::layout:
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Plain)
-                .title("Connecting"),
+                .title(connecting_title(app.login_retry)),
         )
         .gauge_style(Style::default().fg(Color::Green))
         .ratio(app.connecting_progress);
@@ -127,3 +149,14 @@ pub fn render_connecting_view(frame: &mut ratatui::Frame, area: ratatui::layout:
 
     frame.render_widget(updating_gauge, bottom_chunks[1]);
 }
+
+/// Title of the "Connecting" bar, with the retry count while the server
+/// hasn't answered the LoginRequest
+fn connecting_title(login_retry: Option<(u32, u32)>) -> String {
+    match login_retry {
+        Some((attempt, max_attempts)) => {
+            format!("Connecting - retrying login ({}/{})", attempt, max_attempts)
+        }
+        None => "Connecting".to_string(),
+    }
+}
@@ -1,10 +1,52 @@
-use crate::app::App;
+use crate::app::{App, ConnectionError, GameScene};
+use gromnie_events::{ConnectionErrorKind, SimpleClientAction};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
-pub fn render_error_view(frame: &mut Frame, area: Rect, _app: &App, error_message: &str) {
+impl App {
+    /// Ask the client to connect again from a retryable error
+    pub fn retry_connection(&mut self) -> Result<(), String> {
+        if !self
+            .connection_error
+            .as_ref()
+            .is_some_and(|error| error.can_retry)
+        {
+            return Err("Nothing to retry".to_string());
+        }
+
+        let tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| "No action channel available".to_string())?;
+        tx.send(SimpleClientAction::RetryConnection)
+            .map_err(|e| format!("Failed to send retry action: {}", e))?;
+
+        self.connection_error = None;
+        self.auth_failure = None;
+        self.connecting_progress = 0.0;
+        self.login_retry = None;
+        self.updating_progress = 0.0;
+        self.game_scene = GameScene::Logging {
+            authenticated: false,
+            ddd_received: false,
+        };
+        Ok(())
+    }
+}
+
+/// Heading for the error view
+fn error_title(error: Option<&ConnectionError>) -> &'static str {
+    match error.map(|error| error.kind) {
+        Some(ConnectionErrorKind::Timeout) => "Connection timed out",
+        Some(ConnectionErrorKind::Rejected) => "Connection rejected",
+        None => "Error",
+    }
+}
+
+pub fn render_error_view(frame: &mut Frame, area: Rect, app: &App, error_message: &str) {
+    let error = app.connection_error.as_ref();
     let block = Block::default()
-        .title("Error")
+        .title(error_title(error))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Red));
 
@@ -22,14 +64,32 @@ pub fn render_error_view(frame: &mut Frame, area: Rect, _app: &App, error_messag
         .margin(1)
         .split(inner);
 
-    // Error message
-    let error_text = Paragraph::new(error_message)
+    // Error message, then the server's reason if it gave a different one
+    let mut lines = vec![Line::styled(
+        error.map_or(error_message, |error| error.message.as_str()),
+        Style::default().fg(Color::Red).bold(),
+    )];
+    if let Some(reason) = &app.auth_failure
+        && reason != error_message
+    {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            format!("Reason: {}", reason),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    let error_text = Paragraph::new(lines)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Red));
+        .wrap(Wrap { trim: true });
     frame.render_widget(error_text, chunks[1]);
 
     // Instructions at bottom
-    let instructions = Paragraph::new("Press 'q' to quit or try logging in again")
+    let instructions = match error {
+        Some(error) if error.can_retry => "Press 'r' to retry or 'q' to quit",
+        Some(_) => "The client has stopped; press 'q' to quit and start it again",
+        None => "Press 'q' to quit or try logging in again",
+    };
+    let instructions = Paragraph::new(instructions)
         .alignment(Alignment::Center)
         .style(Style::default().fg(Color::Yellow).italic());
     frame.render_widget(instructions, chunks[2]);
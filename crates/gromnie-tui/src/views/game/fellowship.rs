@@ -0,0 +1,100 @@
+use crate::app::App;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Width of each vital bar, in cells
+const BAR_WIDTH: usize = 10;
+
+/// Render the Fellowship tab - one row per member with health, stamina and mana bars
+pub fn render_fellowship_tab(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(fellowship) = &app.fellowship else {
+        let paragraph = Paragraph::new("Not in a fellowship")
+            .block(Block::default().title("Fellowship").borders(Borders::ALL))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Gray));
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let rows: Vec<Row> = fellowship
+        .sorted_members()
+        .into_iter()
+        .map(|member| {
+            let name = if member.object_id.get() == fellowship.leader_id {
+                format!("{} *", member.name)
+            } else {
+                member.name.clone()
+            };
+            Row::new(vec![
+                Cell::from(name),
+                Cell::from(member.level.to_string()),
+                Cell::from(vital_bar(member.health, member.max_health, Color::Red)),
+                Cell::from(vital_bar(member.stamina, member.max_stamina, Color::Yellow)),
+                Cell::from(vital_bar(member.mana, member.max_mana, Color::Blue)),
+            ])
+        })
+        .collect();
+
+    let header = Row::new(vec!["Name", "Level", "Health", "Stamina", "Mana"])
+        .style(Style::default().fg(Color::Yellow).bold());
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Max(6),
+            Constraint::Max(22),
+            Constraint::Max(22),
+            Constraint::Max(22),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(format!(
+                "Fellowship: {} [{} members, * = leader]",
+                fellowship.name,
+                fellowship.members.len()
+            ))
+            .borders(Borders::ALL),
+    )
+    .style(Style::default().fg(Color::White));
+
+    frame.render_widget(table, area);
+}
+
+/// A bar like `██████░░░░ 60/100`
+fn vital_bar(current: u32, max: u32, color: Color) -> Line<'static> {
+    let filled = filled_cells(current, max);
+    Line::from(vec![
+        Span::styled("█".repeat(filled), Style::default().fg(color)),
+        Span::styled(
+            "░".repeat(BAR_WIDTH - filled),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::raw(format!(" {}/{}", current, max)),
+    ])
+}
+
+fn filled_cells(current: u32, max: u32) -> usize {
+    if max == 0 {
+        return 0;
+    }
+    let fraction = current.min(max) as f64 / max as f64;
+    (fraction * BAR_WIDTH as f64).round() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filled_cells() {
+        assert_eq!(filled_cells(0, 100), 0);
+        assert_eq!(filled_cells(60, 100), 6);
+        assert_eq!(filled_cells(100, 100), BAR_WIDTH);
+        // Buffed over max, or max not known yet
+        assert_eq!(filled_cells(150, 100), BAR_WIDTH);
+        assert_eq!(filled_cells(10, 0), 0);
+    }
+}
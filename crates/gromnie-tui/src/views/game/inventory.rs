@@ -0,0 +1,221 @@
+use crate::app::{App, InventoryItem};
+use gromnie_events::SimpleClientAction;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+impl App {
+    /// Use the selected inventory item
+    pub fn use_selected_item(&mut self) -> Result<(), String> {
+        self.send_selected_item_action(|object_id| SimpleClientAction::UseItem {
+            object_id: object_id.into(),
+        })
+    }
+
+    /// Drop the selected inventory item
+    pub fn drop_selected_item(&mut self) -> Result<(), String> {
+        self.send_selected_item_action(|object_id| SimpleClientAction::DropItem {
+            object_id: object_id.into(),
+        })
+    }
+
+    fn send_selected_item_action(
+        &mut self,
+        action: impl FnOnce(u32) -> SimpleClientAction,
+    ) -> Result<(), String> {
+        let object_id = self
+            .inventory_state
+            .selected_item()
+            .map(|item| item.object_id)
+            .ok_or_else(|| "No item selected".to_string())?;
+
+        let tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| "No action channel available".to_string())?;
+        tx.send(action(object_id))
+            .map_err(|e| format!("Failed to send item action: {}", e))
+    }
+}
+
+/// Render the Inventory tab - containers and equipped items on the left,
+/// the selected container's contents on the right
+pub fn render_inventory_tab(frame: &mut Frame, area: Rect, app: &App) {
+    let inventory = &app.inventory_state;
+
+    if inventory.player_id.is_none() {
+        let paragraph = Paragraph::new("Not in world")
+            .block(Block::default().title("Inventory").borders(Borders::ALL))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Gray));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(columns[0]);
+
+    render_container_list(frame, left[0], app);
+    render_equipped_list(frame, left[1], app);
+    render_item_table(frame, columns[1], app);
+}
+
+fn render_container_list(frame: &mut Frame, area: Rect, app: &App) {
+    let inventory = &app.inventory_state;
+
+    let items: Vec<ListItem> = inventory
+        .get_container_list()
+        .into_iter()
+        .enumerate()
+        .map(|(idx, container)| {
+            let style = if idx == inventory.selected_container_index {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            ListItem::new(format!("{} ({})", container.name, container.item_count)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .title("Containers [←/→]")
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(list, area);
+}
+
+fn render_equipped_list(frame: &mut Frame, area: Rect, app: &App) {
+    let rows: Vec<Row> = app
+        .inventory_state
+        .get_equipped_items()
+        .into_iter()
+        .map(|item| {
+            let slot = item
+                .equipped_location
+                .map(equip_slot_name)
+                .unwrap_or_else(|| "?".to_string());
+            Row::new(vec![slot, item.name.clone()])
+        })
+        .collect();
+
+    let table = Table::new(rows, [Constraint::Max(14), Constraint::Min(10)])
+        .header(Row::new(vec!["Slot", "Item"]).style(Style::default().fg(Color::Yellow).bold()))
+        .block(Block::default().title("Equipped").borders(Borders::ALL))
+        .style(Style::default().fg(Color::White));
+    frame.render_widget(table, area);
+}
+
+fn render_item_table(frame: &mut Frame, area: Rect, app: &App) {
+    let inventory = &app.inventory_state;
+    let items = inventory.get_selected_container_items();
+    let total = items.len();
+
+    // Only build rows for the page containing the selection; borders and
+    // header take three lines
+    let page_size = (area.height.saturating_sub(3) as usize).max(1);
+    let selected = inventory.selected_item_index.min(total.saturating_sub(1));
+    let page = selected / page_size;
+    let page_count = total.div_ceil(page_size).max(1);
+    let start = page * page_size;
+
+    let rows: Vec<Row> = items
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(page_size)
+        .map(|(idx, item)| item_row(item, idx == selected))
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Max(8),
+            Constraint::Max(10),
+            Constraint::Max(10),
+        ],
+    )
+    .header(
+        Row::new(vec!["Name", "Stack", "Burden", "Value"])
+            .style(Style::default().fg(Color::Yellow).bold()),
+    )
+    .block(
+        Block::default()
+            .title(format!(
+                "Items [{}] page {}/{} - ↑/↓ select, u use, d drop",
+                total,
+                page + 1,
+                page_count
+            ))
+            .borders(Borders::ALL),
+    )
+    .style(Style::default().fg(Color::White));
+
+    frame.render_widget(table, area);
+}
+
+fn item_row(item: &InventoryItem, selected: bool) -> Row<'static> {
+    let stack = item
+        .stack_size
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let style = if selected {
+        Style::default().fg(Color::Black).bg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+
+    Row::new(vec![
+        item.name.clone(),
+        stack,
+        item.burden.to_string(),
+        item.value.to_string(),
+    ])
+    .style(style)
+}
+
+/// Name of the lowest EquipMask slot set in `mask`
+fn equip_slot_name(mask: u32) -> String {
+    const SLOTS: &[(u32, &str)] = &[
+        (0x0000_0001, "Head"),
+        (0x0000_0002, "Chest"),
+        (0x0000_0004, "Abdomen"),
+        (0x0000_0008, "Upper Arms"),
+        (0x0000_0010, "Lower Arms"),
+        (0x0000_0020, "Hands"),
+        (0x0000_0040, "Upper Legs"),
+        (0x0000_0080, "Lower Legs"),
+        (0x0000_0100, "Feet"),
+        (0x0000_0200, "Chest Armor"),
+        (0x0000_0400, "Abdomen Armor"),
+        (0x0000_0800, "Upper Arm Armor"),
+        (0x0000_1000, "Lower Arm Armor"),
+        (0x0000_2000, "Upper Leg Armor"),
+        (0x0000_4000, "Lower Leg Armor"),
+        (0x0000_8000, "Neck"),
+        (0x0001_0000, "Left Wrist"),
+        (0x0002_0000, "Right Wrist"),
+        (0x0004_0000, "Left Finger"),
+        (0x0008_0000, "Right Finger"),
+        (0x0010_0000, "Melee Weapon"),
+        (0x0020_0000, "Shield"),
+        (0x0040_0000, "Missile Weapon"),
+        (0x0080_0000, "Ammo"),
+        (0x0100_0000, "Held"),
+        (0x0200_0000, "Two-Handed"),
+        (0x0400_0000, "Trinket"),
+        (0x0800_0000, "Cloak"),
+    ];
+
+    SLOTS
+        .iter()
+        .find(|(bit, _)| mask & bit != 0)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| format!("0x{:08X}", mask))
+}
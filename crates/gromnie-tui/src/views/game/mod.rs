@@ -1,6 +1,10 @@
+pub mod allegiance;
 pub mod character_select;
 pub mod connecting;
 pub mod error;
+pub mod fellowship;
+pub mod inventory;
+pub mod nearby;
 pub mod world;
 
 // Re-export the game view rendering functions
@@ -0,0 +1,155 @@
+use crate::app::App;
+use crate::object_tracker::{ObjectState, WorldObject};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+impl App {
+    /// Objects out in the world around the player, sorted by name
+    pub fn nearby_objects(&self) -> Vec<&WorldObject> {
+        let tracker = &self.object_tracker;
+        let mut objects: Vec<&WorldObject> = tracker
+            .objects
+            .values()
+            .filter(|obj| {
+                obj.state != ObjectState::Deleted
+                    && obj.container_id.is_none()
+                    && obj.position.is_some()
+                    && Some(obj.object_id) != tracker.player_id
+            })
+            .collect();
+        objects.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then(a.object_id.cmp(&b.object_id))
+        });
+        objects
+    }
+
+    /// The highlighted entry in the Nearby tab
+    pub fn selected_nearby_object(&self) -> Option<&WorldObject> {
+        let objects = self.nearby_objects();
+        let index = self
+            .nearby_selected_index
+            .min(objects.len().checked_sub(1)?);
+        objects.get(index).copied()
+    }
+
+    pub fn select_next_nearby_object(&mut self) {
+        let count = self.nearby_objects().len();
+        if count > 0 {
+            self.nearby_selected_index = (self.nearby_selected_index + 1).min(count - 1);
+        }
+    }
+
+    pub fn select_previous_nearby_object(&mut self) {
+        self.nearby_selected_index = self.nearby_selected_index.saturating_sub(1);
+    }
+
+    /// Meters from the player to `obj`, if both positions are known
+    pub fn distance_to_object(&self, obj: &WorldObject) -> Option<f32> {
+        let player = self
+            .object_tracker
+            .player_id
+            .and_then(|id| self.object_tracker.get_object(id))?;
+        player.position?.distance_to(&obj.position?)
+    }
+}
+
+/// Render the Nearby tab - objects around the player on the left, details of
+/// the selected one on the right
+pub fn render_nearby_tab(frame: &mut Frame, area: Rect, app: &App) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(area);
+
+    let objects = app.nearby_objects();
+    let selected = app.selected_nearby_object().map(|obj| obj.object_id);
+
+    let rows: Vec<Row> = objects
+        .iter()
+        .map(|obj| {
+            let style = if Some(obj.object_id) == selected {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Row::new(vec![
+                format!("0x{:08X}", obj.object_id),
+                obj.name.clone(),
+                obj.object_type.clone(),
+                format_distance(app.distance_to_object(obj)),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let header = Row::new(vec!["ObjectId", "Name", "Type", "Distance"])
+        .style(Style::default().fg(Color::Yellow).bold());
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Max(12),
+            Constraint::Min(20),
+            Constraint::Max(20),
+            Constraint::Max(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(format!("Nearby [{} objects, ↑/↓]", objects.len()))
+            .borders(Borders::ALL),
+    );
+
+    frame.render_widget(table, columns[0]);
+    render_object_details(frame, columns[1], app);
+}
+
+fn render_object_details(frame: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default().title("Details").borders(Borders::ALL);
+    let Some(obj) = app.selected_nearby_object() else {
+        let paragraph = Paragraph::new("Nothing nearby")
+            .block(block)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Gray));
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", label), Style::default().fg(Color::Yellow)),
+            Span::raw(value),
+        ])
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(obj.name.clone(), Style::default().bold())),
+        field("Id", format!("0x{:08X}", obj.object_id)),
+        field("Type", obj.object_type.clone()),
+        field("Distance", format_distance(app.distance_to_object(obj))),
+        field("Burden", obj.burden.to_string()),
+        field("Value", obj.value.to_string()),
+    ];
+    if let Some(stack_size) = obj.stack_size {
+        lines.push(field("Stack", stack_size.to_string()));
+    }
+    if let Some(position) = obj.position {
+        lines.push(field(
+            "Position",
+            format!(
+                "0x{:08X} ({:.1}, {:.1}, {:.1})",
+                position.landcell, position.x, position.y, position.z
+            ),
+        ));
+    }
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, area);
+}
+
+fn format_distance(distance: Option<f32>) -> String {
+    distance.map_or_else(|| "?".to_string(), |meters| format!("{:.1}m", meters))
+}
@@ -1,5 +1,11 @@
+use super::allegiance::render_allegiance_tab;
+use super::fellowship::render_fellowship_tab;
+use super::inventory::render_inventory_tab;
+use super::nearby::render_nearby_tab;
 use crate::ChatWidget;
-use crate::app::{App, GameWorldState, GameWorldTab};
+use crate::app::{App, ChatCategory, ChatMessage, GameScene, GameWorldState, GameWorldTab};
+use gromnie_client::client::chat::{ChatCommand, parse_chat_message};
+use gromnie_events::SimpleClientAction;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
@@ -9,8 +15,12 @@ impl App {
         self.game_world_tab = match self.game_world_tab {
             GameWorldTab::World => GameWorldTab::Chat,
             GameWorldTab::Chat => GameWorldTab::Map,
-            GameWorldTab::Map => GameWorldTab::Objects,
-            GameWorldTab::Objects => GameWorldTab::World,
+            GameWorldTab::Map => GameWorldTab::Inventory,
+            GameWorldTab::Inventory => GameWorldTab::Objects,
+            GameWorldTab::Objects => GameWorldTab::Nearby,
+            GameWorldTab::Nearby => GameWorldTab::Fellowship,
+            GameWorldTab::Fellowship => GameWorldTab::Allegiance,
+            GameWorldTab::Allegiance => GameWorldTab::World,
         };
         // Don't auto-focus chat input when switching tabs
         // Chat input is only active when explicitly activated by Enter
@@ -19,14 +29,122 @@ impl App {
     /// Switch to the previous tab
     pub fn previous_tab(&mut self) {
         self.game_world_tab = match self.game_world_tab {
-            GameWorldTab::World => GameWorldTab::Objects,
+            GameWorldTab::World => GameWorldTab::Allegiance,
             GameWorldTab::Chat => GameWorldTab::World,
             GameWorldTab::Map => GameWorldTab::Chat,
-            GameWorldTab::Objects => GameWorldTab::Map,
+            GameWorldTab::Inventory => GameWorldTab::Map,
+            GameWorldTab::Objects => GameWorldTab::Inventory,
+            GameWorldTab::Nearby => GameWorldTab::Objects,
+            GameWorldTab::Fellowship => GameWorldTab::Nearby,
+            GameWorldTab::Allegiance => GameWorldTab::Fellowship,
         };
         // Don't auto-focus chat input when switching tabs
         // Chat input is only active when explicitly activated by Enter
     }
+
+    /// Send the chat input line and clear it
+    ///
+    /// Parse errors are shown as a system line in the chat panel rather than
+    /// being sent to the server.
+    pub fn submit_chat_input(&mut self) -> Result<(), String> {
+        let input = std::mem::take(&mut self.chat_input);
+        if input.trim().is_empty() {
+            return Ok(());
+        }
+
+        let action = match parse_chat_input(&input) {
+            Ok(action) => action,
+            Err(e) => {
+                self.add_chat_message(ChatMessage {
+                    text: e,
+                    message_type: 0x05,
+                    timestamp: chrono::Utc::now(),
+                });
+                return Ok(());
+            }
+        };
+
+        let tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| "No action channel available".to_string())?;
+        tx.send(action)
+            .map_err(|e| format!("Failed to send chat action: {}", e))
+    }
+
+    /// Log out of the world and return to character select
+    pub fn logout(&mut self) -> Result<(), String> {
+        if !matches!(
+            self.game_scene,
+            GameScene::GameWorld {
+                state: GameWorldState::InWorld,
+                ..
+            }
+        ) {
+            return Err("Not in world".to_string());
+        }
+
+        let tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| "No action channel available".to_string())?;
+        tx.send(SimpleClientAction::Logout)
+            .map_err(|e| format!("Failed to send logout action: {}", e))
+    }
+}
+
+/// Turn a chat input line into the action that sends it
+///
+/// Chat lines are read by the client's own parser: bare text and `/s <msg>`
+/// are said aloud, `/e <text>` is an emote, and `@name <msg>` sends a tell;
+/// quote names containing spaces, as in `@"Some Name" <msg>`. `/tell <name>
+/// <msg>` is another way to write a tell, and `/squelch <name>` and
+/// `/unsquelch <name>` edit the squelch list.
+pub fn parse_chat_input(input: &str) -> Result<SimpleClientAction, String> {
+    let input = input.trim();
+    let mut usage = "Usage: <message>".to_string();
+    let mut line = input.to_string();
+
+    if let Some(command_line) = input.strip_prefix('/') {
+        let (command, rest) = command_line
+            .split_once(char::is_whitespace)
+            .unwrap_or((command_line, ""));
+        let rest = rest.trim();
+        let name = command;
+        let command = command.to_ascii_lowercase();
+
+        match command.as_str() {
+            "say" | "s" => usage = format!("Usage: /{} <message>", command),
+            "e" | "emote" => usage = format!("Usage: /{} <text>", command),
+            "tell" | "t" => {
+                usage = format!("Usage: /{} <name> <message>", command);
+                line = format!("@{}", rest);
+            }
+            "squelch" | "unsquelch" => {
+                let target = rest.trim_matches('"').trim();
+                if target.is_empty() {
+                    return Err(format!("Usage: /{} <name>", command));
+                }
+                let name = target.to_string();
+                return Ok(if command == "squelch" {
+                    SimpleClientAction::Squelch { name }
+                } else {
+                    SimpleClientAction::Unsquelch { name }
+                });
+            }
+            _ => return Err(format!("Unknown command: /{}", name)),
+        }
+    }
+
+    match parse_chat_message(&line).ok_or(usage)? {
+        // The client reads a said line the same way, so send it as typed
+        ChatCommand::Say(_) => Ok(SimpleClientAction::SendChatSay { message: line }),
+        ChatCommand::Tell { recipient, message } => Ok(SimpleClientAction::SendChatTell {
+            recipient_name: recipient,
+            message,
+        }),
+        ChatCommand::Emote(text) => Ok(SimpleClientAction::SendTextEmote { text }),
+    }
 }
 
 pub fn render_game_world_view(
@@ -70,17 +188,38 @@ pub fn render_game_world_view(
                 GameWorldTab::Map => {
                     render_map_tab(frame, chunks[1]);
                 }
+                GameWorldTab::Inventory => {
+                    render_inventory_tab(frame, chunks[1], app);
+                }
                 GameWorldTab::Objects => {
                     render_objects_tab(frame, chunks[1], app);
                 }
+                GameWorldTab::Nearby => {
+                    render_nearby_tab(frame, chunks[1], app);
+                }
+                GameWorldTab::Fellowship => {
+                    render_fellowship_tab(frame, chunks[1], app);
+                }
+                GameWorldTab::Allegiance => {
+                    render_allegiance_tab(frame, chunks[1], app);
+                }
             }
         }
     }
 }
 
-/// Render the scene tabs for GameWorld (World, Chat, Map, Objects)
+/// Render the scene tabs for GameWorld (World, Chat, Map, Inventory, Objects, Nearby, Fellowship, Allegiance)
 fn render_scene_tabs(frame: &mut Frame, area: Rect, app: &App) {
-    let tabs = ["World", "Chat", "Map", "Objects"];
+    let tabs = [
+        "World",
+        "Chat",
+        "Map",
+        "Inventory",
+        "Objects",
+        "Nearby",
+        "Fellowship",
+        "Allegiance",
+    ];
     let mut spans = vec![];
 
     for (idx, tab_name) in tabs.iter().enumerate() {
@@ -88,7 +227,11 @@ fn render_scene_tabs(frame: &mut Frame, area: Rect, app: &App) {
             0 => GameWorldTab::World,
             1 => GameWorldTab::Chat,
             2 => GameWorldTab::Map,
-            3 => GameWorldTab::Objects,
+            3 => GameWorldTab::Inventory,
+            4 => GameWorldTab::Objects,
+            5 => GameWorldTab::Nearby,
+            6 => GameWorldTab::Fellowship,
+            7 => GameWorldTab::Allegiance,
             _ => unreachable!(),
         };
 
@@ -185,22 +328,32 @@ fn render_chat_tab(frame: &mut Frame, area: Rect, app: &App) {
             .split(area)
     };
 
+    let mut title = if app.hide_chat_spam {
+        "Messages (combat/system hidden, f to show)".to_string()
+    } else {
+        "Messages (f to hide combat/system)".to_string()
+    };
+    if !app.squelched.is_empty() {
+        title.push_str(&format!(" - squelched: {}", app.squelched.join(", ")));
+    }
+
     // Render chat messages using the ChatWidget
     let chat_widget = ChatWidget::new(&app.chat_messages, |msg| {
-        let color = match msg.message_type {
-            0x00 => Color::White,   // Broadcast
-            0x03 => Color::Cyan,    // Tell (incoming)
-            0x04 => Color::Green,   // OutgoingTell
-            0x05 => Color::Yellow,  // System
-            0x06 => Color::Red,     // Combat
-            0x07 => Color::Magenta, // Magic
-            _ => Color::White,
+        let color = match msg.category() {
+            ChatCategory::Say => Color::White,
+            ChatCategory::Tell => Color::Cyan,
+            ChatCategory::Emote => Color::Magenta,
+            ChatCategory::System => Color::Yellow,
+            ChatCategory::Combat => Color::Red,
+            ChatCategory::Other => Color::Gray,
         };
 
         let text_span = Span::styled(msg.text.clone(), Style::default().fg(color));
         Line::from(text_span)
     })
-    .block(Block::default().title("Messages").borders(Borders::ALL));
+    .filter(|msg| app.is_chat_message_visible(msg))
+    .block(Block::default().title(title).borders(Borders::ALL))
+    .scroll(&app.chat_scroll);
 
     frame.render_widget(chat_widget, chunks[0]);
 
@@ -233,7 +386,6 @@ fn render_map_tab(frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
-/// Render the Inventory tab
 /// Render the Objects tab - displays all tracked objects from ObjectTracker
 fn render_objects_tab(frame: &mut Frame, area: Rect, app: &App) {
     use crate::object_tracker::ObjectState;
@@ -310,3 +462,62 @@ fn render_objects_tab(frame: &mut Frame, area: Rect, app: &App) {
 
     frame.render_widget(table, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_say(input: &str, expected: &str) {
+        match parse_chat_input(input) {
+            Ok(SimpleClientAction::SendChatSay { message }) => assert_eq!(message, expected),
+            other => panic!("expected say for {:?}, got {:?}", input, other),
+        }
+    }
+
+    fn assert_tell(input: &str, expected_name: &str, expected_message: &str) {
+        match parse_chat_input(input) {
+            Ok(SimpleClientAction::SendChatTell {
+                recipient_name,
+                message,
+            }) => {
+                assert_eq!(recipient_name, expected_name);
+                assert_eq!(message, expected_message);
+            }
+            other => panic!("expected tell for {:?}, got {:?}", input, other),
+        }
+    }
+
+    #[test]
+    fn test_parse_chat_input() {
+        assert_say("hello there", "hello there");
+        assert_say("/say hi", "hi");
+        assert_tell("/tell Bob  hey, you there?", "Bob", "hey, you there?");
+        assert_tell("/tell Bob, hi", "Bob", "hi");
+        assert_tell("/tell \"Mad Hatter\" hi", "Mad Hatter", "hi");
+        assert_tell("@Bob hi", "Bob", "hi");
+        assert_tell("@\"Mad Hatter\" hi", "Mad Hatter", "hi");
+
+        match parse_chat_input("/e waves hello") {
+            Ok(SimpleClientAction::SendTextEmote { text }) => assert_eq!(text, "waves hello"),
+            other => panic!("expected emote, got {:?}", other),
+        }
+        assert_eq!(parse_chat_input("/e").unwrap_err(), "Usage: /e <text>");
+
+        assert!(parse_chat_input("/tell Bob").is_err());
+        assert!(parse_chat_input("/say").is_err());
+
+        match parse_chat_input("/squelch \"Mad Hatter\"") {
+            Ok(SimpleClientAction::Squelch { name }) => assert_eq!(name, "Mad Hatter"),
+            other => panic!("expected squelch, got {:?}", other),
+        }
+        match parse_chat_input("/UNSQUELCH Bob") {
+            Ok(SimpleClientAction::Unsquelch { name }) => assert_eq!(name, "Bob"),
+            other => panic!("expected unsquelch, got {:?}", other),
+        }
+        assert!(parse_chat_input("/squelch").is_err());
+        assert_eq!(
+            parse_chat_input("/dance now").unwrap_err(),
+            "Unknown command: /dance"
+        );
+    }
+}
@@ -5,13 +5,86 @@ use ratatui::{
     text::Line,
     widgets::{Block, Paragraph, Widget},
 };
+use std::cell::Cell;
 use std::collections::VecDeque;
 
+/// Scroll requests for a [`ChatWidget`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAction {
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+}
+
+/// Scrollback position for a [`ChatWidget`]
+///
+/// The offset counts lines up from the newest message. At 0 the widget
+/// follows new messages; once scrolled up, the view stays on the same
+/// messages as new ones arrive.
+#[derive(Debug, Default)]
+pub struct ChatScroll {
+    offset: usize,
+    /// Viewport height at the last render, used for paging and clamping
+    viewport_height: Cell<usize>,
+}
+
+impl ChatScroll {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lines scrolled up from the bottom
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Whether the view is following the newest messages
+    pub fn is_at_bottom(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Apply a scroll request to a buffer of `total` lines
+    pub fn apply(&mut self, action: ScrollAction, total: usize) {
+        let page = self.page_size();
+        self.offset = match action {
+            ScrollAction::PageUp => self.offset.saturating_add(page),
+            ScrollAction::PageDown => self.offset.saturating_sub(page),
+            ScrollAction::Top => usize::MAX,
+            ScrollAction::Bottom => 0,
+        };
+        self.clamp(total);
+    }
+
+    /// Keep the view in place after a message is appended to a buffer that
+    /// now holds `total` lines (whether or not the oldest one was dropped)
+    pub fn message_added(&mut self, total: usize) {
+        if self.offset > 0 {
+            self.offset += 1;
+            self.clamp(total);
+        }
+    }
+
+    fn page_size(&self) -> usize {
+        self.viewport_height.get().max(1)
+    }
+
+    fn max_offset(&self, total: usize) -> usize {
+        total.saturating_sub(self.page_size())
+    }
+
+    fn clamp(&mut self, total: usize) {
+        self.offset = self.offset.min(self.max_offset(total));
+    }
+}
+
 /// A chat widget that displays messages with proper scrolling to show the latest messages
 pub struct ChatWidget<'a, T> {
     messages: &'a VecDeque<T>,
     block: Option<Block<'a>>,
     renderer: Box<dyn Fn(&T) -> Line<'static> + 'a>,
+    filter: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    scroll: Option<&'a ChatScroll>,
 }
 
 impl<'a, T> ChatWidget<'a, T> {
@@ -23,6 +96,8 @@ impl<'a, T> ChatWidget<'a, T> {
             messages,
             block: None,
             renderer: Box::new(renderer),
+            filter: None,
+            scroll: None,
         }
     }
 
@@ -30,6 +105,22 @@ impl<'a, T> ChatWidget<'a, T> {
         self.block = Some(block);
         self
     }
+
+    /// Only render messages matching `filter`; scrolling counts shown
+    /// messages only
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Render from a scrollback position instead of always showing the tail
+    pub fn scroll(mut self, scroll: &'a ChatScroll) -> Self {
+        self.scroll = Some(scroll);
+        self
+    }
 }
 
 impl<'a, T> Widget for ChatWidget<'a, T> {
@@ -47,7 +138,15 @@ impl<'a, T> Widget for ChatWidget<'a, T> {
         };
 
         let lines = self.messages_to_lines();
-        let visible_lines = Self::visible_lines(lines, inner_area.height);
+        let offset = match self.scroll {
+            Some(scroll) => {
+                scroll.viewport_height.set(inner_area.height as usize);
+                scroll.offset.min(scroll.max_offset(lines.len()))
+            }
+            None => 0,
+        };
+
+        let visible_lines = Self::visible_lines(lines, inner_area.height, offset);
 
         let paragraph = Paragraph::new(visible_lines).style(Style::default().fg(Color::White));
         paragraph.render(inner_area, buf);
@@ -59,19 +158,75 @@ impl<'a, T> ChatWidget<'a, T> {
     fn messages_to_lines(&self) -> Vec<Line<'static>> {
         self.messages
             .iter()
+            .filter(|msg| self.filter.as_ref().is_none_or(|f| f(msg)))
             .map(|msg| (self.renderer)(msg))
             .collect()
     }
 
-    /// Get only the lines that fit in the viewport, showing the bottom lines
-    fn visible_lines(lines: Vec<Line<'static>>, height: u16) -> Vec<Line<'static>> {
+    /// Get only the lines that fit in the viewport, ending `offset` lines
+    /// above the bottom
+    fn visible_lines(lines: Vec<Line<'static>>, height: u16, offset: usize) -> Vec<Line<'static>> {
         let height = height as usize;
 
         if lines.len() > height {
-            let skip_count = lines.len() - height;
-            lines.into_iter().skip(skip_count).collect()
+            let end = lines.len() - offset.min(lines.len() - height);
+            lines.into_iter().take(end).skip(end - height).collect()
         } else {
             lines
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(messages: &VecDeque<String>, scroll: &ChatScroll, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, 10, height);
+        let mut buf = Buffer::empty(area);
+        ChatWidget::new(messages, |m| Line::from(m.clone()))
+            .scroll(scroll)
+            .render(area, &mut buf);
+        (0..height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_scrollback_clamps_and_sticks() {
+        let mut messages: VecDeque<String> = (0..10).map(|i| format!("m{}", i)).collect();
+        let mut scroll = ChatScroll::new();
+
+        assert_eq!(render(&messages, &scroll, 3), ["m7", "m8", "m9"]);
+
+        scroll.apply(ScrollAction::PageUp, messages.len());
+        assert_eq!(render(&messages, &scroll, 3), ["m4", "m5", "m6"]);
+
+        // Can't scroll past the oldest message
+        scroll.apply(ScrollAction::PageUp, messages.len());
+        scroll.apply(ScrollAction::PageUp, messages.len());
+        assert_eq!(scroll.offset(), 7);
+        assert_eq!(render(&messages, &scroll, 3), ["m0", "m1", "m2"]);
+
+        // A new message doesn't move the view while scrolled up
+        messages.push_back("m10".to_string());
+        scroll.message_added(messages.len());
+        assert_eq!(render(&messages, &scroll, 3), ["m0", "m1", "m2"]);
+
+        scroll.apply(ScrollAction::Bottom, messages.len());
+        assert!(scroll.is_at_bottom());
+        messages.push_back("m11".to_string());
+        scroll.message_added(messages.len());
+        assert_eq!(render(&messages, &scroll, 3), ["m9", "m10", "m11"]);
+
+        // Can't scroll past the newest message either
+        scroll.apply(ScrollAction::PageDown, messages.len());
+        assert_eq!(scroll.offset(), 0);
+    }
+}
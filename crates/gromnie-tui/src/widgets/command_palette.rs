@@ -0,0 +1,282 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// Something the command palette can run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    LoginCharacter,
+    Logout,
+    ReloadScripts,
+    SendTell,
+    Quit,
+}
+
+/// A palette entry: the command and how it's shown
+#[derive(Debug, Clone, Copy)]
+pub struct CommandSpec {
+    pub command: PaletteCommand,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// Every command in the palette, in the order shown when nothing is typed
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        command: PaletteCommand::LoginCharacter,
+        name: "Login character",
+        description: "Enter the world as the selected character",
+    },
+    CommandSpec {
+        command: PaletteCommand::Logout,
+        name: "Logout",
+        description: "Return to character select",
+    },
+    CommandSpec {
+        command: PaletteCommand::ReloadScripts,
+        name: "Reload scripts",
+        description: "Reload scripts from the script directory",
+    },
+    CommandSpec {
+        command: PaletteCommand::SendTell,
+        name: "Send tell",
+        description: "Start a /tell in the chat input",
+    },
+    CommandSpec {
+        command: PaletteCommand::Quit,
+        name: "Quit",
+        description: "Disconnect and exit",
+    },
+];
+
+/// Query and selection of the open command palette
+#[derive(Debug, Default)]
+pub struct CommandPalette {
+    query: String,
+    /// Index into the current matches
+    selected: usize,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+
+    /// Index of the highlighted entry in [`CommandPalette::matches`]
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Commands matching the query, best match first
+    pub fn matches(&self) -> Vec<&'static CommandSpec> {
+        let mut scored: Vec<_> = COMMANDS
+            .iter()
+            .filter_map(|spec| fuzzy_score(&self.query, spec.name).map(|score| (score, spec)))
+            .collect();
+        // Stable, so ties keep the list order
+        scored.sort_by_key(|(score, _)| *score);
+        scored.into_iter().map(|(_, spec)| spec).collect()
+    }
+
+    /// The command Enter would run
+    pub fn selected_command(&self) -> Option<PaletteCommand> {
+        self.matches().get(self.selected).map(|spec| spec.command)
+    }
+}
+
+/// Score how well `query` fuzzy-matches `text`, lower being better
+///
+/// Every query character has to appear in `text` in order, ignoring case.
+/// The score counts the characters skipped to find them, so prefixes and
+/// contiguous runs rank first. `None` if `text` doesn't match.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    let mut score = 0;
+    for q in query.chars().flat_map(char::to_lowercase) {
+        if q.is_whitespace() {
+            continue;
+        }
+        loop {
+            let t = text.next()?;
+            if t == q {
+                break;
+            }
+            score += 1;
+        }
+    }
+    Some(score)
+}
+
+/// Centered popup listing the palette's matching commands
+pub struct CommandPaletteWidget<'a> {
+    palette: &'a CommandPalette,
+}
+
+impl<'a> CommandPaletteWidget<'a> {
+    pub fn new(palette: &'a CommandPalette) -> Self {
+        Self { palette }
+    }
+}
+
+impl Widget for CommandPaletteWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let matches = self.palette.matches();
+        // Query line, a line per command (at least one for "no matches"), borders
+        let height = (matches.len().max(1) as u16 + 3).min(area.height);
+        let width = 60.min(area.width);
+        let popup = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled(": ", Style::default().fg(Color::Yellow)),
+            Span::raw(self.palette.query()),
+            Span::styled("_", Style::default().fg(Color::Gray)),
+        ])];
+        if matches.is_empty() {
+            lines.push(Line::styled(
+                "No matching commands",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        for (i, spec) in matches.iter().enumerate() {
+            let style = if i == self.palette.selected() {
+                Style::default()
+                    .bg(Color::Cyan)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<16}", spec.name), style),
+                Span::styled(
+                    format!(" {}", spec.description),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+
+        Clear.render(popup, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Commands (Enter to run, Esc to close)"),
+            )
+            .render(popup, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(palette: &CommandPalette) -> Vec<&'static str> {
+        palette.matches().iter().map(|spec| spec.name).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "Logout"), Some(0));
+        assert_eq!(fuzzy_score("log", "Logout"), Some(0));
+        assert_eq!(fuzzy_score("LGT", "Logout"), Some(3));
+        assert_eq!(fuzzy_score("tuol", "Logout"), None);
+    }
+
+    #[test]
+    fn test_filter_ranks_closest_match_first() {
+        let mut palette = CommandPalette::new();
+        assert_eq!(names(&palette).len(), COMMANDS.len());
+
+        for c in "lo".chars() {
+            palette.push(c);
+        }
+        // "Login character" and "Logout" match at the start; "Reload scripts" skips two
+        assert_eq!(
+            names(&palette),
+            ["Login character", "Logout", "Reload scripts"]
+        );
+
+        palette.push('u');
+        assert_eq!(names(&palette), ["Logout"]);
+        assert_eq!(palette.selected_command(), Some(PaletteCommand::Logout));
+
+        palette.push('z');
+        assert!(palette.matches().is_empty());
+        assert_eq!(palette.selected_command(), None);
+    }
+
+    #[test]
+    fn test_selection_wraps_and_resets_on_edit() {
+        let mut palette = CommandPalette::new();
+        palette.select_previous();
+        assert_eq!(palette.selected_command(), Some(PaletteCommand::Quit));
+        palette.select_next();
+        assert_eq!(
+            palette.selected_command(),
+            Some(PaletteCommand::LoginCharacter)
+        );
+
+        palette.select_next();
+        palette.push('q');
+        assert_eq!(palette.selected(), 0);
+        assert_eq!(palette.selected_command(), Some(PaletteCommand::Quit));
+    }
+
+    #[test]
+    fn test_renders_centered_with_selection() {
+        let area = Rect::new(0, 0, 80, 20);
+        let mut buf = Buffer::empty(area);
+        let mut palette = CommandPalette::new();
+        palette.push('q');
+        CommandPaletteWidget::new(&palette).render(area, &mut buf);
+
+        // 60 wide, 4 tall (query, one match, borders)
+        let row = |y: u16| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert!(row(8).contains("Commands"));
+        assert!(row(9).contains(": q_"));
+        assert!(row(10).contains("Quit"));
+        assert_eq!(buf[(11, 10)].bg, Color::Cyan);
+        assert!(row(7).trim().is_empty());
+    }
+}
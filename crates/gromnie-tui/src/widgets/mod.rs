@@ -1,3 +1,5 @@
 pub mod chat;
+pub mod command_palette;
 
-pub use chat::ChatWidget;
+pub use chat::{ChatScroll, ChatWidget, ScrollAction};
+pub use command_palette::{CommandPalette, CommandPaletteWidget, PaletteCommand};
@@ -43,10 +43,8 @@ fn spawn_recv_loop(
     net_log: NetLogCallback,
     error_tx: tokio::sync::mpsc::Sender<ClientEvent>,
 ) {
-    const KEEPALIVE_INTERVAL_MS: u64 = 5000;
     spawn_local(async move {
         let mut buf = vec![0u8; 65536];
-        let mut last_keepalive_ms = js_sys::Date::now() as u64;
 
         loop {
             match client.recv_packet(&mut buf).await {
@@ -56,12 +54,10 @@ fn spawn_recv_loop(
                         cb.call1(&JsValue::NULL, &msg.into()).ok();
                     }
 
-                    let now_ms = js_sys::Date::now() as u64;
-                    if now_ms - last_keepalive_ms >= KEEPALIVE_INTERVAL_MS {
+                    if client.keepalive_due(gromnie_client::instant::Instant::now()) {
                         if let Err(e) = client.send_keepalive().await {
                             web_sys::console::error_1(&format!("keepalive error: {e}").into());
                         }
-                        last_keepalive_ms = now_ms;
                     }
 
                     client.process_packet(&buf[..len], len, &addr).await;
@@ -208,7 +204,7 @@ impl GromnieClient {
             .ok_or_else(|| js_error("not connected"))?;
 
         tx.send(SimpleClientAction::LoginCharacter {
-            character_id,
+            character_id: character_id.into(),
             character_name: String::new(),
             account: self.account.clone(),
         })
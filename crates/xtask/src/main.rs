@@ -2,9 +2,11 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+const CONFIG_DIR_ENV: &str = "GROMNIE_CONFIG_DIR";
+
 #[derive(Parser)]
 struct Cli {
     #[command(subcommand)]
@@ -29,7 +31,7 @@ enum Commands {
 enum ScriptCommands {
     /// Build all scripts
     Build,
-    /// Install built scripts to ~/.config/gromnie/scripts
+    /// Install built scripts to ~/.config/gromnie/scripts (or $GROMNIE_CONFIG_DIR/scripts)
     Install,
 }
 
@@ -381,13 +383,10 @@ fn install_scripts() -> Result<()> {
     let project_root = project_root()?;
 
     let src_dir = project_root.join("scripts/target/wasm32-wasip2/release");
-    let dest_dir = dirs::config_dir()
-        .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?
-        .join("gromnie")
-        .join("scripts");
+    let dest_dir = config_dir()?.join("scripts");
 
-    // Create destination directory
-    fs::create_dir_all(&dest_dir)?;
+    // Create destination directory, and make sure we can actually write to it
+    ensure_writable(&dest_dir)?;
 
     // Discover all built script files
     let scripts: Vec<_> = fs::read_dir(&src_dir)?
@@ -437,3 +436,31 @@ fn install_scripts() -> Result<()> {
 
     Ok(())
 }
+
+/// gromnie's config directory, honoring the GROMNIE_CONFIG_DIR override
+fn config_dir() -> Result<PathBuf> {
+    if let Some(dir) = env::var_os(CONFIG_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+
+    Ok(dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?
+        .join("gromnie"))
+}
+
+/// Create `dir` and check a file can be written there, so a read-only
+/// config dir fails with a clear message instead of a bare IO error
+fn ensure_writable(dir: &Path) -> Result<()> {
+    let probe = dir.join(".write-test");
+    fs::create_dir_all(dir)
+        .and_then(|()| fs::write(&probe, b""))
+        .and_then(|()| fs::remove_file(&probe))
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "config dir {} is not writable ({}); set {} or fix permissions",
+                dir.display(),
+                e,
+                CONFIG_DIR_ENV
+            )
+        })
+}
@@ -5,6 +5,9 @@ use gromnie::host_interface::ProtocolEvent;
 use gromnie::ScriptEvent;
 use gromnie_scripting_api as gromnie;
 
+/// Object whose health the script watches, so tests can drive quality changes
+const WATCHED_OBJECT: u32 = 0x5000_0001;
+
 /// Helper function to handle protocol events with detailed pattern matching
 /// This demonstrates how scripts can access strongly-typed protocol events
 fn handle_protocol_event(protocol_event: ProtocolEvent) {
@@ -94,6 +97,38 @@ fn handle_protocol_event(protocol_event: ProtocolEvent) {
                     msg.object_teleport_sequence
                 ));
             }
+            S2cEvent::ItemDeleteObject(msg) => {
+                gromnie::log(&format!(
+                    "[Protocol] ItemDeleteObject - obj=0x{:08X}",
+                    msg.object_id
+                ));
+            }
+            S2cEvent::ItemOnViewContents(msg) => {
+                gromnie::log(&format!(
+                    "[Protocol] ItemOnViewContents - container=0x{:08X} ({} items)",
+                    msg.container_id,
+                    msg.items.len()
+                ));
+            }
+            S2cEvent::PlayerContainersReceived(msg) => {
+                gromnie::log(&format!(
+                    "[Protocol] PlayerContainersReceived - player=0x{:08X} ({} containers)",
+                    msg.player_id,
+                    msg.containers.len()
+                ));
+            }
+            S2cEvent::ItemSetState(msg) => {
+                gromnie::log(&format!(
+                    "[Protocol] ItemSetState - obj=0x{:08X} state={}",
+                    msg.object_id, msg.state
+                ));
+            }
+            S2cEvent::QualitiesPrivateUpdateInt(msg) => {
+                gromnie::log(&format!(
+                    "[Protocol] QualitiesPrivateUpdateInt - {} ({}) = {}",
+                    msg.property, msg.key, msg.value
+                ));
+            }
         },
         // Nested game events with metadata
         ProtocolEvent::GameEvent(game_event) => {
@@ -118,11 +153,14 @@ fn handle_protocol_event(protocol_event: ProtocolEvent) {
 }
 
 #[derive(Default)]
-pub struct TestScript;
+pub struct TestScript {
+    /// Timer that sends the greeting once it fires
+    greeting_timer: Option<u64>,
+}
 
 impl gromnie::Script for TestScript {
     fn new() -> Self {
-        Self
+        Self::default()
     }
 
     fn id(&self) -> &str {
@@ -140,7 +178,8 @@ impl gromnie::Script for TestScript {
     fn on_load<'a>(&'a mut self) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + 'a>> {
         Box::pin(async move {
             gromnie::log("Test script loaded successfully");
-            gromnie::send_chat("Hello from test script!");
+            self.greeting_timer = Some(gromnie::schedule_timer(1, "greeting"));
+            gromnie::watch_quality(Some(WATCHED_OBJECT), Some("Health"));
         })
     }
 
@@ -154,7 +193,7 @@ impl gromnie::Script for TestScript {
 
     fn subscribed_events(&self) -> Vec<u32> {
         // Subscribe to all event types for testing
-        vec![0xFFFFFFFF] // All events
+        vec![gromnie::events::EVENT_ALL]
     }
 
     fn on_event<'a>(
@@ -194,6 +233,142 @@ impl gromnie::Script for TestScript {
                         let msg = format!("Chat message: {}", chat_data.message);
                         gromnie::log(&msg);
                     }
+                    QualityChanged(change) => {
+                        let msg = format!(
+                            "Quality changed: {} on {} ({:?} -> {})",
+                            change.property, change.object_id, change.old, change.new
+                        );
+                        gromnie::log(&msg);
+                    }
+                    ActionError(error) => {
+                        let msg = format!(
+                            "Action failed: code=0x{:X}, msg={}",
+                            error.error_code, error.message
+                        );
+                        gromnie::log(&msg);
+                    }
+                    TargetHealth(health) => {
+                        let msg = format!(
+                            "Health of 0x{:08X}: {:.0}%",
+                            health.object_id, health.health_percent
+                        );
+                        gromnie::log(&msg);
+                    }
+                    PlayerDeath(death) => {
+                        let msg = format!(
+                            "Death of 0x{:08X} (killer 0x{:08X}): {}",
+                            death.victim_id, death.killer_id, death.message
+                        );
+                        gromnie::log(&msg);
+                    }
+                    AppraiseResult(appraisal) => {
+                        let msg = format!(
+                            "Appraised 0x{:08X}: success={}, {} properties",
+                            appraisal.object_id,
+                            appraisal.success,
+                            appraisal.int_properties.len()
+                        );
+                        gromnie::log(&msg);
+                    }
+                    VendorInfo(vendor) => {
+                        let msg = format!(
+                            "Vendor 0x{:08X} sells {} items",
+                            vendor.vendor_id,
+                            vendor.items.len()
+                        );
+                        gromnie::log(&msg);
+                    }
+                    ContainerContents(contents) => {
+                        let msg = format!(
+                            "Container 0x{:08X} holds {} items",
+                            contents.container_id,
+                            contents.items.len()
+                        );
+                        gromnie::log(&msg);
+                    }
+                    ConfirmationRequest(request) => {
+                        let msg = format!(
+                            "Confirmation request (type {}, context {}): {}",
+                            request.confirmation_type, request.context_id, request.message
+                        );
+                        gromnie::log(&msg);
+                    }
+                    StackSizeChanged(stack) => {
+                        let msg = format!(
+                            "{} stack: {:?} -> {}",
+                            stack.name, stack.previous_stack_size, stack.stack_size
+                        );
+                        gromnie::log(&msg);
+                    }
+                    DamageDealt(hit) => {
+                        let msg = format!(
+                            "Hit {} for {}{}",
+                            hit.opponent_name,
+                            hit.damage_amount,
+                            if hit.critical { " (critical)" } else { "" }
+                        );
+                        gromnie::log(&msg);
+                    }
+                    DamageTaken(hit) => {
+                        let msg = format!(
+                            "{} hit us for {}{}",
+                            hit.opponent_name,
+                            hit.damage_amount,
+                            if hit.critical { " (critical)" } else { "" }
+                        );
+                        gromnie::log(&msg);
+                    }
+                    AttackEvaded(defender_name) => {
+                        gromnie::log(&format!("{} evaded our attack", defender_name));
+                    }
+                    AttackAvoided(attacker_name) => {
+                        gromnie::log(&format!("Evaded {}", attacker_name));
+                    }
+                    AllegianceUpdated(allegiance) => {
+                        let msg = format!(
+                            "Allegiance {}: rank {}, {} members",
+                            allegiance.name,
+                            allegiance.rank,
+                            allegiance.members.len()
+                        );
+                        gromnie::log(&msg);
+                    }
+                    AllegianceInfo(info) => {
+                        let msg = format!(
+                            "Allegiance of 0x{:08X}: {} ({} members)",
+                            info.target_id,
+                            info.name,
+                            info.members.len()
+                        );
+                        gromnie::log(&msg);
+                    }
+                    AllegianceMemberLogin(login) => {
+                        let msg = format!(
+                            "Allegiance member {} logged {}",
+                            login.name,
+                            if login.logged_in { "in" } else { "out" }
+                        );
+                        gromnie::log(&msg);
+                    }
+                    ChannelMessage(msg) => {
+                        let msg = format!(
+                            "Channel 0x{:X}: {}: {}",
+                            msg.channel, msg.sender_name, msg.message
+                        );
+                        gromnie::log(&msg);
+                    }
+                    TradeOpened(partner) => {
+                        let msg = format!("Trade opened by 0x{:08X}", partner.partner_id);
+                        gromnie::log(&msg);
+                    }
+                    TradeItemAdded(item) => {
+                        let msg = format!("Added to trade: {}", item.name);
+                        gromnie::log(&msg);
+                    }
+                    CombatKill(kill) => {
+                        let msg = format!("Kill (own death: {}): {}", kill.own_death, kill.message);
+                        gromnie::log(&msg);
+                    }
                     Protocol(protocol_event) => {
                         // Demonstrate full protocol event handling
                         handle_protocol_event(protocol_event);
@@ -203,6 +378,13 @@ impl gromnie::Script for TestScript {
                     let msg = format!("State event: {:?}", state_event);
                     gromnie::log(&msg);
                 }
+                WhenFired(fired) => {
+                    let msg = format!(
+                        "When fired: token={} event_id={}",
+                        fired.token, fired.event_id
+                    );
+                    gromnie::log(&msg);
+                }
                 System(system_event) => match system_event {
                     AuthenticationSucceeded => {
                         gromnie::log("System: AuthenticationSucceeded");
@@ -232,6 +414,12 @@ impl gromnie::Script for TestScript {
             let msg = format!("Tick: {}ms", delta_millis);
             gromnie::log(&msg);
 
+            // Test timers
+            if self.greeting_timer.is_some_and(gromnie::check_timer) {
+                self.greeting_timer = None;
+                gromnie::send_chat("Hello from test script!");
+            }
+
             // Test client state access
             let state = gromnie::get_client_state();
             let msg = format!(